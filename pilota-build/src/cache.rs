@@ -0,0 +1,95 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use fxhash::FxHashMap;
+
+use crate::{ir, resolve::ResolveResult};
+
+/// The resolved files of a build, with the content hashes of every file it
+/// was parsed from, including the transitively included ones.
+pub struct CachedResolve {
+    deps: Vec<(PathBuf, u64)>,
+    pub(crate) result: ResolveResult,
+}
+
+impl CachedResolve {
+    /// Returns `None` if any of the files can't be read, as its changes
+    /// could not be noticed.
+    pub(crate) fn new(
+        files: &[Arc<ir::File>],
+        include_dirs: &[PathBuf],
+        result: ResolveResult,
+    ) -> Option<Self> {
+        let deps = files
+            .iter()
+            .map(|f| {
+                // the protobuf files are named relative to the include dirs
+                let path = if f.path.is_absolute() || f.path.exists() {
+                    f.path.to_path_buf()
+                } else {
+                    include_dirs
+                        .iter()
+                        .map(|dir| dir.join(&*f.path))
+                        .find(|path| path.exists())?
+                };
+                content_hash(&path).map(|hash| (path, hash))
+            })
+            .collect::<Option<_>>()?;
+        Some(CachedResolve { deps, result })
+    }
+
+    /// The files the result was parsed from.
+    pub fn deps(&self) -> impl Iterator<Item = &PathBuf> {
+        self.deps.iter().map(|(path, _)| path)
+    }
+
+    /// Whether none of the files changed since the result was cached.
+    pub fn is_fresh(&self) -> bool {
+        self.deps
+            .iter()
+            .all(|(path, hash)| content_hash(path) == Some(*hash))
+    }
+}
+
+fn content_hash(path: &Path) -> Option<u64> {
+    let bytes = std::fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Stores the parse and resolve results between builds, the key is computed
+/// from the input files and the options which change how they are resolved.
+///
+/// A cached result is only reused when none of the files it was parsed from
+/// changed.
+pub trait ResolveCache {
+    fn get(&self, key: u64) -> Option<Arc<CachedResolve>>;
+
+    fn insert(&self, key: u64, resolve: Arc<CachedResolve>);
+}
+
+/// A [`ResolveCache`] keeping the results in memory, which can be shared by
+/// the builds of a watch loop.
+#[derive(Default)]
+pub struct MemoryResolveCache(pub(crate) Mutex<FxHashMap<u64, Arc<CachedResolve>>>);
+
+impl ResolveCache for MemoryResolveCache {
+    fn get(&self, key: u64) -> Option<Arc<CachedResolve>> {
+        self.0.lock().unwrap().get(&key).cloned()
+    }
+
+    fn insert(&self, key: u64, resolve: Arc<CachedResolve>) {
+        self.0.lock().unwrap().insert(key, resolve);
+    }
+}
+
+pub(crate) fn resolve_key(options: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    options.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,97 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{Codegen, CodegenBackend};
+use crate::middle::rir::{self, FieldKind};
+
+impl<B> Codegen<B>
+where
+    B: CodegenBackend,
+{
+    /// `FooBuilder` keeps every field of `Foo` in an `Option` until `build`, which fills the
+    /// unset fields with their defaults and fails on the required ones without a default.
+    pub(super) fn write_builder(&mut self, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let builder_name = format_ident!("{}Builder", name);
+        let message_name = &**s.name;
+        let vis = self.visibility().of_items();
+
+        let mut defs = Vec::with_capacity(s.fields.len());
+        let mut setters = Vec::with_capacity(s.fields.len());
+        let mut lets = Vec::with_capacity(s.fields.len());
+        let mut inits = Vec::with_capacity(s.fields.len());
+        for f in &s.fields {
+            let field = format_ident!("{}", f.name.to_field_case(self.naming()));
+            let ty = self.codegen_field_ty(&f.ty);
+            let mut ty = quote! { #ty };
+            if self.adjust(f.did).is_some_and(|a| a.boxed()) {
+                ty = quote! { ::std::boxed::Box<#ty> }
+            }
+
+            defs.push(quote! { #field: ::std::option::Option<#ty>, });
+            setters.push(quote! {
+                pub fn #field(mut self, #field: #ty) -> Self {
+                    self.#field = Some(#field);
+                    self
+                }
+            });
+
+            let field_name = &*f.original_name;
+            let v = match (&f.kind, &f.default) {
+                (FieldKind::Required, None) => quote! {
+                    self.#field.ok_or(::pilota::MissingField {
+                        message: #message_name,
+                        field: #field_name,
+                    })?
+                },
+                (FieldKind::Optional, None) => quote! { self.#field },
+                (FieldKind::Optional, Some(_)) => {
+                    let default = self.field_default(f);
+                    quote! {
+                        match self.#field {
+                            None => #default,
+                            #field => #field,
+                        }
+                    }
+                }
+                _ => {
+                    let default = self.field_default(f);
+                    quote! {
+                        match self.#field {
+                            Some(#field) => #field,
+                            None => #default,
+                        }
+                    }
+                }
+            };
+            lets.push(quote! { let #field = #v; });
+            inits.push(field);
+        }
+        let extra_fields = self.extra_field_inits();
+
+        stream.extend(quote! {
+            #[derive(Clone, Default)]
+            #vis struct #builder_name {
+                #(#defs)*
+            }
+
+            impl #builder_name {
+                #(#setters)*
+
+                pub fn build(self) -> ::std::result::Result<#name, ::pilota::MissingField> {
+                    #(#lets)*
+                    Ok(#name {
+                        #(#inits,)*
+                        #extra_fields
+                    })
+                }
+            }
+
+            impl #name {
+                pub fn builder() -> #builder_name {
+                    #builder_name::default()
+                }
+            }
+        });
+    }
+}
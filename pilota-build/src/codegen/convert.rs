@@ -0,0 +1,220 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{Codegen, CodegenBackend};
+use crate::{
+    db::RirDatabase,
+    middle::{
+        rir::{self, FieldKind},
+        ty::{self, Ty},
+    },
+    symbol::DefId,
+};
+
+impl<B> Codegen<B>
+where
+    B: CodegenBackend,
+{
+    /// `From` for the messages converted to `s` by `Builder::convert`, which moves the fields
+    /// with the same names.
+    pub(super) fn write_conversions(
+        &self,
+        def_id: DefId,
+        stream: &mut TokenStream,
+        s: &rir::Message,
+    ) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let sources = self
+            .conversions()
+            .iter()
+            .filter(|(_, to)| *to == def_id)
+            .map(|(from, _)| *from)
+            .collect::<Vec<_>>();
+
+        for source_id in sources {
+            let source = match &*self.expect_item(source_id) {
+                rir::Item::Message(m) => m.clone(),
+                _ => unreachable!(),
+            };
+            let source_path = self.cur_related_item_path(source_id);
+
+            let mut inits = Vec::with_capacity(s.fields.len());
+            for f in &s.fields {
+                let field = format_ident!("{}", f.name.to_field_case(self.naming()));
+                let Some(from) = source.fields.iter().find(|from| from.name == f.name) else {
+                    let init = match f.kind {
+                        FieldKind::Optional => quote!(::std::option::Option::None),
+                        FieldKind::Implicit => quote!(::std::default::Default::default()),
+                        FieldKind::Required => panic!(
+                            "the required field `{}` of `{}` is missing in `{}`",
+                            f.name, s.name, source.name
+                        ),
+                    };
+                    inits.push(quote!(#field: #init));
+                    continue;
+                };
+                let from_field = format_ident!("{}", from.name.to_field_case(self.naming()));
+
+                let from_boxed = self.adjust(from.did).is_some_and(|a| a.boxed());
+                let to_boxed = self.adjust(f.did).is_some_and(|a| a.boxed());
+                let same = same_ty(&from.ty, &f.ty) && from_boxed == to_boxed;
+                let convert = |v: TokenStream| {
+                    let v = if from_boxed { quote!(*#v) } else { v };
+                    let value = self.convert_value(&from.ty, &f.ty, v).unwrap_or_else(|| {
+                        panic!(
+                            "the field `{}` of `{}` can't be converted to the one of `{}`",
+                            f.name, source.name, s.name
+                        )
+                    });
+                    if to_boxed {
+                        quote!(::std::boxed::Box::new(#value))
+                    } else {
+                        value
+                    }
+                };
+
+                let v = quote!(value.#from_field);
+                let init = match (from.is_optional(), &f.kind) {
+                    (true, FieldKind::Required) => panic!(
+                        "the field `{}` is optional in `{}` but required in `{}`",
+                        f.name, source.name, s.name
+                    ),
+                    (true, kind) => {
+                        let v = if same {
+                            v
+                        } else {
+                            let value = convert(quote!(v));
+                            quote!(#v.map(|v| #value))
+                        };
+                        match kind {
+                            FieldKind::Implicit => quote!(#v.unwrap_or_default()),
+                            _ => v,
+                        }
+                    }
+                    (false, kind) => {
+                        let v = if same { v } else { convert(v) };
+                        match kind {
+                            FieldKind::Optional => quote!(::std::option::Option::Some(#v)),
+                            _ => v,
+                        }
+                    }
+                };
+                inits.push(quote!(#field: #init));
+            }
+            let extra_fields = self.extra_field_inits();
+
+            stream.extend(quote! {
+                impl ::std::convert::From<#source_path> for #name {
+                    #[allow(unused_variables)]
+                    fn from(value: #source_path) -> Self {
+                        #name {
+                            #(#inits,)*
+                            #extra_fields
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    /// `From` for the oneofs converted to `e` along with their messages, which maps the
+    /// variants with the same names.
+    pub(super) fn write_enum_conversions(
+        &self,
+        def_id: DefId,
+        stream: &mut TokenStream,
+        e: &rir::Enum,
+    ) {
+        let name = format_ident!("{}", e.name.to_upper_camel_case(self.naming()));
+        let sources = self
+            .conversions()
+            .iter()
+            .filter(|(_, to)| *to == def_id)
+            .map(|(from, _)| *from)
+            .collect::<Vec<_>>();
+
+        for source_id in sources {
+            let source = match &*self.expect_item(source_id) {
+                rir::Item::Enum(e) => e.clone(),
+                _ => unreachable!(),
+            };
+            let source_path = self.cur_related_item_path(source_id);
+
+            let arms = source.variants.iter().map(|from| {
+                let Some(to) = e.variants.iter().find(|to| to.name == from.name) else {
+                    panic!(
+                        "the variant `{}` of `{}` is missing in `{}`",
+                        from.name, source.name, e.name
+                    )
+                };
+                let from_variant = format_ident!("{}", from.name.to_variant_case(self.naming()));
+                let to_variant = format_ident!("{}", to.name.to_variant_case(self.naming()));
+                let (Some(from_ty), Some(to_ty)) = (from.fields.first(), to.fields.first()) else {
+                    return quote!(#source_path::#from_variant => #name::#to_variant);
+                };
+
+                let v = if self.is_boxed_variant(from) {
+                    quote!(*v)
+                } else {
+                    quote!(v)
+                };
+                let value = self.convert_value(from_ty, to_ty, v).unwrap_or_else(|| {
+                    panic!(
+                        "the variant `{}` of `{}` can't be converted to the one of `{}`",
+                        from.name, source.name, e.name
+                    )
+                });
+                let value = if self.is_boxed_variant(to) {
+                    quote!(::std::boxed::Box::new(#value))
+                } else {
+                    value
+                };
+                quote!(#source_path::#from_variant(v) => #name::#to_variant(#value))
+            });
+
+            stream.extend(quote! {
+                impl ::std::convert::From<#source_path> for #name {
+                    fn from(value: #source_path) -> Self {
+                        match value {
+                            #(#arms,)*
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    /// Converts `v` of `from` to `to`, `None` if the types are not the same and there is no
+    /// conversion between their messages.
+    fn convert_value(&self, from: &Ty, to: &Ty, v: TokenStream) -> Option<TokenStream> {
+        if same_ty(from, to) {
+            return Some(v);
+        }
+        match (&from.kind, &to.kind) {
+            (ty::Path(a), ty::Path(b)) if self.conversions().contains(&(a.did, b.did)) => {
+                Some(quote!(::std::convert::Into::into(#v)))
+            }
+            (ty::Vec(a), ty::Vec(b)) | (ty::Set(a), ty::Set(b)) => {
+                let el = self.convert_value(a, b, quote!(v))?;
+                Some(quote!(#v.into_iter().map(|v| #el).collect()))
+            }
+            (ty::Map(k1, v1), ty::Map(k2, v2)) => {
+                let k = self.convert_value(k1, k2, quote!(k))?;
+                let val = self.convert_value(v1, v2, quote!(v))?;
+                Some(quote!(#v.into_iter().map(|(k, v)| (#k, #val)).collect()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether the types are the same, whatever their tags are.
+fn same_ty(a: &Ty, b: &Ty) -> bool {
+    match (&a.kind, &b.kind) {
+        (ty::Vec(a), ty::Vec(b)) | (ty::Set(a), ty::Set(b)) | (ty::Arc(a), ty::Arc(b)) => {
+            same_ty(a, b)
+        }
+        (ty::Map(k1, v1), ty::Map(k2, v2)) => same_ty(k1, k2) && same_ty(v1, v2),
+        (a, b) => a == b,
+    }
+}
@@ -0,0 +1,107 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    parse_quote,
+    visit_mut::{self, VisitMut},
+    Attribute, Fields, ImplItem, Item, TraitItem, Visibility,
+};
+
+/// Adds a placeholder doc comment to the public items without one, so the
+/// generated code compiles under `#![deny(missing_docs)]`.
+pub(super) struct DocStubs;
+
+fn add_doc(attrs: &mut Vec<Attribute>, name: impl ToString, kind: &str) {
+    if attrs.iter().any(|attr| attr.path.is_ident("doc")) {
+        return;
+    }
+    let doc = format!(" The `{}` {}.", name.to_string(), kind);
+    attrs.insert(0, parse_quote!(#[doc = #doc]));
+}
+
+fn is_pub(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+fn add_fields_doc(fields: &mut Fields) {
+    match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter_mut()
+            .filter(|f| is_pub(&f.vis))
+            .for_each(|f| add_doc(&mut f.attrs, f.ident.as_ref().unwrap(), "field")),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, f)| is_pub(&f.vis))
+            .for_each(|(idx, f)| add_doc(&mut f.attrs, idx, "field")),
+        Fields::Unit => {}
+    }
+}
+
+/// Documents the `pub static ref` items declared in a `lazy_static!` invocation.
+fn add_lazy_static_docs(tokens: TokenStream) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let is_ident = |idx: usize, name: &str| matches!(tokens.get(idx), Some(TokenTree::Ident(ident)) if ident == name);
+    let mut stream = TokenStream::new();
+    tokens.iter().enumerate().for_each(|(idx, tt)| {
+        if is_ident(idx, "pub") && is_ident(idx + 1, "static") && is_ident(idx + 2, "ref") {
+            if let Some(TokenTree::Ident(name)) = tokens.get(idx + 3) {
+                let doc = format!(" The `{}` static.", name);
+                stream.extend(quote!(#[doc = #doc]));
+            }
+        }
+        stream.extend([tt.clone()]);
+    });
+    stream
+}
+
+impl VisitMut for DocStubs {
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        match item {
+            Item::Struct(s) if is_pub(&s.vis) => {
+                add_doc(&mut s.attrs, &s.ident, "struct");
+                add_fields_doc(&mut s.fields);
+            }
+            Item::Enum(e) if is_pub(&e.vis) => {
+                add_doc(&mut e.attrs, &e.ident, "enum");
+                e.variants
+                    .iter_mut()
+                    .for_each(|v| add_doc(&mut v.attrs, &v.ident, "variant"));
+            }
+            Item::Const(c) if is_pub(&c.vis) => add_doc(&mut c.attrs, &c.ident, "constant"),
+            Item::Static(s) if is_pub(&s.vis) => add_doc(&mut s.attrs, &s.ident, "static"),
+            Item::Fn(f) if is_pub(&f.vis) => add_doc(&mut f.attrs, &f.sig.ident, "function"),
+            Item::Mod(m) if is_pub(&m.vis) => add_doc(&mut m.attrs, &m.ident, "module"),
+            Item::Type(t) if is_pub(&t.vis) => add_doc(&mut t.attrs, &t.ident, "type"),
+            Item::Trait(t) if is_pub(&t.vis) => {
+                add_doc(&mut t.attrs, &t.ident, "trait");
+                t.items.iter_mut().for_each(|item| match item {
+                    TraitItem::Method(m) => add_doc(&mut m.attrs, &m.sig.ident, "method"),
+                    TraitItem::Const(c) => add_doc(&mut c.attrs, &c.ident, "constant"),
+                    TraitItem::Type(t) => add_doc(&mut t.attrs, &t.ident, "type"),
+                    _ => {}
+                });
+            }
+            Item::Macro(m)
+                if m.mac.path.segments.last().map(|s| s.ident == "lazy_static") == Some(true) =>
+            {
+                m.mac.tokens = add_lazy_static_docs(std::mem::take(&mut m.mac.tokens));
+            }
+            // the items of a trait impl are documented by the trait
+            Item::Impl(i) if i.trait_.is_none() => {
+                i.items.iter_mut().for_each(|item| match item {
+                    ImplItem::Method(m) if is_pub(&m.vis) => {
+                        add_doc(&mut m.attrs, &m.sig.ident, "method")
+                    }
+                    ImplItem::Const(c) if is_pub(&c.vis) => {
+                        add_doc(&mut c.attrs, &c.ident, "constant")
+                    }
+                    _ => {}
+                });
+            }
+            _ => {}
+        }
+        visit_mut::visit_item_mut(self, item)
+    }
+}
@@ -0,0 +1,144 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::Codegen;
+use crate::{
+    middle::{
+        rir,
+        ty::{self, Ty},
+    },
+    symbol::DefId,
+};
+
+impl<B> Codegen<B> {
+    /// Converts `v`, a reference to a value of `ty`, into a `::serde_json::Value`.
+    fn json_value(&self, ty: &Ty, v: TokenStream) -> TokenStream {
+        match &ty.kind {
+            ty::String => quote! { ::serde_json::Value::String(#v.to_string()) },
+            ty::Bytes => quote! { ::serde_json::Value::from(#v.to_vec()) },
+            ty::Void => quote! { ::serde_json::Value::Null },
+            ty::Bool
+            | ty::U8
+            | ty::I8
+            | ty::I16
+            | ty::I32
+            | ty::I64
+            | ty::UInt32
+            | ty::UInt64
+            | ty::F32
+            | ty::F64 => quote! { ::serde_json::Value::from(*#v) },
+            ty::Vec(ty) | ty::Set(ty) => {
+                let el = self.json_value(ty, quote!(v));
+                quote! { ::serde_json::Value::Array(#v.iter().map(|v| #el).collect()) }
+            }
+            ty::Map(k, v_ty) => {
+                let key = self.json_value(k, quote!(k));
+                let value = self.json_value(v_ty, quote!(v));
+                quote! {
+                    ::serde_json::Value::Object(#v.iter().map(|(k, v)| {
+                        let key = match #key {
+                            ::serde_json::Value::String(s) => s,
+                            k => k.to_string(),
+                        };
+                        (key, #value)
+                    }).collect())
+                }
+            }
+            ty::Arc(ty) => self.json_value(ty, quote!((&**#v))),
+            ty::Path(_) => quote! { #v.to_json_value() },
+        }
+    }
+
+    fn impl_to_json_value(&self, name: &str, body: TokenStream) -> TokenStream {
+        let name = format_ident!("{}", name);
+        quote! {
+            impl #name {
+                pub fn to_json_value(&self) -> ::serde_json::Value {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Fields are keyed by their names in the IDL, absent optional fields are left out.
+    pub(super) fn write_struct_json_value(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let fields = s.fields.iter().map(|f| {
+            let field = format_ident!("{}", &f.name.to_field_case(self.naming()));
+            let key = &*f.original_name;
+            if f.is_optional() {
+                let v = self.json_value(&f.ty, quote!(v));
+                quote! {
+                    if let Some(v) = &self.#field {
+                        map.insert(#key.to_string(), #v);
+                    }
+                }
+            } else {
+                let v = self.json_value(&f.ty, quote!((&self.#field)));
+                quote! { map.insert(#key.to_string(), #v); }
+            }
+        });
+
+        stream.extend(self.impl_to_json_value(
+            &s.name.to_upper_camel_case(self.naming()),
+            quote! {
+                let mut map = ::serde_json::Map::new();
+                #(#fields)*
+                ::serde_json::Value::Object(map)
+            },
+        ));
+    }
+
+    /// Unit variants become their names in the IDL, the others an object with
+    /// the name as the only key.
+    pub(super) fn write_enum_json_value(
+        &self,
+        def_id: DefId,
+        stream: &mut TokenStream,
+        e: &rir::Enum,
+    ) {
+        let arms = e.variants.iter().map(|v| {
+            let variant = format_ident!("{}", &v.name.to_variant_case(self.naming()));
+            let key = &*v.original_name;
+            match v.fields.first() {
+                None => quote! {
+                    Self::#variant => ::serde_json::Value::String(#key.to_string()),
+                },
+                Some(ty) => {
+                    let value = self.json_value(ty, quote!(v));
+                    let unbox = self.is_boxed_variant(v).then(|| quote! { let v = &**v; });
+                    quote! {
+                        Self::#variant(v) => {
+                            #unbox
+                            let mut map = ::serde_json::Map::new();
+                            map.insert(#key.to_string(), #value);
+                            ::serde_json::Value::Object(map)
+                        }
+                    }
+                }
+            }
+        });
+        let unknown = if self.is_open_enum(e) {
+            Some(quote! { Self::Unknown(v) => ::serde_json::Value::from(*v), })
+        } else {
+            self.has_unknown_variant(def_id)
+                .then(|| quote! { Self::Unknown(_) => ::serde_json::Value::Null, })
+        };
+
+        let body = if e.variants.is_empty() && unknown.is_none() {
+            quote! { match *self {} }
+        } else {
+            quote! {
+                match self {
+                    #(#arms)*
+                    #unknown
+                }
+            }
+        };
+        stream.extend(self.impl_to_json_value(&e.name.to_upper_camel_case(self.naming()), body));
+    }
+
+    pub(super) fn write_new_type_json_value(&self, stream: &mut TokenStream, t: &rir::NewType) {
+        let body = self.json_value(&t.ty, quote!((&self.0)));
+        stream.extend(self.impl_to_json_value(&t.name.to_upper_camel_case(self.naming()), body));
+    }
+}
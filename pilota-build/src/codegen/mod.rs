@@ -1,10 +1,11 @@
 use std::{ops::Deref, sync::Arc};
 
-use fxhash::FxHashMap;
+use fxhash::{FxHashMap, FxHashSet};
 use heck::ToShoutySnakeCase;
 use pkg_tree::PkgNode;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse_quote;
 use traits::CodegenBackend;
 
 use crate::{
@@ -13,17 +14,65 @@ use crate::{
         self,
         context::tls::CUR_ITEM,
         rir::{self, ItemPath, Literal},
-        ty::{AdtDef, AdtKind, CodegenTy},
+        ty::{self, AdtDef, AdtKind, CodegenTy, CollectionKind},
+    },
+    plugin::existing_derives,
+    symbol::{DefId, EnumRepr, FieldCase, Symbol},
+    tags::{
+        protobuf::OneOf,
+        thrift::{MethodArgs, SharedTrait, Union},
+        validate::{Max, Min, NonEmpty},
+        ArcField, Deprecated, Docs, OrdById, RustAttrs,
     },
-    symbol::{DefId, EnumRepr},
     Context,
 };
 
-pub(crate) mod pkg_tree;
+mod builder;
+mod convert;
+mod doc_stubs;
+mod json;
+pub mod pkg_tree;
 pub(crate) mod traits;
 
 pub mod protobuf;
 pub mod thrift;
+mod view;
+
+/// The visibility of the generated structs, enums, fields, constants and re-exports. Traits,
+/// such as the ones of services, and the clients are always `pub` to be usable downstream.
+///
+/// The generated modules reference the items of each other, so `Inherited` only applies to
+/// the fields, and the items are `pub(crate)` then.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// `pub`.
+    #[default]
+    Public,
+    /// `pub(crate)`.
+    Crate,
+    /// No visibility, only usable in the module of the item and its descendants.
+    Inherited,
+}
+
+impl Visibility {
+    /// The visibility of the items, which are at least `pub(crate)`.
+    pub(crate) fn of_items(self) -> Self {
+        match self {
+            Visibility::Inherited => Visibility::Crate,
+            vis => vis,
+        }
+    }
+}
+
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Visibility::Public => tokens.extend(quote!(pub)),
+            Visibility::Crate => tokens.extend(quote!(pub(crate))),
+            Visibility::Inherited => {}
+        }
+    }
+}
 
 pub struct Codegen<B> {
     backend: B,
@@ -56,41 +105,663 @@ where
     B: CodegenBackend,
 {
     pub fn write_struct(&mut self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
-        let name = format_ident!("{}", &s.name.to_upper_camel_case());
-        let fields = s.fields.iter().map(|f| {
-            let name = format_ident!("{}", &f.name.to_snake_case());
-            let adjust = self.adjust(f.did);
-            let ty = self.codegen_item_ty(f.ty.kind.clone());
-            let mut ty = quote::quote! { #ty };
-
-            if let Some(adjust) = adjust {
-                if adjust.boxed() {
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        if let Some(f) = s
+            .fields
+            .iter()
+            .find(|f| !f.is_optional() && self.is_empty_union(&f.ty))
+        {
+            panic!(
+                "the field `{}` of `{}` has no value, as the union of its type is empty, it has to be optional",
+                f.name, s.name
+            )
+        }
+        let field_tys = s
+            .fields
+            .iter()
+            .map(|f| {
+                let ty = self.codegen_field_ty(&f.ty);
+                let mut ty = quote::quote! { #ty };
+
+                if self.adjust(f.did).is_some_and(|a| a.boxed()) {
                     ty = quote::quote! { ::std::boxed::Box<#ty> }
                 }
-            }
 
-            if f.is_optional() {
-                ty = quote::quote! { ::std::option::Option<#ty> }
-            }
-
-            let attrs = adjust.iter().flat_map(|a| a.attrs());
+                if f.is_optional() {
+                    ty = quote::quote! { ::std::option::Option<#ty> }
+                }
+                ty
+            })
+            .collect::<Vec<_>>();
+        let vis = self.visibility();
+        let fields = s.fields.iter().zip(&field_tys).map(|(f, ty)| {
+            let name = format_ident!("{}", &f.name.to_field_case(self.naming()));
+            let attrs = self.adjust(f.did).into_iter().flat_map(|a| a.attrs());
+            let deprecated = self.deprecated_attr(f.did);
+            let docs = self.doc_attrs(f.did);
+            let rust_attrs = self.rust_attrs(f.did);
 
             quote::quote! {
+                #docs
                 #(#attrs)*
-                pub #name: #ty,
+                #(#rust_attrs)*
+                #deprecated
+                #vis #name: #ty,
             }
         });
+        let skip = self.with_serde().then(|| quote!(#[serde(skip)]));
+        let dirty_fields = self
+            .track_dirty_fields()
+            .then(|| quote!(#skip #vis _dirty_fields: ::pilota::dirty::DirtyFields,));
+        let unknown_fields = self.preserve_unknown_fields().then(|| {
+            let ty = self.backend.codegen_unknown_fields_ty();
+            quote!(#skip #vis _unknown_fields: #ty,)
+        });
 
         let lifetime = self.zero_copy.then(|| quote!(<'de>)).into_iter();
+        let item_vis = vis.of_items();
+        // the types ordered by field id are equal when `Ord` says so
+        let partial_eq = (!self.node_contains_tag::<OrdById>(def_id)).then(|| quote!(, PartialEq));
 
         stream.extend(quote::quote! {
-            #[derive(Clone, PartialEq)]
-            pub struct #name #(#lifetime)* {
+            #[derive(Clone #partial_eq)]
+            #item_vis struct #name #(#lifetime)* {
                 #(#fields)*
+                #dirty_fields
+                #unknown_fields
             }
         });
 
         self.backend.codegen_struct_impl(def_id, stream, s);
+
+        if self.gen_field_wire_types() {
+            self.backend.codegen_field_wire_types(stream, s);
+        }
+
+        if self.gen_changed_from() {
+            self.backend.codegen_changed_from(stream, s);
+        }
+
+        if !self.with_validator() {
+            self.write_validate(stream, s);
+        }
+
+        self.write_shared_trait(def_id, stream, s);
+
+        if self.default_impl().is_manual(s) {
+            self.write_default(stream, s);
+        }
+
+        if self.gen_constructors() {
+            self.write_constructor(stream, s, &field_tys);
+        }
+
+        if self.gen_view_types() {
+            self.write_view(stream, s);
+        }
+
+        if self.gen_has_accessors() {
+            self.write_has_accessors(stream, s);
+        }
+
+        self.write_arc_accessors(stream, s);
+
+        if self.with_builders() {
+            self.write_builder(stream, s);
+        }
+
+        self.write_conversions(def_id, stream, s);
+
+        if self.node_contains_tag::<OrdById>(def_id) {
+            self.write_ord_by_id(def_id, stream, s);
+        }
+
+        if self.track_dirty_fields() {
+            self.write_setters(stream, s, &field_tys);
+        }
+
+        if self.gen_to_json_value() {
+            self.write_struct_json_value(stream, s);
+        }
+    }
+
+    fn write_default(&mut self, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        let mut fields = s
+            .fields
+            .iter()
+            .map(|f| {
+                let name = format_ident!("{}", f.name.to_field_case(self.naming()));
+                let v = self.field_default(f);
+                quote! { #name: #v, }
+            })
+            .collect::<Vec<_>>();
+
+        fields.push(self.extra_field_inits());
+
+        stream.extend(quote! {
+            impl ::std::default::Default for #name {
+                fn default() -> Self {
+                    #name {
+                        #(#fields)*
+                    }
+                }
+            }
+        });
+    }
+
+    /// `new` takes the required fields ordered by id, the optional ones get their defaults.
+    /// The messages without required fields only have `Default`.
+    fn write_constructor(
+        &mut self,
+        stream: &mut TokenStream,
+        s: &rir::Message,
+        field_tys: &[TokenStream],
+    ) {
+        let mut required = s
+            .fields
+            .iter()
+            .zip(field_tys)
+            .filter(|(f, _)| !f.is_optional())
+            .collect::<Vec<_>>();
+        if required.is_empty() {
+            return;
+        }
+        required.sort_by_key(|(f, _)| f.id);
+
+        // `field_default` borrows `self` mutably in the closures naming the fields
+        let naming = self.naming().clone();
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(&naming));
+        let params = required.iter().map(|(f, ty)| {
+            let field = format_ident!("{}", f.name.to_field_case(&naming));
+            quote! { #field: #ty }
+        });
+        let mut fields = s
+            .fields
+            .iter()
+            .map(|f| {
+                let field = format_ident!("{}", f.name.to_field_case(&naming));
+                if f.is_optional() {
+                    let v = self.field_default(f);
+                    quote! { #field: #v, }
+                } else {
+                    quote! { #field, }
+                }
+            })
+            .collect::<Vec<_>>();
+        fields.push(self.extra_field_inits());
+
+        stream.extend(quote! {
+            impl #name {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(#(#params),*) -> Self {
+                    #name {
+                        #(#fields)*
+                    }
+                }
+            }
+        });
+    }
+
+    /// `has_x` tells whether the optional field `x` is present, the fields without presence
+    /// have none.
+    fn write_has_accessors(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let accessors = s
+            .fields
+            .iter()
+            .filter(|f| f.is_optional())
+            .map(|f| {
+                let field = format_ident!("{}", f.name.to_field_case(self.naming()));
+                let has = format_ident!("has_{}", &**f.name.to_field_case(self.naming()));
+                quote! {
+                    pub fn #has(&self) -> bool {
+                        self.#field.is_some()
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        stream.extend(quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        });
+    }
+
+    /// Accessors named after the [`ArcField`]s, returning references to the shared values.
+    fn write_arc_accessors(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let accessors = s
+            .fields
+            .iter()
+            .filter(|f| self.node_contains_tag::<ArcField>(f.did))
+            .filter_map(|f| {
+                let ty::Arc(inner) = &f.ty.kind else {
+                    return None;
+                };
+                let field = format_ident!("{}", f.name.to_field_case(self.naming()));
+                // prefixed, the inherent methods such as `encoded_len` may be named like a field
+                let getter = format_ident!("get_{}", &**f.name.to_field_case(self.naming()));
+                let ty = self.codegen_field_ty(inner);
+                Some(if f.is_optional() {
+                    quote! {
+                        pub fn #getter(&self) -> ::std::option::Option<&#ty> {
+                            self.#field.as_deref()
+                        }
+                    }
+                } else {
+                    quote! {
+                        pub fn #getter(&self) -> &#ty {
+                            &self.#field
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        stream.extend(quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        });
+    }
+
+    /// Whether the type is a union without members, which has no default value even with an
+    /// `Unknown` variant.
+    fn is_empty_union(&self, ty: &ty::Ty) -> bool {
+        let ty::Path(path) = &ty.kind else {
+            return false;
+        };
+        self.node_contains_tag::<Union>(path.did)
+            && matches!(&*self.expect_item(path.did), rir::Item::Enum(e) if e.variants.is_empty())
+    }
+
+    /// `PartialOrd` and `Ord` comparing the fields in the ascending order of their ids, with
+    /// `PartialEq` agreeing with them, and `Eq` if it is not derived. Panics if a field can not
+    /// be ordered.
+    fn write_ord_by_id(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        let mut fields = s.fields.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|f| f.id);
+
+        if let Some(f) = fields
+            .iter()
+            .find(|f| !self.is_ord(&self.codegen_field_ty(&f.ty)))
+        {
+            panic!(
+                "can not order `{}` by field id: the type of the field `{}` does not implement `Ord`",
+                s.name, f.name
+            )
+        }
+
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let cmps = fields.iter().map(|f| {
+            let field = format_ident!("{}", f.name.to_field_case(self.naming()));
+            quote! { ::std::cmp::Ord::cmp(&self.#field, &other.#field) }
+        });
+        let eq = (!existing_derives(&self.cx, def_id, &self.expect_item(def_id)).contains("Eq"))
+            .then(|| quote! { impl ::std::cmp::Eq for #name {} });
+
+        stream.extend(quote! {
+            impl ::std::cmp::PartialEq for #name {
+                fn eq(&self, other: &Self) -> bool {
+                    ::std::cmp::Ord::cmp(self, other) == ::std::cmp::Ordering::Equal
+                }
+            }
+
+            impl ::std::cmp::PartialOrd for #name {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            impl ::std::cmp::Ord for #name {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    ::std::cmp::Ordering::Equal
+                        #(.then_with(|| #cmps))*
+                }
+            }
+
+            #eq
+        });
+    }
+
+    /// Whether `ty` implements `Ord`, the generated types do if they derive it.
+    fn is_ord(&self, ty: &CodegenTy) -> bool {
+        match ty {
+            CodegenTy::F32 | CodegenTy::F64 => false,
+            CodegenTy::Set(el, kind) => *kind == CollectionKind::BTree && self.is_ord(el),
+            CodegenTy::Map(k, v, kind) => {
+                *kind == CollectionKind::BTree && self.is_ord(k) && self.is_ord(v)
+            }
+            CodegenTy::Vec(el) | CodegenTy::Arc(el) => self.is_ord(el),
+            CodegenTy::Adt(AdtDef { did, .. }) => {
+                existing_derives(&self.cx, *did, &self.expect_item(*did)).contains("Ord")
+            }
+            // a custom type is left to the user, its arguments are checked
+            CodegenTy::Custom(_, args) => args.iter().all(|arg| self.is_ord(arg)),
+            _ => true,
+        }
+    }
+
+    /// `#[deprecated]` for the fields, variants and methods tagged [`Deprecated`], with the note
+    /// of the tag if any.
+    fn deprecated_attr(&self, def_id: DefId) -> Option<TokenStream> {
+        let tags = self.tags(self.node(def_id)?.tags)?;
+        let deprecated = tags.get::<Deprecated>()?;
+        Some(match deprecated.0.as_deref() {
+            Some(note) => quote! { #[deprecated(note = #note)] },
+            None => quote! { #[deprecated] },
+        })
+    }
+
+    /// The attributes in the [`RustAttrs`] tag of a field.
+    fn rust_attrs(&self, def_id: DefId) -> Vec<syn::Attribute> {
+        let Some(attrs) = self
+            .node(def_id)
+            .and_then(|node| self.tags(node.tags))
+            .and_then(|tags| tags.get::<RustAttrs>().map(|a| a.0.clone()))
+        else {
+            return Vec::new();
+        };
+        attrs
+            .iter()
+            .flat_map(|attr| {
+                syn::parse::Parser::parse_str(syn::Attribute::parse_outer, attr)
+                    .unwrap_or_else(|_| panic!("invalid attribute `{attr}`"))
+            })
+            .collect()
+    }
+
+    /// `#[doc]` for the lines of the doc comments of a definition in the IDL. The code blocks
+    /// are kept from being run as doc tests: the fenced ones are marked as `text` and the
+    /// other lines are indented by 3 spaces at most.
+    fn doc_attrs(&self, def_id: DefId) -> TokenStream {
+        let docs = match self.node(def_id).and_then(|node| self.tags(node.tags)) {
+            Some(tags) => match tags.get::<Docs>() {
+                Some(docs) => docs.0.clone(),
+                None => return TokenStream::new(),
+            },
+            None => return TokenStream::new(),
+        };
+        let mut fenced = false;
+        let docs = docs.iter().map(|line| {
+            let trimmed = line.trim_start();
+            let line = if trimmed.starts_with("```") {
+                fenced = !fenced;
+                if fenced && trimmed == "```" {
+                    format!(" {}```text", &line[..line.len() - trimmed.len()])
+                } else {
+                    format!(" {line}")
+                }
+            } else if fenced || line.len() - trimmed.len() <= 3 {
+                format!(" {line}")
+            } else {
+                format!("    {trimmed}")
+            };
+            if line.trim().is_empty() {
+                quote! { #[doc = ""] }
+            } else {
+                quote! { #[doc = #line] }
+            }
+        });
+        quote! { #(#docs)* }
+    }
+
+    /// The default value of a field, its default in the idl if any.
+    fn field_default(&mut self, f: &rir::Field) -> TokenStream {
+        let ty = self.codegen_field_ty(&f.ty);
+        match &f.default {
+            // build the containers only once and clone them afterwards
+            Some(lit @ (Literal::List(_) | Literal::Map(_)))
+                if ty.should_lazy_static() && !matches!(ty, CodegenTy::Adt(_)) =>
+            {
+                let default = self.def_lit(
+                    "default",
+                    lit,
+                    &CodegenTy::LazyStaticRef(Arc::new(ty)),
+                    TokenStream::new(),
+                );
+                let mut v = quote! {
+                    {
+                        #default
+                        DEFAULT.clone()
+                    }
+                };
+                if f.is_optional() {
+                    v = quote! { Some(#v) }
+                }
+                v
+            }
+            Some(lit) => self.field_lit(f, lit),
+            None if f.is_optional() => quote! { None },
+            None => quote! { ::std::default::Default::default() },
+        }
+    }
+
+    fn write_setters(&self, stream: &mut TokenStream, s: &rir::Message, field_tys: &[TokenStream]) {
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        let setters = s
+            .fields
+            .iter()
+            .zip(field_tys)
+            .enumerate()
+            .map(|(idx, (f, ty))| {
+                let field = format_ident!("{}", &f.name.to_field_case(self.naming()));
+                let setter = format_ident!("set_{}", &**f.name.to_field_case(self.naming()));
+                quote! {
+                    pub fn #setter(&mut self, #field: #ty) {
+                        self.#field = #field;
+                        self._dirty_fields.mark(#idx);
+                    }
+                }
+            });
+        let ids = s.fields.iter().map(|f| f.id as i16);
+        let len = s.fields.len();
+
+        stream.extend(quote! {
+            impl #name {
+                #(#setters)*
+
+                /// The ids of the fields changed by the setters.
+                pub fn dirty_fields(&self) -> impl Iterator<Item = i16> + '_ {
+                    const IDS: [i16; #len] = [#(#ids),*];
+                    self._dirty_fields.iter().map(|idx| IDS[idx])
+                }
+
+                pub fn clear_dirty_fields(&mut self) {
+                    self._dirty_fields.clear()
+                }
+            }
+        });
+    }
+
+    fn write_shared_trait(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        let trait_name = match self.node_tags(def_id).get::<SharedTrait>() {
+            Some(t) => t.0.clone(),
+            None => return,
+        };
+        let members = self.shared_trait_members(&trait_name);
+        let member_fields = members
+            .iter()
+            .map(|did| match &*self.expect_item(*did) {
+                rir::Item::Message(m) => m.fields.clone(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        let common_fields = s.fields.iter().filter(|f| {
+            member_fields.iter().all(|fields| {
+                fields.iter().any(|other| {
+                    other.name == f.name && other.kind == f.kind && other.ty.kind == f.ty.kind
+                })
+            })
+        });
+
+        let (sigs, bodies): (Vec<_>, Vec<_>) = common_fields
+            .map(|f| {
+                let name = format_ident!("{}", f.name.to_field_case(self.naming()));
+                let ty = self.codegen_field_ty(&f.ty);
+                let is_copy = matches!(
+                    f.ty.kind,
+                    ty::Bool
+                        | ty::U8
+                        | ty::I8
+                        | ty::I16
+                        | ty::I32
+                        | ty::I64
+                        | ty::UInt32
+                        | ty::UInt64
+                        | ty::F32
+                        | ty::F64
+                );
+                let boxed = self.adjust(f.did).is_some_and(|a| a.boxed());
+                let (ret, body) = match (f.is_optional(), is_copy) {
+                    (false, true) => (quote! { #ty }, quote! { self.#name }),
+                    (false, false) => (quote! { &#ty }, quote! { &self.#name }),
+                    (true, true) => (quote! { ::std::option::Option<#ty> }, quote! { self.#name }),
+                    (true, false) if boxed => (
+                        quote! { ::std::option::Option<&#ty> },
+                        quote! { self.#name.as_deref() },
+                    ),
+                    (true, false) => (
+                        quote! { ::std::option::Option<&#ty> },
+                        quote! { self.#name.as_ref() },
+                    ),
+                };
+                let sig = quote! { fn #name(&self) -> #ret };
+                (sig.clone(), quote! { #sig { #body } })
+            })
+            .unzip();
+
+        let trait_ident = format_ident!("{}", &*trait_name);
+        if members.first() == Some(&def_id) {
+            stream.extend(quote! {
+                pub trait #trait_ident {
+                    #(#sigs;)*
+                }
+            });
+        }
+
+        let mut trait_path = self.related_item_path(def_id, members[0]);
+        trait_path.segments.last_mut().unwrap().ident = trait_ident;
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        let lifetime = self.zero_copy.then(|| quote!(<'de>));
+        stream.extend(quote! {
+            impl #lifetime #trait_path for #name #lifetime {
+                #(#bodies)*
+            }
+        });
+    }
+
+    fn write_validate(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let checks = s
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let tags = self.node_tags(f.did);
+                let (min, max) = (tags.get::<Min>(), tags.get::<Max>());
+                let non_empty = tags.contains::<NonEmpty>();
+                if min.is_none() && max.is_none() && !non_empty {
+                    return None;
+                }
+
+                let field_name = &**f.name;
+                let err = |kind: TokenStream| {
+                    quote! {
+                        return ::std::result::Result::Err(::pilota::validate::ValidationError {
+                            field: #field_name,
+                            kind: ::pilota::validate::ValidationErrorKind::#kind,
+                        });
+                    }
+                };
+
+                let check = match &f.ty.kind {
+                    ty::String | ty::Bytes | ty::Vec(_) | ty::Set(_) | ty::Map(..) => {
+                        let non_empty = non_empty.then(|| {
+                            let err = err(quote!(Empty));
+                            quote! { if len == 0 { #err } }
+                        });
+                        let min = min.map(|min| {
+                            let min = min.0;
+                            let err = err(quote!(TooShort { min: #min, actual: len }));
+                            quote! { if (len as i64) < #min { #err } }
+                        });
+                        let max = max.map(|max| {
+                            let max = max.0;
+                            let err = err(quote!(TooLong { max: #max, actual: len }));
+                            quote! { if (len as i64) > #max { #err } }
+                        });
+                        quote! {
+                            let len = v.len();
+                            #non_empty
+                            #min
+                            #max
+                        }
+                    }
+                    ty::I8 | ty::I16 | ty::I32 | ty::I64 | ty::UInt32 | ty::UInt64 | ty::U8
+                        if !non_empty =>
+                    {
+                        let min = min.map(|min| {
+                            let min = min.0;
+                            let err = err(quote!(LessThanMin { min: #min, actual }));
+                            quote! { if actual < #min { #err } }
+                        });
+                        let max = max.map(|max| {
+                            let max = max.0;
+                            let err = err(quote!(GreaterThanMax { max: #max, actual }));
+                            quote! { if actual > #max { #err } }
+                        });
+                        quote! {
+                            let actual = ::std::convert::TryFrom::try_from(*v).unwrap_or(i64::MAX);
+                            #min
+                            #max
+                        }
+                    }
+                    _ => panic!(
+                        "constraint annotations are not supported on field {}",
+                        f.name
+                    ),
+                };
+
+                let name = format_ident!("{}", f.name.to_field_case(self.naming()));
+                Some(if f.is_optional() {
+                    quote! {
+                        if let ::std::option::Option::Some(v) = &self.#name {
+                            #check
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let v = &self.#name;
+                            #check
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if checks.is_empty() {
+            return;
+        }
+
+        let name = format_ident!("{}", &s.name.to_upper_camel_case(self.naming()));
+        let lifetime = self.zero_copy.then(|| quote!(<'de>));
+        stream.extend(quote! {
+            impl #lifetime #name #lifetime {
+                pub fn validate(&self) -> ::std::result::Result<(), ::pilota::validate::ValidationError> {
+                    #(#checks)*
+                    ::std::result::Result::Ok(())
+                }
+            }
+        });
     }
 
     pub fn write_item(&mut self, stream: &mut TokenStream, def_id: DefId) {
@@ -98,8 +769,12 @@ where
             let item = self.item(def_id).unwrap();
             let adjust = self.adjust(def_id);
             let attrs = adjust.iter().flat_map(|a| a.attrs());
+            // the docs of a const go on the definition, which may be inside a `lazy_static!`
+            let docs =
+                (!matches!(&*item, middle::rir::Item::Const(_))).then(|| self.doc_attrs(def_id));
 
             stream.extend(quote::quote! {
+                #docs
                 #(#attrs)*
             });
 
@@ -115,7 +790,7 @@ where
                         .iter()
                         .for_each(|def_id| self.write_item(&mut inner, *def_id));
 
-                    let name = format_ident!("{}", m.name.to_snake_case());
+                    let name = format_ident!("{}", m.name.to_snake_case(self.naming()));
                     stream.extend(quote::quote! {
                         pub mod #name {
                             #inner
@@ -127,9 +802,15 @@ where
     }
 
     pub fn write_enum(&mut self, def_id: DefId, stream: &mut TokenStream, e: &middle::rir::Enum) {
-        let name = format_ident!("{}", &e.name.to_upper_camel_case());
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+
+        let is_open = self.is_open_enum(e);
 
         let mut repr = match e.repr {
+            // the discriminants are kept by the conversions, as `Unknown` has a payload
+            Some(EnumRepr::I32) if is_open => quote! {
+               #[non_exhaustive]
+            },
             Some(EnumRepr::I32) => quote! {
                #[repr(i32)]
             },
@@ -141,13 +822,20 @@ where
         }
 
         let variants = e.variants.iter().map(|v| {
-            let name = format_ident!("{}", &v.name.to_upper_camel_case());
+            let name = format_ident!("{}", &v.name.to_variant_case(self.naming()));
             let adjust = self.adjust(v.did);
             let attrs = adjust.iter().flat_map(|a| a.attrs());
+            let boxed = self.is_boxed_variant(v);
             let fields = v
                 .fields
                 .iter()
-                .map(|ty| self.codegen_item_ty(ty.kind.clone()))
+                .map(|ty| {
+                    let ty = self.codegen_item_ty(ty.kind.clone());
+                    match boxed {
+                        true => quote! { ::std::boxed::Box<#ty> },
+                        false => quote! { #ty },
+                    }
+                })
                 .collect::<Vec<_>>();
 
             let fields_stream = if fields.is_empty() {
@@ -158,7 +846,7 @@ where
                 }
             };
 
-            let discr = v.discr.map(|x| {
+            let discr = v.discr.filter(|_| !is_open).map(|x| {
                 let x = isize::try_from(x).unwrap();
                 let x = match e.repr {
                     Some(EnumRepr::I32) => x as i32,
@@ -167,21 +855,330 @@ where
                 quote! { = #x }
             });
 
+            let deprecated = self.deprecated_attr(v.did);
+            let docs = self.doc_attrs(v.did);
+
             quote::quote! {
+                #docs
                 #(#attrs)*
+                #deprecated
                 #name #fields_stream #discr,
             }
         });
 
+        let has_unknown_variant = self.has_unknown_variant(def_id) || is_open;
+        if has_unknown_variant
+            && e.variants
+                .iter()
+                .any(|v| &**v.name.to_variant_case(self.naming()) == "Unknown")
+        {
+            panic!("{} already has a variant named `Unknown`", e.name)
+        }
+        let unknown_variant = if is_open {
+            Some(quote! { Unknown(i32), })
+        } else {
+            has_unknown_variant.then(|| quote! { Unknown(i16), })
+        };
+        let vis = self.visibility().of_items();
+
         stream.extend(quote::quote! {
             #[derive(Clone, PartialEq)]
             #repr
-            pub enum #name {
+            #vis enum #name {
                 #(#variants)*
+                #unknown_variant
             }
         });
 
+        if let Some(v) = crate::plugin::default_variant(self, e).filter(|v| v.default.is_some()) {
+            self.write_variant_default(stream, e, v);
+        }
+
+        if is_open {
+            self.write_open_enum_conversions(stream, e);
+        }
+
+        if self.enum_string_conversions() && e.repr.is_some() {
+            self.write_enum_string_conversions(stream, e, is_open);
+        }
+
         self.backend.codegen_enum_impl(def_id, stream, e);
+
+        if self.gen_to_json_value() {
+            self.write_enum_json_value(def_id, stream, e);
+        }
+
+        if self.reexport_oneof_payloads() && self.node_contains_tag::<OneOf>(def_id) {
+            self.write_oneof_reexports(def_id, stream, e);
+        }
+
+        if self.gen_oneof_from_impls() && self.node_contains_tag::<OneOf>(def_id) {
+            self.write_oneof_from_impls(stream, e);
+        }
+
+        self.write_enum_conversions(def_id, stream, e);
+
+        if self.gen_oneof_dispatcher() && self.node_contains_tag::<OneOf>(def_id) {
+            self.write_oneof_dispatcher(stream, e);
+        }
+    }
+
+    fn write_open_enum_conversions(&self, stream: &mut TokenStream, e: &middle::rir::Enum) {
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+        let (variants, discrs): (Vec<_>, Vec<_>) = e
+            .variants
+            .iter()
+            .zip(discriminants(e))
+            .map(|(v, discr)| {
+                let discr = proc_macro2::Literal::i32_unsuffixed(discr as i32);
+                (
+                    format_ident!("{}", &v.name.to_variant_case(self.naming())),
+                    discr,
+                )
+            })
+            .unzip();
+
+        stream.extend(quote! {
+            impl ::std::convert::From<i32> for #name {
+                fn from(v: i32) -> Self {
+                    match v {
+                        #(#discrs => #name::#variants,)*
+                        v => #name::Unknown(v),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<#name> for i32 {
+                fn from(v: #name) -> Self {
+                    match v {
+                        #(#name::#variants => #discrs,)*
+                        #name::Unknown(v) => v,
+                    }
+                }
+            }
+        });
+    }
+
+    /// The default of a union is its member with a default value in the idl.
+    fn write_variant_default(
+        &mut self,
+        stream: &mut TokenStream,
+        e: &middle::rir::Enum,
+        v: &rir::EnumVariant,
+    ) {
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+        let variant = format_ident!("{}", &v.name.to_variant_case(self.naming()));
+        let ty = self.codegen_item_ty(v.fields[0].kind.clone());
+        let mut value = self.lit_into_ty(v.default.as_ref().unwrap(), &ty);
+        if self.is_boxed_variant(v) {
+            value = quote! { ::std::boxed::Box::new(#value) };
+        }
+
+        stream.extend(quote! {
+            impl ::std::default::Default for #name {
+                fn default() -> Self {
+                    #name::#variant(#value)
+                }
+            }
+        });
+    }
+
+    /// Open enums write their unknown values as numbers and parse any number back.
+    fn write_enum_string_conversions(
+        &self,
+        stream: &mut TokenStream,
+        e: &middle::rir::Enum,
+        is_open: bool,
+    ) {
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+        let enum_name = e.name.to_string();
+        let mut seen = FxHashSet::default();
+        let (variants, strs): (Vec<_>, Vec<_>) = e
+            .variants
+            .iter()
+            .map(|v| {
+                let s = v.original_name.to_string();
+                if !seen.insert(s.clone()) {
+                    panic!(
+                        "more than one variant of `{}` is written as `{}`",
+                        e.name, s
+                    )
+                }
+                (
+                    format_ident!("{}", &v.name.to_variant_case(self.naming())),
+                    s,
+                )
+            })
+            .unzip();
+
+        let err = quote! {
+            ::pilota::ParseEnumError {
+                enum_name: #enum_name,
+                name: s.to_string(),
+            }
+        };
+        let (display_unknown, parse_unknown) = if is_open {
+            (
+                quote! { #name::Unknown(v) => return ::std::fmt::Display::fmt(v, f), },
+                quote! { s => s.parse::<i32>().map(#name::from).map_err(|_| #err), },
+            )
+        } else {
+            (quote! {}, quote! { s => Err(#err), })
+        };
+
+        stream.extend(quote! {
+            impl ::std::fmt::Display for #name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(match self {
+                        #(#name::#variants => #strs,)*
+                        #display_unknown
+                    })
+                }
+            }
+
+            impl ::std::str::FromStr for #name {
+                type Err = ::pilota::ParseEnumError;
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    match s {
+                        #(#strs => Ok(#name::#variants),)*
+                        #parse_unknown
+                    }
+                }
+            }
+        });
+    }
+
+    fn write_oneof_from_impls(&self, stream: &mut TokenStream, e: &middle::rir::Enum) {
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+
+        let payloads = e
+            .variants
+            .iter()
+            .filter(|v| v.fields.len() == 1)
+            .map(|v| {
+                let ty = self.codegen_item_ty(v.fields[0].kind.clone());
+                (v, quote!(#ty))
+            })
+            .collect::<Vec<_>>();
+
+        payloads.iter().for_each(|(v, ty)| {
+            // `From` would be ambiguous for payloads shared by several variants
+            if payloads
+                .iter()
+                .filter(|(_, other)| other.to_string() == ty.to_string())
+                .count()
+                > 1
+            {
+                return;
+            }
+            let variant = format_ident!("{}", &v.name.to_variant_case(self.naming()));
+            let value = match self.is_boxed_variant(v) {
+                true => quote! { ::std::boxed::Box::new(v) },
+                false => quote! { v },
+            };
+            stream.extend(quote! {
+                impl ::std::convert::From<#ty> for #name {
+                    fn from(v: #ty) -> Self {
+                        #name::#variant(#value)
+                    }
+                }
+            });
+        });
+    }
+
+    fn write_oneof_dispatcher(&self, stream: &mut TokenStream, e: &middle::rir::Enum) {
+        let name = format_ident!("{}", &e.name.to_upper_camel_case(self.naming()));
+
+        let (handlers, arms): (Vec<_>, Vec<_>) = e
+            .variants
+            .iter()
+            .map(|v| {
+                let variant = format_ident!("{}", &v.name.to_variant_case(self.naming()));
+                let handler = format_ident!("on_{}", &**v.name.to_snake_case(self.naming()));
+                let boxed = self.is_boxed_variant(v);
+                let tys = v
+                    .fields
+                    .iter()
+                    .map(|ty| self.codegen_item_ty(ty.kind.clone()));
+                let values = (0..v.fields.len())
+                    .map(|i| format_ident!("v{}", i))
+                    .collect::<Vec<_>>();
+                let args = values.iter().map(|v| match boxed {
+                    true => quote! { *#v },
+                    false => quote! { #v },
+                });
+                (
+                    quote! { #handler: impl FnOnce(#(#tys),*) -> R },
+                    quote! { #name::#variant(#(#values),*) => #handler(#(#args),*), },
+                )
+            })
+            .unzip();
+
+        stream.extend(quote! {
+            impl #name {
+                /// Calls the handler of the variant, every variant must be handled.
+                pub fn map_variant<R>(self, #(#handlers),*) -> R {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        });
+    }
+
+    fn write_oneof_reexports(
+        &self,
+        def_id: DefId,
+        stream: &mut TokenStream,
+        e: &middle::rir::Enum,
+    ) {
+        // names which are already defined in the module of the oneof enum
+        let node = self.node(def_id).unwrap();
+        let siblings = match node.parent {
+            Some(parent) => match &*self.expect_item(parent) {
+                rir::Item::Mod(m) => m.items.clone(),
+                _ => vec![],
+            },
+            None => {
+                let file = self.file(node.file_id).unwrap();
+                self.pkg(file.package.clone()).unwrap().items.clone()
+            }
+        };
+        let mut names = siblings
+            .iter()
+            .map(|def_id| self.expect_item(*def_id))
+            .filter(|item| !matches!(&**item, rir::Item::Mod(_)))
+            .map(|item| item.symbol_name().to_upper_camel_case(self.naming()))
+            .collect::<FxHashSet<_>>();
+
+        e.variants.iter().flat_map(|v| &v.fields).for_each(|ty| {
+            let did = match &ty.kind {
+                middle::ty::Path(p) => p.did,
+                _ => return,
+            };
+            if !matches!(&*self.expect_item(did), rir::Item::Message(_)) {
+                return;
+            }
+            let path = self.cur_related_item_path(did);
+            // the payload is already visible
+            if path.segments.len() == 1 {
+                return;
+            }
+            let name = self.symbol_name(did).to_upper_camel_case(self.naming());
+            if !names.insert(name.clone()) {
+                tracing::warn!(
+                    "skip re-exporting {} for oneof {}, the name is already defined",
+                    name,
+                    e.name
+                );
+                return;
+            }
+            let vis = self.visibility().of_items();
+            stream.extend(quote! {
+                #vis use #path;
+            });
+        });
     }
 
     pub fn write_service(
@@ -190,12 +1187,22 @@ where
         stream: &mut TokenStream,
         s: &middle::rir::Service,
     ) {
-        let name = format_ident!("{}", s.name.to_upper_camel_case());
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
         let methods = self.service_methods(def_id);
 
-        let methods = methods
-            .iter()
-            .map(|m| self.backend.codegen_service_method(def_id, m));
+        let methods = methods.iter().filter_map(|m| {
+            let method = self.backend.codegen_service_method(def_id, m);
+            if method.is_empty() {
+                return None;
+            }
+            let deprecated = self.deprecated_attr(m.def_id);
+            let docs = self.doc_attrs(m.def_id);
+            Some(quote! {
+                #docs
+                #deprecated
+                #method
+            })
+        });
 
         stream.extend(quote::quote! {
             #[::async_trait::async_trait]
@@ -212,11 +1219,12 @@ where
         stream: &mut TokenStream,
         t: &middle::rir::NewType,
     ) {
-        let name = format_ident!("{}", &t.name.to_upper_camel_case());
+        let name = format_ident!("{}", &t.name.to_upper_camel_case(self.naming()));
         let ty = self.codegen_item_ty(t.ty.kind.clone());
+        let vis = self.visibility().of_items();
         stream.extend(quote::quote! {
             #[derive(Clone, PartialEq)]
-            pub struct #name(#ty);
+            #vis struct #name(#ty);
 
             impl ::std::ops::Deref for #name {
                 type Target = #ty;
@@ -233,22 +1241,35 @@ where
             }
         });
         self.backend.codegen_newtype_impl(def_id, stream, t);
+
+        if self.gen_to_json_value() {
+            self.write_new_type_json_value(stream, t);
+        }
     }
 
-    fn def_lit(&mut self, name: &str, lit: &Literal, ty: &CodegenTy) -> TokenStream {
+    fn def_lit(
+        &mut self,
+        name: &str,
+        lit: &Literal,
+        ty: &CodegenTy,
+        docs: TokenStream,
+    ) -> TokenStream {
         let should_lazy_static = ty.should_lazy_static();
         let name = format_ident!("{}", name.to_shouty_snake_case());
+        let vis = self.visibility().of_items();
         if should_lazy_static {
             let lit = self.lit_as_rvalue(lit, ty);
             quote::quote! {
                 ::pilota::lazy_static::lazy_static! {
-                    pub static ref #name: #ty = #lit;
+                    #docs
+                    #vis static ref #name: #ty = #lit;
                 }
             }
         } else {
             let lit = self.lit_into_ty(lit, ty);
             quote::quote! {
-                pub const #name: #ty = #lit;
+                #docs
+                #vis const #name: #ty = #lit;
             }
         }
     }
@@ -256,7 +1277,8 @@ where
     pub fn write_const(&mut self, did: DefId, stream: &mut TokenStream, c: &middle::rir::Const) {
         let ty = self.codegen_ty(did);
 
-        stream.extend(self.def_lit(&c.name, &c.lit, &ty))
+        let docs = self.doc_attrs(did);
+        stream.extend(self.def_lit(&c.name, &c.lit, &ty, docs))
     }
 
     fn ident_into_ty(
@@ -265,20 +1287,50 @@ where
         ident_ty: &CodegenTy,
         target: &CodegenTy,
     ) -> TokenStream {
+        if let CodegenTy::Arc(inner) = target {
+            let stream = self.ident_into_ty(did, ident_ty, inner);
+            return quote! { ::std::sync::Arc::new(#stream) };
+        }
+        let path = self.cur_related_item_path(did);
+        let mut stream = quote! { #path };
+        // the consts in a `lazy_static!` only deref to their type, so the value is cloned
+        let ident_ty = match ident_ty {
+            CodegenTy::LazyStaticRef(inner) => {
+                stream = quote! { (*#path).clone() };
+                &**inner
+            }
+            ty if ty.should_lazy_static()
+                && matches!(self.item(did).as_deref(), Some(rir::Item::Const(_))) =>
+            {
+                stream = quote! { (*#path).clone() };
+                ty
+            }
+            ty => ty,
+        };
+        let target = match target {
+            CodegenTy::LazyStaticRef(inner) => &**inner,
+            target => target,
+        };
         if ident_ty == target {
-            let stream = self.cur_related_item_path(did);
             return quote! { #stream };
         }
+        if let (CodegenTy::Str, CodegenTy::String) = (ident_ty, target) {
+            return quote! { #stream.to_string() };
+        }
+        if let (CodegenTy::Str, CodegenTy::Custom(..)) = (ident_ty, target) {
+            return quote! { ::std::convert::From::from(#stream) };
+        }
         panic!("invalid convert {:?} to {:?}", ident_ty, target)
     }
 
     fn lit_as_rvalue(&mut self, lit: &Literal, ty: &CodegenTy) -> TokenStream {
         match (lit, ty) {
             (Literal::Map(m), CodegenTy::LazyStaticRef(map)) => match &**map {
-                CodegenTy::Map(k_ty, v_ty) => {
+                CodegenTy::Map(k_ty, v_ty, kind) => {
                     let k_ty = &**k_ty;
                     let v_ty = &**v_ty;
                     let len = m.len();
+                    let new_map = kind.with_capacity(kind.map_path(), quote!(#len));
                     let kvs = m.iter().map(|(k, v)| {
                         let k = self.lit_into_ty(k, k_ty);
                         let v = self.lit_into_ty(v, v_ty);
@@ -288,7 +1340,7 @@ where
                     });
                     let stream = quote::quote! {
                         {
-                            let mut map = ::std::collections::HashMap::with_capacity(#len);
+                            let mut map = #new_map;
                             #(#kvs)*
                             map
                         }
@@ -297,7 +1349,15 @@ where
                 }
                 _ => panic!("invalid map type {:?}", map),
             },
+            (
+                Literal::Map(_),
+                CodegenTy::Adt(AdtDef {
+                    kind: AdtKind::Struct,
+                    ..
+                }),
+            ) => self.lit_into_ty(lit, ty),
             (Literal::Map(_), _) => panic!(),
+            (Literal::List(_), CodegenTy::LazyStaticRef(inner)) => self.lit_into_ty(lit, inner),
             _ => self.lit_into_ty(lit, ty),
         }
     }
@@ -317,6 +1377,14 @@ where
                 let s = &**s;
                 quote! { #s.to_string() }
             }
+            (Literal::String(s), CodegenTy::Custom(..)) => {
+                let s = &**s;
+                quote! { ::std::convert::From::from(#s) }
+            }
+            (Literal::String(s), CodegenTy::Bytes) => {
+                let s = proc_macro2::Literal::byte_string(s.as_bytes());
+                quote! { ::pilota::bytes::Bytes::from_static(#s) }
+            }
             (Literal::Int(i), CodegenTy::I16) => {
                 let i = *i as i16;
                 quote! { #i }
@@ -329,10 +1397,30 @@ where
                 let i = *i as i64;
                 quote! { #i }
             }
+            (Literal::Int(i), CodegenTy::UInt32) => {
+                let i = *i as u32;
+                quote! { #i }
+            }
+            (Literal::Int(i), CodegenTy::UInt64) => {
+                let i = *i as u64;
+                quote! { #i }
+            }
+            (Literal::Float(f), CodegenTy::F32) => {
+                let f = f.parse::<f32>().unwrap();
+                quote! { #f }
+            }
+            (Literal::Int(i), CodegenTy::F64) => {
+                let f = *i as f64;
+                quote! { #f }
+            }
             (Literal::Float(f), CodegenTy::F64) => {
                 let f = f.parse::<f64>().unwrap();
                 quote! { #f }
             }
+            (l, CodegenTy::Arc(inner)) => {
+                let stream = self.lit_into_ty(l, inner);
+                quote! { ::std::sync::Arc::new(#stream) }
+            }
             (
                 l,
                 CodegenTy::Adt(AdtDef {
@@ -344,11 +1432,48 @@ where
                 let stream = self.lit_into_ty(l, inner_ty);
                 quote! { #ident(#stream) }
             }
-            // Literal::List(_) => todo!(),
+            (
+                Literal::Map(m),
+                CodegenTy::Adt(AdtDef {
+                    kind: AdtKind::Struct,
+                    did,
+                }),
+            ) => self.struct_lit_into_ty(*did, m),
+            (Literal::List(l), CodegenTy::Vec(inner)) => {
+                let items = l
+                    .iter()
+                    .map(|l| self.lit_into_ty(l, inner))
+                    .collect::<Vec<_>>();
+                quote! { ::std::vec![#(#items),*] }
+            }
+            (Literal::List(l), CodegenTy::Set(inner, kind)) => {
+                let items = l
+                    .iter()
+                    .map(|l| self.lit_into_ty(l, inner))
+                    .collect::<Vec<_>>();
+                let path = kind.set_path();
+                quote! { #path::from([#(#items),*]) }
+            }
+            (Literal::Map(m), CodegenTy::Map(k_ty, v_ty, kind)) => {
+                let kvs = m
+                    .iter()
+                    .map(|(k, v)| {
+                        let k = self.lit_into_ty(k, k_ty);
+                        let v = self.lit_into_ty(v, v_ty);
+                        quote! { (#k, #v) }
+                    })
+                    .collect::<Vec<_>>();
+                let path = kind.map_path();
+                quote! { #path::from([#(#kvs),*]) }
+            }
             (Literal::Map(_), CodegenTy::StaticRef(map)) => match &**map {
-                CodegenTy::Map(_, _) => {
-                    let lazy_map =
-                        self.def_lit("inner_map", lit, &CodegenTy::LazyStaticRef(map.clone()));
+                CodegenTy::Map(..) => {
+                    let lazy_map = self.def_lit(
+                        "inner_map",
+                        lit,
+                        &CodegenTy::LazyStaticRef(map.clone()),
+                        TokenStream::new(),
+                    );
                     let stream = quote::quote! {
                         {
                             #lazy_map
@@ -363,18 +1488,170 @@ where
         }
     }
 
+    fn field_lit(&mut self, f: &rir::Field, lit: &Literal) -> TokenStream {
+        let ty = self.codegen_field_ty(&f.ty);
+        let mut v = self.lit_into_ty(lit, &ty);
+        if self.adjust(f.did).is_some_and(|a| a.boxed()) {
+            v = quote! { ::std::boxed::Box::new(#v) }
+        }
+        if f.is_optional() {
+            v = quote! { Some(#v) }
+        }
+        v
+    }
+
+    fn struct_lit_into_ty(&mut self, did: DefId, kvs: &[(Literal, Literal)]) -> TokenStream {
+        let item = self.expect_item(did);
+        let s = match &*item {
+            rir::Item::Message(s) => s,
+            _ => panic!("invalid struct literal for {:?}", item),
+        };
+
+        let naming = self.naming().clone();
+        let lit_field_name = |k: &Literal| match k {
+            Literal::String(k) => Symbol::from(k.clone()).to_field_case(&naming),
+            _ => panic!("invalid field name {:?} in struct literal of {}", k, s.name),
+        };
+
+        if let Some((k, _)) = kvs
+            .iter()
+            .find(|(k, _)| !s.fields.iter().any(|f| **f.name == *lit_field_name(k)))
+        {
+            panic!("unknown field {:?} in struct literal of {}", k, s.name)
+        }
+
+        let mut fields = Vec::with_capacity(s.fields.len());
+        for f in &s.fields {
+            let name = format_ident!("{}", f.name.to_field_case(self.naming()));
+            let v = kvs
+                .iter()
+                .find(|(k, _)| **f.name == *lit_field_name(k))
+                .map(|(_, v)| v);
+
+            let v = match v.or(f.default.as_ref()) {
+                Some(v) => self.field_lit(f, v),
+                None if f.is_optional() => quote! { None },
+                None => panic!(
+                    "required field {} is missing in struct literal of {}",
+                    f.name, s.name
+                ),
+            };
+
+            fields.push(quote! { #name: #v, });
+        }
+
+        fields.push(self.extra_field_inits());
+
+        let path = self.cur_related_item_path(did);
+        quote! {
+            #path {
+                #(#fields)*
+            }
+        }
+    }
+
     pub fn write_pkgs(&mut self, pkgs: &[ItemPath]) {
         pkgs.iter().for_each(|p| {
             let stream: &mut TokenStream =
                 unsafe { std::mem::transmute(self.pkgs.entry(p.clone()).or_default()) };
             let pkg = self.pkg(p.clone()).unwrap();
 
+            if let Some(doc) = self.module_doc(p) {
+                let lines = doc.lines().map(|l| format!(" {}", l));
+                stream.extend(quote! { #(#![doc = #lines])* });
+            }
+
+            for (name, target) in &pkg.uses {
+                let path = self.pkg_use_path(&pkg.path, target);
+                let name = format_ident!("{}", name);
+                let vis = self.visibility().of_items();
+                stream.extend(quote! { #vis use #path as #name; });
+            }
+
             for def_id in &pkg.items {
                 self.write_item(stream, *def_id)
             }
+
+            if self.gen_any_message() {
+                self.write_any_message(stream, &pkg.items);
+            }
         })
     }
 
+    fn write_any_message(&self, stream: &mut TokenStream, items: &[DefId]) {
+        let names = items
+            .iter()
+            .map(|def_id| (*def_id, self.expect_item(*def_id)))
+            .filter(|(_, item)| !matches!(&**item, rir::Item::Mod(_)))
+            .map(|(def_id, item)| {
+                (
+                    def_id,
+                    item.symbol_name().to_upper_camel_case(self.naming()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if names.iter().any(|(_, name)| &**name == "AnyMessage") {
+            tracing::warn!("skip generating AnyMessage, the name is already defined");
+            return;
+        }
+
+        let variants = names
+            .into_iter()
+            .filter(|(def_id, _)| {
+                matches!(&*self.expect_item(*def_id), rir::Item::Message(_))
+                    && !self.node_contains_tag::<MethodArgs>(*def_id)
+            })
+            .map(|(def_id, name)| {
+                (
+                    any_message_tag(&self.idl_name(def_id)),
+                    format_ident!("{}", name),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if variants.is_empty() {
+            return;
+        }
+
+        let mut seen = FxHashMap::default();
+        for (tag, name) in &variants {
+            if let Some(other) = seen.insert(*tag, name) {
+                panic!("the AnyMessage tags of `{other}` and `{name}` collide: {tag}");
+            }
+        }
+
+        let names = variants.iter().map(|(_, name)| name).collect::<Vec<_>>();
+        let vis = self.visibility().of_items();
+        let tags = variants
+            .iter()
+            .map(|(tag, _)| proc_macro2::Literal::i32_unsuffixed(*tag));
+
+        stream.extend(quote! {
+            /// All the messages of the package, tagged by a hash of their full names.
+            #[derive(Debug, Clone, PartialEq)]
+            #vis enum AnyMessage {
+                #(#names(#names),)*
+            }
+
+            impl AnyMessage {
+                pub fn tag(&self) -> i32 {
+                    match self {
+                        #(AnyMessage::#names(_) => #tags,)*
+                    }
+                }
+            }
+
+            #(impl ::std::convert::From<#names> for AnyMessage {
+                fn from(v: #names) -> Self {
+                    AnyMessage::#names(v)
+                }
+            })*
+        });
+
+        self.backend.codegen_any_message_impl(stream, &variants);
+    }
+
     pub fn link(mut self, ns_name: &str) -> TokenStream {
         fn write_stream(
             pkgs: &mut FxHashMap<ItemPath, TokenStream>,
@@ -397,18 +1674,71 @@ where
                 });
             }
         }
-        let mut stream = TokenStream::default();
+        // the package flattened to the root of the output
+        let mut stream = self
+            .pkgs
+            .remove(&ItemPath::from(Vec::new()))
+            .unwrap_or_default();
         let pkg_node = PkgNode::from_pkgs(&self.pkgs.keys().cloned().collect::<Vec<_>>());
 
         write_stream(&mut self.pkgs, &mut stream, &pkg_node);
 
         let ns_name = format_ident!("{}", ns_name);
+        // the generated code uses the deprecated definitions itself
+        let deprecated = self
+            .nodes()
+            .values()
+            .any(|node| self.contains_tag::<Deprecated>(node.tags))
+            .then(|| quote!(#![allow(deprecated)]));
+        let case = match self.naming().field_case {
+            FieldCase::SnakeCase => None,
+            FieldCase::Preserve => Some(quote!(#![allow(non_snake_case, non_camel_case_types)])),
+            FieldCase::CamelCase => Some(quote!(#![allow(non_snake_case)])),
+        };
 
-        quote! {
+        if !self.gen_doc_stubs() {
+            return quote! {
+                pub mod #ns_name {
+                    #![allow(unused_variables, dead_code, missing_docs, clippy::unused_unit, clippy::needless_borrow, unused_mut)]
+                    #deprecated
+                    #case
+                    #stream
+                }
+            };
+        }
+
+        let mut file: syn::File = parse_quote! {
             pub mod #ns_name {
-                #![allow(unused_variables, dead_code, missing_docs, clippy::unused_unit, clippy::needless_borrow, unused_mut)]
+                #![allow(unused_variables, dead_code, clippy::unused_unit, clippy::needless_borrow, unused_mut)]
+                #deprecated
+                #case
                 #stream
             }
-        }
+        };
+        syn::visit_mut::VisitMut::visit_file_mut(&mut doc_stubs::DocStubs, &mut file);
+        file.into_token_stream()
     }
 }
+
+/// The discriminants of the variants, a variant without one takes the one after the previous
+/// variant, starting from 0, like in thrift and Rust.
+fn discriminants(e: &middle::rir::Enum) -> Vec<i64> {
+    let mut next = 0;
+    e.variants
+        .iter()
+        .map(|v| {
+            let discr = v.discr.unwrap_or(next);
+            next = discr + 1;
+            discr
+        })
+        .collect()
+}
+
+/// The tag of a message in `AnyMessage`: the 32-bit FNV-1a hash of its full name, without the
+/// sign bit, so that the tags stay the same when the messages are added, removed or reordered.
+fn any_message_tag(full_name: &str) -> i32 {
+    let hash = full_name.bytes().fold(0x811c9dc5u32, |hash, b| {
+        (hash ^ b as u32).wrapping_mul(0x01000193)
+    });
+    (hash & 0x7fff_ffff) as i32
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
 use itertools::Itertools;
+use proc_macro2::Span;
+use quote::format_ident;
+use syn::PathSegment;
 
 use crate::{middle::rir::ItemPath, symbol::Symbol};
 
@@ -11,27 +14,35 @@ pub struct PkgNode {
 }
 
 fn from_pkgs(base_path: &[Symbol], pkgs: &[ItemPath]) -> Arc<[PkgNode]> {
-    let groups = pkgs.iter().into_group_map_by(|p| p.first().unwrap());
+    let groups = pkgs
+        .iter()
+        .filter(|p| !p.is_empty())
+        .into_group_map_by(|p| p.first().unwrap());
 
-    Arc::from_iter(groups.into_iter().map(|(k, v)| {
-        let path = base_path
-            .iter()
-            .chain(Some(k).into_iter())
-            .cloned()
-            .collect::<Vec<_>>();
-
-        let pkgs = v
+    Arc::from_iter(
+        groups
             .into_iter()
-            .filter(|p| p.len() > 1)
-            .map(|p| ItemPath::from(&p[1..]))
-            .collect::<Vec<_>>();
-
-        let children = from_pkgs(&path, &pkgs);
-        PkgNode {
-            path: ItemPath::from(path),
-            children,
-        }
-    }))
+            .sorted_by(|a, b| a.0.cmp(b.0))
+            .map(|(k, v)| {
+                let path = base_path
+                    .iter()
+                    .chain(Some(k).into_iter())
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let pkgs = v
+                    .into_iter()
+                    .filter(|p| p.len() > 1)
+                    .map(|p| ItemPath::from(&p[1..]))
+                    .collect::<Vec<_>>();
+
+                let children = from_pkgs(&path, &pkgs);
+                PkgNode {
+                    path: ItemPath::from(path),
+                    children,
+                }
+            }),
+    )
 }
 
 impl PkgNode {
@@ -42,4 +53,60 @@ impl PkgNode {
     pub fn ident(&self) -> Symbol {
         self.path.last().unwrap().clone()
     }
+
+    /// The path of `target` from the module of this package, such as `super::d::E` for `a.d.E`
+    /// from `a.b`.
+    pub fn rust_path_to(&self, target: &ItemPath) -> syn::Path {
+        related_path(&self.path, target)
+    }
+}
+
+/// How the generated code refers to the items of the modules other than its own and their
+/// descendants.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PathBase {
+    /// Relative paths going through `super`, which work wherever the output is included.
+    #[default]
+    Relative,
+    /// Absolute paths for an output included at the root of the crate, such as
+    /// `crate::out::a::b::C`.
+    Crate,
+    /// Absolute paths for an output included in the given module, such as `crate::proto` or
+    /// `::protos`.
+    Module(Arc<str>),
+}
+
+impl PathBase {
+    /// The path of the module named `ns_name` which wraps the output, `None` for relative paths.
+    pub(crate) fn root_path(&self, ns_name: &str) -> Option<syn::Path> {
+        let base = match self {
+            PathBase::Relative => return None,
+            PathBase::Crate => "crate",
+            PathBase::Module(module) => module,
+        };
+        Some(
+            syn::parse_str(&format!("{base}::{ns_name}"))
+                .unwrap_or_else(|_| panic!("invalid module path `{base}`")),
+        )
+    }
+}
+
+/// The path of `to` from the module `from`, made of `super`s only as the generated code may be
+/// included in any module of a crate.
+pub(crate) fn related_path(from: &[Symbol], to: &[Symbol]) -> syn::Path {
+    if from == to {
+        return syn::Path::from(format_ident!("{}", to.last().unwrap()));
+    }
+    let common = from.iter().zip(to).take_while(|(a, b)| a == b).count();
+
+    let supers =
+        (common..from.len()).map(|_| PathSegment::from(syn::token::Super(Span::call_site())));
+    let idents = to[common..]
+        .iter()
+        .map(|s| PathSegment::from(format_ident!("{}", s)));
+
+    syn::Path {
+        leading_colon: None,
+        segments: supers.chain(idents).collect(),
+    }
 }
@@ -1,20 +1,24 @@
 use std::sync::Arc;
 
 use itertools::Itertools;
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::parse_quote;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, LitByteStr};
 
 use crate::{
     db::RirDatabase,
-    middle::ty::{self, Ty},
+    middle::ty::{self, CollectionKind, Ty},
     rir::{self, Field, FieldKind},
-    tags::protobuf::{Fixed32, Fixed64, OneOf, SFixed32, SFixed64, SInt32, SInt64},
+    symbol::DefId,
+    tags::protobuf::{
+        ClientStreaming, DefaultValue, Enumeration, Fixed32, Fixed64, FullName, OneOf, Packed,
+        SFixed32, SFixed64, SInt32, SInt64, ServerStreaming,
+    },
     CodegenBackend, Context,
 };
 
 pub struct ProtobufBackend {
-    _cx: Arc<Context>,
+    cx: Arc<Context>,
     #[allow(dead_code)]
     zero_copy: bool,
 }
@@ -22,13 +26,657 @@ pub struct ProtobufBackend {
 impl ProtobufBackend {
     pub(crate) fn new(cx: Arc<Context>) -> Self {
         ProtobufBackend {
-            _cx: cx,
+            cx,
             zero_copy: false,
         }
     }
+
+    /// The wire type of a field of `ty`, the repeated fields are length delimited as they are
+    /// packed unless their `Packed` tag says otherwise.
+    fn wire_type(&self, ty: &Ty) -> Ident {
+        let wire_type = match &ty.kind {
+            ty::I32 if self.cx.contains_tag::<SFixed32>(ty.tags_id) => "ThirtyTwoBit",
+            ty::I64 if self.cx.contains_tag::<SFixed64>(ty.tags_id) => "SixtyFourBit",
+            ty::UInt32 if self.cx.contains_tag::<Fixed32>(ty.tags_id) => "ThirtyTwoBit",
+            ty::UInt64 if self.cx.contains_tag::<Fixed64>(ty.tags_id) => "SixtyFourBit",
+            ty::Bool | ty::I32 | ty::I64 | ty::UInt32 | ty::UInt64 => "Varint",
+            ty::F32 => "ThirtyTwoBit",
+            ty::F64 => "SixtyFourBit",
+            ty::Path(p) if matches!(&*self.cx.expect_item(p.did), rir::Item::Enum(_)) => "Varint",
+            ty::Arc(ty) => return self.wire_type(ty),
+            _ => "LengthDelimited",
+        };
+        format_ident!("{}", wire_type)
+    }
+
+    /// The module of `prost::encoding` encoding a value of `ty`.
+    fn encoding_module(&self, ty: &Ty) -> Ident {
+        let module = match &ty.kind {
+            ty::String => "string",
+            ty::Bool => "bool",
+            ty::Bytes => "bytes",
+            ty::I32 if self.cx.contains_tag::<SInt32>(ty.tags_id) => "sint32",
+            ty::I64 if self.cx.contains_tag::<SInt64>(ty.tags_id) => "sint64",
+            ty::I32 if self.cx.contains_tag::<SFixed32>(ty.tags_id) => "sfixed32",
+            ty::I64 if self.cx.contains_tag::<SFixed64>(ty.tags_id) => "sfixed64",
+            ty::I32 => "int32",
+            ty::I64 => "int64",
+            ty::UInt32 if self.cx.contains_tag::<Fixed32>(ty.tags_id) => "fixed32",
+            ty::UInt64 if self.cx.contains_tag::<Fixed64>(ty.tags_id) => "fixed64",
+            ty::UInt32 => "uint32",
+            ty::UInt64 => "uint64",
+            ty::F32 => "float",
+            ty::F64 => "double",
+            ty::Path(_) => "message",
+            ty::Arc(ty) => return self.encoding_module(ty),
+            _ => unreachable!(),
+        };
+        format_ident!("{}", module)
+    }
+
+    /// What prost derives for the messages, plus reading the fields with unknown tags into
+    /// `_unknown_fields` and writing them back after the known ones.
+    fn codegen_message_impl(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.cx.naming()));
+        let name_str = &**s.name;
+
+        let mut fields = s.fields.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|f| self.field_tags(f).into_iter().min());
+
+        let mut encode = Vec::with_capacity(fields.len());
+        let mut merge = Vec::with_capacity(fields.len());
+        let mut encoded_len = Vec::with_capacity(fields.len());
+        let mut clear = Vec::with_capacity(fields.len());
+        for f in fields {
+            let ident = format_ident!("{}", f.name.to_field_case(self.cx.naming()));
+            let field_str = ident.to_string();
+            let tags = self.field_tags(f);
+            let tag = tags[0];
+            let (e, m, l) = match &f.ty.kind {
+                ty::Path(p) if self.cx.node_contains_tag::<OneOf>(p.did) => {
+                    let ty = self.cx.codegen_item_ty(f.ty.kind.clone());
+                    (
+                        quote! {
+                            if let ::std::option::Option::Some(oneof) = &self.#ident {
+                                oneof.encode(buf);
+                            }
+                        },
+                        quote!(<#ty>::merge(&mut self.#ident, tag, wire_type, buf, ctx)),
+                        quote!(self.#ident.as_ref().map_or(0, |oneof| oneof.encoded_len())),
+                    )
+                }
+                ty::Map(k, v) => {
+                    let map = match self.cx.collection_kind() {
+                        CollectionKind::Hash => quote!(hash_map),
+                        CollectionKind::BTree => quote!(btree_map),
+                        CollectionKind::Index => panic!("prost does not support `IndexMap` fields"),
+                    };
+                    let k = self.encoding_module(k);
+                    let v = self.encoding_module(v);
+                    (
+                        quote! {
+                            ::prost::encoding::#map::encode(
+                                ::prost::encoding::#k::encode,
+                                ::prost::encoding::#k::encoded_len,
+                                ::prost::encoding::#v::encode,
+                                ::prost::encoding::#v::encoded_len,
+                                #tag,
+                                &self.#ident,
+                                buf,
+                            );
+                        },
+                        quote! {
+                            ::prost::encoding::#map::merge(
+                                ::prost::encoding::#k::merge,
+                                ::prost::encoding::#v::merge,
+                                &mut self.#ident,
+                                buf,
+                                ctx,
+                            )
+                        },
+                        quote! {
+                            ::prost::encoding::#map::encoded_len(
+                                ::prost::encoding::#k::encoded_len,
+                                ::prost::encoding::#v::encoded_len,
+                                #tag,
+                                &self.#ident,
+                            )
+                        },
+                    )
+                }
+                ty::Vec(el) => {
+                    let module = self.encoding_module(el);
+                    let packed = self
+                        .cx
+                        .node_tags(f.did)
+                        .get::<Packed>()
+                        .map(|p| p.0)
+                        .unwrap_or(!matches!(
+                            &*module.to_string(),
+                            "string" | "bytes" | "message"
+                        ));
+                    let (encode_fn, len_fn) = if packed {
+                        (quote!(encode_packed), quote!(encoded_len_packed))
+                    } else {
+                        (quote!(encode_repeated), quote!(encoded_len_repeated))
+                    };
+                    (
+                        quote!(::prost::encoding::#module::#encode_fn(#tag, &self.#ident, buf);),
+                        quote! {
+                            ::prost::encoding::#module::merge_repeated(wire_type, &mut self.#ident, buf, ctx)
+                        },
+                        quote!(::prost::encoding::#module::#len_fn(#tag, &self.#ident)),
+                    )
+                }
+                _ => {
+                    let module = self.encoding_module(&f.ty);
+                    match f.kind {
+                        FieldKind::Optional => (
+                            quote! {
+                                if let ::std::option::Option::Some(value) = &self.#ident {
+                                    ::prost::encoding::#module::encode(#tag, value, buf);
+                                }
+                            },
+                            quote! {
+                                ::prost::encoding::#module::merge(
+                                    wire_type,
+                                    self.#ident.get_or_insert_with(::std::default::Default::default),
+                                    buf,
+                                    ctx,
+                                )
+                            },
+                            quote! {
+                                self.#ident.as_ref().map_or(0, |value| {
+                                    ::prost::encoding::#module::encoded_len(#tag, value)
+                                })
+                            },
+                        ),
+                        _ => {
+                            // the implicit fields are not written with their default values
+                            let present = match (&f.kind, &f.ty.kind) {
+                                (FieldKind::Implicit, ty::String | ty::Bytes) => {
+                                    Some(quote!(!self.#ident.is_empty()))
+                                }
+                                (FieldKind::Implicit, ty::Bool) => Some(quote!(self.#ident)),
+                                (FieldKind::Implicit, ty::F32 | ty::F64) => {
+                                    Some(quote!(self.#ident != 0.0))
+                                }
+                                (FieldKind::Implicit, ty::Path(_)) => None,
+                                (FieldKind::Implicit, _) => Some(quote!(self.#ident != 0)),
+                                _ => None,
+                            };
+                            let encode = quote!(::prost::encoding::#module::encode(#tag, &self.#ident, buf););
+                            let len =
+                                quote!(::prost::encoding::#module::encoded_len(#tag, &self.#ident));
+                            match present {
+                                Some(present) => (
+                                    quote!(if #present { #encode }),
+                                    quote! {
+                                        ::prost::encoding::#module::merge(wire_type, &mut self.#ident, buf, ctx)
+                                    },
+                                    quote!(if #present { #len } else { 0 }),
+                                ),
+                                None => (
+                                    encode,
+                                    quote! {
+                                        ::prost::encoding::#module::merge(wire_type, &mut self.#ident, buf, ctx)
+                                    },
+                                    len,
+                                ),
+                            }
+                        }
+                    }
+                }
+            };
+            encode.push(e);
+            merge.push(quote! {
+                #(#tags)|* => #m.map_err(|mut error| {
+                    error.push(#name_str, #field_str);
+                    error
+                }),
+            });
+            encoded_len.push(l);
+            let default = match f.kind {
+                FieldKind::Optional => None,
+                _ => self.default_value(def_id, f).map(|(owned, _)| owned),
+            }
+            .unwrap_or_else(|| quote!(::std::default::Default::default()));
+            clear.push(quote!(self.#ident = #default;));
+        }
+
+        stream.extend(quote! {
+            impl ::prost::Message for #name {
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::prost::bytes::BufMut,
+                {
+                    #(#encode)*
+                    self._unknown_fields.encode_raw(buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::prost::encoding::DecodeContext,
+                ) -> ::std::result::Result<(), ::prost::DecodeError>
+                where
+                    B: ::prost::bytes::Buf,
+                {
+                    match tag {
+                        #(#merge)*
+                        _ => self
+                            ._unknown_fields
+                            .merge_field(tag, wire_type as u8, buf)
+                            .map_err(::prost::DecodeError::new),
+                    }
+                }
+
+                fn encoded_len(&self) -> usize {
+                    0 #(+ #encoded_len)* + self._unknown_fields.encoded_len()
+                }
+
+                fn clear(&mut self) {
+                    #(#clear)*
+                    self._unknown_fields.clear();
+                }
+            }
+        });
+    }
+
+    /// The `Default` prost derives for the messages with default values in the IDL.
+    fn codegen_default_impl(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.cx.naming()));
+        let fields = s.fields.iter().map(|f| {
+            let ident = format_ident!("{}", f.name.to_field_case(self.cx.naming()));
+            let default = match f.kind {
+                FieldKind::Optional => None,
+                _ => self.default_value(def_id, f).map(|(owned, _)| owned),
+            }
+            .unwrap_or_else(|| quote!(::std::default::Default::default()));
+            quote!(#ident: #default,)
+        });
+        let extra_fields = self.cx.extra_field_inits();
+        stream.extend(quote! {
+            impl ::std::default::Default for #name {
+                fn default() -> Self {
+                    #name {
+                        #(#fields)*
+                        #extra_fields
+                    }
+                }
+            }
+        });
+    }
+
+    /// The getters prost derives for the optional scalar fields and the getters and setters of
+    /// the enum fields, which are `i32`.
+    fn codegen_prost_accessors(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        let accessors = s
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let ident = format_ident!("{}", f.name.to_field_case(self.cx.naming()));
+                let name = &**f.name.to_field_case(self.cx.naming());
+                let default = self.default_value(def_id, f).map(|(_, borrowed)| borrowed);
+
+                if let Some(Enumeration(e)) = self.cx.node_tags(f.did).get::<Enumeration>() {
+                    let path = self.cx.related_item_path(def_id, *e);
+                    let default = default.unwrap_or_else(|| quote!(#path::default()));
+                    let set = format_ident!("set_{}", name);
+                    let set_doc = format!("Sets `{name}` to the provided enum value.");
+                    return Some(match f.kind {
+                        FieldKind::Optional => {
+                            let get_doc = format!(
+                                "Returns the enum value of `{name}`, or the default if the field \
+                                 is unset or set to an invalid enum value."
+                            );
+                            quote! {
+                                #[doc = #get_doc]
+                                pub fn #ident(&self) -> #path {
+                                    self.#ident.and_then(#path::from_i32).unwrap_or(#default)
+                                }
+
+                                #[doc = #set_doc]
+                                pub fn #set(&mut self, value: #path) {
+                                    self.#ident = ::std::option::Option::Some(value as i32);
+                                }
+                            }
+                        }
+                        _ => {
+                            let get_doc = format!(
+                                "Returns the enum value of `{name}`, or the default if the field \
+                                 is set to an invalid enum value."
+                            );
+                            quote! {
+                                #[doc = #get_doc]
+                                pub fn #ident(&self) -> #path {
+                                    #path::from_i32(self.#ident).unwrap_or(#default)
+                                }
+
+                                #[doc = #set_doc]
+                                pub fn #set(&mut self, value: #path) {
+                                    self.#ident = value as i32;
+                                }
+                            }
+                        }
+                    });
+                }
+
+                if !matches!(f.kind, FieldKind::Optional) {
+                    return None;
+                }
+                let (ty, value, default) = match &f.ty.kind {
+                    ty::String => (
+                        quote!(&str),
+                        quote!(&value[..]),
+                        default.unwrap_or(quote!("")),
+                    ),
+                    ty::Bytes => (
+                        quote!(&[u8]),
+                        quote!(&value[..]),
+                        default.unwrap_or(quote!(&[])),
+                    ),
+                    ty::Bool | ty::I32 | ty::I64 | ty::UInt32 | ty::UInt64 | ty::F32 | ty::F64 => (
+                        self.cx.codegen_item_ty(f.ty.kind.clone()).to_token_stream(),
+                        quote!(*value),
+                        default.unwrap_or(quote!(::std::default::Default::default())),
+                    ),
+                    _ => return None,
+                };
+                let doc = format!(
+                    "Returns the value of `{name}`, or the default value if `{name}` is unset."
+                );
+                Some(quote! {
+                    #[doc = #doc]
+                    pub fn #ident(&self) -> #ty {
+                        match &self.#ident {
+                            ::std::option::Option::Some(value) => #value,
+                            ::std::option::Option::None => #default,
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.cx.naming()));
+        stream.extend(quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        });
+    }
+
+    /// The value of the `default` option of a proto2 field, both owned and as returned by its
+    /// getter.
+    fn default_value(&self, def_id: DefId, f: &Field) -> Option<(TokenStream, TokenStream)> {
+        let tags = self.cx.node_tags(f.did);
+        let value = &*tags.get::<DefaultValue>()?.0;
+
+        if let Some(Enumeration(e)) = tags.get::<Enumeration>() {
+            let path = self.cx.related_item_path(def_id, *e);
+            let variant = format_ident!("{}", enum_variant(&self.cx, *e, value));
+            return Some((quote!(#path::#variant as i32), quote!(#path::#variant)));
+        }
+
+        Some(match &f.ty.kind {
+            ty::String => (quote!(#value.into()), quote!(#value)),
+            ty::Bytes => {
+                let lit = LitByteStr::new(&unescape_bytes(value), Span::call_site());
+                (quote!((&#lit[..]).into()), quote!(&#lit[..]))
+            }
+            ty::F32 | ty::F64 => {
+                let ty = if matches!(f.ty.kind, ty::F32) {
+                    quote!(f32)
+                } else {
+                    quote!(f64)
+                };
+                let value = match value {
+                    "inf" => quote!(#ty::INFINITY),
+                    "-inf" => quote!(#ty::NEG_INFINITY),
+                    "nan" => quote!(#ty::NAN),
+                    // a whole number is written without the fraction
+                    _ if !value.contains(['.', 'e', 'E']) => format!("{value}.0").parse().unwrap(),
+                    _ => value.parse().unwrap(),
+                };
+                (value.clone(), value)
+            }
+            _ => {
+                let value: TokenStream = value.parse().unwrap();
+                (value.clone(), value)
+            }
+        })
+    }
+
+    /// The tags of a field, all the ones of the variants for a oneof field.
+    fn field_tags(&self, f: &Field) -> Vec<u32> {
+        match &f.ty.kind {
+            ty::Path(p) if self.cx.node_contains_tag::<OneOf>(p.did) => {
+                match &*self.cx.expect_item(p.did) {
+                    rir::Item::Enum(e) => e.variants.iter().map(|v| v.id.unwrap() as u32).collect(),
+                    _ => unreachable!(),
+                }
+            }
+            _ => vec![f.id as u32],
+        }
+    }
+}
+
+impl CodegenBackend for ProtobufBackend {
+    fn codegen_field_wire_types(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.cx.naming()));
+        let entries = s.fields.iter().flat_map(|f| match &f.ty.kind {
+            // a oneof field holds the tags of all its variants
+            ty::Path(p) if self.cx.node_contains_tag::<OneOf>(p.did) => {
+                match &*self.cx.expect_item(p.did) {
+                    rir::Item::Enum(e) => e
+                        .variants
+                        .iter()
+                        .map(|v| (v.id.unwrap() as u32, self.wire_type(&v.fields[0])))
+                        .collect::<Vec<_>>(),
+                    _ => unreachable!(),
+                }
+            }
+            // the elements of an unpacked repeated field are written one by one
+            ty::Vec(el)
+                if matches!(
+                    self.cx.node_tags(f.did).get::<Packed>(),
+                    Some(Packed(false))
+                ) =>
+            {
+                vec![(f.id as u32, self.wire_type(el))]
+            }
+            _ => vec![(f.id as u32, self.wire_type(&f.ty))],
+        });
+        let entries = entries.map(|(id, wire_type)| {
+            let id = proc_macro2::Literal::u32_unsuffixed(id);
+            quote! { (#id, ::prost::encoding::WireType::#wire_type) }
+        });
+        stream.extend(quote! {
+            impl #name {
+                pub const FIELD_WIRE_TYPES: &'static [(u32, ::prost::encoding::WireType)] = &[#(#entries),*];
+            }
+        });
+    }
+
+    fn codegen_unknown_fields_ty(&self) -> TokenStream {
+        quote!(::pilota::protobuf::UnknownFields)
+    }
+
+    fn codegen_any_message_impl(&self, stream: &mut TokenStream, variants: &[(i32, Ident)]) {
+        let tags = variants
+            .iter()
+            .map(|(tag, _)| proc_macro2::Literal::i32_unsuffixed(*tag));
+        let names = variants.iter().map(|(_, name)| name).collect::<Vec<_>>();
+
+        stream.extend(quote! {
+            impl AnyMessage {
+                pub fn decode_by_tag(
+                    tag: i32,
+                    buf: impl ::prost::bytes::Buf,
+                ) -> ::std::result::Result<Self, ::prost::DecodeError> {
+                    match tag {
+                        #(#tags => Ok(AnyMessage::#names(::prost::Message::decode(buf)?)),)*
+                        _ => Err(::prost::DecodeError::new(format!("unknown message tag {}", tag))),
+                    }
+                }
+
+                pub fn encode(
+                    &self,
+                    buf: &mut impl ::prost::bytes::BufMut,
+                ) -> ::std::result::Result<(), ::prost::EncodeError> {
+                    match self {
+                        #(AnyMessage::#names(v) => ::prost::Message::encode(v, buf),)*
+                    }
+                }
+            }
+        });
+    }
+
+    fn codegen_service_method(&self, _service_def_id: DefId, m: &rir::Method) -> TokenStream {
+        if !self.cx.gen_service_methods() {
+            return TokenStream::default();
+        }
+        let name = format_ident!("{}", m.name.to_snake_case(self.cx.naming()));
+        let codegen_ty = |ty: &Ty| {
+            if self.cx.contains_tag::<ClientStreaming>(ty.tags_id)
+                || self.cx.contains_tag::<ServerStreaming>(ty.tags_id)
+            {
+                self.cx.codegen_stream_ty(ty.clone())
+            } else {
+                self.cx.codegen_item_ty(ty.kind.clone())
+            }
+        };
+        let ret_ty = codegen_ty(&m.ret);
+        let args = m.args.iter().map(|a| {
+            let ty = codegen_ty(&a.ty);
+            let ident = format_ident!("{}", a.name.to_field_case(self.cx.naming()));
+            quote! {
+                #ident: #ty
+            }
+        });
+
+        quote! {
+            async fn #name(&self, #(#args),*) -> ::core::result::Result<#ret_ty, ::pilota::AnyhowError>;
+        }
+    }
+
+    fn codegen_struct_impl(&self, def_id: DefId, stream: &mut TokenStream, s: &rir::Message) {
+        if self.cx.preserve_unknown_fields() {
+            self.codegen_message_impl(def_id, stream, s);
+            if has_default_values(&self.cx, s) && !self.cx.default_impl().is_manual(s) {
+                self.codegen_default_impl(def_id, stream, s);
+            }
+            self.codegen_prost_accessors(def_id, stream, s);
+        }
+
+        if !self.cx.gen_wire_names() {
+            return;
+        }
+        let full_name = match self.cx.node_tags(def_id).get::<FullName>() {
+            Some(n) => n.0.clone(),
+            None => return,
+        };
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.cx.naming()));
+        let wire_name = &*full_name;
+
+        stream.extend(quote! {
+            impl ::pilota::protobuf::WireName for #name {
+                const WIRE_NAME: &'static str = #wire_name;
+            }
+        });
+
+        if wire_name == "google.protobuf.Any" {
+            stream.extend(quote! {
+                impl #name {
+                    pub fn pack<T: ::prost::Message + ::pilota::protobuf::WireName>(msg: &T) -> Self {
+                        #name {
+                            type_url: T::type_url(),
+                            value: msg.encode_to_vec().into(),
+                        }
+                    }
+
+                    /// Returns `None` if the packed message is not a `T`.
+                    pub fn unpack<T: ::prost::Message + ::pilota::protobuf::WireName + Default>(
+                        &self,
+                    ) -> ::std::result::Result<::std::option::Option<T>, ::prost::DecodeError> {
+                        if self.type_url.rsplit('/').next() != Some(T::WIRE_NAME) {
+                            return Ok(None);
+                        }
+                        T::decode(&*self.value).map(Some)
+                    }
+                }
+            });
+        }
+    }
 }
 
-impl CodegenBackend for ProtobufBackend {}
+/// Whether a field of the message has a value other than the zero value by default.
+fn has_default_values(cx: &Context, s: &rir::Message) -> bool {
+    s.fields.iter().any(|f| {
+        !matches!(f.kind, FieldKind::Optional) && cx.node_contains_tag::<DefaultValue>(f.did)
+    })
+}
+
+/// The name of the variant of the enum `def_id` written as `name` in the IDL.
+fn enum_variant(cx: &Context, def_id: DefId, name: &str) -> String {
+    match &*cx.expect_item(def_id) {
+        rir::Item::Enum(e) => e
+            .variants
+            .iter()
+            .find(|v| &**v.name == name)
+            .map(|v| v.name.to_variant_case(cx.naming()).to_string())
+            .unwrap_or_else(|| panic!("`{name}` is not a variant of `{}`", e.name)),
+        _ => unreachable!(),
+    }
+}
+
+/// Reverses the C escapes of the default values of the bytes fields, such as `\001` or `\n`.
+fn unescape_bytes(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut chars = value.bytes().peekable();
+    while let Some(b) = chars.next() {
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'x') => {
+                let mut v = 0;
+                while let Some(d) = chars.peek().and_then(|d| (*d as char).to_digit(16)) {
+                    v = v * 16 + d;
+                    chars.next();
+                }
+                bytes.push(v as u8);
+            }
+            Some(d @ b'0'..=b'7') => {
+                let mut v = (d - b'0') as u32;
+                for _ in 0..2 {
+                    match chars.peek() {
+                        Some(d @ b'0'..=b'7') => {
+                            v = v * 8 + (d - b'0') as u32;
+                            chars.next();
+                        }
+                        _ => break,
+                    }
+                }
+                bytes.push(v as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => bytes.push(b'\\'),
+        }
+    }
+    bytes
+}
 
 pub struct ProstPlugin;
 
@@ -37,6 +685,7 @@ impl ProstPlugin {
         match &ty.kind {
             ty::String => quote!(string),
             ty::Bool => quote!(bool),
+            ty::Bytes if cx.use_bytes() => quote!(bytes = "bytes"),
             ty::Bytes => quote!(bytes),
             ty::I32 if cx.contains_tag::<SInt32>(ty.tags_id) => quote!(sint32),
             ty::I64 if cx.contains_tag::<SInt64>(ty.tags_id) => quote!(sint64),
@@ -62,7 +711,11 @@ impl ProstPlugin {
                 let key = self.mk_ty_attr(cx, k);
                 let val = self.mk_ty_attr(cx, v);
                 let ty = quote!(#key, #val).to_string();
-                quote!(map = #ty)
+                match cx.collection_kind() {
+                    CollectionKind::Hash => quote!(map = #ty),
+                    CollectionKind::BTree => quote!(btree_map = #ty),
+                    CollectionKind::Index => panic!("prost does not support `IndexMap` fields"),
+                }
             }
             ty::Path(_) => quote!(message),
             ty::Arc(ty) => self.mk_ty_attr(cx, ty),
@@ -76,6 +729,14 @@ impl ProstPlugin {
 impl crate::Plugin for ProstPlugin {
     fn on_item(&mut self, cx: &mut Context, def_id: crate::DefId, item: Arc<crate::rir::Item>) {
         match &*item {
+            // `Message` is implemented by the backend to keep the unknown fields
+            crate::rir::Item::Message(s) if cx.preserve_unknown_fields() => {
+                let default = (!cx.default_impl().is_manual(s) && !has_default_values(cx, s))
+                    .then(|| quote!(Default,));
+                cx.with_adjust(def_id, |adj| {
+                    adj.add_attrs(&[parse_quote!(#[derive(#default Debug)])]);
+                })
+            }
             crate::rir::Item::Message(_) => cx.with_adjust(def_id, |adj| {
                 adj.add_attrs(&[parse_quote!(#[derive(::prost::Message)])]);
             }),
@@ -117,6 +778,13 @@ impl crate::Plugin for ProstPlugin {
             _ => None,
         });
 
+        let label = match f.kind {
+            FieldKind::Required => Some(quote!(required)),
+            FieldKind::Optional => Some(quote!(optional)),
+            FieldKind::Implicit => None,
+        }
+        .into_iter();
+
         let attrs = if let Some(target_enum) = target_enum {
             if one_of {
                 let path = cx
@@ -132,6 +800,15 @@ impl crate::Plugin for ProstPlugin {
                     .to_token_stream()
                     .to_string();
                 let tag = format!("{}", f.id);
+                let default = cx
+                    .node_tags(def_id)
+                    .get::<DefaultValue>()
+                    .map(|v| {
+                        let variant = enum_variant(cx, target_def_id.unwrap(), &v.0);
+                        quote!(default = #variant)
+                    })
+                    .into_iter();
+                cx.insert_node_tag(def_id, Enumeration(target_def_id.unwrap()));
 
                 // hack
                 unsafe { (Arc::as_ptr(&f) as *mut Field).as_mut().unwrap() }.ty = Ty {
@@ -139,20 +816,40 @@ impl crate::Plugin for ProstPlugin {
                     kind: ty::I32,
                 };
 
-                quote!(enumeration = #path, tag = #tag)
+                quote!(enumeration = #path, tag = #tag #(, #label)* #(, #default)*)
             }
         } else {
-            let optional = matches!(f.kind, FieldKind::Optional)
-                .then(|| quote! {optional})
+            let packed = cx
+                .node_tags(def_id)
+                .get::<Packed>()
+                .map(|p| {
+                    let packed = p.0.to_string();
+                    quote!(packed = #packed)
+                })
+                .into_iter();
+            let default = cx
+                .node_tags(def_id)
+                .get::<DefaultValue>()
+                .map(|v| {
+                    let value = match &f.ty.kind {
+                        ty::Bytes => LitByteStr::new(&unescape_bytes(&v.0), Span::call_site())
+                            .to_token_stream()
+                            .to_string(),
+                        _ => v.0.to_string(),
+                    };
+                    quote!(default = #value)
+                })
                 .into_iter();
             let ty = self.mk_ty_attr(cx, &f.ty);
             let tag = format!("{}", f.id);
-            quote!(#ty, tag = #tag #(, #optional)*)
+            quote!(#ty, tag = #tag #(, #label)* #(, #packed)* #(, #default)*)
         };
 
-        cx.with_adjust(def_id, |adj| {
-            adj.add_attrs(&[parse_quote!(#[prost(#attrs)])]);
-        });
+        if !cx.preserve_unknown_fields() {
+            cx.with_adjust(def_id, |adj| {
+                adj.add_attrs(&[parse_quote!(#[prost(#attrs)])]);
+            });
+        }
         crate::plugin::walk_filed(self, cx, def_id, f)
     }
 }
@@ -64,6 +64,18 @@ impl DecodeHelper {
         }
     }
 
+    pub fn codegen_read_unknown(&self, tt: TokenStream) -> TokenStream {
+        if self.is_async {
+            quote! {
+                ::pilota::thrift::unknown::TValue::read_async(protocol, #tt).await?
+            }
+        } else {
+            quote! {
+                ::pilota::thrift::unknown::TValue::read(protocol, #tt)?
+            }
+        }
+    }
+
     pub fn codegen_item_decode(&self) -> TokenStream {
         if self.is_async {
             quote! { ::pilota::thrift::Message::decode_async(protocol).await? }
@@ -0,0 +1,460 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::ThriftBackend;
+use crate::{
+    db::RirDatabase,
+    middle::{
+        rir,
+        ty::{self, Ty},
+    },
+    symbol::{DefId, EnumRepr},
+    tags::thrift::EmptyByDefault,
+};
+
+impl ThriftBackend {
+    /// The type name of `ty` in the thrift JSON protocol, which tags every value.
+    fn tjson_tag(&self, ty: &Ty) -> &'static str {
+        match &ty.kind {
+            ty::Bool => "tf",
+            ty::U8 | ty::I8 => "i8",
+            ty::I16 => "i16",
+            ty::I32 => "i32",
+            ty::I64 => "i64",
+            ty::F64 => "dbl",
+            ty::String | ty::Bytes => "str",
+            ty::Void => "rec",
+            ty::Vec(_) => "lst",
+            ty::Set(_) => "set",
+            ty::Map(..) => "map",
+            ty::Path(path) => match &*self.expect_item(path.did) {
+                rir::Item::Message(_) => "rec",
+                rir::Item::Enum(e) if e.repr.is_some() => "i32",
+                rir::Item::Enum(_) => "rec",
+                rir::Item::NewType(t) => self.tjson_tag(&t.ty),
+                item => panic!("unsupported type {:?}", item),
+            },
+            ty::Arc(ty) => self.tjson_tag(ty),
+            _ => unimplemented!(),
+        }
+    }
+
+    /// `value` as the only entry of an object keyed by the type name of `ty`.
+    fn tjson_tagged(&self, ty: &Ty, value: TokenStream) -> TokenStream {
+        let tag = self.tjson_tag(ty);
+        quote! {{
+            let value = #value;
+            ::serde_json::Value::Object(::std::iter::once((#tag.to_string(), value)).collect())
+        }}
+    }
+
+    /// Converts `v`, a reference to a value of `ty`, into its untagged thrift JSON value,
+    /// returning the errors from the enclosing function.
+    fn tjson_value(&self, ty: &Ty, v: TokenStream) -> TokenStream {
+        match &ty.kind {
+            ty::Bool => quote! { ::serde_json::Value::from(*#v as i32) },
+            ty::U8 => quote! { ::serde_json::Value::from(*#v as i8) },
+            ty::I8 => {
+                let v = self.codegen_narrow(v, "i8");
+                quote! { ::serde_json::Value::from(#v) }
+            }
+            ty::I16 => {
+                let v = self.codegen_narrow(v, "i16");
+                quote! { ::serde_json::Value::from(#v) }
+            }
+            ty::I32 | ty::I64 => quote! { ::serde_json::Value::from(*#v) },
+            ty::F64 => quote! {
+                match ::pilota::thrift::json::non_finite_double(*#v) {
+                    Some(s) => ::serde_json::Value::String(s.to_string()),
+                    None => ::serde_json::Value::from(*#v),
+                }
+            },
+            ty::String => quote! { ::serde_json::Value::String(#v.to_string()) },
+            ty::Bytes => {
+                quote! { ::serde_json::Value::String(::pilota::thrift::json::encode_base64(#v)) }
+            }
+            ty::Void => quote! { ::serde_json::Value::Object(::serde_json::Map::new()) },
+            ty::Vec(el) | ty::Set(el) => {
+                let tag = self.tjson_tag(el);
+                let el = self.tjson_value(el, quote!(v));
+                quote! {{
+                    let mut items = ::std::vec![
+                        ::serde_json::Value::from(#tag),
+                        ::serde_json::Value::from(#v.len()),
+                    ];
+                    for v in #v.iter() {
+                        items.push(#el);
+                    }
+                    ::serde_json::Value::Array(items)
+                }}
+            }
+            ty::Map(k, v_ty) => {
+                let k_tag = self.tjson_tag(k);
+                let v_tag = self.tjson_tag(v_ty);
+                let key = self.tjson_value(k, quote!(k));
+                let value = self.tjson_value(v_ty, quote!(v));
+                quote! {{
+                    let mut entries = ::serde_json::Map::new();
+                    for (k, v) in #v.iter() {
+                        let key = #key;
+                        let value = #value;
+                        let key = match key {
+                            ::serde_json::Value::String(s) => s,
+                            k => k.to_string(),
+                        };
+                        entries.insert(key, value);
+                    }
+                    ::serde_json::Value::Array(::std::vec![
+                        ::serde_json::Value::from(#k_tag),
+                        ::serde_json::Value::from(#v_tag),
+                        ::serde_json::Value::from(#v.len()),
+                        ::serde_json::Value::Object(entries),
+                    ])
+                }}
+            }
+            ty::Path(_) => quote! { #v.to_thrift_json()? },
+            ty::Arc(ty) => self.tjson_value(ty, quote!((&**#v))),
+            _ => unimplemented!(),
+        }
+    }
+
+    /// Converts `v`, a reference to an untagged thrift JSON value, into a value of `ty`,
+    /// returning the errors from the enclosing function.
+    fn tjson_decode(&self, ty: &Ty, v: TokenStream) -> TokenStream {
+        let tag = self.tjson_tag(ty);
+        let unexpected = quote! { ::pilota::thrift::json::unexpected(#tag) };
+        let int = |int_ty: TokenStream| {
+            quote! {
+                #v.as_i64()
+                    .and_then(|v| #int_ty::try_from(v).ok())
+                    .ok_or_else(|| #unexpected)?
+            }
+        };
+        match &ty.kind {
+            ty::Bool => quote! { #v.as_i64().ok_or_else(|| #unexpected)? != 0 },
+            ty::U8 => {
+                let v = int(quote!(i8));
+                quote! { #v as u8 }
+            }
+            ty::I8 => self.codegen_widen(int(quote!(i8))),
+            ty::I16 => self.codegen_widen(int(quote!(i16))),
+            ty::I32 => int(quote!(i32)),
+            ty::I64 => quote! { #v.as_i64().ok_or_else(|| #unexpected)? },
+            ty::F64 => quote! {
+                match #v {
+                    ::serde_json::Value::String(s) => s.parse::<f64>().ok(),
+                    v => v.as_f64(),
+                }
+                .ok_or_else(|| #unexpected)?
+            },
+            ty::String => quote! {
+                ::std::convert::From::from(#v.as_str().ok_or_else(|| #unexpected)?.to_string())
+            },
+            ty::Bytes => quote! {
+                ::std::convert::From::from(::pilota::thrift::json::decode_base64(
+                    #v.as_str().ok_or_else(|| #unexpected)?,
+                )?)
+            },
+            ty::Void => quote! {{
+                #v.as_object().ok_or_else(|| #unexpected)?;
+            }},
+            ty::Vec(el) | ty::Set(el) => {
+                let codegen_ty = self.codegen_field_ty(ty);
+                let el_ty = self.codegen_field_ty(el);
+                let el_tag = self.tjson_tag(el);
+                let decode_el = self.tjson_decode(el, quote!(v));
+                quote! {
+                    match #v.as_array().map(|a| a.as_slice()) {
+                        Some([tag, len, items @ ..])
+                            if *tag == #el_tag && len.as_u64() == Some(items.len() as u64) =>
+                        {
+                            items
+                                .iter()
+                                .map(|v| -> ::std::result::Result<#el_ty, ::pilota::thrift::Error> {
+                                    Ok(#decode_el)
+                                })
+                                .collect::<::std::result::Result<#codegen_ty, _>>()?
+                        }
+                        _ => return Err(#unexpected),
+                    }
+                }
+            }
+            ty::Map(k, v_ty) => {
+                let codegen_ty = self.codegen_field_ty(ty);
+                let k_codegen_ty = self.codegen_field_ty(k);
+                let v_codegen_ty = self.codegen_field_ty(v_ty);
+                let k_tag = self.tjson_tag(k);
+                let v_tag = self.tjson_tag(v_ty);
+                // the keys are written as strings, the ones of the other types as their JSON
+                let key = if k_tag == "str" {
+                    quote! { ::serde_json::Value::String(k.clone()) }
+                } else {
+                    quote! {
+                        ::serde_json::from_str::<::serde_json::Value>(k)
+                            .map_err(|_| ::pilota::thrift::json::unexpected(#k_tag))?
+                    }
+                };
+                let decode_key = self.tjson_decode(k, quote!((&key)));
+                let decode_value = self.tjson_decode(v_ty, quote!(v));
+                quote! {
+                    match #v.as_array().map(|a| a.as_slice()) {
+                        Some([k_tag, v_tag, len, ::serde_json::Value::Object(map)])
+                            if *k_tag == #k_tag
+                                && *v_tag == #v_tag
+                                && len.as_u64() == Some(map.len() as u64) =>
+                        {
+                            map.iter()
+                                .map(|(k, v)| -> ::std::result::Result<
+                                    (#k_codegen_ty, #v_codegen_ty),
+                                    ::pilota::thrift::Error,
+                                > {
+                                    let key = #key;
+                                    Ok((#decode_key, #decode_value))
+                                })
+                                .collect::<::std::result::Result<#codegen_ty, _>>()?
+                        }
+                        _ => return Err(#unexpected),
+                    }
+                }
+            }
+            ty::Path(_) => {
+                let codegen_ty = self.codegen_field_ty(ty);
+                quote! { <#codegen_ty>::from_thrift_json(#v)? }
+            }
+            ty::Arc(inner) => {
+                let decode = self.tjson_decode(inner, v);
+                quote! { ::std::convert::From::from(#decode) }
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn tjson_impl(&self, name: &str, to: TokenStream, from: TokenStream) -> TokenStream {
+        let name = format_ident!("{}", name);
+        quote! {
+            impl #name {
+                pub fn to_thrift_json(
+                    &self,
+                ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                    #to
+                }
+
+                pub fn from_thrift_json(
+                    value: &::serde_json::Value,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    #from
+                }
+            }
+        }
+    }
+
+    /// A struct is an object keyed by the field ids, each value is tagged with its type,
+    /// absent optional fields are left out. The unknown ids and the values of other types
+    /// are skipped when decoding.
+    pub(super) fn codegen_struct_thrift_json(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let to_fields = s.fields.iter().map(|f| {
+            let field = format_ident!("{}", f.name);
+            let id = f.id.to_string();
+            let v = if self.field_is_box(f) {
+                quote!((&**v))
+            } else {
+                quote!(v)
+            };
+            let value = self.tjson_tagged(&f.ty, self.tjson_value(&f.ty, v));
+            if f.is_optional() {
+                quote! {
+                    if let Some(v) = &self.#field {
+                        map.insert(#id.to_string(), #value);
+                    }
+                }
+            } else {
+                quote! {{
+                    let v = &self.#field;
+                    map.insert(#id.to_string(), #value);
+                }}
+            }
+        });
+
+        let from_fields = s.fields.iter().map(|f| {
+            let field = format_ident!("{}", f.name);
+            let id = f.id.to_string();
+            let tag = self.tjson_tag(&f.ty);
+            let ty = self.codegen_field_ty(&f.ty);
+            let mut ty = quote! { #ty };
+            let mut decode = self.tjson_decode(&f.ty, quote!(v));
+            if self.field_is_box(f) {
+                ty = quote! { ::std::boxed::Box<#ty> };
+                decode = quote! { ::std::boxed::Box::new(#decode) };
+            }
+            let unwrap = if f.is_optional() {
+                None
+            } else if self.node_contains_tag::<EmptyByDefault>(f.did) {
+                Some(quote! { let #field = #field.unwrap_or_default(); })
+            } else {
+                let err = format!("field {} is required", f.name);
+                Some(quote! {
+                    let #field = #field.ok_or_else(|| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            #err,
+                        )
+                    })?;
+                })
+            };
+            quote! {
+                let #field = map
+                    .get(#id)
+                    .and_then(|v| v.get(#tag))
+                    .map(|v| -> ::std::result::Result<#ty, ::pilota::thrift::Error> {
+                        Ok(#decode)
+                    })
+                    .transpose()?;
+                #unwrap
+            }
+        });
+        let fields = s.fields.iter().map(|f| format_ident!("{}", f.name));
+        let extra_fields = self.extra_field_inits();
+
+        stream.extend(self.tjson_impl(
+            &s.name.to_upper_camel_case(self.naming()),
+            quote! {
+                let mut map = ::serde_json::Map::new();
+                #(#to_fields)*
+                Ok(::serde_json::Value::Object(map))
+            },
+            quote! {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                #(#from_fields)*
+                Ok(Self {
+                    #(#fields,)*
+                    #extra_fields
+                })
+            },
+        ));
+    }
+
+    /// The enums are their values, the unions the structs with a single field.
+    pub(super) fn codegen_enum_thrift_json(
+        &self,
+        def_id: DefId,
+        stream: &mut TokenStream,
+        e: &rir::Enum,
+    ) {
+        let name = e.name.to_upper_camel_case(self.naming());
+        let (to, from) = match e.repr {
+            Some(EnumRepr::I32) if self.is_open_enum(e) => (
+                quote! { Ok(::serde_json::Value::from(i32::from(*self))) },
+                quote! {
+                    Ok(Self::from(
+                        value
+                            .as_i64()
+                            .and_then(|v| i32::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i32"))?,
+                    ))
+                },
+            ),
+            Some(EnumRepr::I32) => {
+                let err_msg_tmpl = format!("invalid enum value for {}, value: {{}}", name);
+                (
+                    quote! { Ok(::serde_json::Value::from(*self as i32)) },
+                    quote! {
+                        let value = value
+                            .as_i64()
+                            .and_then(|v| i32::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i32"))?;
+                        Self::try_from(value).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                format!(#err_msg_tmpl, value),
+                            )
+                        })
+                    },
+                )
+            }
+            None => {
+                let has_unknown_variant = self.has_unknown_variant(def_id);
+                let to_variants = e.variants.iter().map(|v| {
+                    let variant = format_ident!("{}", v.name.to_variant_case(self.naming()));
+                    let id = v.id.unwrap().to_string();
+                    let value =
+                        self.tjson_tagged(&v.fields[0], self.tjson_value(&v.fields[0], quote!(v)));
+                    let unbox = self.is_boxed_variant(v).then(|| quote! { let v = &**v; });
+                    quote! {
+                        Self::#variant(v) => {
+                            #unbox
+                            map.insert(#id.to_string(), #value);
+                        }
+                    }
+                });
+                let to_unknown = has_unknown_variant.then(|| quote! { Self::Unknown(_) => {} });
+                let to = if e.variants.is_empty() && !has_unknown_variant {
+                    quote! { match *self {} }
+                } else {
+                    quote! {
+                        let mut map = ::serde_json::Map::new();
+                        match self {
+                            #(#to_variants)*
+                            #to_unknown
+                        }
+                        Ok(::serde_json::Value::Object(map))
+                    }
+                };
+
+                let from_variants = e.variants.iter().map(|v| {
+                    let variant = format_ident!("{}", v.name.to_variant_case(self.naming()));
+                    let id = v.id.unwrap().to_string();
+                    let tag = self.tjson_tag(&v.fields[0]);
+                    let mut decode = self.tjson_decode(&v.fields[0], quote!(v));
+                    if self.is_boxed_variant(v) {
+                        decode = quote! { ::std::boxed::Box::new(#decode) };
+                    }
+                    quote! {
+                        if let Some(v) = map.get(#id).and_then(|v| v.get(#tag)) {
+                            return Ok(Self::#variant(#decode));
+                        }
+                    }
+                });
+                let from_unknown = has_unknown_variant.then(|| {
+                    quote! {
+                        if let Some(id) = map.keys().find_map(|k| k.parse::<i16>().ok()) {
+                            return Ok(Self::Unknown(id));
+                        }
+                    }
+                });
+                let from = quote! {
+                    let map = value
+                        .as_object()
+                        .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                    if map.len() > 1 {
+                        return Err(::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "received multiple fields for union from remote Message",
+                        ));
+                    }
+                    #(#from_variants)*
+                    #from_unknown
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                };
+                (to, from)
+            }
+            #[allow(unreachable_patterns)]
+            _ => return,
+        };
+        stream.extend(self.tjson_impl(&name, to, from));
+    }
+
+    pub(super) fn codegen_newtype_thrift_json(&self, stream: &mut TokenStream, t: &rir::NewType) {
+        let to = self.tjson_value(&t.ty, quote!((&self.0)));
+        let from = self.tjson_decode(&t.ty, quote!(value));
+        stream.extend(self.tjson_impl(
+            &t.name.to_upper_camel_case(self.naming()),
+            quote! { Ok(#to) },
+            quote! { Ok(Self(#from)) },
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 use std::{ops::Deref, sync::Arc};
 
+use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
@@ -11,7 +12,7 @@ use crate::{
         rir::{self, Enum, Field, Message, Method, NewType, Service},
     },
     symbol::{DefId, EnumRepr},
-    tags::thrift::EntryMessage,
+    tags::thrift::{EmptyByDefault, EntryMessage, MethodException, Streaming},
 };
 
 mod ty;
@@ -19,6 +20,7 @@ mod ty;
 pub use self::decode_helper::DecodeHelper;
 
 mod decode_helper;
+mod json;
 
 pub struct ThriftBackend {
     cx: Arc<Context>,
@@ -39,6 +41,39 @@ impl Deref for ThriftBackend {
 }
 
 impl ThriftBackend {
+    /// `From` each exception of a method into its exception enum and into the `UserError` the
+    /// method returns, so that `?` works on them. An exception thrown by several variants is
+    /// left out as it's ambiguous.
+    fn codegen_exception_conversions(&self, stream: &mut TokenStream, e: &Enum) {
+        let name = format_ident!("{}", e.name.to_upper_camel_case(self.naming()));
+        e.variants
+            .iter()
+            .filter(|v| {
+                e.variants
+                    .iter()
+                    .filter(|other| other.fields[0].kind == v.fields[0].kind)
+                    .count()
+                    == 1
+            })
+            .for_each(|v| {
+                let variant_name = format_ident!("{}", v.name.to_variant_case(self.naming()));
+                let ty = self.codegen_item_ty(v.fields[0].kind.clone());
+                stream.extend(quote! {
+                    impl ::std::convert::From<#ty> for #name {
+                        fn from(e: #ty) -> Self {
+                            #name::#variant_name(e)
+                        }
+                    }
+
+                    impl ::std::convert::From<#ty> for ::pilota::thrift::UserError<#name> {
+                        fn from(e: #ty) -> Self {
+                            ::pilota::thrift::UserError::UserException(#name::#variant_name(e))
+                        }
+                    }
+                });
+            });
+    }
+
     fn codegen_encode_fields_size<'a>(
         &'a self,
         fields: &'a [Arc<rir::Field>],
@@ -123,6 +158,16 @@ impl ThriftBackend {
         decode: TokenStream,
         decode_async: TokenStream,
     ) -> TokenStream {
+        let encoded_len = self.gen_encoded_len().then(|| {
+            quote! {
+                impl #name {
+                    /// The length of the message encoded with the binary protocol, without encoding it.
+                    pub fn encoded_len(&self) -> usize {
+                        ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+                    }
+                }
+            }
+        });
         quote! {
             #[::async_trait::async_trait]
             impl ::pilota::thrift::Message for #name {
@@ -151,6 +196,8 @@ impl ThriftBackend {
                     #size
                 }
             }
+
+            #encoded_len
         }
     }
 
@@ -169,24 +216,40 @@ impl ThriftBackend {
     fn codegen_decode(&self, helper: &DecodeHelper, s: &rir::Message) -> TokenStream {
         let mut required_field_names = Vec::with_capacity(s.fields.len());
         let mut optional_field_names = Vec::with_capacity(s.fields.len());
+        let mut empty_field_names = Vec::new();
+        // the messages name the fields without the escaping of keywords
+        let mut required_errs = Vec::with_capacity(s.fields.len());
         s.fields.iter().for_each(|f| {
             if f.is_optional() {
                 optional_field_names.push(format_ident!("{}", f.name))
+            } else if self.node_contains_tag::<EmptyByDefault>(f.did) {
+                empty_field_names.push(format_ident!("{}", f.name))
             } else {
-                required_field_names.push(format_ident!("{}", f.name))
+                required_field_names.push(format_ident!("{}", f.name));
+                required_errs.push(format!("field {} is required", f.name));
             }
         });
 
         let read_struct_begin = helper.codegen_read_struct_begin();
         let read_struct_end = helper.codegen_read_struct_end();
         let read_fields = self.codegen_decode_fields(helper, &s.fields);
-        let required_errs = required_field_names
-            .iter()
-            .map(|i| format!("field {} is required", i));
+        let dirty_fields = self
+            .track_dirty_fields()
+            .then(|| quote!(_dirty_fields: ::std::default::Default::default(),));
+        let (unknown_fields, unknown_fields_init) = if self.preserve_unknown_fields() {
+            (
+                quote!(let mut _unknown_fields = ::pilota::thrift::unknown::UnknownFields::default();),
+                quote!(_unknown_fields,),
+            )
+        } else {
+            Default::default()
+        };
 
         quote! {
             #(let mut #required_field_names = None;)*
             #(let mut #optional_field_names = None;)*
+            #(let mut #empty_field_names = None;)*
+            #unknown_fields
 
             #read_struct_begin;
             #read_fields;
@@ -204,10 +267,14 @@ impl ThriftBackend {
                      )
                  )
             };)*
+            #(let #empty_field_names = #empty_field_names.unwrap_or_default();)*
 
             let data = Self {
                 #(#optional_field_names,)*
+                #(#empty_field_names,)*
                 #(#required_field_names,)*
+                #dirty_fields
+                #unknown_fields_init
             };
             Ok(data)
         }
@@ -230,6 +297,150 @@ impl ThriftBackend {
         // TODO
     }
 
+    fn codegen_service_client(&self, def_id: DefId, stream: &mut TokenStream, s: &Service) {
+        let name = format_ident!(
+            "{}",
+            self.type_name_affixes().derive(&s.name, |name| format!(
+                "{}Client",
+                name.to_upper_camel_case(self.naming())
+            ))
+        );
+        let methods = self.service_methods(def_id);
+        let methods = methods.iter().filter_map(|m| {
+            // the client transports only send a request and receive a response
+            if self.node_contains_tag::<Streaming>(m.def_id) {
+                tracing::warn!(
+                    "skip generating the client method of `{}`, streaming methods are not supported",
+                    m.name
+                );
+                return None;
+            }
+            Some(self.codegen_client_method(def_id, m))
+        });
+
+        stream.extend(quote! {
+            #[derive(Clone)]
+            pub struct #name {
+                transport: ::std::sync::Arc<dyn ::pilota::thrift::ClientTransport>,
+            }
+
+            impl #name {
+                pub fn new(transport: ::std::sync::Arc<dyn ::pilota::thrift::ClientTransport>) -> Self {
+                    Self { transport }
+                }
+
+                #(#methods)*
+            }
+        });
+    }
+
+    fn codegen_client_method(&self, service_def_id: DefId, m: &Method) -> TokenStream {
+        let name = format_ident!("{}", m.name.to_snake_case(self.naming()));
+        let method_name_str = &**m.name;
+        let ret_ty = self.codegen_item_ty(m.ret.kind.clone());
+
+        // the args and result of a method are generated along with the service
+        // which defines it, so we locate them relative to that service.
+        let source_def_id = match m.source {
+            rir::MethodSource::Extend(def_id) => def_id,
+            rir::MethodSource::Own => service_def_id,
+        };
+        let service_name = self.item(source_def_id).unwrap().symbol_name();
+        let related_path = |suffix: &str| {
+            let mut path = self.cur_related_item_path(source_def_id);
+            let name = self
+                .type_name_affixes()
+                .derive(&service_name, |service_name| {
+                    format!(
+                        "{}{}{}",
+                        service_name.to_upper_camel_case(self.naming()),
+                        m.name.to_upper_camel_case(self.naming()),
+                        suffix
+                    )
+                    .to_upper_camel_case()
+                });
+            path.segments.last_mut().unwrap().ident = format_ident!("{}", name);
+            path
+        };
+        let args_path = related_path("Args");
+        let result_path = related_path("Result");
+
+        let arg_idents = m
+            .args
+            .iter()
+            .map(|a| format_ident!("{}", a.name.to_field_case(self.naming())))
+            .collect::<Vec<_>>();
+        let arg_fields = m
+            .args
+            .iter()
+            .map(|a| format_ident!("{}", a.name.to_field_case(self.naming())));
+        let args = m.args.iter().zip(&arg_idents).map(|(a, ident)| {
+            let ty = self.codegen_item_ty(a.ty.kind.clone());
+            quote! { #ident: #ty }
+        });
+
+        let (err_ty, exceptions) = if let Some(p) = &m.exceptions {
+            let exception = self.cur_related_item_path(p.did);
+            let variants = match &*self.expect_item(p.did) {
+                rir::Item::Enum(e) => e
+                    .variants
+                    .iter()
+                    .map(|v| format_ident!("{}", v.name.to_variant_case(self.naming())))
+                    .collect::<Vec<_>>(),
+                _ => panic!("invalid exception of method {}", m.name),
+            };
+            (
+                quote! { ::pilota::thrift::UserError<#exception> },
+                quote! {
+                    #(#result_path::#variants(e) => Err(::pilota::thrift::UserError::UserException(#exception::#variants(e))),)*
+                },
+            )
+        } else {
+            (quote!(::pilota::AnyhowError), quote!())
+        };
+
+        let extra_fields = self.extra_field_inits();
+        let encode = quote! {
+            let args = #args_path {
+                #(#arg_fields: #arg_idents,)*
+                #extra_fields
+            };
+            let mut buf = ::pilota::bytes::BytesMut::new();
+            ::pilota::thrift::Message::encode(
+                &args,
+                &mut ::pilota::thrift::TBinaryProtocol::new(&mut buf),
+            )
+            .map_err(err)?;
+        };
+
+        let call = if m.oneway {
+            quote! {
+                self.transport.oneway(#method_name_str, buf.freeze()).await.map_err(err)?;
+                Ok(())
+            }
+        } else {
+            quote! {
+                let mut resp = self.transport.call(#method_name_str, buf.freeze()).await.map_err(err)?;
+                let result: #result_path = ::pilota::thrift::Message::decode(
+                    &mut ::pilota::thrift::TBinaryProtocol::new(&mut resp),
+                )
+                .map_err(err)?;
+                match result {
+                    #result_path::Ok(v) => Ok(v),
+                    #exceptions
+                }
+            }
+        };
+
+        quote! {
+            pub async fn #name(&self, #(#args),*) -> ::core::result::Result<#ret_ty, #err_ty> {
+                let err = |e: ::pilota::thrift::Error| -> #err_ty { ::pilota::AnyhowError::from(e).into() };
+                #encode
+                #call
+            }
+        }
+    }
+
     fn codegen_decode_fields<'a>(
         &'a self,
         helper: &DecodeHelper,
@@ -256,7 +467,12 @@ impl ThriftBackend {
                 },
             }
         });
-        let skip_ttype = helper.codegen_skip_ttype(quote! { ttype });
+        let skip_ttype = if self.preserve_unknown_fields() {
+            let read_unknown = helper.codegen_read_unknown(quote! { ttype });
+            quote! { _unknown_fields.push(field_id.unwrap_or_default(), #read_unknown) }
+        } else {
+            helper.codegen_skip_ttype(quote! { ttype })
+        };
         let read_field_end = helper.codegen_read_field_end();
         quote! {
             loop {
@@ -280,16 +496,95 @@ impl ThriftBackend {
 }
 
 impl CodegenBackend for ThriftBackend {
+    fn codegen_field_wire_types(&self, stream: &mut TokenStream, s: &Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let entries = s.fields.iter().map(|f| {
+            let id = proc_macro2::Literal::i16_unsuffixed(f.id as i16);
+            let ttype = self.ttype(&f.ty);
+            quote! { (#id, #ttype) }
+        });
+        stream.extend(quote! {
+            impl #name {
+                pub const FIELD_WIRE_TYPES: &'static [(i16, ::pilota::thrift::TType)] = &[#(#entries),*];
+            }
+        });
+    }
+
+    fn codegen_changed_from(&self, stream: &mut TokenStream, s: &Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let checks = s.fields.iter().map(|f| {
+            let field_name = format_ident!("{}", f.name);
+            let id = proc_macro2::Literal::i16_unsuffixed(f.id as i16);
+            quote! {
+                if self.#field_name != other.#field_name {
+                    changed.push(#id);
+                }
+            }
+        });
+        stream.extend(quote! {
+            impl #name {
+                /// Returns the ids of the fields which differ from `other`, the floats are
+                /// compared with exact equality.
+                pub fn changed_from(&self, other: &Self) -> ::std::vec::Vec<i16> {
+                    let mut changed = ::std::vec::Vec::new();
+                    #(#checks)*
+                    changed
+                }
+            }
+        });
+    }
+
+    fn codegen_any_message_impl(&self, stream: &mut TokenStream, variants: &[(i32, Ident)]) {
+        let tags = variants
+            .iter()
+            .map(|(tag, _)| proc_macro2::Literal::i32_unsuffixed(*tag));
+        let names = variants.iter().map(|(_, name)| name).collect::<Vec<_>>();
+
+        stream.extend(quote! {
+            impl AnyMessage {
+                pub fn decode_by_tag<T: ::pilota::thrift::TInputProtocol>(
+                    tag: i32,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    match tag {
+                        #(#tags => Ok(AnyMessage::#names(::pilota::thrift::Message::decode(protocol)?)),)*
+                        _ => Err(::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            format!("unknown message tag {}", tag),
+                        )),
+                    }
+                }
+
+                pub fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    match self {
+                        #(AnyMessage::#names(v) => ::pilota::thrift::Message::encode(v, protocol),)*
+                    }
+                }
+            }
+        });
+    }
+
     fn codegen_struct_impl(
         &self,
         _def_id: DefId,
         stream: &mut proc_macro2::TokenStream,
         s: &Message,
     ) {
-        let name = format_ident!("{}", s.name.to_upper_camel_case());
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
         let name_str = &**s.name;
         let encode_fields = self.codegen_encode_fields(&s.fields);
         let encode_fields_size = self.codegen_encode_fields_size(&s.fields);
+        let (encode_unknown, unknown_size) = if self.preserve_unknown_fields() {
+            (
+                quote!(self._unknown_fields.encode(protocol)?;),
+                quote!(self._unknown_fields.size(protocol) +),
+            )
+        } else {
+            Default::default()
+        };
         stream.extend(self.codegen_impl_message_with_helper(
             &name,
             quote! {
@@ -299,6 +594,7 @@ impl CodegenBackend for ThriftBackend {
 
                 protocol.write_struct_begin(&struct_ident)?;
                 #(#encode_fields)*
+                #encode_unknown
                 protocol.write_field_stop()?;
                 protocol.write_struct_end()?;
                 Ok(())
@@ -306,26 +602,33 @@ impl CodegenBackend for ThriftBackend {
             quote! {
                 protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
                     name: #name_str,
-                }) + #(#encode_fields_size+)*  protocol.write_field_stop_len() + protocol.write_struct_end_len()
+                }) + #(#encode_fields_size+)* #unknown_size protocol.write_field_stop_len() + protocol.write_struct_end_len()
             },
             |helper| self.codegen_decode(helper, s),
         ));
+
+        if self.gen_thrift_json() {
+            self.codegen_struct_thrift_json(stream, s);
+        }
     }
 
     fn codegen_service_impl(
         &self,
-        _def_id: DefId,
-        _stream: &mut proc_macro2::TokenStream,
-        _s: &Service,
+        def_id: DefId,
+        stream: &mut proc_macro2::TokenStream,
+        s: &Service,
     ) {
+        if self.gen_client() {
+            self.codegen_service_client(def_id, stream, s)
+        }
     }
 
     fn codegen_service_method(&self, _service_def_id: DefId, m: &Method) -> TokenStream {
-        let name = format_ident!("{}", m.name.to_snake_case());
+        let name = format_ident!("{}", m.name.to_snake_case(self.naming()));
         let ret_ty = self.codegen_item_ty(m.ret.kind.clone());
         let args = m.args.iter().map(|a| {
             let ty = self.codegen_item_ty(a.ty.kind.clone());
-            let ident = format_ident!("{}", a.name);
+            let ident = format_ident!("{}", a.name.to_field_case(self.naming()));
             quote! {
                 #ident: #ty
             }
@@ -344,9 +647,27 @@ impl CodegenBackend for ThriftBackend {
     }
 
     fn codegen_enum_impl(&self, def_id: DefId, stream: &mut proc_macro2::TokenStream, e: &Enum) {
-        let name = format_ident!("{}", e.name.to_upper_camel_case());
+        let name = format_ident!("{}", e.name.to_upper_camel_case(self.naming()));
         let is_entry_message = self.node_contains_tag::<EntryMessage>(def_id);
         match e.repr {
+            Some(EnumRepr::I32) if self.is_open_enum(e) => {
+                stream.extend(self.codegen_impl_message_with_helper(
+                    &name,
+                    quote! {
+                        protocol.write_i32(i32::from(*self))?;
+                        Ok(())
+                    },
+                    quote! {
+                        protocol.write_i32_len(i32::from(*self))
+                    },
+                    |helper| {
+                        let read_i32 = helper.codegen_read_i32();
+                        quote! {
+                            Ok(Self::from(#read_i32))
+                        }
+                    },
+                ))
+            }
             Some(EnumRepr::I32) => stream.extend(self.codegen_impl_message_with_helper(
                 &name,
                 quote! {
@@ -373,17 +694,25 @@ impl CodegenBackend for ThriftBackend {
             )),
             None if is_entry_message => self.codegen_entry_enum(def_id, stream, e),
             None => {
-                let name = format_ident!("{}", e.name.to_upper_camel_case());
+                let name = format_ident!("{}", e.name.to_upper_camel_case(self.naming()));
                 let name_str = &**e.name;
+                let has_unknown_variant = self.has_unknown_variant(def_id);
+                let encode_unknown =
+                    has_unknown_variant.then(|| quote! { #name::Unknown(_) => {}, });
+                let size_unknown = has_unknown_variant.then(|| quote! { #name::Unknown(_) => 0, });
                 let encode_variants = e.variants.iter().map(|v| {
-                    let variant_name = format_ident!("{}", v.name.to_upper_camel_case());
+                    let variant_name = format_ident!("{}", v.name.to_variant_case(self.naming()));
                     let variant_name_str = &**v.name;
                     assert_eq!(v.fields.len(), 1);
                     let ty = self.ttype(&v.fields[0]);
                     let variant_id = v.id.unwrap() as i16;
                     let encode = self.codegen_encode_ty(&v.fields[0], &format_ident!("value"));
+                    let unbox = self
+                        .is_boxed_variant(v)
+                        .then(|| quote! { let value = &**value; });
                     quote! {
                         #name::#variant_name(ref value) => {
+                            #unbox
                             protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
                                 name: Some(#variant_name_str),
                                 field_type: #ty,
@@ -396,13 +725,17 @@ impl CodegenBackend for ThriftBackend {
                 });
 
                 let variants_size = e.variants.iter().map(|v| {
-                    let variant_name = format_ident!("{}", v.name.to_upper_camel_case());
+                    let variant_name = format_ident!("{}", v.name.to_variant_case(self.naming()));
                     let variant_name_str = &**v.name;
                     let ty = self.ttype(&v.fields[0]);
                     let variant_id = v.id.unwrap() as i16;
                     let size = self.codegen_ty_size(&v.fields[0], &format_ident!("value"));
+                    let unbox = self
+                        .is_boxed_variant(v)
+                        .then(|| quote! { let value = &**value; });
                     quote! {
                         #name::#variant_name(ref value) => {
+                            #unbox
                             protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
                                 name: Some(#variant_name_str),
                                 field_type: #ty,
@@ -412,37 +745,61 @@ impl CodegenBackend for ThriftBackend {
                     }
                 });
 
+                // an empty union has no value to encode, a reference to it is not known to be
+                // uninhabited though
+                let (encode, size) = if e.variants.is_empty() && !has_unknown_variant {
+                    (quote! { match *self {} }, quote! { match *self {} })
+                } else {
+                    (
+                        quote! {
+                            protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                                name: #name_str,
+                            })?;
+                            match self {
+                                #(#encode_variants)*
+                                #encode_unknown
+                            }
+                            protocol.write_field_stop()?;
+                            protocol.write_struct_end()?;
+                            Ok(())
+                        },
+                        quote! {
+                            protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                                name: #name_str,
+                            }) + match self {
+                                #(#variants_size)*
+                                #size_unknown
+                            } +  protocol.write_field_stop_len() + protocol.write_struct_end_len()
+                        },
+                    )
+                };
+
                 stream.extend(self.codegen_impl_message_with_helper(
                     &name,
-                    quote! {
-                        protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                            name: #name_str,
-                        })?;
-                        match self {
-                            #(#encode_variants)*
-                        }
-                        protocol.write_field_stop()?;
-                        protocol.write_struct_end()?;
-                        Ok(())
-                    },
-                    quote! {
-                        protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: #name_str,
-                        }) + match self {
-                            #(#variants_size)*
-                        } +  protocol.write_field_stop_len() + protocol.write_struct_end_len()
-                    },
+                    encode,
+                    size,
                     |helper| {
                         let read_struct_begin = helper.codegen_read_struct_begin();
                         let read_field_begin = helper.codegen_read_field_begin();
                         let read_field_end = helper.codegen_read_field_end();
                         let read_struct_end = helper.codegen_read_struct_end();
                         let skip = helper.codegen_skip_ttype(quote! { field_ident.field_type });
+                        let record_unknown = has_unknown_variant.then(|| {
+                            quote! {
+                                if ret.is_none() {
+                                    ret = Some(#name::Unknown(field_id.unwrap_or_default()));
+                                }
+                            }
+                        });
                         let fields = e.variants.iter().map(|v| {
-                            let variant_name = format_ident!("{}", v.name.to_upper_camel_case());
+                            let variant_name =
+                                format_ident!("{}", v.name.to_variant_case(self.naming()));
                             assert_eq!(v.fields.len(), 1);
                             let variant_id = v.id.unwrap() as i16;
-                            let decode = self.codegen_decode_ty(helper, &v.fields[0]);
+                            let mut decode = self.codegen_decode_ty(helper, &v.fields[0]);
+                            if self.is_boxed_variant(v) {
+                                decode = quote! { ::std::boxed::Box::new(#decode) };
+                            }
                             quote! {
                                 Some(#variant_id) => {
                                     if ret.is_none() {
@@ -469,6 +826,7 @@ impl CodegenBackend for ThriftBackend {
                                     #(#fields)*
                                     _ => {
                                         #skip;
+                                        #record_unknown
                                     },
                                 }
                             }
@@ -489,6 +847,14 @@ impl CodegenBackend for ThriftBackend {
             #[allow(unreachable_patterns)]
             _ => {}
         }
+
+        if self.gen_thrift_json() && (e.repr.is_some() || !is_entry_message) {
+            self.codegen_enum_thrift_json(def_id, stream, e);
+        }
+
+        if self.node_contains_tag::<MethodException>(def_id) {
+            self.codegen_exception_conversions(stream, e);
+        }
     }
 
     fn codegen_newtype_impl(
@@ -497,7 +863,7 @@ impl CodegenBackend for ThriftBackend {
         stream: &mut proc_macro2::TokenStream,
         t: &NewType,
     ) {
-        let name = format_ident!("{}", t.name.to_upper_camel_case());
+        let name = format_ident!("{}", t.name.to_upper_camel_case(self.naming()));
         let encode = self.codegen_encode_ty(&t.ty, &format_ident!("value"));
         let encode_size = self.codegen_ty_size(&t.ty, &format_ident!("value"));
 
@@ -519,5 +885,9 @@ impl CodegenBackend for ThriftBackend {
                 quote! { Ok(#name(#decode)) }
             },
         ));
+
+        if self.gen_thrift_json() {
+            self.codegen_newtype_thrift_json(stream, t);
+        }
     }
 }
@@ -1,13 +1,51 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 
 use super::{decode_helper::DecodeHelper, ThriftBackend};
 use crate::{
     db::RirDatabase,
-    middle::{rir, ty, ty::Ty},
+    middle::{
+        rir, ty,
+        ty::{CodegenTy, CollectionKind, SmallIntWidth, Ty},
+    },
 };
 
 impl ThriftBackend {
+    /// The Rust integer of the `i8` and `i16` widened by `Builder::small_int_width`, if any.
+    fn widened_int(&self) -> Option<TokenStream> {
+        match self.small_int_width() {
+            SmallIntWidth::Declared => None,
+            SmallIntWidth::I32 => Some(quote!(i32)),
+            SmallIntWidth::I64 => Some(quote!(i64)),
+        }
+    }
+
+    /// Widens `v`, an `i8` or `i16` read from the wire, to the generated integer.
+    pub(crate) fn codegen_widen(&self, v: TokenStream) -> TokenStream {
+        match self.widened_int() {
+            Some(int_ty) => quote! { #int_ty::from(#v) },
+            None => v,
+        }
+    }
+
+    /// Narrows the integer `v` refers to back to the `int_ty` it's declared as, returning an
+    /// error from the enclosing function when it doesn't fit.
+    pub(crate) fn codegen_narrow(&self, v: impl ToTokens, int_ty: &str) -> TokenStream {
+        if self.widened_int().is_none() {
+            return quote! { *#v };
+        }
+        let int_ty_ident = format_ident!("{}", int_ty);
+        let msg = format!("{{}} is out of the range of {int_ty}");
+        quote! {
+            #int_ty_ident::try_from(*#v).map_err(|_| {
+                ::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    ::std::format!(#msg, #v),
+                )
+            })?
+        }
+    }
+
     pub(crate) fn ttype(&self, ty: &Ty) -> TokenStream {
         match &ty.kind {
             ty::String => quote! {::pilota::thrift::TType::String},
@@ -36,6 +74,7 @@ impl ThriftBackend {
                     _ => panic!("unsupported type {:?}", item),
                 }
             }
+            ty::Arc(ty) => self.ttype(ty),
             _ => unimplemented!(),
         }
     }
@@ -50,8 +89,14 @@ impl ThriftBackend {
             ty::U8 => quote! { protocol.write_byte(*#ident)?; },
             ty::Bool => quote! { protocol.write_bool(*#ident)?; },
             ty::Bytes => quote! { protocol.write_bytes(&#ident)?;},
-            ty::I8 => quote! { protocol.write_i8(*#ident)?; },
-            ty::I16 => quote! { protocol.write_i16(*#ident)?; },
+            ty::I8 => {
+                let v = self.codegen_narrow(ident, "i8");
+                quote! { protocol.write_i8(#v)?; }
+            }
+            ty::I16 => {
+                let v = self.codegen_narrow(ident, "i16");
+                quote! { protocol.write_i16(#v)?; }
+            }
             ty::I32 => quote! { protocol.write_i32(*#ident)?; },
             ty::I64 => quote! { protocol.write_i64(*#ident)?; },
             ty::F64 => quote! { protocol.write_double(*#ident)?; },
@@ -108,7 +153,17 @@ impl ThriftBackend {
                     protocol.write_map_end()?;
                 }
             }
-            ty::Path(_) => quote! { ::pilota::thrift::Message::encode(#ident, protocol)?; },
+            // the `Arc`s of the other types are built by `Builder::arc_fields_if`
+            ty::Arc(inner) if !matches!(inner.kind, ty::Path(_)) => {
+                let encode = self.codegen_encode_ty(inner, ident);
+                quote! {{
+                    let #ident = &**#ident;
+                    #encode
+                }}
+            }
+            ty::Path(_) | ty::Arc(_) => {
+                quote! { ::pilota::thrift::Message::encode(#ident, protocol)?; }
+            }
             _ => unimplemented!(),
         }
     }
@@ -124,6 +179,13 @@ impl ThriftBackend {
             }
             ty::Bool => quote! { protocol.write_bool_len(*#ident) },
             ty::Bytes => quote! { protocol.write_bytes_len(#ident)},
+            // the size doesn't depend on the value, so a widened one is truncated
+            ty::I8 if self.widened_int().is_some() => {
+                quote! { protocol.write_i8_len(*#ident as i8) }
+            }
+            ty::I16 if self.widened_int().is_some() => {
+                quote! { protocol.write_i16_len(*#ident as i16) }
+            }
             ty::I8 => quote! { protocol.write_i8_len(*#ident) },
             ty::I16 => quote! { protocol.write_i16_len(*#ident) },
             ty::I32 => quote! { protocol.write_i32_len(*#ident) },
@@ -194,12 +256,44 @@ impl ThriftBackend {
                     }
                 }
             }
-            ty::Path(_) => quote! { ::pilota::thrift::Size::size(#ident, protocol) },
+            ty::Arc(inner) if !matches!(inner.kind, ty::Path(_)) => {
+                let size = self.codegen_ty_size(inner, ident);
+                quote! {{
+                    let #ident = &**#ident;
+                    #size
+                }}
+            }
+            ty::Path(_) | ty::Arc(_) => quote! { ::pilota::thrift::Size::size(#ident, protocol) },
             _ => unimplemented!(),
         }
     }
 
     pub(crate) fn codegen_decode_ty(&self, helper: &DecodeHelper, ty: &Ty) -> TokenStream {
+        let codegen_ty = self.codegen_field_ty(ty);
+        let collection = match &codegen_ty {
+            CodegenTy::Set(_, kind) | CodegenTy::Map(_, _, kind) => *kind,
+            _ => CollectionKind::Hash,
+        };
+        let decode = self.codegen_decode_builtin_ty(helper, ty, collection);
+        // `Bytes` and the types mapped by a custom `TyTransformer` are built from the
+        // default ones
+        match (&ty.kind, codegen_ty) {
+            (ty::String | ty::Bytes, CodegenTy::Bytes | CodegenTy::Custom(..)) => {
+                quote! { ::std::convert::From::from(#decode) }
+            }
+            (ty::Vec(_) | ty::Set(_) | ty::Map(..), CodegenTy::Custom(..)) => {
+                quote! { ::std::iter::FromIterator::from_iter(#decode) }
+            }
+            _ => decode,
+        }
+    }
+
+    fn codegen_decode_builtin_ty(
+        &self,
+        helper: &DecodeHelper,
+        ty: &Ty,
+        collection: CollectionKind,
+    ) -> TokenStream {
         match &ty.kind {
             ty::String => helper.codegen_read_string(),
             ty::Void => {
@@ -214,8 +308,8 @@ impl ThriftBackend {
             ty::U8 => helper.codegen_read_byte(),
             ty::Bool => helper.codegen_read_bool(),
             ty::Bytes => helper.codegen_read_bytes(),
-            ty::I8 => helper.codegen_read_i8(),
-            ty::I16 => helper.codegen_read_i16(),
+            ty::I8 => self.codegen_widen(helper.codegen_read_i8()),
+            ty::I16 => self.codegen_widen(helper.codegen_read_i16()),
             ty::I32 => helper.codegen_read_i32(),
             ty::I64 => helper.codegen_read_i64(),
             ty::F64 => helper.codegen_read_double(),
@@ -239,9 +333,11 @@ impl ThriftBackend {
                 let read_set_begin = helper.codegen_read_set_begin();
                 let read_set_end = helper.codegen_read_set_end();
                 let read_el = self.codegen_decode_ty(helper, ty);
+                let new_set =
+                    collection.with_capacity(collection.set_path(), quote!(list_ident.size));
                 quote! {{
                     let list_ident  = #read_set_begin;
-                    let mut val = ::std::collections::HashSet::with_capacity(list_ident.size);
+                    let mut val = #new_set;
                     for _ in 0..list_ident.size {
                         val.insert(#read_el);
                     };
@@ -255,11 +351,13 @@ impl ThriftBackend {
 
                 let read_map_begin = helper.codegen_read_map_begin();
                 let read_map_end = helper.codegen_read_map_end();
+                let new_map =
+                    collection.with_capacity(collection.map_path(), quote!(map_ident.size));
 
                 quote! {
                     {
                         let map_ident = #read_map_begin;
-                        let mut val = ::std::collections::HashMap::with_capacity(map_ident.size);
+                        let mut val = #new_map;
                         for _ in 0..map_ident.size {
                             let el_key = #read_el_key;
                             let el_val = #read_el_val;
@@ -271,7 +369,11 @@ impl ThriftBackend {
                     }
                 }
             }
-            ty::Path(_) => helper.codegen_item_decode(),
+            ty::Arc(inner) if !matches!(inner.kind, ty::Path(_)) => {
+                let decode = self.codegen_decode_ty(helper, inner);
+                quote! { ::std::sync::Arc::new(#decode) }
+            }
+            ty::Path(_) | ty::Arc(_) => helper.codegen_item_decode(),
             _ => unimplemented!(),
         }
     }
@@ -1,4 +1,5 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
 
 use crate::{
     middle::rir::{self, Method},
@@ -13,4 +14,16 @@ pub trait CodegenBackend {
     }
     fn codegen_enum_impl(&self, _def_id: DefId, _stream: &mut TokenStream, _e: &rir::Enum) {}
     fn codegen_newtype_impl(&self, _def_id: DefId, _stream: &mut TokenStream, _t: &rir::NewType) {}
+    /// Emits a `FIELD_WIRE_TYPES` const with the id and the wire type of every field.
+    fn codegen_field_wire_types(&self, _stream: &mut TokenStream, _s: &rir::Message) {}
+    /// Emits a `changed_from` method returning the ids of the fields differing from another
+    /// instance.
+    fn codegen_changed_from(&self, _stream: &mut TokenStream, _s: &rir::Message) {}
+    /// `variants` are the tags and names of the messages wrapped by the
+    /// `AnyMessage` enum of a package.
+    fn codegen_any_message_impl(&self, _stream: &mut TokenStream, _variants: &[(i32, Ident)]) {}
+    /// The type of the `_unknown_fields` of the messages kept by `preserve_unknown_fields`.
+    fn codegen_unknown_fields_ty(&self) -> TokenStream {
+        quote!(::pilota::thrift::unknown::UnknownFields)
+    }
 }
@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::Codegen;
+use crate::middle::{
+    rir,
+    ty::{self, AdtDef, AdtKind, CodegenTy},
+};
+
+/// How a field is held by the view of its message.
+enum ViewField {
+    /// `&'a str`, copied into a `String` again.
+    Str,
+    /// `&'a [u8]`, copied into `Bytes` or a `Vec<u8>` again.
+    Bytes { use_bytes: bool },
+    /// The view of a nested message.
+    Message(syn::Path),
+    /// Copied as it is.
+    Copy(CodegenTy),
+    /// A reference to the owned field, cloned again.
+    Ref(TokenStream),
+}
+
+impl ViewField {
+    fn borrows(&self) -> bool {
+        !matches!(self, ViewField::Copy(_))
+    }
+
+    fn ty(&self) -> TokenStream {
+        match self {
+            ViewField::Str => quote! { &'a str },
+            ViewField::Bytes { .. } => quote! { &'a [u8] },
+            ViewField::Message(path) => quote! { #path<'a> },
+            ViewField::Copy(ty) => quote! { #ty },
+            ViewField::Ref(ty) => quote! { &'a #ty },
+        }
+    }
+
+    /// Borrows the field `v` of an owned message.
+    fn borrow(&self, v: TokenStream, optional: bool) -> TokenStream {
+        match (self, optional) {
+            (ViewField::Str | ViewField::Bytes { .. }, true) => quote! { #v.as_deref() },
+            (ViewField::Str, false) => quote! { #v.as_str() },
+            (ViewField::Bytes { .. }, false) => quote! { &#v[..] },
+            (ViewField::Message(_), true) => quote! { #v.as_ref().map(|v| v.as_ref_view()) },
+            (ViewField::Message(_), false) => quote! { #v.as_ref_view() },
+            (ViewField::Copy(_), _) => v,
+            (ViewField::Ref(_), true) => quote! { #v.as_ref() },
+            (ViewField::Ref(_), false) => quote! { &#v },
+        }
+    }
+
+    /// Builds the owned field from the field `v` of a view.
+    fn owned(&self, v: TokenStream, optional: bool) -> TokenStream {
+        let owned = |v: TokenStream| match self {
+            ViewField::Str => quote! { #v.to_string() },
+            ViewField::Bytes { use_bytes: true } => {
+                quote! { ::pilota::bytes::Bytes::copy_from_slice(#v) }
+            }
+            ViewField::Bytes { use_bytes: false } => quote! { #v.to_vec() },
+            ViewField::Message(_) => quote! { ::std::convert::From::from(#v) },
+            ViewField::Copy(_) => v,
+            ViewField::Ref(_) => quote! { #v.clone() },
+        };
+        match (self, optional) {
+            (ViewField::Copy(_), _) => v,
+            (ViewField::Ref(_), true) => quote! { #v.cloned() },
+            (ViewField::Message(_), true) => quote! { #v.map(::std::convert::From::from) },
+            (_, true) => {
+                let owned = owned(quote!(v));
+                quote! { #v.map(|v| #owned) }
+            }
+            (_, false) => owned(v),
+        }
+    }
+}
+
+impl<B> Codegen<B> {
+    fn view_field(&self, f: &rir::Field) -> ViewField {
+        let ty = self.codegen_field_ty(&f.ty);
+        if self.adjust(f.did).is_some_and(|a| a.boxed()) {
+            return ViewField::Ref(quote! { ::std::boxed::Box<#ty> });
+        }
+        match (&f.ty.kind, ty) {
+            (ty::String, CodegenTy::String) => ViewField::Str,
+            (ty::Bytes, CodegenTy::Bytes) => ViewField::Bytes { use_bytes: true },
+            (ty::Bytes, CodegenTy::Vec(_)) => ViewField::Bytes { use_bytes: false },
+            (
+                _,
+                CodegenTy::Adt(AdtDef {
+                    did,
+                    kind: AdtKind::Struct,
+                }),
+            ) => {
+                let mut path = self.cur_related_item_path(did);
+                let last = path.segments.last_mut().unwrap();
+                last.ident = format_ident!("{}Ref", last.ident);
+                ViewField::Message(path)
+            }
+            (
+                _,
+                ty @ (CodegenTy::Bool
+                | CodegenTy::U8
+                | CodegenTy::I8
+                | CodegenTy::I16
+                | CodegenTy::I32
+                | CodegenTy::I64
+                | CodegenTy::UInt32
+                | CodegenTy::UInt64
+                | CodegenTy::F32
+                | CodegenTy::F64),
+            ) => ViewField::Copy(ty),
+            (_, ty) => ViewField::Ref(quote! { #ty }),
+        }
+    }
+
+    /// `FooRef<'a>` borrows the strings, bytes, collections and nested messages of `Foo`, which
+    /// is borrowed by `as_ref_view` and built back with `From`.
+    pub(super) fn write_view(&self, stream: &mut TokenStream, s: &rir::Message) {
+        let name = format_ident!("{}", s.name.to_upper_camel_case(self.naming()));
+        let view_name = format_ident!("{}Ref", name);
+        let vis = self.visibility();
+        let fields = s
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f,
+                    format_ident!("{}", f.name.to_field_case(self.naming())),
+                    self.view_field(f),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let defs = fields.iter().map(|(f, field, view)| {
+            let ty = view.ty();
+            if f.is_optional() {
+                quote! { #vis #field: ::std::option::Option<#ty>, }
+            } else {
+                quote! { #vis #field: #ty, }
+            }
+        });
+        let borrows = fields.iter().map(|(f, field, view)| {
+            let v = view.borrow(quote!(self.#field), f.is_optional());
+            quote! { #field: #v, }
+        });
+        let owned = fields.iter().map(|(f, field, view)| {
+            let v = view.owned(quote!(view.#field), f.is_optional());
+            quote! { #field: #v, }
+        });
+        // the lifetime has to be used even if every field is copied
+        let marker = (!fields.iter().any(|(_, _, view)| view.borrows()))
+            .then(|| quote! { #vis _marker: ::std::marker::PhantomData<&'a ()>, });
+        let marker_init = marker
+            .is_some()
+            .then(|| quote! { _marker: ::std::marker::PhantomData, });
+        let extra_fields = self.extra_field_inits();
+        let item_vis = vis.of_items();
+
+        stream.extend(quote! {
+            #[derive(Clone, Copy, PartialEq)]
+            #item_vis struct #view_name<'a> {
+                #(#defs)*
+                #marker
+            }
+
+            impl #name {
+                pub fn as_ref_view(&self) -> #view_name<'_> {
+                    #view_name {
+                        #(#borrows)*
+                        #marker_init
+                    }
+                }
+            }
+
+            impl ::std::convert::From<#view_name<'_>> for #name {
+                fn from(view: #view_name<'_>) -> Self {
+                    #name {
+                        #(#owned)*
+                        #extra_fields
+                    }
+                }
+            }
+        });
+    }
+}
@@ -5,7 +5,7 @@ use fxhash::FxHashMap;
 use crate::{
     middle::{
         rir::{self},
-        ty::{AdtDef, AdtKind, CodegenTy, TyKind},
+        ty::{AdtDef, AdtKind, CodegenTy, ItemTyTransformer, Ty, TyKind},
         type_graph::TypeGraph,
     },
     symbol::{DefId, FileId},
@@ -41,6 +41,8 @@ pub trait RirDatabase {
     fn pkgs(&self) -> Arc<FxHashMap<rir::ItemPath, Arc<rir::Pkg>>>;
     #[salsa::input]
     fn type_graph(&self) -> Arc<TypeGraph>;
+    #[salsa::input]
+    fn ty_transformer(&self) -> ItemTyTransformer;
 
     fn node(&self, def_id: DefId) -> Option<rir::Node>;
     fn file(&self, file_id: FileId) -> Option<Arc<rir::File>>;
@@ -49,6 +51,7 @@ pub trait RirDatabase {
     fn pkg(&self, path: rir::ItemPath) -> Option<Arc<rir::Pkg>>;
     fn codegen_item_ty(&self, ty: TyKind) -> CodegenTy;
     fn codegen_const_ty(&self, ty: TyKind) -> CodegenTy;
+    fn codegen_stream_ty(&self, ty: Ty) -> CodegenTy;
     fn codegen_ty(&self, def_id: DefId) -> CodegenTy;
     fn service_methods(&self, def_id: DefId) -> Arc<[Arc<rir::Method>]>;
 }
@@ -81,8 +84,12 @@ fn file(db: &dyn RirDatabase, file_id: FileId) -> Option<Arc<rir::File>> {
     db.files().get(&file_id).cloned()
 }
 
-fn codegen_item_ty(_db: &dyn RirDatabase, ty: TyKind) -> CodegenTy {
-    ty.to_codegen_item_ty()
+fn codegen_item_ty(db: &dyn RirDatabase, ty: TyKind) -> CodegenTy {
+    db.ty_transformer().0.codegen_item_ty(&ty)
+}
+
+fn codegen_stream_ty(db: &dyn RirDatabase, ty: Ty) -> CodegenTy {
+    db.ty_transformer().0.stream(&ty)
 }
 
 fn codegen_const_ty(_db: &dyn RirDatabase, ty: TyKind) -> CodegenTy {
@@ -121,30 +128,15 @@ fn codegen_ty(db: &dyn RirDatabase, did: DefId) -> CodegenTy {
     }
 }
 
+/// The methods of a service, the resolver adds the inherited ones to the own methods already.
 fn service_methods(db: &dyn RirDatabase, def_id: DefId) -> Arc<[Arc<rir::Method>]> {
     let item = db.expect_item(def_id);
     let service = match &*item {
         rir::Item::Service(s) => s,
         _ => panic!(),
     };
-    let methods = service
-        .extend
-        .iter()
-        .flat_map(|p| {
-            db.service_methods(p.did)
-                .iter()
-                .map(|m| match m.source {
-                    rir::MethodSource::Extend(_) => m.clone(),
-                    rir::MethodSource::Own => Arc::from(rir::Method {
-                        source: rir::MethodSource::Extend(p.did),
-                        ..(**m).clone()
-                    }),
-                })
-                .collect::<Vec<_>>()
-        })
-        .chain(service.methods.iter().cloned());
 
-    Arc::from_iter(methods)
+    Arc::from_iter(service.methods.iter().cloned())
 }
 
 impl salsa::Database for RootDatabase {}
@@ -4,6 +4,39 @@ use std::{
     process::{exit, Command},
 };
 
+use proc_macro2::TokenStream;
+
+/// How the generated code is formatted before it is written out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Formatter {
+    /// Write the tokens as they are printed, which is mostly a single line.
+    None,
+    /// Run `rustfmt`, or the binary set by the `RUSTFMT` env var, on the file.
+    #[default]
+    Rustfmt,
+    /// Format in process with `prettyplease`, without requiring a toolchain.
+    #[cfg(feature = "prettyplease")]
+    PrettyPlease,
+}
+
+pub fn write_file<P: AsRef<Path>>(stream: TokenStream, file: P, formatter: Formatter) {
+    let file = file.as_ref();
+    let content = match formatter {
+        #[cfg(feature = "prettyplease")]
+        Formatter::PrettyPlease => prettyplease::unparse(&syn::parse2(stream).unwrap()),
+        _ => stream.to_string(),
+    };
+
+    let mut f = std::io::BufWriter::new(std::fs::File::create(file).unwrap());
+    f.write_all(content.as_bytes()).unwrap();
+    f.flush().unwrap();
+    drop(f);
+
+    if formatter == Formatter::Rustfmt {
+        fmt_file(file)
+    }
+}
+
 pub fn fmt_file<P: AsRef<Path>>(file: P) {
     let file = file.as_ref();
     if let Some(a) = file.extension() {
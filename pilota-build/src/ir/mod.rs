@@ -1,10 +1,10 @@
-use std::{fmt::Display, sync::Arc};
+use std::{fmt::Display, path::PathBuf, sync::Arc};
 
 use fxhash::FxHashMap;
 use itertools::Itertools;
 
 use crate::{
-    symbol::{EnumRepr, FileId, Ident, Symbol},
+    symbol::{EnumRepr, FileId, Ident, Span, Symbol},
     tags::Tags,
 };
 
@@ -88,6 +88,8 @@ pub struct Const {
 pub enum FieldKind {
     Required,
     Optional,
+    /// A protobuf field without presence, which is left at its default when absent.
+    Implicit,
 }
 
 #[derive(Clone, Debug)]
@@ -97,6 +99,7 @@ pub struct Field {
     pub ty: Ty,
     pub kind: FieldKind,
     pub tags: Arc<Tags>,
+    pub default: Option<Literal>,
 }
 
 #[derive(Clone, Debug)]
@@ -111,6 +114,8 @@ pub struct EnumVariant {
     pub name: Ident,
     pub discr: Option<i64>,
     pub fields: Vec<Ty>,
+    /// The default value of a union member, which makes its variant the default one.
+    pub default: Option<Literal>,
     pub tags: Arc<Tags>,
 }
 
@@ -148,6 +153,7 @@ pub enum ItemKind {
 pub struct Item {
     pub kind: ItemKind,
     pub tags: Arc<Tags>,
+    pub span: Option<Span>,
 }
 
 impl Item {
@@ -171,6 +177,7 @@ pub struct Use {
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Path {
     pub segments: Arc<[Ident]>,
+    pub span: Option<Span>,
 }
 
 impl Display for Path {
@@ -181,6 +188,7 @@ impl Display for Path {
 
 #[derive(Clone, Debug)]
 pub struct File {
+    pub path: Arc<PathBuf>,
     pub package: Path,
     pub items: Vec<Arc<Item>>,
     pub id: FileId,
@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+
+use crate::{codegen::pkg_tree::PkgNode, db::RirDatabase, rir::ItemPath, Context};
+
+/// The modules of the output and the items defined in each of them, as reported by
+/// [`Builder::dry_run`](crate::Builder::dry_run).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModuleLayout {
+    /// The names of the items of every module keyed by its path relative to the output, like
+    /// `a::b`. A package flattened to the root of the output has an empty path.
+    pub modules: BTreeMap<String, Vec<String>>,
+}
+
+impl ModuleLayout {
+    pub(crate) fn new(cx: &Context) -> Self {
+        let mut modules = BTreeMap::new();
+        let pkgs = cx.pkgs();
+
+        let mut add = |path: &ItemPath| {
+            let items = pkgs
+                .get(path)
+                .into_iter()
+                .flat_map(|pkg| &pkg.items)
+                .map(|def_id| cx.item_path(*def_id).last().unwrap().to_string())
+                .collect();
+            modules.insert(path.iter().join("::"), items);
+        };
+
+        fn walk(nodes: &[PkgNode], add: &mut impl FnMut(&ItemPath)) {
+            nodes.iter().for_each(|node| {
+                add(&node.path);
+                walk(&node.children, add);
+            })
+        }
+
+        let root = ItemPath::from(Vec::new());
+        if pkgs.contains_key(&root) {
+            add(&root);
+        }
+        walk(
+            &PkgNode::from_pkgs(&pkgs.keys().cloned().collect::<Vec<_>>()),
+            &mut add,
+        );
+
+        ModuleLayout { modules }
+    }
+}
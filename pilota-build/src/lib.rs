@@ -3,11 +3,13 @@
 )]
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
+mod cache;
 pub mod codegen;
 pub mod db;
 mod fmt;
 mod index;
 mod ir;
+mod layout;
 mod middle;
 pub mod parser;
 mod resolve;
@@ -15,7 +17,6 @@ mod symbol;
 pub mod tags;
 mod util;
 use std::{
-    io::Write,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -23,30 +24,51 @@ use std::{
 pub mod plugin;
 mod test;
 
+use cache::{resolve_key, CachedResolve};
+pub use cache::{MemoryResolveCache, ResolveCache};
 use codegen::protobuf::{ProstPlugin, ProtobufBackend};
-pub use codegen::{thrift::ThriftBackend, traits::CodegenBackend, Codegen};
+pub use codegen::{
+    pkg_tree::PathBase, thrift::ThriftBackend, traits::CodegenBackend, Codegen, Visibility,
+};
 use db::RootDatabase;
-use fmt::fmt_file;
-use fxhash::FxHashMap;
+use fmt::write_file;
+pub use fmt::Formatter;
+use fxhash::{FxHashMap, FxHashSet};
+use itertools::Itertools;
+pub use layout::ModuleLayout;
 use middle::{
     context::tls::CONTEXT,
-    rir::{NodeKind, Pkg},
+    rir::{ItemPath, NodeKind, Pkg},
+    ty::{ItemTyTransformer, TyTransformer},
     type_graph::TypeGraph,
 };
-pub use middle::{rir, ty};
+pub use middle::{
+    rir, ty,
+    ty::{CollectionKind, SmallIntWidth},
+};
 use parser::{protobuf::ProtobufParser, thrift::ThriftParser, ParseResult, Parser};
 use plugin::{
-    AutoDerivePlugin, BoxedPlugin, EnumNumPlugin, ImplDefaultPlugin, PredicateResult,
+    AutoDerivePlugin, BoxedPlugin, EnumNumPlugin, ImplDefaultPlugin, PredicateResult, SerdePlugin,
     WithAttrsPlugin,
 };
-pub use plugin::{BoxClonePlugin, ClonePlugin, Plugin};
-use resolve::{ResolveResult, Resolver};
+pub use plugin::{
+    BoxClonePlugin, ClonePlugin, DefaultImpl, DerivePlugin, NodePlugin, Plugin, SerdeEnumRepr,
+    ValidatorPlugin,
+};
+pub use resolve::ResolveResult;
+use resolve::Resolver;
 use salsa::{Durability, ParallelDatabase};
-pub use symbol::DefId;
+pub use symbol::{DefId, FieldCase, LeadingUnderscore, Naming, Symbol};
+use symbol::{FileId, TypeNameAffixes};
 use syn::parse_quote;
 
 use crate::db::RirDatabase;
-pub use crate::middle::context::Context;
+use crate::middle::context::ModuleDoc;
+pub use crate::middle::context::{tls::with_cx, Context};
+
+type RenameModules = Box<dyn FnMut(&ItemPath) -> Symbol>;
+
+type ArcFields = Box<dyn Fn(DefId, &str) -> bool>;
 
 pub trait MakeBackend: Sized {
     type Target: CodegenBackend;
@@ -77,6 +99,59 @@ pub struct Builder<MkB, P> {
     mk_backend: MkB,
     parser: P,
     plugins: Vec<Box<dyn Plugin>>,
+    node_plugins: Vec<Box<dyn NodePlugin>>,
+    gen_client: bool,
+    gen_service_methods: bool,
+    reexport_oneof_payloads: bool,
+    gen_oneof_dispatcher: bool,
+    gen_oneof_from_impls: bool,
+    gen_any_message: bool,
+    gen_wire_names: bool,
+    gen_field_wire_types: bool,
+    gen_changed_from: bool,
+    gen_constructors: bool,
+    gen_view_types: bool,
+    gen_has_accessors: bool,
+    with_builders: bool,
+    unknown_union_variant: bool,
+    open_enums: bool,
+    enum_string_conversions: bool,
+    collection_kind: CollectionKind,
+    small_int_width: SmallIntWidth,
+    path_base: PathBase,
+    flatten_single_package: bool,
+    use_bytes: bool,
+    track_dirty_fields: bool,
+    gen_encoded_len: bool,
+    preserve_unknown_fields: bool,
+    with_serde: bool,
+    with_validator: bool,
+    serde_enum_repr: SerdeEnumRepr,
+    gen_to_json_value: bool,
+    gen_thrift_json: bool,
+    gen_doc_stubs: bool,
+    default_impl: DefaultImpl,
+    visibility: Visibility,
+    transparent_typedefs: bool,
+    empty_optional_collections: bool,
+    disambiguate_names: bool,
+    strict_resolution: bool,
+    naming: Naming,
+    type_name_affixes: TypeNameAffixes,
+    ty_transformer: ItemTyTransformer,
+    box_cycles: bool,
+    box_large_variants: Option<usize>,
+    arc_fields: Option<ArcFields>,
+    conversions: Vec<(String, String)>,
+    module_doc: Option<ModuleDoc>,
+    rename_modules: Option<RenameModules>,
+    formatter: Formatter,
+    output_size_limit: Option<u64>,
+    include_dirs: Vec<PathBuf>,
+    input_globs: Vec<(String, bool)>,
+    extern_packages: Vec<(ItemPath, syn::Path)>,
+    well_known_types: bool,
+    resolve_cache: Option<Arc<dyn ResolveCache>>,
 }
 
 impl Builder<MkThriftBackend, ThriftParser> {
@@ -89,6 +164,59 @@ impl Builder<MkThriftBackend, ThriftParser> {
                 Box::new(ImplDefaultPlugin),
                 Box::new(EnumNumPlugin),
             ],
+            node_plugins: Vec::new(),
+            gen_client: false,
+            gen_service_methods: false,
+            reexport_oneof_payloads: false,
+            gen_oneof_dispatcher: false,
+            gen_oneof_from_impls: false,
+            gen_any_message: false,
+            gen_wire_names: false,
+            gen_field_wire_types: false,
+            gen_changed_from: false,
+            gen_constructors: false,
+            gen_view_types: false,
+            gen_has_accessors: false,
+            with_builders: false,
+            unknown_union_variant: false,
+            open_enums: false,
+            enum_string_conversions: false,
+            collection_kind: CollectionKind::default(),
+            small_int_width: SmallIntWidth::default(),
+            path_base: PathBase::default(),
+            flatten_single_package: false,
+            use_bytes: false,
+            track_dirty_fields: false,
+            gen_encoded_len: false,
+            preserve_unknown_fields: false,
+            with_serde: false,
+            with_validator: false,
+            serde_enum_repr: SerdeEnumRepr::default(),
+            gen_to_json_value: false,
+            gen_thrift_json: false,
+            gen_doc_stubs: false,
+            default_impl: DefaultImpl::default(),
+            visibility: Visibility::default(),
+            transparent_typedefs: false,
+            empty_optional_collections: false,
+            disambiguate_names: false,
+            strict_resolution: false,
+            naming: Naming::default(),
+            type_name_affixes: TypeNameAffixes::default(),
+            ty_transformer: ItemTyTransformer::default(),
+            box_cycles: false,
+            box_large_variants: None,
+            arc_fields: None,
+            conversions: Vec::new(),
+            module_doc: None,
+            rename_modules: None,
+            formatter: Formatter::default(),
+            output_size_limit: None,
+            include_dirs: Vec::new(),
+            input_globs: Vec::new(),
+            extern_packages: Vec::new(),
+            well_known_types: false,
+            resolve_cache: None,
         }
     }
 }
@@ -99,6 +227,59 @@ impl Builder<MkProtobufBackend, ProtobufParser> {
             mk_backend: MkProtobufBackend,
             parser: ProtobufParser::default(),
             plugins: vec![Box::new(ProstPlugin)],
+            node_plugins: Vec::new(),
+            gen_client: false,
+            gen_service_methods: false,
+            reexport_oneof_payloads: false,
+            gen_oneof_dispatcher: false,
+            gen_oneof_from_impls: false,
+            gen_any_message: false,
+            gen_wire_names: false,
+            gen_field_wire_types: false,
+            gen_changed_from: false,
+            gen_constructors: false,
+            gen_view_types: false,
+            gen_has_accessors: false,
+            with_builders: false,
+            unknown_union_variant: false,
+            open_enums: false,
+            enum_string_conversions: false,
+            collection_kind: CollectionKind::default(),
+            small_int_width: SmallIntWidth::default(),
+            path_base: PathBase::default(),
+            flatten_single_package: false,
+            use_bytes: false,
+            track_dirty_fields: false,
+            gen_encoded_len: false,
+            preserve_unknown_fields: false,
+            with_serde: false,
+            with_validator: false,
+            serde_enum_repr: SerdeEnumRepr::default(),
+            gen_to_json_value: false,
+            gen_thrift_json: false,
+            gen_doc_stubs: false,
+            default_impl: DefaultImpl::default(),
+            visibility: Visibility::default(),
+            transparent_typedefs: false,
+            empty_optional_collections: false,
+            disambiguate_names: false,
+            strict_resolution: false,
+            naming: Naming::default(),
+            type_name_affixes: TypeNameAffixes::default(),
+            ty_transformer: ItemTyTransformer::default(),
+            box_cycles: true,
+            box_large_variants: None,
+            arc_fields: None,
+            conversions: Vec::new(),
+            module_doc: None,
+            rename_modules: None,
+            formatter: Formatter::default(),
+            output_size_limit: None,
+            include_dirs: Vec::new(),
+            input_globs: Vec::new(),
+            extern_packages: Vec::new(),
+            well_known_types: false,
+            resolve_cache: None,
         }
     }
 }
@@ -108,9 +289,44 @@ where
     P: Parser,
 {
     pub fn include_dirs(mut self, include_dirs: Vec<PathBuf>) -> Self {
+        self.include_dirs = include_dirs.clone();
         self.parser.include_dirs(include_dirs);
         self
     }
+
+    /// Compile the files matching `pattern` along with the ones given to
+    /// [`Builder::compile`], see [`Parser::input_glob`]. The pattern is expanded when building.
+    pub fn input_glob(mut self, pattern: impl Into<String>, skip_include_only: bool) -> Self {
+        self.input_globs.push((pattern.into(), skip_include_only));
+        self
+    }
+}
+
+impl<MkB> Builder<MkB, ThriftParser> {
+    /// Mark the thrift enums `#[non_exhaustive]` and add an `Unknown(i32)` variant which keeps
+    /// the unrecognized values instead of failing the decoding. There is no such option for
+    /// protobuf, whose enum fields are `i32`s keeping the unknown values already, as prost
+    /// requires the enums to be C-like.
+    pub fn open_enums(mut self, open_enums: bool) -> Self {
+        self.open_enums = open_enums;
+        self
+    }
+
+    /// Generate setters for the fields of thrift structs, which remember the ids of the
+    /// changed fields for `dirty_fields`. There is no such option for protobuf, as the derive
+    /// of prost cannot skip the field keeping them.
+    pub fn track_dirty_fields(mut self, track_dirty_fields: bool) -> Self {
+        self.track_dirty_fields = track_dirty_fields;
+        self
+    }
+
+    /// Generate an `encoded_len` method on the thrift messages, which computes the length of
+    /// the message encoded with the binary protocol without encoding it, to preallocate the
+    /// buffers. The protobuf messages have one from prost already.
+    pub fn gen_encoded_len(mut self, gen_encoded_len: bool) -> Self {
+        self.gen_encoded_len = gen_encoded_len;
+        self
+    }
 }
 
 impl<MkB, P> Builder<MkB, P> {
@@ -119,9 +335,455 @@ impl<MkB, P> Builder<MkB, P> {
             mk_backend,
             parser: self.parser,
             plugins: self.plugins,
+            node_plugins: self.node_plugins,
+            gen_client: self.gen_client,
+            gen_service_methods: self.gen_service_methods,
+            reexport_oneof_payloads: self.reexport_oneof_payloads,
+            gen_oneof_dispatcher: self.gen_oneof_dispatcher,
+            gen_oneof_from_impls: self.gen_oneof_from_impls,
+            gen_any_message: self.gen_any_message,
+            gen_wire_names: self.gen_wire_names,
+            gen_field_wire_types: self.gen_field_wire_types,
+            gen_changed_from: self.gen_changed_from,
+            gen_constructors: self.gen_constructors,
+            gen_view_types: self.gen_view_types,
+            gen_has_accessors: self.gen_has_accessors,
+            with_builders: self.with_builders,
+            unknown_union_variant: self.unknown_union_variant,
+            open_enums: self.open_enums,
+            enum_string_conversions: self.enum_string_conversions,
+            collection_kind: self.collection_kind,
+            small_int_width: self.small_int_width,
+            path_base: self.path_base,
+            flatten_single_package: self.flatten_single_package,
+            use_bytes: self.use_bytes,
+            track_dirty_fields: self.track_dirty_fields,
+            gen_encoded_len: self.gen_encoded_len,
+            preserve_unknown_fields: self.preserve_unknown_fields,
+            with_serde: self.with_serde,
+            with_validator: self.with_validator,
+            serde_enum_repr: self.serde_enum_repr,
+            gen_to_json_value: self.gen_to_json_value,
+            gen_thrift_json: self.gen_thrift_json,
+            gen_doc_stubs: self.gen_doc_stubs,
+            default_impl: self.default_impl,
+            visibility: self.visibility,
+            transparent_typedefs: self.transparent_typedefs,
+            empty_optional_collections: self.empty_optional_collections,
+            disambiguate_names: self.disambiguate_names,
+            strict_resolution: self.strict_resolution,
+            naming: self.naming,
+            type_name_affixes: self.type_name_affixes,
+            ty_transformer: self.ty_transformer,
+            box_cycles: self.box_cycles,
+            box_large_variants: self.box_large_variants,
+            arc_fields: self.arc_fields,
+            conversions: self.conversions,
+            module_doc: self.module_doc,
+            rename_modules: self.rename_modules,
+            formatter: self.formatter,
+            output_size_limit: self.output_size_limit,
+            include_dirs: self.include_dirs,
+            input_globs: self.input_globs,
+            extern_packages: self.extern_packages,
+            well_known_types: self.well_known_types,
+            resolve_cache: self.resolve_cache,
         }
     }
 
+    /// Generate a client stub delegating to a user provided transport for each service.
+    /// The streaming methods, marked by the `streaming.mode` annotation, are left out of the
+    /// thrift clients, as the transport only sends a request and receives a response.
+    pub fn gen_client(mut self, gen_client: bool) -> Self {
+        self.gen_client = gen_client;
+        self
+    }
+
+    /// Generate the methods of the protobuf service traits, taking and returning a
+    /// `pilota::BoxStream` for the streaming requests and responses. The traits are empty
+    /// otherwise, as the RPC frameworks usually generate the services themselves. The methods
+    /// of the thrift services are always generated.
+    pub fn gen_service_methods(mut self, gen_service_methods: bool) -> Self {
+        self.gen_service_methods = gen_service_methods;
+        self
+    }
+
+    /// Re-export the message payloads of oneof variants alongside the oneof enum.
+    pub fn reexport_oneof_payloads(mut self, reexport_oneof_payloads: bool) -> Self {
+        self.reexport_oneof_payloads = reexport_oneof_payloads;
+        self
+    }
+
+    /// Generate a `map_variant` method on oneof enums taking a handler for every variant.
+    pub fn gen_oneof_dispatcher(mut self, gen_oneof_dispatcher: bool) -> Self {
+        self.gen_oneof_dispatcher = gen_oneof_dispatcher;
+        self
+    }
+
+    /// Implement `From` the payload of each variant for the oneof enums, except for the
+    /// payload types shared by several variants.
+    pub fn gen_oneof_from_impls(mut self, gen_oneof_from_impls: bool) -> Self {
+        self.gen_oneof_from_impls = gen_oneof_from_impls;
+        self
+    }
+
+    /// Generate an `AnyMessage` enum over the messages of every package, which
+    /// can decode a message by its tag. The tag of a message is a hash of its full
+    /// name, so it stays the same when the other messages change.
+    pub fn gen_any_message(mut self, gen_any_message: bool) -> Self {
+        self.gen_any_message = gen_any_message;
+        self
+    }
+
+    /// Implement `pilota::protobuf::WireName` for the protobuf messages with their fully
+    /// qualified names, and the `pack` and `unpack` methods for `google.protobuf.Any`,
+    /// which need it on the packed messages.
+    pub fn gen_wire_names(mut self, gen_wire_names: bool) -> Self {
+        self.gen_wire_names = gen_wire_names;
+        self
+    }
+
+    /// Generate a `FIELD_WIRE_TYPES` const for every message, which lists the id and the
+    /// wire type of its fields: a `TType` for thrift and a prost `WireType` for protobuf.
+    pub fn gen_field_wire_types(mut self, gen_field_wire_types: bool) -> Self {
+        self.gen_field_wire_types = gen_field_wire_types;
+        self
+    }
+
+    /// Generate a `changed_from` method for every thrift message, which returns the ids of the
+    /// fields differing from another instance. The fields are compared with `==`, so nested
+    /// messages are compared as a whole and floats with exact equality.
+    pub fn gen_changed_from(mut self, gen_changed_from: bool) -> Self {
+        self.gen_changed_from = gen_changed_from;
+        self
+    }
+
+    /// Generate a `new` constructor for every message with required fields, which takes them in
+    /// the order of their ids and initializes the optional fields to their defaults.
+    pub fn gen_constructors(mut self, gen_constructors: bool) -> Self {
+        self.gen_constructors = gen_constructors;
+        self
+    }
+
+    /// Generate a `FooRef<'a>` view for every message `Foo`, borrowing its strings, bytes,
+    /// collections and nested messages. `Foo::as_ref_view` borrows a message and
+    /// `Foo::from` copies a view into an owned message.
+    pub fn gen_view_types(mut self, gen_view_types: bool) -> Self {
+        self.gen_view_types = gen_view_types;
+        self
+    }
+
+    /// Generate a `has_x` method for every optional field `x`, which tells whether it is
+    /// present. The proto3 fields not marked `optional` have no presence and get none.
+    pub fn gen_has_accessors(mut self, gen_has_accessors: bool) -> Self {
+        self.gen_has_accessors = gen_has_accessors;
+        self
+    }
+
+    /// Generate a `FooBuilder` for every message `Foo`, created by `Foo::builder`, with a
+    /// setter for every field. `build` fails with a [`pilota::MissingField`] when a required
+    /// field without a default is not set.
+    pub fn with_builders(mut self, with_builders: bool) -> Self {
+        self.with_builders = with_builders;
+        self
+    }
+
+    /// Add an `Unknown` variant to thrift unions which keeps the id of an unrecognized field.
+    pub fn unknown_union_variant(mut self, unknown_union_variant: bool) -> Self {
+        self.unknown_union_variant = unknown_union_variant;
+        self
+    }
+
+    /// Box the payloads of the thrift union and protobuf oneof variants estimated to be larger
+    /// than `threshold` bytes, so that a large variant does not grow every value of the enum.
+    /// See [`Context::estimated_size`] for how the sizes are estimated.
+    pub fn box_large_variants(mut self, threshold: usize) -> Self {
+        self.box_large_variants = Some(threshold);
+        self
+    }
+
+    /// Wrap the types of all the fields of every message in an `Arc` so the messages are cheap
+    /// to clone, see [`Builder::arc_fields_if`].
+    pub fn arc_fields(self) -> Self {
+        self.arc_fields_if(|_, _| true)
+    }
+
+    /// Wrap the types of the fields of the messages selected by `predicate`, which gets the id
+    /// and the name of the message, in an `Arc` and generate accessors returning references to
+    /// the shared values, `get_foo` for the field `foo`. Only used by thrift as prost can not
+    /// encode an `Arc`.
+    pub fn arc_fields_if<F: Fn(DefId, &str) -> bool + 'static>(mut self, predicate: F) -> Self {
+        self.arc_fields = Some(Box::new(predicate));
+        self
+    }
+
+    /// Implement `Display` and `FromStr` for the enums with the variant names of the IDL, the
+    /// parsing is case sensitive and fails with `pilota::ParseEnumError` on an unknown name.
+    pub fn enum_string_conversions(mut self, enum_string_conversions: bool) -> Self {
+        self.enum_string_conversions = enum_string_conversions;
+        self
+    }
+
+    /// Choose the collection generated for maps and sets, `HashMap` and `HashSet` by default.
+    /// A thrift field can override it with the `rust.collection` annotation, and `Index`
+    /// requires the `indexmap` crate.
+    pub fn collection_kind(mut self, collection_kind: CollectionKind) -> Self {
+        self.collection_kind = collection_kind;
+        self
+    }
+
+    /// Choose the Rust integers generated for thrift `i8` and `i16`, they are still one and two
+    /// bytes on the wire and encoding a value which doesn't fit fails.
+    pub fn small_int_width(mut self, small_int_width: SmallIntWidth) -> Self {
+        self.small_int_width = small_int_width;
+        self
+    }
+
+    /// Choose how the generated code refers to the items of other modules, relative paths are
+    /// used by default. Absolute paths need to know where the output is included.
+    pub fn path_base(mut self, path_base: PathBase) -> Self {
+        self.path_base = path_base;
+        self
+    }
+
+    /// Generate `From<from> for to` between the messages named like `old.Foo`, which moves the
+    /// fields with the same names. The fields missing in `to` are dropped, the optional ones
+    /// missing in `from` are `None`. The types of the fields have to be the same, or messages
+    /// converted too. The names are the ones of the IDL, the full names for protobuf, such as
+    /// `pkg.Outer.Inner`; the oneofs of the same name are converted with their messages.
+    /// Generating panics on a field which can't be converted.
+    pub fn convert(mut self, from: &str, to: &str) -> Self {
+        self.conversions.push((from.into(), to.into()));
+        self
+    }
+
+    /// Refer to the items of `package`, such as `google.protobuf`, and of its sub packages through
+    /// `path`, such as `::prost_types`, instead of generating them. The package is generated in
+    /// another crate, with the same derives for the types to be usable in the generated ones.
+    pub fn extern_package(mut self, package: &str, path: proc_macro2::TokenStream) -> Self {
+        let path = syn::parse2(path)
+            .unwrap_or_else(|e| panic!("invalid path for the package `{}`: {}", package, e));
+        let package = ItemPath::from(package.split('.').map(Symbol::from).collect::<Vec<_>>());
+        self.extern_packages.push((package, path));
+        self
+    }
+
+    /// Map the well-known types of `google.protobuf` like prost-build does instead of
+    /// generating them: the wrappers become the primitives they wrap, `Empty` becomes `()` and
+    /// the others, such as `Timestamp`, refer to `::prost_types`, which the crate of the
+    /// generated code has to depend on. The packages given to
+    /// [`extern_package`](Self::extern_package) take precedence.
+    pub fn with_well_known_types(mut self, well_known_types: bool) -> Self {
+        self.well_known_types = well_known_types;
+        self
+    }
+
+    /// Strip the package prefix shared by all the idl files, so the items of a single package
+    /// are generated at the root of the output instead of in nested modules.
+    pub fn flatten_single_package(mut self, flatten_single_package: bool) -> Self {
+        self.flatten_single_package = flatten_single_package;
+        self
+    }
+
+    /// Generate `Bytes` instead of `Vec<u8>` for thrift binary and protobuf bytes.
+    pub fn use_bytes(mut self, use_bytes: bool) -> Self {
+        self.use_bytes = use_bytes;
+        self
+    }
+
+    /// Keep the fields of thrift structs and protobuf messages with unknown ids when decoding
+    /// and write them back when encoding. The protobuf messages implement `prost::Message`
+    /// themselves then, instead of deriving it, along with the getters, the setters and the
+    /// default values the derive generates.
+    pub fn preserve_unknown_fields(mut self, preserve_unknown_fields: bool) -> Self {
+        self.preserve_unknown_fields = preserve_unknown_fields;
+        self
+    }
+
+    /// Derive `Serialize` and `Deserialize` for the generated types, keeping the names in the IDL.
+    pub fn with_serde(mut self, with_serde: bool) -> Self {
+        self.with_serde = with_serde;
+        self
+    }
+
+    /// Add a placeholder doc comment to the generated public items without one, so the output
+    /// compiles under `#![deny(missing_docs)]`.
+    pub fn gen_doc_stubs(mut self, gen_doc_stubs: bool) -> Self {
+        self.gen_doc_stubs = gen_doc_stubs;
+        self
+    }
+
+    /// Derive `validator::Validate` for the messages with constraint annotations, instead of the
+    /// inherent `validate` which would shadow the one of the trait.
+    pub fn with_validator(mut self, with_validator: bool) -> Self {
+        self.with_validator = with_validator;
+        self
+    }
+
+    /// Choose how serde represents enums with discriminants, by the name of the variant by
+    /// default.
+    pub fn serde_enum_repr(mut self, serde_enum_repr: SerdeEnumRepr) -> Self {
+        self.serde_enum_repr = serde_enum_repr;
+        self
+    }
+
+    /// Generate a `to_json_value` method converting the generated types into a
+    /// `serde_json::Value`, without deriving serde.
+    pub fn gen_to_json_value(mut self, gen_to_json_value: bool) -> Self {
+        self.gen_to_json_value = gen_to_json_value;
+        self
+    }
+
+    /// Generate `to_thrift_json` and `from_thrift_json` methods for the thrift messages,
+    /// which read and write the field-id keyed and type-tagged JSON of `TJSONProtocol`.
+    /// As in the binary protocol, writing an `i8` or `i16` widened by `small_int_width` fails
+    /// when its value doesn't fit.
+    pub fn gen_thrift_json(mut self, gen_thrift_json: bool) -> Self {
+        self.gen_thrift_json = gen_thrift_json;
+        self
+    }
+
+    /// Choose between deriving `Default` for messages and implementing it with the default
+    /// values in the IDL, only used by thrift as prost implements it for protobuf messages.
+    pub fn default_impl(mut self, default_impl: DefaultImpl) -> Self {
+        self.default_impl = default_impl;
+        self
+    }
+
+    /// Choose the visibility of the generated structs, enums, fields and constants, `pub` by
+    /// default. The traits of services and the clients stay `pub`, and with
+    /// `Visibility::Inherited` the items are `pub(crate)` as the modules use each other's.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Use the aliased types in place of typedefs, the typedefs themselves are still generated.
+    pub fn transparent_typedefs(mut self, transparent_typedefs: bool) -> Self {
+        self.transparent_typedefs = transparent_typedefs;
+        self
+    }
+
+    /// Generate the optional list, set and map fields as bare collections instead of `Option`s,
+    /// which are left empty when absent from the wire and are always encoded.
+    pub fn empty_optional_collections(mut self, empty_optional_collections: bool) -> Self {
+        self.empty_optional_collections = empty_optional_collections;
+        self
+    }
+
+    /// Add a suffix to the fields and items named like an earlier one once converted to the case
+    /// of Rust, such as `foo_bar_2` for `fooBar` after `foo_bar`, instead of failing.
+    pub fn disambiguate_names(mut self, disambiguate_names: bool) -> Self {
+        self.disambiguate_names = disambiguate_names;
+        self
+    }
+
+    /// Only resolve the names matching a definition exactly. By default a name which is not
+    /// found is also looked up in the module named after it in snake case, which is where a
+    /// protobuf message with nested items is generated, and that lookup may find another item.
+    pub fn strict_resolution(mut self, strict_resolution: bool) -> Self {
+        self.strict_resolution = strict_resolution;
+        self
+    }
+
+    /// Choose how leading underscores of IDL names are handled, they are stripped by default.
+    pub fn leading_underscore(mut self, leading_underscore: LeadingUnderscore) -> Self {
+        self.naming.leading_underscore = leading_underscore;
+        self
+    }
+
+    /// Choose the case of the fields and method arguments, `snake_case` by default. It applies
+    /// to all the files of the build so that the references between them agree.
+    pub fn field_case(mut self, field_case: FieldCase) -> Self {
+        self.naming.field_case = field_case;
+        self
+    }
+
+    /// Set the suffix appended to the names which are Rust keywords that can not be raw
+    /// identifiers, like `self_` for `self`, `_` by default. The other keywords are written as
+    /// raw identifiers, like `r#type`.
+    pub fn keyword_suffix(mut self, keyword_suffix: impl Into<String>) -> Self {
+        self.naming.keyword_suffix = Arc::from(keyword_suffix.into());
+        self
+    }
+
+    /// Prepend a prefix to the names of all generated types, module and field names are kept.
+    pub fn type_name_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.type_name_affixes.prefix = prefix.into();
+        self
+    }
+
+    /// Append a suffix to the names of all generated types, module and field names are kept.
+    pub fn type_name_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.type_name_affixes.suffix = suffix.into();
+        self
+    }
+
+    /// Replace the mapping from IDL types to rust types, consts keep the default mapping.
+    pub fn ty_transformer<T: TyTransformer + Send + Sync + 'static>(mut self, t: T) -> Self {
+        self.ty_transformer = ItemTyTransformer(Arc::new(t));
+        self
+    }
+
+    /// Add a `//!` doc comment to the generated module of every package the hook returns one
+    /// for.
+    pub fn module_doc<F: Fn(&rir::ItemPath) -> Option<String> + 'static>(mut self, f: F) -> Self {
+        self.module_doc = Some(Box::new(f));
+        self
+    }
+
+    /// Generate the items of every package in the module at the root of the output named by the
+    /// hook, such as `api_v1` for `com.example.api.v1`. Two packages can not be given the same
+    /// name.
+    pub fn rename_modules<F: FnMut(&rir::ItemPath) -> Symbol + 'static>(mut self, f: F) -> Self {
+        self.rename_modules = Some(Box::new(f));
+        self
+    }
+
+    /// Reuse the parse and resolve results of a previous build whose files are unchanged.
+    pub fn resolve_cache(mut self, resolve_cache: Arc<dyn ResolveCache>) -> Self {
+        self.resolve_cache = Some(resolve_cache);
+        self
+    }
+
+    /// Choose how the generated file is formatted, `rustfmt` is run on it by default.
+    pub fn formatter(mut self, formatter: Formatter) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    /// Warn when the generated file is larger than `limit` bytes, which can make rustc slow,
+    /// so the idl files can be split and compiled separately.
+    pub fn output_size_limit(mut self, limit: u64) -> Self {
+        self.output_size_limit = Some(limit);
+        self
+    }
+
+    /// Derive the traits, such as `Hash` or `serde::Serialize`, for every generated message,
+    /// enum and newtype. The build panics when a type can not derive one of them, such as
+    /// `Hash` for a type with a float field, or with a map or set which is not a `BTreeMap`
+    /// or `BTreeSet`.
+    pub fn derive(self, derives: &[&str]) -> Self {
+        self.derive_if(derives, |_, _| true)
+    }
+
+    /// Derive the traits for the generated types selected by `predicate`, which gets the id
+    /// and the name of the type.
+    pub fn derive_if<F: Fn(DefId, &str) -> bool + 'static>(
+        self,
+        derives: &[&str],
+        predicate: F,
+    ) -> Self {
+        self.plugin(DerivePlugin::new(derives, predicate))
+    }
+
+    /// Run the plugin over the resolved nodes before the types are generated, the node
+    /// plugins run in the order they are added.
+    pub fn node_plugin<Plu: NodePlugin + 'static>(mut self, p: Plu) -> Self {
+        self.node_plugins.push(Box::new(p));
+        self
+    }
+
     pub fn plugin<Plu: Plugin + 'static>(mut self, p: Plu) -> Self {
         self.plugins.push(Box::new(p));
 
@@ -134,14 +796,192 @@ where
     MkB: MakeBackend,
     P: Parser,
 {
-    pub fn compile<O: AsRef<Path>>(mut self, files: &[impl AsRef<Path>], out: O) {
+    fn resolve_key(&self, files: &[impl AsRef<Path>]) -> u64 {
+        resolve_key((
+            std::any::type_name::<P>(),
+            files.iter().map(|f| f.as_ref()).collect::<Vec<_>>(),
+            &self.include_dirs,
+            self.naming.leading_underscore,
+            self.naming.field_case,
+            &self.type_name_affixes,
+            self.transparent_typedefs,
+            self.empty_optional_collections,
+            self.disambiguate_names,
+            self.strict_resolution,
+        ))
+    }
+
+    fn cached_resolve(&self, key: u64) -> Option<ResolveResult> {
+        let cached = self
+            .resolve_cache
+            .as_ref()?
+            .get(key)
+            .filter(|cached| cached.is_fresh())?;
+        cached
+            .deps()
+            .for_each(|path| println!("cargo:rerun-if-changed={}", path.display()));
+        Some(cached.result.clone())
+    }
+
+    pub fn compile<O: AsRef<Path>>(self, files: &[impl AsRef<Path>], out: O) {
+        // the module wrapping the output is named after the file
+        let ns_name = out
+            .as_ref()
+            .file_name()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.split('.').next())
+            .unwrap()
+            .to_string();
+
+        let formatter = self.formatter;
+        let output_size_limit = self.output_size_limit;
+        let (cx, mk_backend) = self.build_context(files, Some(&ns_name));
+        let context = Arc::from(cx);
+        let pkgs = context.pkgs().keys().cloned().collect::<Vec<_>>();
+        CONTEXT.set(&context.clone(), || {
+            let mut cg = Codegen::new(context.clone(), mk_backend.make_backend(context));
+            cg.write_pkgs(&pkgs);
+
+            let stream = cg.link(&ns_name);
+
+            write_file(stream, &out, formatter)
+        });
+
+        if let Some(warning) =
+            output_size_limit.and_then(|limit| output_size_warning(out.as_ref(), limit))
+        {
+            println!("cargo:warning={warning}");
+        }
+    }
+
+    /// Parses and resolves `files` and runs the plugins like [`Builder::compile`], but passes
+    /// the resolved [`Context`] to `f` instead of writing Rust code, so a custom generator can
+    /// emit something else from it. [`with_cx`] works inside `f`, and so does
+    /// [`Context::cur_related_item_path`] within [`Context::in_item`].
+    pub fn generate<R>(self, files: &[impl AsRef<Path>], f: impl FnOnce(&Context) -> R) -> R {
+        let context = Arc::from(self.build_context(files, None).0);
+        CONTEXT.set(&context, || f(&context))
+    }
+
+    /// Parses and resolves `files` like [`Builder::compile`] and reports the modules it would
+    /// generate with the items of each of them, without generating or writing any code.
+    pub fn dry_run(self, files: &[impl AsRef<Path>]) -> ModuleLayout {
+        let context = Arc::from(self.build_context(files, None).0);
+        CONTEXT.set(&context, || ModuleLayout::new(&context))
+    }
+
+    /// `ns_name` is the name of the module wrapping the output, if any.
+    fn build_context(
+        mut self,
+        files: &[impl AsRef<Path>],
+        ns_name: Option<&str>,
+    ) -> (Context, MkB) {
         let _ = tracing_subscriber::fmt::try_init();
 
+        let mut inputs = files
+            .iter()
+            .map(|f| f.as_ref().to_path_buf())
+            .collect::<Vec<_>>();
+        for (pattern, skip_include_only) in &self.input_globs {
+            let paths = parser::glob(pattern).into_iter().filter(|p| {
+                !inputs.contains(p) && (!skip_include_only || !self.parser.is_include_only(p))
+            });
+            inputs.extend(paths.collect::<Vec<_>>());
+        }
+        let files = &*inputs;
+
+        let key = self.resolve_key(files);
+        let cached = self.cached_resolve(key);
+
         let mut db = RootDatabase::default();
-        self.parser.inputs(files);
-        let ParseResult { files } = self.parser.parse();
+        db.set_ty_transformer_with_durability(self.ty_transformer, Durability::HIGH);
+        let ResolveResult {
+            mut files,
+            mut nodes,
+            mut tags,
+        } = match cached {
+            Some(result) => result,
+            None => {
+                let start = std::time::Instant::now();
+                self.parser.inputs(files);
+                self.parser.naming(self.naming.clone());
+                let ParseResult { files, .. } = self
+                    .parser
+                    .parse()
+                    .unwrap_or_else(|e| util::error_abort(e.to_string()));
+
+                let result = Resolver::default()
+                    .with_naming(self.naming.clone())
+                    .with_type_name_affixes(self.type_name_affixes.clone())
+                    .with_transparent_typedefs(self.transparent_typedefs)
+                    .with_empty_optional_collections(self.empty_optional_collections)
+                    .with_disambiguate_names(self.disambiguate_names)
+                    .with_strict_resolution(self.strict_resolution)
+                    .resolve_files(&files)
+                    .unwrap_or_else(|errors| {
+                        util::error_abort(
+                            errors
+                                .iter()
+                                .map(|e| e.to_string())
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        )
+                    });
+
+                tracing::debug!(
+                    "parsed and resolved {} files in {:?}",
+                    files.len(),
+                    start.elapsed()
+                );
+
+                if let Some(cache) = &self.resolve_cache {
+                    if let Some(cached) =
+                        CachedResolve::new(&files, &self.include_dirs, result.clone())
+                    {
+                        cache.insert(key, Arc::new(cached));
+                    }
+                }
+                result
+            }
+        };
+
+        // the cached result is left untouched, the tags are copied on write
+        if let Some(predicate) = self.arc_fields.as_ref().filter(|_| !self.box_cycles) {
+            resolve::wrap_fields_in_arc(&mut nodes, &mut tags, predicate);
+        }
+        let mut def_ids = nodes.keys().copied().collect::<Vec<_>>();
+        def_ids.sort();
+        self.node_plugins.iter_mut().for_each(|p| {
+            def_ids.iter().for_each(|def_id| {
+                let node = nodes.get_mut(def_id).unwrap();
+                let node_tags = Arc::make_mut(tags.entry(node.tags).or_default());
+                p.on_node(*def_id, node, node_tags)
+            })
+        });
+
+        let google_protobuf =
+            ItemPath::from(vec![Symbol::from("google"), Symbol::from("protobuf")]);
+        let well_known_types = self.well_known_types
+            && !self
+                .extern_packages
+                .iter()
+                .any(|(package, _)| google_protobuf.starts_with(package));
+        if well_known_types {
+            self.extern_packages.extend(well_known_type_paths());
+        }
+
+        // the modules are renamed, the names in the IDL are kept for `convert`
+        let idl_packages = files
+            .iter()
+            .map(|(file_id, f)| (*file_id, f.package.clone()))
+            .collect::<FxHashMap<_, _>>();
+        if self.flatten_single_package {
+            flatten_packages(&mut files);
+        }
+        if let Some(rename) = &mut self.rename_modules {
+            rename_packages(&mut files, &self.extern_packages, rename);
+        }
 
-        let ResolveResult { files, nodes, tags } = Resolver::default().resolve_files(&files);
         db.set_files_with_durability(Arc::new(files), Durability::HIGH);
         let items = nodes.iter().filter_map(|(k, v)| {
             if let NodeKind::Item(item) = &v.kind {
@@ -155,39 +995,119 @@ where
         db.set_type_graph_with_durability(type_graph, Durability::HIGH);
         db.set_nodes_with_durability(Arc::new(nodes), Durability::HIGH);
 
+        // the derives of the types of prost-types can not be told from their definitions
+        let mut prost_types = FxHashSet::default();
+        if well_known_types {
+            db.files()
+                .values()
+                .filter(|f| f.package == google_protobuf)
+                .flat_map(|f| &f.items)
+                .filter(|def_id| {
+                    let name = db.item(**def_id).unwrap().symbol_name();
+                    !WELL_KNOWN_PRIMITIVES
+                        .iter()
+                        .any(|(primitive, _)| *name == **primitive)
+                })
+                .for_each(|def_id| {
+                    prost_types.insert(*def_id);
+                });
+        }
+        let is_prost_type =
+            |ty: &ty::Ty| matches!(&ty.kind, ty::Path(p) if prost_types.contains(&p.did));
+
         let mut pkgs = FxHashMap::default();
 
         db.files().iter().for_each(|(_, f)| {
             let pkg_path = &f.package;
+            if self
+                .extern_packages
+                .iter()
+                .any(|(package, _)| pkg_path.starts_with(package))
+            {
+                return;
+            }
             let pkg = pkgs.entry(pkg_path.clone()).or_insert_with(|| Pkg {
                 path: pkg_path.clone(),
                 items: Default::default(),
+                uses: Default::default(),
             });
 
             pkg.items.extend_from_slice(&f.items);
+            pkg.uses.extend(f.uses.iter().map(|(name, file_id)| {
+                (
+                    name.to_snake_case(&self.naming),
+                    db.file(*file_id).unwrap().package.clone(),
+                )
+            }));
         });
 
+        let pkg_paths = pkgs.keys().cloned().collect::<Vec<_>>();
+        pkgs.values_mut()
+            .for_each(|pkg| pkg.uses = pkg_uses(&db, pkg, &pkg_paths, &self.naming));
+
         let pkgs = Arc::from(
             pkgs.into_iter()
                 .map(|(path, pkg)| (path, Arc::from(pkg)))
                 .collect::<FxHashMap<_, _>>(),
         );
 
-        db.set_pkgs_with_durability(pkgs.clone(), Durability::HIGH);
+        db.set_pkgs_with_durability(pkgs, Durability::HIGH);
 
         let mut cx = Context::new(db.snapshot());
         cx.set_tags_map(tags);
+        cx.set_gen_client(self.gen_client);
+        cx.set_gen_service_methods(self.gen_service_methods);
+        cx.set_reexport_oneof_payloads(self.reexport_oneof_payloads);
+        cx.set_gen_oneof_dispatcher(self.gen_oneof_dispatcher);
+        cx.set_gen_oneof_from_impls(self.gen_oneof_from_impls);
+        cx.set_gen_any_message(self.gen_any_message);
+        cx.set_gen_wire_names(self.gen_wire_names);
+        cx.set_gen_field_wire_types(self.gen_field_wire_types);
+        cx.set_gen_changed_from(self.gen_changed_from);
+        cx.set_gen_constructors(self.gen_constructors);
+        cx.set_gen_view_types(self.gen_view_types);
+        cx.set_gen_has_accessors(self.gen_has_accessors);
+        cx.set_with_builders(self.with_builders);
+        cx.set_box_cycles(self.box_cycles);
+        cx.set_box_large_variants(self.box_large_variants);
+        cx.set_unknown_union_variant(self.unknown_union_variant);
+        cx.set_open_enums(self.open_enums);
+        cx.set_enum_string_conversions(self.enum_string_conversions);
+        cx.set_collection_kind(self.collection_kind);
+        cx.set_small_int_width(self.small_int_width);
+
+        cx.set_root_path(ns_name.and_then(|ns_name| self.path_base.root_path(ns_name)));
+        cx.set_extern_packages(self.extern_packages);
+        cx.set_use_bytes(self.use_bytes);
+        cx.set_track_dirty_fields(self.track_dirty_fields);
+        cx.set_gen_encoded_len(self.gen_encoded_len);
+        cx.set_preserve_unknown_fields(self.preserve_unknown_fields);
+        cx.set_with_serde(self.with_serde);
+        cx.set_with_validator(self.with_validator);
+        cx.set_serde_enum_repr(self.serde_enum_repr);
+        cx.set_gen_to_json_value(self.gen_to_json_value);
+        cx.set_gen_thrift_json(self.gen_thrift_json);
+        cx.set_gen_doc_stubs(self.gen_doc_stubs);
+        cx.set_default_impl(self.default_impl);
+        cx.set_visibility(self.visibility);
+        cx.set_module_doc(self.module_doc);
+        cx.set_naming(self.naming);
+        cx.set_type_name_affixes(self.type_name_affixes);
+        cx.set_idl_packages(idl_packages);
+        let conversions = cx
+            .resolve_conversions(&self.conversions)
+            .unwrap_or_else(|e| util::error_abort(e));
+        cx.set_conversions(conversions);
 
         cx.exec_plugin(BoxedPlugin);
 
         cx.exec_plugin(AutoDerivePlugin::new(
             vec![parse_quote!(#[derive(PartialOrd)])],
-            |ty| {
-                let ty = match &ty.kind {
-                    ty::Vec(ty) => ty,
-                    _ => ty,
-                };
-                if matches!(ty.kind, ty::Map(_, _) | ty::Set(_)) {
+            |mut ty| {
+                while let ty::Vec(inner) | ty::Arc(inner) = &ty.kind {
+                    ty = inner;
+                }
+                if matches!(ty.kind, ty::Map(_, _) | ty::Set(_)) || is_prost_type(ty) {
                     PredicateResult::No
                 } else {
                     PredicateResult::GoOn
@@ -197,12 +1117,13 @@ where
 
         cx.exec_plugin(AutoDerivePlugin::new(
             vec![parse_quote!(#[derive(Hash, Eq, Ord)])],
-            |ty| {
-                let ty = match &ty.kind {
-                    ty::Vec(ty) => ty,
-                    _ => ty,
-                };
-                if matches!(ty.kind, ty::Map(_, _) | ty::Set(_) | ty::F64) {
+            |mut ty| {
+                while let ty::Vec(inner) | ty::Arc(inner) = &ty.kind {
+                    ty = inner;
+                }
+                if matches!(ty.kind, ty::Map(_, _) | ty::Set(_) | ty::F32 | ty::F64)
+                    || is_prost_type(ty)
+                {
                     PredicateResult::No
                 } else {
                     PredicateResult::GoOn
@@ -210,26 +1131,160 @@ where
             },
         ));
 
+        if self.with_serde {
+            cx.exec_plugin(SerdePlugin);
+        }
+
+        if self.with_validator {
+            cx.exec_plugin(ValidatorPlugin);
+        }
+
         self.plugins.into_iter().for_each(|p| cx.exec_plugin(p));
 
-        let context = Arc::from(cx);
-        CONTEXT.set(&context.clone(), || {
-            let mut cg = Codegen::new(context.clone(), self.mk_backend.make_backend(context));
-            cg.write_pkgs(&pkgs.keys().cloned().collect::<Vec<_>>());
-
-            let file_name = out
-                .as_ref()
-                .file_name()
-                .and_then(|s| s.to_str())
-                .and_then(|s| s.split('.').next())
-                .unwrap();
-
-            let stream = cg.link(file_name);
-
-            let mut file = std::io::BufWriter::new(std::fs::File::create(&out).unwrap());
-            file.write_all(stream.to_string().as_bytes()).unwrap();
-            file.flush().unwrap();
-            fmt_file(out)
-        });
+        // the derives attached through the `Derives` tag
+        cx.exec_plugin(DerivePlugin::new(&[], |_, _| false));
+
+        (cx, self.mk_backend)
+    }
+}
+
+fn output_size_warning(out: &Path, limit: u64) -> Option<String> {
+    let size = std::fs::metadata(out).ok()?.len();
+    (size > limit).then(|| {
+        format!(
+            "the generated `{}` is {size} bytes, more than the limit of {limit} bytes, consider \
+             splitting the idl files and compiling them separately",
+            out.display()
+        )
+    })
+}
+
+/// The well-known types of `google.protobuf` which are mapped to rust types instead of the
+/// ones of prost-types.
+const WELL_KNOWN_PRIMITIVES: &[(&str, &str)] = &[
+    ("BoolValue", "bool"),
+    ("UInt32Value", "u32"),
+    ("UInt64Value", "u64"),
+    ("Int32Value", "i32"),
+    ("Int64Value", "i64"),
+    ("FloatValue", "f32"),
+    ("DoubleValue", "f64"),
+    ("StringValue", "::std::string::String"),
+    ("BytesValue", "::std::vec::Vec<u8>"),
+    ("Empty", "::pilota::protobuf::Empty"),
+];
+
+/// The extern packages installed by [`Builder::with_well_known_types`], the primitives come
+/// before the rest of `google.protobuf`.
+fn well_known_type_paths() -> impl Iterator<Item = (ItemPath, syn::Path)> {
+    WELL_KNOWN_PRIMITIVES
+        .iter()
+        .map(|(name, path)| {
+            let item = ItemPath::from(
+                ["google", "protobuf", name]
+                    .into_iter()
+                    .map(Symbol::from)
+                    .collect::<Vec<_>>(),
+            );
+            (item, syn::parse_str(path).unwrap())
+        })
+        .chain(std::iter::once((
+            ItemPath::from(vec![Symbol::from("google"), Symbol::from("protobuf")]),
+            parse_quote!(::prost_types),
+        )))
+}
+
+/// Strips the prefix shared by the packages of all the files, so the items of a single package
+/// are generated at the root of the output.
+fn flatten_packages(files: &mut FxHashMap<FileId, Arc<rir::File>>) {
+    let mut packages = files.values().map(|f| f.package.clone());
+    let first = match packages.next() {
+        Some(first) => first,
+        None => return,
+    };
+    let common = packages.fold(first.len(), |common, p| {
+        first[..common]
+            .iter()
+            .zip(p.iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    });
+    if common == 0 {
+        return;
     }
+    files.values_mut().for_each(|f| {
+        let f = Arc::make_mut(f);
+        f.package = ItemPath::from(&f.package[common..]);
+    });
+}
+
+/// Moves the items of every generated package to the module named by `rename`, the packages
+/// of `extern_packages` keep their paths.
+fn rename_packages(
+    files: &mut FxHashMap<FileId, Arc<rir::File>>,
+    extern_packages: &[(ItemPath, syn::Path)],
+    rename: &mut RenameModules,
+) {
+    let mut renamed = FxHashMap::<Symbol, ItemPath>::default();
+    let mut files = files
+        .iter_mut()
+        .filter(|(_, f)| {
+            !extern_packages
+                .iter()
+                .any(|(package, _)| f.package.starts_with(package))
+        })
+        .collect::<Vec<_>>();
+    files.sort_by_key(|(file_id, _)| **file_id);
+    files.into_iter().for_each(|(_, f)| {
+        let name = rename(&f.package);
+        match renamed.get(&name) {
+            Some(other) if *other != f.package => panic!(
+                "the packages `{}` and `{}` are both renamed to `{}`",
+                other.iter().join("."),
+                f.package.iter().join("."),
+                name
+            ),
+            _ => {
+                renamed.insert(name.clone(), f.package.clone());
+            }
+        }
+        Arc::make_mut(f).package = ItemPath::from(vec![name]);
+    });
+}
+
+/// Keeps the includes of `pkg` which can be re-exported as a module named
+/// after the include without shadowing anything else in the package.
+fn pkg_uses(
+    db: &RootDatabase,
+    pkg: &Pkg,
+    pkg_paths: &[ItemPath],
+    naming: &Naming,
+) -> Vec<(Symbol, ItemPath)> {
+    let mut uses = pkg.uses.clone();
+    uses.sort_by(|a, b| a.0.cmp(&b.0));
+    uses.dedup();
+
+    let ambiguous = uses
+        .windows(2)
+        .filter(|w| w[0].0 == w[1].0)
+        .map(|w| w[0].0.clone())
+        .collect::<Vec<_>>();
+
+    uses.into_iter()
+        .filter(|(name, target)| {
+            !ambiguous.contains(name)
+                && pkg_paths.contains(target)
+                && syn::parse_str::<syn::Ident>(name).is_ok()
+                && !pkg.path.starts_with(target)
+                && !pkg_paths.iter().any(|p| {
+                    p.len() > pkg.path.len()
+                        && p.starts_with(&pkg.path)
+                        && p[pkg.path.len()] == *name
+                })
+                && !pkg.items.iter().any(|did| match &*db.expect_item(*did) {
+                    rir::Item::Mod(m) => m.name.sym.to_snake_case(naming) == *name,
+                    _ => false,
+                })
+        })
+        .collect()
 }
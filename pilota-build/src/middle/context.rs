@@ -2,25 +2,75 @@ use core::panic;
 use std::{ops::Deref, sync::Arc};
 
 use fxhash::FxHashMap;
-use proc_macro2::Span;
-use quote::format_ident;
-use syn::PathSegment;
+use quote::{format_ident, quote};
 
 use self::tls::with_cur_item;
-use super::{adjust::Adjust, rir::NodeKind};
+use super::{
+    adjust::Adjust,
+    rir::{self, NodeKind},
+    ty::{AdtDef, AdtKind, CodegenTy, CollectionKind, SmallIntWidth, Ty, TyKind},
+};
 use crate::{
+    codegen::{pkg_tree::related_path, Visibility},
     db::{RirDatabase, RootDatabase},
-    symbol::{DefId, Symbol},
-    tags::{TagId, Tags},
-    Plugin,
+    symbol::{DefId, FileId, Ident, Naming, Symbol, TypeNameAffixes},
+    tags::{
+        protobuf::{FullName, OneOf},
+        thrift::{BoxedStr, RustCollection, RustType, SharedTrait, Union},
+        TagId, Tags,
+    },
+    DefaultImpl, Plugin, SerdeEnumRepr,
 };
 
 type Segments = Vec<Symbol>;
 
+pub(crate) type ModuleDoc = Box<dyn Fn(&rir::ItemPath) -> Option<String>>;
+
+/// The resolved items of the idl files and the options of the build, shared by the plugins
+/// and the backends. The nodes, files and types are queried through [`RirDatabase`].
 pub struct Context {
     pub db: salsa::Snapshot<RootDatabase>,
     adjusts: FxHashMap<DefId, Adjust>,
     tags_map: FxHashMap<TagId, Arc<Tags>>,
+    gen_client: bool,
+    gen_service_methods: bool,
+    reexport_oneof_payloads: bool,
+    gen_oneof_dispatcher: bool,
+    gen_oneof_from_impls: bool,
+    gen_any_message: bool,
+    gen_wire_names: bool,
+    gen_field_wire_types: bool,
+    gen_changed_from: bool,
+    gen_constructors: bool,
+    gen_view_types: bool,
+    gen_has_accessors: bool,
+    with_builders: bool,
+    box_cycles: bool,
+    box_large_variants: Option<usize>,
+    unknown_union_variant: bool,
+    open_enums: bool,
+    enum_string_conversions: bool,
+    collection_kind: CollectionKind,
+    small_int_width: SmallIntWidth,
+    root_path: Option<syn::Path>,
+    extern_packages: Vec<(rir::ItemPath, syn::Path)>,
+    use_bytes: bool,
+    track_dirty_fields: bool,
+    gen_encoded_len: bool,
+    preserve_unknown_fields: bool,
+    with_serde: bool,
+    with_validator: bool,
+    serde_enum_repr: SerdeEnumRepr,
+    gen_to_json_value: bool,
+    gen_thrift_json: bool,
+    gen_doc_stubs: bool,
+    default_impl: DefaultImpl,
+    visibility: Visibility,
+    module_doc: Option<ModuleDoc>,
+    naming: Naming,
+    type_name_affixes: TypeNameAffixes,
+    conversions: Vec<(DefId, DefId)>,
+    idl_packages: FxHashMap<FileId, rir::ItemPath>,
 }
 
 impl Deref for Context {
@@ -37,6 +87,45 @@ impl Context {
             db,
             adjusts: Default::default(),
             tags_map: Default::default(),
+            gen_client: false,
+            gen_service_methods: false,
+            reexport_oneof_payloads: false,
+            gen_oneof_dispatcher: false,
+            gen_oneof_from_impls: false,
+            gen_any_message: false,
+            gen_wire_names: false,
+            gen_field_wire_types: false,
+            gen_changed_from: false,
+            gen_constructors: false,
+            gen_view_types: false,
+            gen_has_accessors: false,
+            with_builders: false,
+            box_cycles: false,
+            box_large_variants: None,
+            unknown_union_variant: false,
+            open_enums: false,
+            enum_string_conversions: false,
+            collection_kind: CollectionKind::default(),
+            small_int_width: SmallIntWidth::default(),
+            root_path: None,
+            extern_packages: Vec::new(),
+            use_bytes: false,
+            track_dirty_fields: false,
+            gen_encoded_len: false,
+            preserve_unknown_fields: false,
+            with_serde: false,
+            with_validator: false,
+            serde_enum_repr: SerdeEnumRepr::default(),
+            gen_to_json_value: false,
+            gen_thrift_json: false,
+            gen_doc_stubs: false,
+            default_impl: DefaultImpl::default(),
+            visibility: Visibility::default(),
+            module_doc: None,
+            naming: Default::default(),
+            type_name_affixes: Default::default(),
+            conversions: Vec::new(),
+            idl_packages: FxHashMap::default(),
         }
     }
 
@@ -44,6 +133,464 @@ impl Context {
         self.tags_map = tags_map
     }
 
+    pub fn set_gen_client(&mut self, gen_client: bool) {
+        self.gen_client = gen_client
+    }
+
+    pub fn gen_client(&self) -> bool {
+        self.gen_client
+    }
+
+    pub fn set_gen_service_methods(&mut self, gen_service_methods: bool) {
+        self.gen_service_methods = gen_service_methods
+    }
+
+    pub fn gen_service_methods(&self) -> bool {
+        self.gen_service_methods
+    }
+
+    pub fn set_reexport_oneof_payloads(&mut self, reexport_oneof_payloads: bool) {
+        self.reexport_oneof_payloads = reexport_oneof_payloads
+    }
+
+    pub fn reexport_oneof_payloads(&self) -> bool {
+        self.reexport_oneof_payloads
+    }
+
+    pub fn set_gen_oneof_dispatcher(&mut self, gen_oneof_dispatcher: bool) {
+        self.gen_oneof_dispatcher = gen_oneof_dispatcher
+    }
+
+    pub fn gen_oneof_dispatcher(&self) -> bool {
+        self.gen_oneof_dispatcher
+    }
+
+    pub fn set_gen_oneof_from_impls(&mut self, gen_oneof_from_impls: bool) {
+        self.gen_oneof_from_impls = gen_oneof_from_impls
+    }
+
+    pub fn gen_oneof_from_impls(&self) -> bool {
+        self.gen_oneof_from_impls
+    }
+
+    pub fn set_gen_any_message(&mut self, gen_any_message: bool) {
+        self.gen_any_message = gen_any_message
+    }
+
+    pub fn gen_any_message(&self) -> bool {
+        self.gen_any_message
+    }
+
+    pub fn set_gen_wire_names(&mut self, gen_wire_names: bool) {
+        self.gen_wire_names = gen_wire_names
+    }
+
+    pub fn gen_wire_names(&self) -> bool {
+        self.gen_wire_names
+    }
+
+    pub fn set_gen_field_wire_types(&mut self, gen_field_wire_types: bool) {
+        self.gen_field_wire_types = gen_field_wire_types
+    }
+
+    pub fn gen_field_wire_types(&self) -> bool {
+        self.gen_field_wire_types
+    }
+
+    pub fn set_gen_changed_from(&mut self, gen_changed_from: bool) {
+        self.gen_changed_from = gen_changed_from
+    }
+
+    pub fn gen_changed_from(&self) -> bool {
+        self.gen_changed_from
+    }
+
+    pub fn set_gen_constructors(&mut self, gen_constructors: bool) {
+        self.gen_constructors = gen_constructors
+    }
+
+    pub fn gen_constructors(&self) -> bool {
+        self.gen_constructors
+    }
+
+    pub fn set_gen_view_types(&mut self, gen_view_types: bool) {
+        self.gen_view_types = gen_view_types
+    }
+
+    pub fn gen_view_types(&self) -> bool {
+        self.gen_view_types
+    }
+
+    pub fn set_gen_has_accessors(&mut self, gen_has_accessors: bool) {
+        self.gen_has_accessors = gen_has_accessors
+    }
+
+    pub fn gen_has_accessors(&self) -> bool {
+        self.gen_has_accessors
+    }
+
+    pub fn set_with_builders(&mut self, with_builders: bool) {
+        self.with_builders = with_builders
+    }
+
+    pub fn with_builders(&self) -> bool {
+        self.with_builders
+    }
+
+    pub fn set_box_cycles(&mut self, box_cycles: bool) {
+        self.box_cycles = box_cycles
+    }
+
+    /// Whether the types which close a reference cycle are boxed instead of wrapped in an `Arc`.
+    pub fn box_cycles(&self) -> bool {
+        self.box_cycles
+    }
+
+    pub fn set_box_large_variants(&mut self, threshold: Option<usize>) {
+        self.box_large_variants = threshold
+    }
+
+    /// The [`Context::estimated_size`] above which the payloads of union and oneof variants
+    /// are boxed, if any.
+    pub fn box_large_variants(&self) -> Option<usize> {
+        self.box_large_variants
+    }
+
+    /// Resolves the names given to `Builder::convert` to the messages: the full names of
+    /// protobuf, such as `pkg.Outer.Inner`, and the package and the name of thrift, such as
+    /// `pkg.Foo`, as written in the IDL. The oneofs of the same name in two converted messages
+    /// are converted along with them.
+    pub(crate) fn resolve_conversions(
+        &self,
+        conversions: &[(String, String)],
+    ) -> Result<Vec<(DefId, DefId)>, String> {
+        let messages = self
+            .nodes()
+            .iter()
+            .filter_map(|(def_id, node)| match &node.kind {
+                NodeKind::Item(item) if matches!(&**item, rir::Item::Message(_)) => {
+                    Some((self.idl_name(*def_id), *def_id))
+                }
+                _ => None,
+            })
+            .collect::<FxHashMap<_, _>>();
+        // the thrift names are given without the prefix and the suffix of the types
+        let message = |name: &str| {
+            messages
+                .get(name)
+                .or_else(|| {
+                    let (package, name) = name.rsplit_once('.')?;
+                    let name = self
+                        .type_name_affixes
+                        .apply(&Ident::new(name.into()), &self.naming);
+                    messages.get(&format!("{package}.{name}"))
+                })
+                .copied()
+                .ok_or_else(|| format!("no message named `{name}` to convert"))
+        };
+
+        let mut pairs = Vec::with_capacity(conversions.len());
+        let mut errors = Vec::new();
+        for (from, to) in conversions {
+            match (message(from), message(to)) {
+                (Ok(from), Ok(to)) => pairs.push((from, to)),
+                (from, to) => errors.extend(from.err().into_iter().chain(to.err())),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
+
+        let oneofs = pairs
+            .iter()
+            .flat_map(|(from, to)| self.oneof_conversions(*from, *to))
+            .collect::<Vec<_>>();
+        pairs.extend(oneofs);
+        Ok(pairs)
+    }
+
+    pub(crate) fn set_conversions(&mut self, conversions: Vec<(DefId, DefId)>) {
+        self.conversions = conversions
+    }
+
+    /// The pairs of the source and the target messages and oneofs of the generated `From`
+    /// impls.
+    pub fn conversions(&self) -> &[(DefId, DefId)] {
+        &self.conversions
+    }
+
+    /// The packages of the files in the IDL, before they are flattened or renamed to modules.
+    pub(crate) fn set_idl_packages(&mut self, idl_packages: FxHashMap<FileId, rir::ItemPath>) {
+        self.idl_packages = idl_packages
+    }
+
+    /// The full name of an item as written in the IDL: the full name of protobuf, such as
+    /// `pkg.Outer.Inner`, or the package and the name of thrift, such as `pkg.Foo`.
+    pub(crate) fn idl_name(&self, def_id: DefId) -> String {
+        match self.node_tags(def_id).get::<FullName>() {
+            Some(full_name) => full_name.0.to_string(),
+            None => self.thrift_name(def_id),
+        }
+    }
+
+    /// The name of a thrift item prefixed with its package, such as `pkg.Foo`.
+    fn thrift_name(&self, def_id: DefId) -> String {
+        let node = self.node(def_id).unwrap();
+        let file = self.file(node.file_id).unwrap();
+        let package = self
+            .idl_packages
+            .get(&node.file_id)
+            .unwrap_or(&file.package);
+        match &node.kind {
+            NodeKind::Item(item) => format!(
+                "{}.{}",
+                package.iter().map(|s| &**s).collect::<Vec<_>>().join("."),
+                item.symbol_name()
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    /// The oneofs of the same name in the messages `from` and `to`.
+    fn oneof_conversions(&self, from: DefId, to: DefId) -> Vec<(DefId, DefId)> {
+        let (from, to) = match (&*self.expect_item(from), &*self.expect_item(to)) {
+            (rir::Item::Message(from), rir::Item::Message(to)) => (from.clone(), to.clone()),
+            _ => unreachable!(),
+        };
+        to.fields
+            .iter()
+            .filter_map(|f| {
+                let source = from.fields.iter().find(|source| source.name == f.name)?;
+                match (&source.ty.kind, &f.ty.kind) {
+                    (TyKind::Path(a), TyKind::Path(b))
+                        if self.node_contains_tag::<OneOf>(a.did)
+                            && self.node_contains_tag::<OneOf>(b.did) =>
+                    {
+                        Some((a.did, b.did))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    pub fn set_unknown_union_variant(&mut self, unknown_union_variant: bool) {
+        self.unknown_union_variant = unknown_union_variant
+    }
+
+    pub fn unknown_union_variant(&self) -> bool {
+        self.unknown_union_variant
+    }
+
+    pub fn set_open_enums(&mut self, open_enums: bool) {
+        self.open_enums = open_enums
+    }
+
+    pub fn open_enums(&self) -> bool {
+        self.open_enums
+    }
+
+    pub fn set_enum_string_conversions(&mut self, enum_string_conversions: bool) {
+        self.enum_string_conversions = enum_string_conversions
+    }
+
+    pub fn enum_string_conversions(&self) -> bool {
+        self.enum_string_conversions
+    }
+
+    /// The containers generated for maps and sets, unless a field overrides it.
+    pub fn set_collection_kind(&mut self, collection_kind: CollectionKind) {
+        self.collection_kind = collection_kind
+    }
+
+    pub fn collection_kind(&self) -> CollectionKind {
+        self.collection_kind
+    }
+
+    pub fn set_small_int_width(&mut self, small_int_width: SmallIntWidth) {
+        self.small_int_width = small_int_width
+    }
+
+    pub fn small_int_width(&self) -> SmallIntWidth {
+        self.small_int_width
+    }
+
+    /// The path of the module wrapping the output, which makes the paths to other modules
+    /// absolute.
+    pub fn set_root_path(&mut self, root_path: Option<syn::Path>) {
+        self.root_path = root_path
+    }
+
+    pub(crate) fn set_extern_packages(&mut self, extern_packages: Vec<(rir::ItemPath, syn::Path)>) {
+        self.extern_packages = extern_packages
+    }
+
+    /// The path of an item of a package generated in another crate, where the types are named
+    /// without the prefix and the suffix of the types of this build.
+    fn extern_item_path(&self, def_id: DefId, path: &[Symbol]) -> Option<syn::Path> {
+        let (package, extern_path) = self
+            .extern_packages
+            .iter()
+            .find(|(package, _)| path.starts_with(package))?;
+        let is_type = !matches!(&*self.expect_item(def_id), rir::Item::Const(_));
+        let mut extern_path = extern_path.clone();
+        extern_path
+            .segments
+            .extend(path[package.len()..].iter().enumerate().map(|(idx, s)| {
+                let s = if is_type && idx == path.len() - package.len() - 1 {
+                    self.type_name_affixes.strip(s)
+                } else {
+                    s
+                };
+                syn::PathSegment::from(format_ident!("{}", s))
+            }));
+        Some(extern_path)
+    }
+
+    pub fn set_use_bytes(&mut self, use_bytes: bool) {
+        self.use_bytes = use_bytes
+    }
+
+    pub fn use_bytes(&self) -> bool {
+        self.use_bytes
+    }
+
+    pub fn set_track_dirty_fields(&mut self, track_dirty_fields: bool) {
+        self.track_dirty_fields = track_dirty_fields
+    }
+
+    pub fn track_dirty_fields(&self) -> bool {
+        self.track_dirty_fields
+    }
+
+    pub fn set_gen_encoded_len(&mut self, gen_encoded_len: bool) {
+        self.gen_encoded_len = gen_encoded_len
+    }
+
+    pub fn gen_encoded_len(&self) -> bool {
+        self.gen_encoded_len
+    }
+
+    pub fn set_preserve_unknown_fields(&mut self, preserve_unknown_fields: bool) {
+        self.preserve_unknown_fields = preserve_unknown_fields
+    }
+
+    pub fn preserve_unknown_fields(&self) -> bool {
+        self.preserve_unknown_fields
+    }
+
+    /// The initializers of the fields which are added to every generated struct.
+    pub(crate) fn extra_field_inits(&self) -> proc_macro2::TokenStream {
+        let dirty_fields = self
+            .track_dirty_fields()
+            .then(|| quote!(_dirty_fields: ::std::default::Default::default(),));
+        let unknown_fields = self
+            .preserve_unknown_fields()
+            .then(|| quote!(_unknown_fields: ::std::default::Default::default(),));
+        quote!(#dirty_fields #unknown_fields)
+    }
+
+    pub fn set_with_serde(&mut self, with_serde: bool) {
+        self.with_serde = with_serde
+    }
+
+    pub fn with_serde(&self) -> bool {
+        self.with_serde
+    }
+
+    pub fn set_with_validator(&mut self, with_validator: bool) {
+        self.with_validator = with_validator
+    }
+
+    /// Whether the messages derive `validator::Validate`, whose `validate` replaces the inherent
+    /// one generated from the constraint annotations.
+    pub fn with_validator(&self) -> bool {
+        self.with_validator
+    }
+
+    pub fn set_serde_enum_repr(&mut self, serde_enum_repr: SerdeEnumRepr) {
+        self.serde_enum_repr = serde_enum_repr
+    }
+
+    pub fn serde_enum_repr(&self) -> SerdeEnumRepr {
+        self.serde_enum_repr
+    }
+
+    pub fn set_gen_to_json_value(&mut self, gen_to_json_value: bool) {
+        self.gen_to_json_value = gen_to_json_value
+    }
+
+    pub fn gen_to_json_value(&self) -> bool {
+        self.gen_to_json_value
+    }
+
+    pub fn set_gen_thrift_json(&mut self, gen_thrift_json: bool) {
+        self.gen_thrift_json = gen_thrift_json
+    }
+
+    pub fn gen_thrift_json(&self) -> bool {
+        self.gen_thrift_json
+    }
+
+    pub fn set_gen_doc_stubs(&mut self, gen_doc_stubs: bool) {
+        self.gen_doc_stubs = gen_doc_stubs
+    }
+
+    pub fn gen_doc_stubs(&self) -> bool {
+        self.gen_doc_stubs
+    }
+
+    pub fn set_default_impl(&mut self, default_impl: DefaultImpl) {
+        self.default_impl = default_impl
+    }
+
+    pub fn default_impl(&self) -> DefaultImpl {
+        self.default_impl
+    }
+
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub(crate) fn set_module_doc(&mut self, module_doc: Option<ModuleDoc>) {
+        self.module_doc = module_doc
+    }
+
+    /// The doc comment of the module of `path`.
+    pub fn module_doc(&self, path: &rir::ItemPath) -> Option<String> {
+        self.module_doc.as_ref().and_then(|f| f(path))
+    }
+
+    pub(crate) fn set_naming(&mut self, naming: Naming) {
+        self.naming = naming
+    }
+
+    /// How the names of the IDL are converted to the names of the generated code.
+    pub fn naming(&self) -> &Naming {
+        &self.naming
+    }
+
+    pub(crate) fn set_type_name_affixes(&mut self, type_name_affixes: TypeNameAffixes) {
+        self.type_name_affixes = type_name_affixes
+    }
+
+    pub(crate) fn type_name_affixes(&self) -> &TypeNameAffixes {
+        &self.type_name_affixes
+    }
+
+    pub fn has_unknown_variant(&self, def_id: DefId) -> bool {
+        self.unknown_union_variant && self.node_contains_tag::<Union>(def_id)
+    }
+
+    /// Whether the enum gets an `Unknown` variant keeping the unrecognized discriminants.
+    pub fn is_open_enum(&self, e: &rir::Enum) -> bool {
+        self.open_enums && e.repr.is_some()
+    }
+
     pub fn adjust(&self, def_id: DefId) -> Option<&Adjust> {
         self.adjusts.get(&def_id)
     }
@@ -65,6 +612,19 @@ impl Context {
         self.tags(tag_id).unwrap()
     }
 
+    /// Adds `tag` to the tags of the node `def_id`, for the plugins to pass what they know of
+    /// it to the backends.
+    pub(crate) fn insert_node_tag<T: Clone + 'static + Sync + Send>(
+        &mut self,
+        def_id: DefId,
+        tag: T,
+    ) {
+        let tags_id = self.node(def_id).unwrap().tags;
+        let mut tags = self.tags(tags_id).as_deref().cloned().unwrap_or_default();
+        tags.insert(tag);
+        self.tags_map.insert(tags_id, Arc::new(tags));
+    }
+
     pub fn contains_tag<T: 'static>(&self, tags_id: TagId) -> bool {
         self.tags(tags_id)
             .and_then(|tags| tags.contains::<T>().then_some(true))
@@ -75,48 +635,195 @@ impl Context {
         self.node_tags(def_id).contains::<T>()
     }
 
-    pub fn symbol_name(&self, def_id: DefId) -> Symbol {
-        let item = self.item(def_id).unwrap();
-        item.symbol_name()
+    /// The type of a field, string fields annotated with `pilota.boxed_str` are
+    /// generated as `Box<str>` and the collection of a map or set can be
+    /// chosen with `rust.collection`.
+    pub fn codegen_field_ty(&self, ty: &Ty) -> CodegenTy {
+        if let Some(rust_type) = self
+            .tags(ty.tags_id)
+            .and_then(|tags| tags.get::<RustType>().map(|t| t.0.clone()))
+        {
+            return CodegenTy::Custom(rust_type, Arc::from([]));
+        }
+        let collection = self
+            .tags(ty.tags_id)
+            .and_then(|tags| tags.get::<RustCollection>().map(|c| c.0));
+        match (&ty.kind, collection) {
+            (TyKind::String, _) if self.contains_tag::<BoxedStr>(ty.tags_id) => {
+                CodegenTy::Custom(Arc::from("::std::boxed::Box<str>"), Arc::from([]))
+            }
+            (TyKind::Set(_) | TyKind::Map(..), Some(kind)) => {
+                match self.codegen_item_ty(ty.kind.clone()) {
+                    CodegenTy::Set(el, _) => CodegenTy::Set(el, kind),
+                    CodegenTy::Map(k, v, _) => CodegenTy::Map(k, v, kind),
+                    ty => ty,
+                }
+            }
+            _ => self.codegen_item_ty(ty.kind.clone()),
+        }
     }
 
-    fn related_path(&self, p1: &Segments, p2: &Segments) -> syn::Path {
-        if p1 == p2 {
-            return syn::Path::from(format_ident!("{}", p2.last().unwrap()));
+    /// Whether the payload of the union or oneof variant is boxed for being larger than the
+    /// threshold of [`Context::box_large_variants`]. The payloads which close a reference cycle
+    /// are already behind a pointer and are never boxed again, and only the messages are boxed
+    /// in a oneof.
+    pub fn is_boxed_variant(&self, v: &rir::EnumVariant) -> bool {
+        let threshold = match self.box_large_variants {
+            Some(threshold) => threshold,
+            None => return false,
+        };
+        let e = self.node(v.did).unwrap().parent.unwrap();
+        let one_of = self.node_contains_tag::<OneOf>(e);
+        if !self.node_contains_tag::<Union>(e) && !one_of {
+            return false;
         }
-        let mut i = 0;
-        while i < p1.len() && i < p2.len() && p1[i] == p2[i] {
-            i += 1
+        match &*v.fields {
+            // prost only encodes the messages of a oneof behind a `Box`
+            [ty] if one_of && !self.is_message(ty) => false,
+            [ty] if !matches!(ty.kind, TyKind::Arc(_)) => {
+                self.estimated_size(&self.codegen_item_ty(ty.kind.clone())) > threshold
+            }
+            _ => false,
         }
-        let mut segs = syn::punctuated::Punctuated::new();
+    }
 
-        enum Kind {
-            Super,
-            Ident(Symbol),
+    fn is_message(&self, ty: &Ty) -> bool {
+        match &ty.kind {
+            TyKind::Path(p) => matches!(&*self.expect_item(p.did), rir::Item::Message(_)),
+            _ => false,
         }
-        let path = (0..p1.len() - i)
-            .map(|_| Kind::Super)
-            .chain((i..p2.len()).map(|i| Kind::Ident(p2[i].clone())))
-            .collect::<Vec<_>>();
+    }
 
-        let _length = path.len();
+    /// A rough estimate of the size in bytes of a value of `ty` on a 64-bit target:
+    ///
+    /// - the numbers and `bool` take their own size;
+    /// - strings, `Vec`s and the ordered collections take 24 bytes, the hash collections 48,
+    ///   `Bytes` 32 and the other custom types 24;
+    /// - references, `Arc`s and `Box`es take 8 bytes, 16 for `&str` and streams;
+    /// - a struct takes the sum of its fields, with 8 more bytes for each optional field;
+    /// - an enum with discriminants takes 4 bytes, a union or oneof its largest variant and
+    ///   8 bytes for the tag.
+    ///
+    /// Padding and niches are ignored, and a type met again inside itself counts as 8 bytes.
+    pub fn estimated_size(&self, ty: &CodegenTy) -> usize {
+        self.estimated_size_in(ty, &mut Vec::new())
+    }
 
-        for (_idx, k) in path.into_iter().enumerate() {
-            segs.push(match k {
-                Kind::Super => PathSegment::from(syn::token::Super(Span::call_site())),
-                Kind::Ident(ident) => {
-                    let ident = format_ident!("{}", ident);
-                    PathSegment::from(ident)
-                }
-            });
+    fn estimated_size_in(&self, ty: &CodegenTy, visiting: &mut Vec<DefId>) -> usize {
+        match ty {
+            CodegenTy::Void => 0,
+            CodegenTy::U8 | CodegenTy::Bool | CodegenTy::I8 => 1,
+            CodegenTy::I16 => 2,
+            CodegenTy::I32 | CodegenTy::UInt32 | CodegenTy::F32 => 4,
+            CodegenTy::I64 | CodegenTy::UInt64 | CodegenTy::F64 => 8,
+            CodegenTy::Str | CodegenTy::Stream(_) => 16,
+            CodegenTy::LazyStaticRef(_) | CodegenTy::StaticRef(_) | CodegenTy::Arc(_) => 8,
+            CodegenTy::String | CodegenTy::Vec(_) => 24,
+            CodegenTy::Bytes => 32,
+            CodegenTy::Set(_, kind) | CodegenTy::Map(_, _, kind) => match kind {
+                CollectionKind::BTree => 24,
+                _ => 48,
+            },
+            CodegenTy::Custom(path, _) if path.starts_with("::std::boxed::Box") => 8,
+            CodegenTy::Custom(..) => 24,
+            CodegenTy::Adt(AdtDef { did, .. }) if visiting.contains(did) => 8,
+            CodegenTy::Adt(AdtDef { did, kind }) => {
+                visiting.push(*did);
+                let size = match (kind, &*self.expect_item(*did)) {
+                    (AdtKind::NewType(inner), _) => self.estimated_size_in(inner, visiting),
+                    (_, rir::Item::Message(s)) => s
+                        .fields
+                        .iter()
+                        .map(|f| {
+                            if self.adjust(f.did).is_some_and(|a| a.boxed()) {
+                                return 8;
+                            }
+                            let size =
+                                self.estimated_size_in(&self.codegen_field_ty(&f.ty), visiting);
+                            if f.is_optional() {
+                                size + 8
+                            } else {
+                                size
+                            }
+                        })
+                        .sum(),
+                    (_, rir::Item::Enum(e)) if e.repr.is_some() => 4,
+                    (_, rir::Item::Enum(e)) => {
+                        let largest = e
+                            .variants
+                            .iter()
+                            .map(|v| {
+                                if self.is_boxed_variant(v) {
+                                    return 8;
+                                }
+                                v.fields
+                                    .iter()
+                                    .map(|ty| {
+                                        let ty = self.codegen_item_ty(ty.kind.clone());
+                                        self.estimated_size_in(&ty, visiting)
+                                    })
+                                    .sum()
+                            })
+                            .max()
+                            .unwrap_or(0);
+                        largest + 8
+                    }
+                    _ => 8,
+                };
+                visiting.pop();
+                size
+            }
         }
+    }
+
+    /// The messages sharing the trait `name`, ordered by their `DefId`.
+    pub fn shared_trait_members(&self, name: &str) -> Vec<DefId> {
+        let mut members = self
+            .nodes()
+            .iter()
+            .filter(|(_, node)| match &node.kind {
+                NodeKind::Item(item) => matches!(&**item, rir::Item::Message(_)),
+                _ => false,
+            })
+            .filter(|(def_id, _)| {
+                self.node_tags(**def_id)
+                    .get::<SharedTrait>()
+                    .is_some_and(|t| &*t.0 == name)
+            })
+            .map(|(def_id, _)| *def_id)
+            .collect::<Vec<_>>();
+        members.sort();
+        members
+    }
 
-        syn::Path {
-            leading_colon: None,
-            segments: segs,
+    pub fn symbol_name(&self, def_id: DefId) -> Symbol {
+        let item = self.item(def_id).unwrap();
+        item.symbol_name()
+    }
+
+    /// The path of `to` from the module `from`, which is absolute from the root path when it
+    /// would go through `super`.
+    fn module_path(&self, from: &[Symbol], to: &[Symbol]) -> syn::Path {
+        let path = related_path(from, to);
+        match &self.root_path {
+            Some(root) if path.segments.first().is_some_and(|s| s.ident == "super") => {
+                let mut root = root.clone();
+                root.segments.extend(
+                    to.iter()
+                        .map(|s| syn::PathSegment::from(format_ident!("{}", s))),
+                );
+                root
+            }
+            _ => path,
         }
     }
 
+    /// Runs `f` with `def_id` as the current item of [`Context::cur_related_item_path`].
+    pub fn in_item<T>(&self, def_id: DefId, f: impl FnOnce() -> T) -> T {
+        tls::CUR_ITEM.set(&def_id, f)
+    }
+
+    /// The path of `did` from the module of the current item.
     pub fn cur_related_item_path(&self, did: DefId) -> syn::Path {
         let a = with_cur_item(|def_id| def_id);
         self.related_item_path(a, did)
@@ -133,10 +840,45 @@ impl Context {
             });
 
         let other_item_path = self.item_path(b);
-        self.related_path(&mod_segs, &other_item_path)
+        if let Some(path) = self.extern_item_path(b, &other_item_path) {
+            return path;
+        }
+        let other_item_path = self
+            .include_item_path(a, b, &other_item_path)
+            .unwrap_or(other_item_path);
+        self.module_path(&mod_segs, &other_item_path)
     }
 
-    fn item_path(&self, def_id: DefId) -> Segments {
+    /// Rewrites the path of `b` to go through the module re-exported for the
+    /// include of `b`'s package in `a`'s package, if there is one.
+    fn include_item_path(&self, a: DefId, b: DefId, b_path: &Segments) -> Option<Segments> {
+        let pkg_a = &self.file(self.node(a)?.file_id)?.package;
+        if b_path.starts_with(pkg_a) {
+            return None;
+        }
+        let pkg_b = &self.file(self.node(b)?.file_id)?.package;
+        let (name, _) = self
+            .pkg(pkg_a.clone())?
+            .uses
+            .iter()
+            .find(|(_, target)| target == pkg_b)?
+            .clone();
+
+        Some(
+            pkg_a
+                .iter()
+                .cloned()
+                .chain(Some(name))
+                .chain(b_path[pkg_b.len()..].iter().cloned())
+                .collect(),
+        )
+    }
+
+    pub fn pkg_use_path(&self, pkg: &rir::ItemPath, target: &rir::ItemPath) -> syn::Path {
+        self.module_path(pkg, target)
+    }
+
+    pub(crate) fn item_path(&self, def_id: DefId) -> Segments {
         fn calc_item_path(cx: &Context, def_id: DefId, segs: &mut Vec<Symbol>) {
             let node = cx.node(def_id).unwrap();
             if let Some(parent) = node.parent {
@@ -148,8 +890,14 @@ impl Context {
             }
 
             let name = match node.kind {
-                NodeKind::Item(item) => item.symbol_name().to_upper_camel_case(),
-                NodeKind::Variant(v) => (*v.name).to_upper_camel_case(),
+                NodeKind::Item(item) if matches!(&*item, rir::Item::Mod(_)) => {
+                    item.symbol_name().to_snake_case(&cx.naming)
+                }
+                NodeKind::Item(item) if matches!(&*item, rir::Item::Const(_)) => {
+                    item.symbol_name().to_shouty_snake_case(&cx.naming)
+                }
+                NodeKind::Item(item) => item.symbol_name().to_upper_camel_case(&cx.naming),
+                NodeKind::Variant(v) => (*v.name).to_variant_case(&cx.naming),
                 _ => panic!(),
             };
             segs.push(name);
@@ -1,5 +1,7 @@
 use std::{ops::Deref, sync::Arc};
 
+use fxhash::FxHashMap;
+
 use super::ty::Ty;
 use crate::{
     symbol::{DefId, EnumRepr, FileId, Ident, Symbol},
@@ -20,6 +22,7 @@ pub enum Literal {
 pub struct Arg {
     pub ty: Ty,
     pub name: Ident,
+    pub original_name: Symbol,
     pub id: i32,
 }
 
@@ -63,15 +66,21 @@ pub struct Const {
 pub enum FieldKind {
     Required,
     Optional,
+    /// A protobuf field without presence, generated as the bare type which is left at its
+    /// default when absent.
+    Implicit,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Field {
     pub did: DefId,
     pub name: Ident,
+    /// The name as written in the IDL, `name` is snake cased.
+    pub original_name: Symbol,
     pub id: i32,
     pub ty: Ty,
     pub kind: FieldKind,
+    pub default: Option<Literal>,
 }
 
 impl Field {
@@ -91,8 +100,10 @@ pub struct EnumVariant {
     pub id: Option<i32>,
     pub did: DefId,
     pub name: Ident,
+    pub original_name: Symbol,
     pub discr: Option<i64>,
     pub fields: Vec<Ty>,
+    pub default: Option<Literal>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -136,6 +147,21 @@ impl Item {
         }
     }
 
+    /// A copy of the item named `name`.
+    pub fn renamed(&self, name: Symbol) -> Item {
+        let mut item = self.clone();
+        let ident = match &mut item {
+            Item::Message(s) => &mut s.name,
+            Item::Enum(e) => &mut e.name,
+            Item::Service(s) => &mut s.name,
+            Item::NewType(t) => &mut t.name,
+            Item::Const(c) => &mut c.name,
+            Item::Mod(m) => &mut m.name,
+        };
+        *ident = Ident::new(name);
+        item
+    }
+
     pub fn is_ty(&self) -> bool {
         matches!(
             self,
@@ -181,6 +207,7 @@ pub struct File {
     pub package: ItemPath,
     pub items: Vec<DefId>,
     pub file_id: FileId,
+    pub uses: FxHashMap<Symbol, FileId>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -199,8 +226,20 @@ pub struct Node {
     pub tags: TagId,
 }
 
+impl Node {
+    pub fn expect_item(&self) -> &Arc<Item> {
+        match &self.kind {
+            NodeKind::Item(item) => item,
+            _ => panic!("{:?} is not an item", self.kind),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Pkg {
     pub path: ItemPath,
     pub items: Vec<DefId>,
+    /// The packages included by the files of this package, keyed by the name
+    /// they are included as.
+    pub uses: Vec<(Symbol, ItemPath)>,
 }
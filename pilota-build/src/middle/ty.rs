@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 pub use TyKind::*;
 
@@ -51,6 +52,7 @@ pub enum AdtKind {
 pub enum CodegenTy {
     String,
     Str, // static str,
+    Bytes,
     Void,
     U8,
     Bool,
@@ -65,20 +67,92 @@ pub enum CodegenTy {
     LazyStaticRef(Arc<CodegenTy>),
     StaticRef(Arc<CodegenTy>),
     Vec(Arc<CodegenTy>),
-    Set(Arc<CodegenTy>),
-    Map(Arc<CodegenTy>, Arc<CodegenTy>),
+    Set(Arc<CodegenTy>, CollectionKind),
+    Map(Arc<CodegenTy>, Arc<CodegenTy>, CollectionKind),
     Adt(AdtDef),
     Arc(Arc<CodegenTy>),
+    /// The messages of a streaming request or response.
+    Stream(Arc<CodegenTy>),
+    /// A type provided by a custom [`TyTransformer`], like `::bytes::Bytes` or
+    /// `::indexmap::IndexMap` with its generic arguments.
+    Custom(Arc<str>, Arc<[CodegenTy]>),
+}
+
+/// The containers generated for maps and sets.
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum CollectionKind {
+    /// `HashMap` and `HashSet`.
+    #[default]
+    Hash,
+    /// `BTreeMap` and `BTreeSet`, which iterate in the order of the keys.
+    BTree,
+    /// `indexmap::IndexMap` and `indexmap::IndexSet`, which iterate in the order of insertion.
+    Index,
+}
+
+/// The Rust integers generated for thrift `i8` and `i16`.
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum SmallIntWidth {
+    /// `i8` and `i16`.
+    #[default]
+    Declared,
+    /// `i32` for both.
+    I32,
+    /// `i64` for both.
+    I64,
+}
+
+impl SmallIntWidth {
+    /// The type generated for an integer declared as `declared`.
+    fn codegen_ty(self, declared: CodegenTy) -> CodegenTy {
+        match self {
+            SmallIntWidth::Declared => declared,
+            SmallIntWidth::I32 => CodegenTy::I32,
+            SmallIntWidth::I64 => CodegenTy::I64,
+        }
+    }
+}
+
+impl CollectionKind {
+    pub(crate) fn map_path(self) -> TokenStream {
+        match self {
+            CollectionKind::Hash => quote! { ::std::collections::HashMap },
+            CollectionKind::BTree => quote! { ::std::collections::BTreeMap },
+            CollectionKind::Index => quote! { ::indexmap::IndexMap },
+        }
+    }
+
+    pub(crate) fn set_path(self) -> TokenStream {
+        match self {
+            CollectionKind::Hash => quote! { ::std::collections::HashSet },
+            CollectionKind::BTree => quote! { ::std::collections::BTreeSet },
+            CollectionKind::Index => quote! { ::indexmap::IndexSet },
+        }
+    }
+
+    /// An empty collection of `path` with room for `len` elements.
+    pub(crate) fn with_capacity(self, path: TokenStream, len: TokenStream) -> TokenStream {
+        match self {
+            CollectionKind::BTree => quote! { #path::new() },
+            _ => quote! { #path::with_capacity(#len) },
+        }
+    }
 }
 
 impl CodegenTy {
     pub fn should_lazy_static(&self) -> bool {
         match self {
             CodegenTy::String
+            | CodegenTy::Bytes
             | CodegenTy::LazyStaticRef(_)
             | CodegenTy::StaticRef(_)
             | CodegenTy::Vec(_)
-            | CodegenTy::Map(_, _) => true,
+            | CodegenTy::Map(..)
+            | CodegenTy::Custom(_, _)
+            | CodegenTy::Adt(AdtDef {
+                did: _,
+                kind: AdtKind::Struct,
+            }) => true,
             CodegenTy::Adt(AdtDef {
                 did: _,
                 kind: AdtKind::NewType(inner),
@@ -93,6 +167,7 @@ impl ToTokens for CodegenTy {
         match self {
             CodegenTy::String => tokens.extend(quote! { ::std::string::String }),
             CodegenTy::Str => tokens.extend(quote! { &'static str }),
+            CodegenTy::Bytes => tokens.extend(quote! { ::pilota::bytes::Bytes }),
             CodegenTy::Void => tokens.extend(quote! { () }),
             CodegenTy::U8 => tokens.extend(quote! { u8 }),
             CodegenTy::Bool => tokens.extend(quote! { bool }),
@@ -112,14 +187,16 @@ impl ToTokens for CodegenTy {
                 let ty = &**ty;
                 tokens.extend(quote! { ::std::vec::Vec<#ty> })
             }
-            CodegenTy::Set(ty) => {
+            CodegenTy::Set(ty, kind) => {
                 let ty = &**ty;
-                tokens.extend(quote! { ::std::collections::HashSet<#ty> })
+                let path = kind.set_path();
+                tokens.extend(quote! { #path<#ty> })
             }
-            CodegenTy::Map(k, v) => {
+            CodegenTy::Map(k, v, kind) => {
                 let k = &**k;
                 let v = &**v;
-                tokens.extend(quote! { ::std::collections::HashMap<#k, #v> })
+                let path = kind.map_path();
+                tokens.extend(quote! { #path<#k, #v> })
             }
             CodegenTy::Adt(def) => with_cx(|cx| {
                 let path = cx.cur_related_item_path(def.did);
@@ -127,18 +204,28 @@ impl ToTokens for CodegenTy {
             }),
             CodegenTy::Arc(ty) => {
                 let ty = &**ty;
-                tokens.extend(quote!( ::alloc::sync::Arc<#ty> ))
+                tokens.extend(quote!( ::std::sync::Arc<#ty> ))
             }
             CodegenTy::LazyStaticRef(ty) => ty.to_tokens(tokens),
+            CodegenTy::Stream(ty) => {
+                let ty = &**ty;
+                tokens.extend(quote!( ::pilota::BoxStream<#ty> ))
+            }
+            CodegenTy::Custom(path, generics) => {
+                let path = syn::parse_str::<syn::Path>(path)
+                    .unwrap_or_else(|_| panic!("invalid custom type path `{path}`"));
+                if generics.is_empty() {
+                    tokens.extend(quote! { #path })
+                } else {
+                    let generics = generics.iter();
+                    tokens.extend(quote! { #path<#(#generics),*> })
+                }
+            }
         }
     }
 }
 
 impl TyKind {
-    pub(crate) fn to_codegen_item_ty(&self) -> CodegenTy {
-        DefaultTyTransformer.codegen_item_ty(self)
-    }
-
     pub(crate) fn to_codegen_const_ty(&self) -> CodegenTy {
         ConstTyTransformer.codegen_item_ty(self)
     }
@@ -167,17 +254,21 @@ pub trait TyTransformer {
 
     #[inline]
     fn bytes(&self) -> CodegenTy {
-        CodegenTy::Vec(Arc::from(CodegenTy::U8))
+        if with_cx(|cx| cx.use_bytes()) {
+            CodegenTy::Bytes
+        } else {
+            CodegenTy::Vec(Arc::from(CodegenTy::U8))
+        }
     }
 
     #[inline]
     fn i8(&self) -> CodegenTy {
-        CodegenTy::I8
+        with_cx(|cx| cx.small_int_width()).codegen_ty(CodegenTy::I8)
     }
 
     #[inline]
     fn i16(&self) -> CodegenTy {
-        CodegenTy::I16
+        with_cx(|cx| cx.small_int_width()).codegen_ty(CodegenTy::I16)
     }
 
     #[inline]
@@ -190,6 +281,21 @@ pub trait TyTransformer {
         CodegenTy::I64
     }
 
+    #[inline]
+    fn u32(&self) -> CodegenTy {
+        CodegenTy::UInt32
+    }
+
+    #[inline]
+    fn u64(&self) -> CodegenTy {
+        CodegenTy::UInt64
+    }
+
+    #[inline]
+    fn f32(&self) -> CodegenTy {
+        CodegenTy::F32
+    }
+
     #[inline]
     fn f64(&self) -> CodegenTy {
         CodegenTy::F64
@@ -202,14 +308,31 @@ pub trait TyTransformer {
 
     #[inline]
     fn set(&self, ty: &Ty) -> CodegenTy {
-        CodegenTy::Set(Arc::from(self.codegen_item_ty(&ty.kind)))
+        CodegenTy::Set(
+            Arc::from(self.codegen_item_ty(&ty.kind)),
+            with_cx(|cx| cx.collection_kind()),
+        )
     }
 
     #[inline]
     fn map(&self, key: &Ty, value: &Ty) -> CodegenTy {
         let key = self.codegen_item_ty(&key.kind);
         let value = self.codegen_item_ty(&value.kind);
-        CodegenTy::Map(Arc::from(key), Arc::from(value))
+        CodegenTy::Map(
+            Arc::from(key),
+            Arc::from(value),
+            with_cx(|cx| cx.collection_kind()),
+        )
+    }
+
+    #[inline]
+    fn arc(&self, ty: &Ty) -> CodegenTy {
+        let ty = self.codegen_item_ty(&ty.kind);
+        if with_cx(|cx| cx.box_cycles()) {
+            CodegenTy::Custom("::std::boxed::Box".into(), Arc::new([ty]))
+        } else {
+            CodegenTy::Arc(Arc::from(ty))
+        }
     }
 
     #[inline]
@@ -218,9 +341,11 @@ pub trait TyTransformer {
         with_cx(|cx| cx.codegen_ty(did))
     }
 
+    /// Maps the request or response of a streaming method, `ty` is the type of
+    /// each message.
     #[inline]
-    fn stream(&self) -> CodegenTy {
-        todo!();
+    fn stream(&self, ty: &Ty) -> CodegenTy {
+        CodegenTy::Stream(Arc::from(self.codegen_item_ty(&ty.kind)))
     }
 
     #[inline]
@@ -240,19 +365,44 @@ pub trait TyTransformer {
             Set(ty) => self.set(ty),
             Map(k, v) => self.map(k, v),
             Path(path) => self.path(path),
-            UInt32 => todo!(),
-            UInt64 => todo!(),
-            F32 => todo!(),
-            TyKind::Arc(_) => todo!(),
+            UInt32 => self.u32(),
+            UInt64 => self.u64(),
+            F32 => self.f32(),
+            TyKind::Arc(ty) => self.arc(ty),
         }
     }
 }
 
-pub(crate) struct DefaultTyTransformer;
+pub struct DefaultTyTransformer;
 
 impl TyTransformer for DefaultTyTransformer {}
 
-pub(crate) struct ConstTyTransformer;
+/// The [`TyTransformer`] which maps the types of fields, arguments and return
+/// values, it can be replaced by `Builder::ty_transformer`.
+#[derive(Clone)]
+pub struct ItemTyTransformer(pub Arc<dyn TyTransformer + Send + Sync>);
+
+impl Default for ItemTyTransformer {
+    fn default() -> Self {
+        ItemTyTransformer(Arc::new(DefaultTyTransformer))
+    }
+}
+
+impl PartialEq for ItemTyTransformer {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.0) as *const () == Arc::as_ptr(&other.0) as *const ()
+    }
+}
+
+impl Eq for ItemTyTransformer {}
+
+impl std::fmt::Debug for ItemTyTransformer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemTyTransformer").finish()
+    }
+}
+
+pub struct ConstTyTransformer;
 
 impl TyTransformer for ConstTyTransformer {
     #[inline]
@@ -269,16 +419,21 @@ impl TyTransformer for ConstTyTransformer {
 
     #[inline]
     fn set(&self, ty: &Ty) -> CodegenTy {
-        CodegenTy::StaticRef(Arc::from(CodegenTy::Set(Arc::from(
-            self.codegen_item_ty(&ty.kind),
-        ))))
+        CodegenTy::StaticRef(Arc::from(CodegenTy::Set(
+            Arc::from(self.codegen_item_ty(&ty.kind)),
+            with_cx(|cx| cx.collection_kind()),
+        )))
     }
 
     #[inline]
     fn map(&self, key: &Ty, value: &Ty) -> CodegenTy {
         let key = self.codegen_item_ty(&key.kind);
         let value = self.codegen_item_ty(&value.kind);
-        CodegenTy::StaticRef(Arc::from(CodegenTy::Map(Arc::from(key), Arc::from(value))))
+        CodegenTy::StaticRef(Arc::from(CodegenTy::Map(
+            Arc::from(key),
+            Arc::from(value),
+            with_cx(|cx| cx.collection_kind()),
+        )))
     }
 }
 
@@ -308,6 +463,7 @@ pub(crate) fn walk_ty<V: Visitor>(v: &mut V, ty: &Ty) {
         Vec(el) => v.visit_vec(el),
         Set(el) => v.visit_set(el),
         Map(key, value) => v.visit_map(key, value),
+        Arc(el) => v.visit(el),
         Path(p) => v.visit_path(p),
         _ => {}
     }
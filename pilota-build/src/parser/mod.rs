@@ -1,9 +1,15 @@
 use std::{
+    fmt,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
-use crate::ir::File;
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::{
+    ir::{File, ItemKind},
+    symbol::{FileId, Naming},
+};
 
 pub(crate) mod protobuf;
 pub(crate) mod thrift;
@@ -12,10 +18,125 @@ pub use thrift::ThriftParser;
 
 pub use self::protobuf::ProtobufParser;
 
+#[non_exhaustive]
 pub struct ParseResult {
     pub files: Vec<Arc<File>>,
+    /// The files included or imported by each parsed file, sorted by their ids.
+    pub deps: FxHashMap<FileId, Vec<FileId>>,
+}
+
+impl ParseResult {
+    pub(crate) fn new(files: Vec<Arc<File>>) -> Self {
+        let deps = files
+            .iter()
+            .map(|f| {
+                // two includes may share a name in `uses`, but not a `Use` item
+                let mut deps = f
+                    .uses
+                    .values()
+                    .copied()
+                    .chain(f.items.iter().filter_map(|item| match &item.kind {
+                        ItemKind::Use(u) => Some(u.file),
+                        _ => None,
+                    }))
+                    .collect::<Vec<_>>();
+                deps.sort();
+                deps.dedup();
+                (f.id, deps)
+            })
+            .collect();
+        ParseResult { files, deps }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(path: impl Into<PathBuf>, message: impl Into<String>) -> Self {
+        ParseError {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// The error of an include which is found in none of the searched dirs, listed in the order
+/// they are searched.
+pub(crate) fn include_not_found(
+    path: impl Into<PathBuf>,
+    include: &str,
+    dirs: &[PathBuf],
+) -> ParseError {
+    ParseError::new(
+        path,
+        format!(
+            "include file `{}` not found, searched in: {}",
+            include,
+            dirs.iter()
+                .map(|d| d.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    )
+}
+
+/// Follows the includes of `files` depth first and fails on a file including itself, directly
+/// or through other files, with the error naming the files of the cycle. `includes` returns the
+/// paths of the files included by a file, which is visited only once.
+pub(crate) fn check_include_cycles(
+    files: &[PathBuf],
+    mut includes: impl FnMut(&Path) -> Result<Vec<PathBuf>, ParseError>,
+) -> Result<(), ParseError> {
+    fn visit(
+        path: &Path,
+        stack: &mut Vec<PathBuf>,
+        visited: &mut FxHashSet<PathBuf>,
+        includes: &mut impl FnMut(&Path) -> Result<Vec<PathBuf>, ParseError>,
+    ) -> Result<(), ParseError> {
+        // the same file may be included through different relative paths
+        let key = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        if let Some(start) = stack.iter().position(|p| *p == key) {
+            let cycle = stack[start..]
+                .iter()
+                .chain(Some(&key))
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(ParseError::new(
+                stack.last().unwrap(),
+                format!("include cycle: {cycle}"),
+            ));
+        }
+        if !visited.insert(key.clone()) {
+            return Ok(());
+        }
+
+        stack.push(key);
+        for include in includes(path)? {
+            visit(&include, stack, visited, includes)?;
+        }
+        stack.pop();
+        Ok(())
+    }
+
+    let mut visited = FxHashSet::default();
+    files
+        .iter()
+        .try_for_each(|f| visit(f, &mut Vec::new(), &mut visited, &mut includes))
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
 }
 
+impl std::error::Error for ParseError {}
+
 pub trait Parser {
     fn input<P: AsRef<Path>>(&mut self, path: P);
 
@@ -23,7 +144,94 @@ pub trait Parser {
         paths.into_iter().for_each(|p| self.input(p))
     }
 
+    /// Adds the files matching `pattern` in the order of their paths, such as
+    /// `idl/**/*.thrift`, where `*` and `?` match within a component and `**` matches any
+    /// number of dirs. A relative pattern is relative to the working dir. The files defining
+    /// nothing, which are only included by the others, are skipped if `skip_include_only`.
+    fn input_glob(&mut self, pattern: &str, skip_include_only: bool) {
+        let paths = glob(pattern)
+            .into_iter()
+            .filter(|p| !skip_include_only || !self.is_include_only(p))
+            .collect::<Vec<_>>();
+        self.inputs(paths)
+    }
+
+    /// Whether the file at `path` only has includes and namespaces, an unreadable or
+    /// invalid file is not, so the error is reported when parsing it.
+    fn is_include_only(&self, _path: &Path) -> bool {
+        false
+    }
+
     fn include_dirs(&mut self, dirs: Vec<PathBuf>);
 
-    fn parse(self) -> ParseResult;
+    /// Sets how the names are converted, the parser converts the names of the items it
+    /// adds itself, such as the modules of the nested protobuf messages.
+    fn naming(&mut self, _naming: Naming) {}
+
+    fn parse(self) -> Result<ParseResult, ParseError>;
+}
+
+/// The sorted paths of the files matching `pattern`, see [`Parser::input_glob`].
+pub fn glob(pattern: &str) -> Vec<PathBuf> {
+    fn walk(dir: &Path, components: &[&str], out: &mut Vec<PathBuf>) {
+        let Some((first, rest)) = components.split_first() else {
+            if dir.is_file() {
+                out.push(dir.to_path_buf());
+            }
+            return;
+        };
+        if *first == "**" {
+            walk(dir, rest, out);
+        }
+        if !first.contains(['*', '?']) {
+            return walk(&dir.join(first), rest, out);
+        }
+        let Ok(entries) = std::fs::read_dir(if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        }) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            if *first == "**" {
+                // the symlinked dirs are not followed, they may form a loop
+                if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                    walk(&dir.join(name), components, out);
+                }
+            } else if matches(first.as_bytes(), name.as_bytes()) {
+                walk(&dir.join(name), rest, out);
+            }
+        }
+    }
+
+    fn matches(pattern: &[u8], name: &[u8]) -> bool {
+        match (pattern.split_first(), name.split_first()) {
+            (None, None) => true,
+            (Some((b'*', p)), _) => {
+                matches(p, name) || !name.is_empty() && matches(pattern, &name[1..])
+            }
+            (Some((b'?', p)), Some((_, n))) => matches(p, n),
+            (Some((c, p)), Some((n, rest))) => c == n && matches(p, rest),
+            _ => false,
+        }
+    }
+
+    let (base, pattern) = match pattern.strip_prefix('/') {
+        Some(pattern) => (PathBuf::from("/"), pattern),
+        None => (PathBuf::new(), pattern),
+    };
+    let components = pattern
+        .split('/')
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect::<Vec<_>>();
+    let mut paths = Vec::new();
+    walk(&base, &components, &mut paths);
+    paths.sort();
+    paths.dedup();
+    paths
 }
@@ -0,0 +1,155 @@
+use fxhash::FxHashMap;
+
+/// The lines of the leading comments of the definitions of a proto file, which protobuf-parse
+/// drops, keyed by their full names such as `pkg.Outer.field`. Like protoc, the comments right
+/// above a definition are its docs, the ones separated from it by a blank line and the ones
+/// after a token on the same line are not.
+pub(super) fn docs(source: &str) -> FxHashMap<String, Vec<String>> {
+    let mut docs = FxHashMap::default();
+    let mut package = None;
+    // the names of the enclosing messages, enums and services, `None` for the other blocks
+    let mut scopes: Vec<Option<&str>> = Vec::new();
+    let mut stmt: Vec<&str> = Vec::new();
+    let mut stmt_docs = Vec::new();
+    let mut pending = Vec::new();
+    // the depth of the brackets and parens, in which the braces of the option values are
+    let mut nested = 0;
+    let mut line_has_token = false;
+    let mut line_has_comment = false;
+
+    let mut rest = source;
+    while let Some(c) = rest.chars().next() {
+        if c == '\n' {
+            if !line_has_token && !line_has_comment {
+                pending.clear();
+            }
+            line_has_token = false;
+            line_has_comment = false;
+            rest = &rest[1..];
+            continue;
+        }
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        if let Some(comment) = rest.strip_prefix("//") {
+            let end = comment.find('\n').unwrap_or(comment.len());
+            if !line_has_token {
+                let line = comment[..end].trim_end();
+                pending.push(line.strip_prefix(' ').unwrap_or(line).to_string());
+            }
+            line_has_comment = true;
+            rest = &comment[end..];
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment.find("*/").unwrap_or(comment.len());
+            if !line_has_token {
+                pending.extend(block_lines(&comment[..end]));
+            }
+            line_has_comment = true;
+            rest = comment.get(end + 2..).unwrap_or_default();
+            continue;
+        }
+
+        let len = match c {
+            '"' | '\'' => {
+                let mut escaped = false;
+                rest[1..]
+                    .find(|ch| {
+                        let end = !escaped && ch == c;
+                        escaped = !escaped && ch == '\\';
+                        end
+                    })
+                    .map_or(rest.len(), |end| end + 2)
+            }
+            c if c.is_alphanumeric() || "_.-+".contains(c) => rest
+                .find(|ch: char| !(ch.is_alphanumeric() || "_.-+".contains(ch)))
+                .unwrap_or(rest.len()),
+            c => c.len_utf8(),
+        };
+        let (token, tail) = rest.split_at(len);
+        rest = tail;
+
+        if stmt.is_empty() && nested == 0 {
+            stmt_docs = std::mem::take(&mut pending);
+        } else {
+            pending.clear();
+        }
+        line_has_token = true;
+
+        match token {
+            "[" | "(" => nested += 1,
+            "]" | ")" => nested -= 1,
+            ";" | "{" if nested == 0 => {
+                let name = match stmt.first().copied() {
+                    Some("package") => {
+                        package = stmt.get(1).copied();
+                        None
+                    }
+                    Some("message" | "enum" | "service" | "oneof" | "rpc") => stmt.get(1).copied(),
+                    Some("option" | "syntax" | "import" | "reserved" | "extensions" | "extend")
+                    | None => None,
+                    // the fields and the enum values
+                    Some(_) => stmt
+                        .iter()
+                        .position(|t| *t == "=")
+                        .and_then(|idx| idx.checked_sub(1))
+                        .map(|idx| stmt[idx]),
+                };
+                if let Some(name) = name.filter(|_| !stmt_docs.is_empty()) {
+                    let path = package
+                        .into_iter()
+                        .chain(scopes.iter().flatten().copied())
+                        .chain(Some(name))
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    docs.insert(path, std::mem::take(&mut stmt_docs));
+                }
+                if token == "{" {
+                    // the fields of a oneof belong to the message
+                    let scope = matches!(stmt.first(), Some(&("message" | "enum" | "service")))
+                        .then_some(name)
+                        .flatten();
+                    scopes.push(scope);
+                }
+                stmt.clear();
+                stmt_docs.clear();
+                continue;
+            }
+            "}" if nested == 0 => {
+                scopes.pop();
+                stmt.clear();
+                stmt_docs.clear();
+                continue;
+            }
+            _ => {}
+        }
+        stmt.push(token);
+    }
+
+    docs
+}
+
+/// The lines of a block comment without the leading `*` of each line and the blank lines
+/// around the text.
+fn block_lines(block: &str) -> Vec<String> {
+    let lines = block
+        .lines()
+        .map(|l| {
+            let l = l.trim();
+            let l = l.strip_prefix('*').unwrap_or(l);
+            l.strip_prefix(' ').unwrap_or(l).trim_end()
+        })
+        .collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |end| end + 1);
+    lines[start..end].iter().map(|l| l.to_string()).collect()
+}
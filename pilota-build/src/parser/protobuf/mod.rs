@@ -1,36 +1,57 @@
-use std::sync::Arc;
+mod docs;
 
-use fxhash::FxHashMap;
+use std::{path::PathBuf, sync::Arc};
+
+use fxhash::{FxHashMap, FxHashSet};
 use heck::ToSnakeCase;
 use itertools::Itertools;
-use protobuf::descriptor::{
-    field_descriptor_proto::{Label, Type},
-    DescriptorProto, EnumDescriptorProto, ServiceDescriptorProto,
+use protobuf::{
+    descriptor::{
+        field_descriptor_proto::{Label, Type},
+        DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, ServiceDescriptorProto,
+    },
+    UnknownValueRef,
 };
 
-use super::Parser;
+use super::{ParseError, Parser};
 use crate::{
     index::Idx,
     ir::{self, FieldKind, Item, Path, TyKind},
-    symbol::{EnumRepr, FileId, Ident},
+    symbol::{EnumRepr, FileId, Ident, Naming},
     tags::{
         protobuf::{
-            ClientStreaming, Fixed32, Fixed64, OneOf, Repeated, SFixed32, SFixed64, SInt32, SInt64,
-            ServerStreaming,
+            ClientStreaming, CustomOptions, DefaultValue, Fixed32, Fixed64, FullName, MessageMod,
+            OneOf, Packed, Repeated, SFixed32, SFixed64, SInt32, SInt64, ServerStreaming,
         },
-        Tags,
+        Deprecated, Docs, Tags,
     },
 };
 
+/// Imported to declare custom options, the types of this file are not generated.
+const DESCRIPTOR_PROTO: &str = "google/protobuf/descriptor.proto";
+
 #[derive(Default)]
 pub struct ProtobufParser {
     inner: protobuf_parse::Parser,
+    files: Vec<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+    naming: Naming,
 }
 
 struct Lower {
     next_file_id: FileId,
     files: FxHashMap<String, FileId>,
+    // the package and top level type names of every file
+    scopes: FxHashMap<String, (Option<String>, FxHashSet<String>)>,
     cur_package: Option<String>,
+    // the scalar fields of proto2 have presence unless they are repeated
+    cur_proto2: bool,
+    cur_deps: Vec<String>,
+    // the full name and the type of the custom field options, keyed by their number
+    field_options: FxHashMap<u32, (String, Type)>,
+    // the lines of the doc comments of the definitions, keyed by their full names
+    docs: FxHashMap<String, Vec<String>>,
+    naming: Naming,
 }
 
 impl Default for Lower {
@@ -38,7 +59,13 @@ impl Default for Lower {
         Self {
             next_file_id: FileId::from_u32(0),
             files: Default::default(),
+            scopes: Default::default(),
             cur_package: None,
+            cur_proto2: false,
+            cur_deps: Default::default(),
+            field_options: Default::default(),
+            docs: Default::default(),
+            naming: Default::default(),
         }
     }
 }
@@ -54,6 +81,112 @@ impl Lower {
                     .chain(std::iter::once(segs[segs.len() - 1].to_string()))
                     .map(Ident::from),
             ),
+            span: None,
+        }
+    }
+
+    /// Lowers a name from another package to a path through the imported file
+    /// which defines it.
+    fn dep_path(&self, name: &str) -> Option<ir::Path> {
+        self.cur_deps.iter().find_map(|dep| {
+            let (package, names) = self.scopes.get(dep)?;
+            if *package == self.cur_package {
+                return None;
+            }
+            let rest = match package {
+                Some(package) => name.strip_prefix(package.as_str())?.strip_prefix('.')?,
+                None => name,
+            };
+            if !names.contains(rest.split('.').next().unwrap()) {
+                return None;
+            }
+            let alias = Ident::from(dep.trim_end_matches(".proto").rsplit('/').next().unwrap());
+            Some(ir::Path {
+                segments: std::iter::once(alias)
+                    .chain(self.str2path(rest).segments.iter().cloned())
+                    .collect(),
+                span: None,
+            })
+        })
+    }
+
+    /// Collects the extensions of `google.protobuf.FieldOptions` declared in `scope`.
+    fn collect_field_options(
+        &mut self,
+        scope: &str,
+        extensions: &[FieldDescriptorProto],
+        messages: &[DescriptorProto],
+    ) {
+        extensions
+            .iter()
+            .filter(|e| e.extendee() == ".google.protobuf.FieldOptions")
+            .for_each(|e| {
+                self.field_options.insert(
+                    e.number() as u32,
+                    (format!("{scope}{}", e.name()), e.type_()),
+                );
+            });
+        messages.iter().for_each(|m| {
+            self.collect_field_options(
+                &format!("{scope}{}.", m.name()),
+                &m.extension,
+                &m.nested_type,
+            )
+        });
+    }
+
+    fn field_option_tags(&self, f: &FieldDescriptorProto, tags: &mut Tags) {
+        if let Some(default) = f.default_value.as_deref() {
+            tags.insert(DefaultValue(default.into()));
+        }
+        // the repeated scalars of proto2 are only packed when asked to
+        let scalar = !matches!(
+            f.type_(),
+            Type::TYPE_STRING | Type::TYPE_BYTES | Type::TYPE_MESSAGE | Type::TYPE_GROUP
+        );
+        match f.options.as_ref().and_then(|options| options.packed) {
+            Some(packed) => tags.insert(Packed(packed)),
+            None if self.cur_proto2 && scalar && matches!(f.label(), Label::LABEL_REPEATED) => {
+                tags.insert(Packed(false))
+            }
+            None => {}
+        }
+
+        let options = match f.options.as_ref() {
+            Some(options) => options,
+            None => return,
+        };
+        if options.deprecated() {
+            tags.insert(Deprecated(None));
+        }
+
+        let mut custom = options
+            .special_fields
+            .unknown_fields()
+            .iter()
+            .filter_map(|(number, value)| {
+                let (name, ty) = self.field_options.get(&number)?;
+                Some((Arc::from(&**name), Arc::from(option_value(*ty, value))))
+            })
+            .collect::<Vec<_>>();
+        if !custom.is_empty() {
+            custom.sort();
+            tags.insert(CustomOptions(custom));
+        }
+    }
+
+    /// Tags the definition named `full_name` with the lines of its doc comments, if any.
+    fn insert_docs(&self, tags: &mut Tags, full_name: &str) {
+        if let Some(docs) = self.docs.get(full_name) {
+            tags.insert(Docs(docs.iter().map(|d| Arc::from(d.as_str())).collect()))
+        }
+    }
+
+    /// The full name of the top level definition `name` of the current file.
+    fn full_name(&self, name: &str) -> String {
+        match &self.cur_package {
+            Some(package) => format!("{package}.{name}"),
+            None => name.to_string(),
         }
     }
 
@@ -89,12 +222,21 @@ impl Lower {
             }
 
             assert_eq!(".", &name[..1]);
-            let cur_pkg = self.cur_package.as_deref().unwrap_or("");
-            if name[1..].starts_with(cur_pkg) {
+            if let Some(path) = self.dep_path(&name[1..]) {
                 return ir::Ty {
-                    kind: ir::TyKind::Path(ir::Path {
-                        segments: Arc::from_iter([Ident::from(name.split('.').last().unwrap())]),
-                    }),
+                    kind: ir::TyKind::Path(path),
+                    tags: Default::default(),
+                };
+            }
+            let relative_name = match self.cur_package.as_deref() {
+                Some(cur_pkg) => name[1..]
+                    .strip_prefix(cur_pkg)
+                    .and_then(|name| name.strip_prefix('.')),
+                None => Some(&name[1..]),
+            };
+            if let Some(relative_name) = relative_name {
+                return ir::Ty {
+                    kind: ir::TyKind::Path(self.str2path(relative_name)),
                     tags: Default::default(),
                 };
             } else {
@@ -163,19 +305,31 @@ impl Lower {
     }
 
     fn lower_enum(&self, e: &EnumDescriptorProto) -> ir::Item {
+        let full_name = self.full_name(e.name());
+        let mut tags = Tags::default();
+        self.insert_docs(&mut tags, &full_name);
         ir::Item {
-            tags: Default::default(),
+            tags: Arc::new(tags),
+            span: None,
             kind: ir::ItemKind::Enum(ir::Enum {
                 name: e.name().into(),
                 variants: e
                     .value
                     .iter()
-                    .map(|v| ir::EnumVariant {
-                        id: v.number,
-                        name: v.name().into(),
-                        discr: v.number.map(|v| v as i64),
-                        tags: Default::default(),
-                        fields: Default::default(),
+                    .map(|v| {
+                        let mut tags = Tags::default();
+                        if v.options.deprecated() {
+                            tags.insert(Deprecated(None));
+                        }
+                        self.insert_docs(&mut tags, &format!("{full_name}.{}", v.name()));
+                        ir::EnumVariant {
+                            id: v.number,
+                            name: v.name().into(),
+                            discr: v.number.map(|v| v as i64),
+                            tags: Arc::new(tags),
+                            fields: Default::default(),
+                            default: None,
+                        }
                     })
                     .collect_vec(),
                 repr: Some(EnumRepr::I32),
@@ -183,13 +337,8 @@ impl Lower {
         }
     }
 
-    fn lower_message(&self, message: &DescriptorProto) -> ir::Item {
-        let fq_message_name = format!(
-            "{}{}.{}",
-            if self.cur_package.is_none() { "" } else { "." },
-            self.cur_package.as_deref().unwrap_or(""),
-            message.name()
-        );
+    fn lower_message(&self, message: &DescriptorProto, scope: &str) -> ir::Item {
+        let fq_message_name = format!("{}.{}", scope, message.name());
 
         let nested_messages = message
             .nested_type
@@ -213,12 +362,28 @@ impl Lower {
             }
         });
 
+        // protoc wraps every proto3 `optional` field in a oneof of its own, which is skipped
+        // like the empty oneofs
+        let used_oneofs = message
+            .field
+            .iter()
+            .filter(|f| !f.proto3_optional())
+            .filter_map(|f| f.oneof_index)
+            .collect::<FxHashSet<_>>();
+        let oneof_decls = message
+            .oneof_decl
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| used_oneofs.contains(&(*idx as i32)))
+            .collect::<Vec<_>>();
+
         let mut nested_items: Vec<_> = Default::default();
 
-        message.oneof_decl.iter().enumerate().for_each(|(idx, d)| {
-            let fields = oneof_fields.remove(&(idx as i32)).unwrap();
+        oneof_decls.iter().for_each(|(idx, d)| {
+            let fields = oneof_fields.remove(&(*idx as i32)).unwrap();
             nested_items.push(Arc::new(ir::Item {
                 tags: Arc::new(crate::tags!(OneOf)),
+                span: None,
                 kind: ir::ItemKind::Enum(ir::Enum {
                     name: d.name().into(),
                     repr: None,
@@ -233,7 +398,15 @@ impl Lower {
                                 f.type_name.as_deref(),
                                 &nested_messages,
                             )],
-                            tags: Default::default(),
+                            default: None,
+                            tags: {
+                                let mut tags = Tags::default();
+                                self.insert_docs(
+                                    &mut tags,
+                                    &format!("{}.{}", &fq_message_name[1..], f.name()),
+                                );
+                                Arc::new(tags)
+                            },
                         })
                         .collect_vec(),
                 }),
@@ -243,10 +416,15 @@ impl Lower {
         nested_messages
             .iter()
             .filter(|(_, m)| !m.options.has_map_entry())
-            .for_each(|(_, m)| nested_items.push(Arc::new(self.lower_message(m))));
+            .for_each(|(_, m)| {
+                nested_items.push(Arc::new(self.lower_message(m, &fq_message_name)))
+            });
 
+        let mut tags = crate::tags!(FullName(fq_message_name[1..].into()));
+        self.insert_docs(&mut tags, &fq_message_name[1..]);
         let item = ir::Item {
-            tags: Default::default(),
+            tags: Arc::new(tags),
+            span: None,
             kind: ir::ItemKind::Message(ir::Message {
                 fields: fields
                     .iter()
@@ -264,39 +442,59 @@ impl Lower {
                             }
                         }
 
+                        // the message fields always have presence
+                        let message = !repeated && matches!(f.type_(), Type::TYPE_MESSAGE);
                         let optional = !is_map && {
                             f.proto3_optional()
-                                || (!repeated && matches!(f.type_(), Type::TYPE_MESSAGE))
+                                || message
+                                || (self.cur_proto2 && matches!(f.label(), Label::LABEL_OPTIONAL))
                         };
 
                         let mut tags = Tags::default();
                         if repeated {
                             tags.insert(Repeated);
                         }
+                        self.field_option_tags(f, &mut tags);
+                        self.insert_docs(
+                            &mut tags,
+                            &format!("{}.{}", &fq_message_name[1..], f.name()),
+                        );
 
                         ir::Field {
                             id: f.number(),
                             name: f.name().into(),
                             ty,
                             tags: Arc::new(tags),
-                            kind: if optional {
+                            kind: if !message && matches!(f.label(), Label::LABEL_REQUIRED) {
+                                FieldKind::Required
+                            } else if optional {
                                 FieldKind::Optional
                             } else {
-                                FieldKind::Required
+                                FieldKind::Implicit
                             },
+                            default: None,
                         }
                     })
-                    .chain(message.oneof_decl.iter().map(|d| ir::Field {
+                    .chain(oneof_decls.iter().map(|(_, d)| ir::Field {
                         name: d.name().into(),
                         id: -1,
                         ty: ir::Ty {
                             kind: ir::TyKind::Path(Path {
                                 segments: Arc::from([d.name().into()]),
+                                span: None,
                             }),
                             tags: Default::default(),
                         },
-                        tags: Arc::new(crate::tags!(OneOf)),
+                        tags: {
+                            let mut tags = crate::tags!(OneOf);
+                            self.insert_docs(
+                                &mut tags,
+                                &format!("{}.{}", &fq_message_name[1..], d.name()),
+                            );
+                            Arc::new(tags)
+                        },
                         kind: ir::FieldKind::Optional,
+                        default: None,
                     }))
                     .collect(),
                 name: message.name().into(),
@@ -306,10 +504,12 @@ impl Lower {
         if nested_items.is_empty() {
             item
         } else {
-            let name = item.name().to_lower_camel_case();
+            let name = item.name().to_snake_case(&self.naming);
+            let tags = crate::tags!(MessageMod(item.name()));
             nested_items.push(Arc::new(item));
             Item {
-                tags: Default::default(),
+                tags: Arc::new(tags),
+                span: None,
                 kind: ir::ItemKind::Mod(ir::Mod {
                     name: Ident::new(name),
                     items: nested_items,
@@ -319,8 +519,12 @@ impl Lower {
     }
 
     pub fn lower_service(&self, service: &ServiceDescriptorProto) -> ir::Item {
+        let full_name = self.full_name(service.name());
+        let mut tags = Tags::default();
+        self.insert_docs(&mut tags, &full_name);
         ir::Item {
-            tags: Default::default(),
+            tags: Arc::new(tags),
+            span: None,
             kind: ir::ItemKind::Service(ir::Service {
                 name: service.name().into(),
                 methods: service
@@ -328,26 +532,33 @@ impl Lower {
                     .iter()
                     .map(|m| {
                         let mut tags = Tags::default();
+                        let mut arg =
+                            self.lower_ty(None, m.input_type.as_deref(), &Default::default());
+                        let mut ret =
+                            self.lower_ty(None, m.output_type.as_deref(), &Default::default());
+                        // tag the types too, the `TyTransformer` only sees those
                         if m.client_streaming() {
                             tags.insert(ClientStreaming);
+                            arg.tags = Arc::new(crate::tags!(ClientStreaming));
                         }
                         if m.server_streaming() {
                             tags.insert(ServerStreaming);
+                            ret.tags = Arc::new(crate::tags!(ServerStreaming));
                         }
+                        if m.options.deprecated() {
+                            tags.insert(Deprecated(None));
+                        }
+                        self.insert_docs(&mut tags, &format!("{full_name}.{}", m.name()));
                         ir::Method {
                             name: m.name().into(),
                             tags: Arc::new(tags),
                             args: vec![ir::Arg {
                                 name: "req".into(),
                                 id: -1,
-                                ty: self.lower_ty(
-                                    None,
-                                    m.input_type.as_deref(),
-                                    &Default::default(),
-                                ),
+                                ty: arg,
                             }],
                             oneway: false,
-                            ret: self.lower_ty(None, m.output_type.as_deref(), &Default::default()),
+                            ret,
                             exceptions: None,
                         }
                     })
@@ -364,12 +575,34 @@ impl Lower {
         files.iter().for_each(|f| {
             self.files
                 .insert(f.name().to_string(), self.next_file_id.inc_one());
+            let names = f
+                .message_type
+                .iter()
+                .map(|m| m.name())
+                .chain(f.enum_type.iter().map(|e| e.name()))
+                .map(String::from)
+                .collect();
+            self.scopes
+                .insert(f.name().to_string(), (f.package.clone(), names));
+            let scope = f
+                .package
+                .as_ref()
+                .map(|p| format!("{p}."))
+                .unwrap_or_default();
+            self.collect_field_options(&scope, &f.extension, &f.message_type);
         });
 
         files
             .iter()
             .map(|f| {
                 self.cur_package = f.package.clone();
+                self.cur_proto2 = f.syntax() != "proto3";
+                self.cur_deps = f.dependency.clone();
+                let scope = f
+                    .package
+                    .as_ref()
+                    .map(|p| format!(".{p}"))
+                    .unwrap_or_default();
 
                 let file_id = *self.files.get(f.name()).unwrap();
 
@@ -380,14 +613,16 @@ impl Lower {
                 );
 
                 let enums = f.enum_type.iter().map(|e| self.lower_enum(e));
-                let messages = f.message_type.iter().map(|m| self.lower_message(m));
+                let messages = f.message_type.iter().map(|m| self.lower_message(m, &scope));
                 let services = f.service.iter().map(|s| self.lower_service(s));
 
                 let f = Arc::from(ir::File {
+                    path: Arc::new(PathBuf::from(f.name())),
                     package,
                     uses: f
                         .dependency
                         .iter()
+                        .filter(|d| *d != DESCRIPTOR_PROTO)
                         .map(|d| {
                             (
                                 d.trim_end_matches(".proto")
@@ -400,14 +635,19 @@ impl Lower {
                         })
                         .collect(),
                     id: file_id,
-                    items: messages
-                        .chain(enums)
-                        .chain(services)
-                        .map(Arc::from)
-                        .collect::<Vec<_>>(),
+                    items: if f.name() == DESCRIPTOR_PROTO {
+                        Vec::new()
+                    } else {
+                        messages
+                            .chain(enums)
+                            .chain(services)
+                            .map(Arc::from)
+                            .collect::<Vec<_>>()
+                    },
                 });
 
                 self.cur_package = None;
+                self.cur_deps.clear();
 
                 f
             })
@@ -415,20 +655,145 @@ impl Lower {
     }
 }
 
+/// Renders the value of a custom option of type `ty`, the string and bytes values are kept
+/// as is.
+fn option_value(ty: Type, value: UnknownValueRef) -> String {
+    match (ty, value) {
+        (Type::TYPE_BOOL, UnknownValueRef::Varint(v)) => (v != 0).to_string(),
+        (Type::TYPE_SINT32 | Type::TYPE_SINT64, UnknownValueRef::Varint(v)) => {
+            ((v >> 1) as i64 ^ -((v & 1) as i64)).to_string()
+        }
+        (Type::TYPE_INT32 | Type::TYPE_INT64 | Type::TYPE_ENUM, UnknownValueRef::Varint(v)) => {
+            (v as i64).to_string()
+        }
+        (Type::TYPE_FLOAT, UnknownValueRef::Fixed32(v)) => f32::from_bits(v).to_string(),
+        (Type::TYPE_SFIXED32, UnknownValueRef::Fixed32(v)) => (v as i32).to_string(),
+        (Type::TYPE_DOUBLE, UnknownValueRef::Fixed64(v)) => f64::from_bits(v).to_string(),
+        (Type::TYPE_SFIXED64, UnknownValueRef::Fixed64(v)) => (v as i64).to_string(),
+        (_, UnknownValueRef::Varint(v) | UnknownValueRef::Fixed64(v)) => v.to_string(),
+        (_, UnknownValueRef::Fixed32(v)) => v.to_string(),
+        (_, UnknownValueRef::LengthDelimited(v)) => String::from_utf8_lossy(v).into_owned(),
+    }
+}
+
+/// The well-known types embedded in protobuf-parse, which are imported without being in any of
+/// the include dirs.
+const EMBEDDED_IMPORTS: &[&str] = &[
+    "google/protobuf/any.proto",
+    "google/protobuf/api.proto",
+    "google/protobuf/descriptor.proto",
+    "google/protobuf/duration.proto",
+    "google/protobuf/empty.proto",
+    "google/protobuf/field_mask.proto",
+    "google/protobuf/source_context.proto",
+    "google/protobuf/struct.proto",
+    "google/protobuf/timestamp.proto",
+    "google/protobuf/type.proto",
+    "google/protobuf/wrappers.proto",
+];
+
+/// The paths imported by a proto file, as parsed by protobuf-parse. A file which can not be
+/// parsed imports nothing here, its error is reported when it is parsed again with the others.
+fn imports(source: &str) -> Vec<String> {
+    protobuf_parse::pure::parse_dependencies(source)
+        .map(|file| file.dependency)
+        .unwrap_or_default()
+}
+
 impl Parser for ProtobufParser {
     fn input<P: AsRef<std::path::Path>>(&mut self, path: P) {
+        self.files.push(path.as_ref().into());
         self.inner.input(path);
     }
 
     fn include_dirs(&mut self, dirs: Vec<std::path::PathBuf>) {
+        self.include_dirs.extend_from_slice(&dirs);
         self.inner.includes(dirs);
     }
 
-    fn parse(self) -> super::ParseResult {
-        let descriptors = self.inner.parse_and_typecheck().unwrap().file_descriptors;
+    fn naming(&mut self, naming: Naming) {
+        self.naming = naming;
+    }
+
+    fn is_include_only(&self, path: &std::path::Path) -> bool {
+        // the file is found in its own dir if it is in none of the include dirs
+        let mut parser = protobuf_parse::Parser::new();
+        parser
+            .includes(&self.include_dirs)
+            .includes(path.parent())
+            .input(path);
+        parser.parse_and_typecheck().is_ok_and(|parsed| {
+            let name = parsed.relative_paths[0].as_path().to_str();
+            parsed
+                .file_descriptors
+                .iter()
+                .find(|f| f.name() == name)
+                .is_some_and(|f| {
+                    f.message_type.is_empty()
+                        && f.enum_type.is_empty()
+                        && f.service.is_empty()
+                        && f.extension.is_empty()
+                })
+        })
+    }
 
-        super::ParseResult {
-            files: Lower::default().lower(&descriptors),
-        }
+    fn parse(self) -> Result<super::ParseResult, ParseError> {
+        // protobuf-parse recurses into the imports without tracking the files being parsed, so
+        // a cycle has to be found before
+        super::check_include_cycles(&self.files, |path| {
+            let source = std::fs::read_to_string(path)
+                .map_err(|e| ParseError::new(path, format!("failed to read the file: {e}")))?;
+            imports(&source)
+                .into_iter()
+                .filter_map(|import| {
+                    let found = self
+                        .include_dirs
+                        .iter()
+                        .map(|dir| dir.join(&import))
+                        .find(|path| path.exists());
+                    match found {
+                        Some(found) => Some(Ok(found)),
+                        None if EMBEDDED_IMPORTS.contains(&&*import) => None,
+                        None => Some(Err(super::include_not_found(
+                            path,
+                            &import,
+                            &self.include_dirs,
+                        ))),
+                    }
+                })
+                .collect()
+        })?;
+
+        let descriptors = self
+            .inner
+            .parse_and_typecheck()
+            .map_err(|e| {
+                let message = format!("{:#}", e);
+                // errors raised while parsing a file are prefixed with its path
+                let path = message
+                    .strip_prefix("error in `")
+                    .and_then(|s| s.split_once('`'))
+                    .map(|(path, _)| PathBuf::from(path))
+                    .or_else(|| self.files.first().cloned())
+                    .unwrap_or_default();
+                ParseError::new(path, message)
+            })?
+            .file_descriptors;
+
+        let mut lower = Lower {
+            naming: self.naming,
+            ..Default::default()
+        };
+        // the well-known types embedded in protobuf-parse are found in none of the include dirs
+        descriptors
+            .iter()
+            .filter_map(|f| {
+                self.include_dirs
+                    .iter()
+                    .find_map(|dir| std::fs::read_to_string(dir.join(f.name())).ok())
+            })
+            .for_each(|source| lower.docs.extend(docs::docs(&source)));
+
+        Ok(super::ParseResult::new(lower.lower(&descriptors)))
     }
 }
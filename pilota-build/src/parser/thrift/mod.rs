@@ -10,26 +10,52 @@ use crate::{
     index::Idx,
     ir,
     ir::{Arg, Enum, EnumVariant, FieldKind, File, Item, ItemKind, Path},
-    symbol::{EnumRepr, FileId, Ident, Symbol},
-    tags::Tags,
-    util::error_abort,
+    symbol::{EnumRepr, FileId, Ident, Span, Symbol},
+    tags::{
+        thrift::{
+            BoxedStr, DefaultVariant, Internal, MethodArgs, MethodException, RustCollection,
+            RustType, SharedTrait, Streaming, Union,
+        },
+        validate::{Max, Min, NonEmpty},
+        Deprecated, Derives, Docs, OrdById, Tags,
+    },
+    ty::CollectionKind,
 };
 
+use super::{include_not_found, ParseError};
+
 #[salsa::query_group(SourceDatabaseStorage)]
 trait SourceDatabase {
-    fn file_text(&self, path: PathBuf) -> Arc<String>;
-    fn parse(&self, path: PathBuf) -> Arc<thrift_parser::File>;
+    fn file_text(&self, path: PathBuf) -> Result<Arc<String>, ParseError>;
+    fn parse(&self, path: PathBuf) -> Result<Arc<thrift_parser::File>, ParseError>;
 }
 
-fn file_text(_db: &dyn SourceDatabase, path: PathBuf) -> Arc<String> {
-    Arc::new(unsafe { String::from_utf8_unchecked(std::fs::read(path).unwrap()) })
+fn file_text(_db: &dyn SourceDatabase, path: PathBuf) -> Result<Arc<String>, ParseError> {
+    let bytes = std::fs::read(&path).map_err(|e| ParseError::new(path, e.to_string()))?;
+    Ok(Arc::new(unsafe { String::from_utf8_unchecked(bytes) }))
 }
 
-fn parse(db: &dyn SourceDatabase, path: PathBuf) -> Arc<thrift_parser::File> {
-    let text = db.file_text(path.clone());
-    let mut ast = thrift_parser::File::parse(&text).unwrap().1;
+fn parse(db: &dyn SourceDatabase, path: PathBuf) -> Result<Arc<thrift_parser::File>, ParseError> {
+    let text = db.file_text(path.clone())?;
+    let mut ast = match thrift_parser::File::parse(&text) {
+        Ok((remain, _)) if !remain.trim().is_empty() => {
+            let offset = text.len() - remain.trim_start().len();
+            let line = text[..offset].matches('\n').count() + 1;
+            return Err(ParseError::new(
+                path,
+                format!("syntax error at line {}", line),
+            ));
+        }
+        Ok((_, ast)) => ast,
+        Err(e) => {
+            return Err(ParseError::new(
+                path,
+                format!("invalid thrift file: {:?}", e),
+            ))
+        }
+    };
     ast.path = Arc::from(path);
-    Arc::from(ast)
+    Ok(Arc::from(ast))
 }
 
 #[derive(Default)]
@@ -54,7 +80,7 @@ pub struct LowerResult {
 }
 
 pub trait Lower<Ast> {
-    fn lower(&mut self, file: Ast) -> FileId;
+    fn lower(&mut self, file: Ast) -> Result<FileId, ParseError>;
 
     fn finish(self) -> LowerResult;
 }
@@ -80,27 +106,53 @@ impl ThriftLower {
         }
     }
 
-    pub fn with_cur_file<F>(&mut self, file: Arc<thrift_parser::File>, f: F) -> Arc<File>
+    pub fn with_cur_file<F>(
+        &mut self,
+        file: Arc<thrift_parser::File>,
+        f: F,
+    ) -> Result<Arc<File>, ParseError>
     where
-        F: FnOnce(&mut Self) -> ir::File,
+        F: FnOnce(&mut Self) -> Result<ir::File, ParseError>,
     {
         let old_file = self.cur_file.clone();
         self.cur_file = Some(file);
 
-        let f = Arc::from(f(self));
+        let f = f(self);
         self.cur_file = old_file;
+        let f = Arc::from(f?);
         self.files.insert(f.id, f.clone());
-        f
+        Ok(f)
     }
 
     fn lower_path(&mut self, path: &thrift_parser::Path) -> ir::Path {
         Path {
             segments: Arc::from_iter(path.segments.iter().map(|i| self.lower_ident(i))),
+            span: self.span(path.rest_len),
         }
     }
 
+    fn span(&self, rest_len: usize) -> Option<Span> {
+        if rest_len == 0 {
+            return None;
+        }
+        let text = self
+            .db
+            .file_text(self.cur_file.as_ref().unwrap().path.to_path_buf())
+            .unwrap();
+        let offset = text.len() - rest_len;
+        let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        Some(Span {
+            line: text[..offset].matches('\n').count() + 1,
+            column: text[line_start..offset].chars().count() + 1,
+        })
+    }
+
     fn mk_item(&self, kind: ItemKind, tags: Arc<Tags>) -> ir::Item {
-        ir::Item { kind, tags }
+        ir::Item {
+            kind,
+            tags,
+            span: None,
+        }
     }
 
     fn lower_service(&mut self, service: &thrift_parser::Service) -> Vec<ir::Item> {
@@ -118,7 +170,8 @@ impl ThriftLower {
                 .map(|f| self.lower_method(service, f))
                 .collect(),
         });
-        let mut result = vec![self.mk_item(kind, Default::default())];
+        let mut result =
+            vec![self.mk_item(kind, Arc::new(deprecated_tags(Some(&service.annotations))))];
 
         service.functions.iter().for_each(|f| {
             let exception = f
@@ -139,6 +192,7 @@ impl ThriftLower {
                     tags: Default::default(),
                     discr: None,
                     fields: vec![self.lower_ty(&f.ty)],
+                    default: None,
                 })
                 .collect::<Vec<_>>();
 
@@ -155,6 +209,7 @@ impl ThriftLower {
                     tags: Default::default(),
                     discr: None,
                     fields: vec![self.lower_ty(&f.result_type)],
+                    default: None,
                 })
                 .chain(exception.clone())
                 .collect(),
@@ -173,7 +228,7 @@ impl ThriftLower {
                     variants: exception,
                     repr: None,
                 });
-                result.push(self.mk_item(kind, Default::default()));
+                result.push(self.mk_item(kind, Arc::new(crate::tags!(MethodException))));
             }
             let kind = ir::ItemKind::Message(ir::Message {
                 name: format!(
@@ -184,7 +239,7 @@ impl ThriftLower {
                 .into(),
                 fields: f.arguments.iter().map(|a| self.lower_field(a)).collect(),
             });
-            result.push(self.mk_item(kind, Default::default()));
+            result.push(self.mk_item(kind, Arc::new(crate::tags!(MethodArgs))));
         });
 
         result
@@ -208,11 +263,25 @@ impl ThriftLower {
                 .collect(),
             ret: self.lower_ty(&method.result_type),
             oneway: method.oneway,
-            tags: Default::default(),
+            tags: {
+                let mut tags = deprecated_tags(Some(&method.annotations));
+                if method.annotations.iter().any(|a| {
+                    a.key
+                        .segments
+                        .iter()
+                        .map(|s| &*s.0)
+                        .eq(["streaming", "mode"])
+                }) {
+                    tags.insert(Streaming);
+                }
+                insert_docs(&mut tags, &method.docs);
+                Arc::new(tags)
+            },
             exceptions: if method.throws.is_empty() {
                 None
             } else {
                 Some(Path {
+                    span: None,
                     segments: Arc::from([Ident::from(format!(
                         "{}{}Exception",
                         service.name.to_upper_camel_case().as_str(),
@@ -229,12 +298,17 @@ impl ThriftLower {
             variants: e
                 .values
                 .iter()
-                .map(|v| ir::EnumVariant {
-                    id: None,
-                    name: self.lower_ident(&v.name),
-                    discr: v.value.map(|v| v.0),
-                    fields: vec![],
-                    tags: Default::default(),
+                .map(|v| {
+                    let mut tags = deprecated_tags(v.annotations.as_ref());
+                    insert_docs(&mut tags, &v.docs);
+                    ir::EnumVariant {
+                        id: None,
+                        name: self.lower_ident(&v.name),
+                        discr: v.value.map(|v| v.0),
+                        fields: vec![],
+                        default: None,
+                        tags: Arc::new(tags),
+                    }
                 })
                 .collect(),
             repr: Some(EnumRepr::I32),
@@ -278,9 +352,22 @@ impl ThriftLower {
             thrift_parser::Item::Typedef(t) => ir::ItemKind::NewType(self.lower_typedef(t)),
             thrift_parser::Item::Constant(c) => ir::ItemKind::Const(self.lower_const(c)),
             thrift_parser::Item::Enum(e) => ir::ItemKind::Enum(self.lower_enum(e)),
-            thrift_parser::Item::Struct(s) => ir::ItemKind::Message(self.lower_struct(s)),
-            thrift_parser::Item::Union(u) => ir::ItemKind::Enum(self.lower_union(u)),
-            thrift_parser::Item::Exception(s) => ir::ItemKind::Message(self.lower_struct(s)),
+            thrift_parser::Item::Struct(s) => {
+                let kind = ir::ItemKind::Message(self.lower_struct(s));
+                let tags = self.extract_struct_tags(s.annotations.as_ref());
+                return vec![self.mk_item(kind, Arc::new(tags))];
+            }
+            thrift_parser::Item::Union(u) => {
+                let kind = ir::ItemKind::Enum(self.lower_union(u));
+                let mut tags = self.extract_struct_tags(u.annotations.as_ref());
+                tags.insert(Union);
+                return vec![self.mk_item(kind, Arc::new(tags))];
+            }
+            thrift_parser::Item::Exception(s) => {
+                let kind = ir::ItemKind::Message(self.lower_struct(s));
+                let tags = self.extract_struct_tags(s.annotations.as_ref());
+                return vec![self.mk_item(kind, Arc::new(tags))];
+            }
             thrift_parser::Item::Service(s) => return self.lower_service(s),
         };
 
@@ -293,12 +380,26 @@ impl ThriftLower {
             variants: union
                 .fields
                 .iter()
-                .map(|f| EnumVariant {
-                    id: Some(f.id),
-                    name: self.lower_ident(&f.name),
-                    discr: None,
-                    fields: vec![self.lower_ty(&f.ty)],
-                    tags: Default::default(),
+                .map(|f| {
+                    let mut tags = Tags::default();
+                    if f.default.is_some()
+                        || f.annotations.iter().any(|a| {
+                            a.key.segments.len() == 1
+                                && a.key.segments[0] == "default"
+                                && &*a.value == "true"
+                        })
+                    {
+                        tags.insert(DefaultVariant);
+                    }
+                    insert_docs(&mut tags, &f.docs);
+                    EnumVariant {
+                        id: Some(f.id),
+                        name: self.lower_ident(&f.name),
+                        discr: None,
+                        fields: vec![self.lower_ty(&f.ty)],
+                        default: f.default.as_ref().map(|d| self.lower_lit(d)),
+                        tags: Arc::new(tags),
+                    }
                 })
                 .collect(),
             repr: None,
@@ -336,18 +437,133 @@ impl ThriftLower {
     }
 
     fn lower_field(&mut self, f: &thrift_parser::Field) -> ir::Field {
+        let mut tags = self.extract_field_tags(&f.annotations);
+        insert_docs(&mut tags, &f.docs);
+        let mut ty = self.lower_ty(&f.ty);
+        // tag the type too, it decides the generated type of the field
+        let mut ty_tags = Tags::default();
+        if tags.contains::<BoxedStr>() {
+            ty_tags.insert(BoxedStr);
+        }
+        if let Some(rust_type) = tags.get::<RustType>() {
+            if !matches!(
+                ty.kind,
+                ir::TyKind::String
+                    | ir::TyKind::Bytes
+                    | ir::TyKind::Vec(_)
+                    | ir::TyKind::Set(_)
+                    | ir::TyKind::Map(..)
+            ) {
+                panic!(
+                    "`rust.type` is only supported on string, binary and container fields, \
+                     but `{}` is not",
+                    &*f.name.0
+                )
+            }
+            ty_tags.insert(RustType(rust_type.0.clone()));
+        }
+        if let Some(collection) = tags.get::<RustCollection>() {
+            if !matches!(ty.kind, ir::TyKind::Set(_) | ir::TyKind::Map(..)) {
+                panic!(
+                    "`rust.collection` is only supported on set and map fields, but `{}` is not",
+                    &*f.name.0
+                )
+            }
+            ty_tags.insert(RustCollection(collection.0));
+        }
+        if !ty_tags.is_empty() {
+            let mut tags = (*ty.tags).clone();
+            tags.merge(ty_tags);
+            ty.tags = Arc::new(tags);
+        }
         ir::Field {
             name: self.lower_ident(&f.name),
             id: f.id,
-            ty: self.lower_ty(&f.ty),
+            ty,
             kind: match f.attribute {
                 thrift_parser::Attribute::Required => FieldKind::Required,
                 _ => FieldKind::Optional,
             },
-            tags: Default::default(),
+            tags: Arc::new(tags),
+            default: f.default.as_ref().map(|d| self.lower_lit(d)),
         }
     }
 
+    fn extract_struct_tags(&self, annotations: Option<&thrift_parser::Annotations>) -> Tags {
+        let mut tags = Tags::default();
+        annotations
+            .into_iter()
+            .flat_map(|a| a.iter())
+            .for_each(
+                |a| match &*a.key.segments.iter().map(|s| &*s.0).collect::<Vec<_>>() {
+                    ["shared_trait"] => tags.insert(SharedTrait(Arc::from(&*a.value.0))),
+                    ["ord_by_id"] if &*a.value == "true" => tags.insert(OrdById),
+                    ["derive"] => tags.insert(Derives(
+                        a.value
+                            .split(',')
+                            .map(|d| Arc::from(d.trim()))
+                            .filter(|d: &Arc<str>| !d.is_empty())
+                            .collect(),
+                    )),
+                    _ => {}
+                },
+            );
+        tags
+    }
+
+    /// The recognized field annotations are `pilota.min`, `pilota.max`, `pilota.non_empty`,
+    /// `internal`, `pilota.boxed_str`, `deprecated`, `rust.type` and `rust.collection`, the
+    /// others are ignored. A bound which is not an integer is skipped with a warning.
+    fn extract_field_tags(&self, annotations: &thrift_parser::Annotations) -> Tags {
+        let mut tags = Tags::default();
+        annotations.iter().for_each(|a| {
+            let key = a.key.segments.iter().map(|s| &*s.0).collect::<Vec<_>>();
+            let parse_bound = || {
+                let bound = a.value.parse::<i64>().ok();
+                if bound.is_none() {
+                    tracing::warn!(
+                        "skip annotation `{}`, the value `{}` is not an integer",
+                        key.join("."),
+                        &*a.value
+                    );
+                }
+                bound
+            };
+            match &*key {
+                ["pilota", "min"] => {
+                    if let Some(min) = parse_bound() {
+                        tags.insert(Min(min))
+                    }
+                }
+                ["pilota", "max"] => {
+                    if let Some(max) = parse_bound() {
+                        tags.insert(Max(max))
+                    }
+                }
+                ["pilota", "non_empty"] if &*a.value == "true" => tags.insert(NonEmpty),
+                ["internal"] if &*a.value == "true" => tags.insert(Internal),
+                ["pilota", "boxed_str"] if &*a.value == "true" => tags.insert(BoxedStr),
+                ["deprecated"] => {
+                    if let Some(deprecated) = deprecated(&a.value) {
+                        tags.insert(deprecated)
+                    }
+                }
+                ["rust", "type"] => tags.insert(RustType(Arc::from(&*a.value.0))),
+                ["rust", "collection"] => tags.insert(RustCollection(match &*a.value.0 {
+                    "hash" => CollectionKind::Hash,
+                    "btree" => CollectionKind::BTree,
+                    "index" => CollectionKind::Index,
+                    value => panic!(
+                        "invalid value `{value}` for annotation `rust.collection`, expected \
+                         `hash`, `btree` or `index`"
+                    ),
+                })),
+                _ => {}
+            }
+        });
+        tags
+    }
+
     fn lower_struct(&mut self, s: &thrift_parser::StructLike) -> ir::Message {
         ir::Message {
             name: self.lower_ident(&s.name),
@@ -355,36 +571,70 @@ impl ThriftLower {
         }
     }
 
-    fn lower_include(&mut self, s: &thrift_parser::Include) -> ir::Use {
-        // add current file's dir to include dirs
-        let current_dir = self.cur_file.as_ref().unwrap().path.parent().unwrap();
-        let mut include_dirs = vec![current_dir.to_path_buf()];
-        include_dirs.extend_from_slice(&self.include_dirs);
+    fn lower_include(&mut self, s: &thrift_parser::Include) -> Result<ir::Use, ParseError> {
+        let cur_path = self.cur_file.as_ref().unwrap().path.clone();
+        let target_path = resolve_include(&cur_path, &s.path.0, &self.include_dirs)?;
 
-        // search for the first existing include path
-        let target_dir = include_dirs.into_iter().find(|p| {
-            let path = p.join(&s.path.0);
-            path.exists()
-        });
-        let target_path = match target_dir {
-            Some(dir) => dir.join(&s.path.0),
-            None => {
-                error_abort(format!("{}: include file not found", s.path.0));
-            }
-        };
+        let ast = self.db.parse(target_path)?;
+
+        let file_id = self.lower(ast)?;
+
+        Ok(ir::Use { file: file_id })
+    }
+}
+
+/// The path of the file included as `include` by the file at `cur_path`, searched in the dir
+/// of the file first and then in `include_dirs`.
+fn resolve_include(
+    cur_path: &std::path::Path,
+    include: &str,
+    include_dirs: &[PathBuf],
+) -> Result<PathBuf, ParseError> {
+    let dirs = cur_path
+        .parent()
+        .into_iter()
+        .map(std::path::Path::to_path_buf)
+        .chain(include_dirs.iter().cloned())
+        .collect::<Vec<_>>();
+
+    dirs.iter()
+        .map(|dir| dir.join(include))
+        .find(|path| path.exists())
+        .ok_or_else(|| include_not_found(cur_path, include, &dirs))
+}
 
-        let ast = self.db.parse(target_path);
+/// `deprecated = "true"` marks a definition as deprecated, a value other than `true` or
+/// `false` is the note of the deprecation.
+fn deprecated(value: &str) -> Option<Deprecated> {
+    match value {
+        "false" => None,
+        "true" | "" => Some(Deprecated(None)),
+        note => Some(Deprecated(Some(Arc::from(note)))),
+    }
+}
 
-        let file_id = self.lower(ast);
+fn deprecated_tags(annotations: Option<&thrift_parser::Annotations>) -> Tags {
+    let mut tags = Tags::default();
+    annotations
+        .into_iter()
+        .flat_map(|a| a.iter())
+        .filter(|a| a.key.segments.len() == 1 && &*a.key.segments[0].0 == "deprecated")
+        .filter_map(|a| deprecated(&a.value))
+        .for_each(|d| tags.insert(d));
+    tags
+}
 
-        ir::Use { file: file_id }
+/// Tags a definition with the lines of its doc comments, if any.
+fn insert_docs(tags: &mut Tags, docs: &[String]) {
+    if !docs.is_empty() {
+        tags.insert(Docs(docs.iter().map(|d| Arc::from(d.as_str())).collect()))
     }
 }
 
 impl Lower<Arc<thrift_parser::File>> for ThriftLower {
-    fn lower(&mut self, f: Arc<thrift_parser::File>) -> FileId {
+    fn lower(&mut self, f: Arc<thrift_parser::File>) -> Result<FileId, ParseError> {
         if let Some(file_id) = self.cached_files.get(&f.path) {
-            return *file_id;
+            return Ok(*file_id);
         }
 
         let file_id = self.next_file_id.inc_one();
@@ -395,7 +645,7 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
                 .includes
                 .iter()
                 .map(|i| {
-                    (
+                    Ok((
                         Arc::<str>::from(
                             i.path
                                 .0
@@ -404,16 +654,17 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
                                 .unwrap()
                                 .trim_end_matches(".thrift"),
                         ),
-                        this.lower_include(i),
-                    )
+                        this.lower_include(i)?,
+                    ))
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, ParseError>>()?;
 
             let includes = include_files
                 .iter()
                 .map(|(_, file)| Item {
                     kind: ir::ItemKind::Use(ir::Use { file: file.file }),
                     tags: Default::default(),
+                    span: None,
                 })
                 .collect::<Vec<_>>();
 
@@ -422,27 +673,44 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
                 .map(|(name, u)| (name.into(), u.file))
                 .collect::<FxHashMap<Symbol, FileId>>();
 
-            ir::File {
+            Ok(ir::File {
+                path: f.path.clone(),
                 package: f
                     .package
                     .as_ref()
                     .map(|p| this.lower_path(p))
                     .unwrap_or_else(|| Path {
                         segments: Arc::from([f.path.file_stem().unwrap().to_str().unwrap().into()]),
+                        span: None,
                     }),
                 items: f
                     .items
                     .iter()
-                    .flat_map(|i| this.lower_item(i))
+                    .enumerate()
+                    .flat_map(|(idx, i)| {
+                        let span = f
+                            .item_rest_lens
+                            .get(idx)
+                            .and_then(|rest_len| this.span(*rest_len));
+                        let mut items = this.lower_item(i);
+                        // the items generated for the methods of a service are not documented
+                        if let Some(docs) = f.item_docs.get(idx) {
+                            insert_docs(Arc::make_mut(&mut items[0].tags), docs);
+                        }
+                        items.into_iter().map(move |mut item| {
+                            item.span = span;
+                            item
+                        })
+                    })
                     .chain(includes)
                     .map(Arc::from)
                     .collect(),
                 id: file_id,
                 uses,
-            }
-        });
+            })
+        })?;
 
-        file.id
+        Ok(file.id)
     }
 
     fn finish(self) -> LowerResult {
@@ -468,18 +736,31 @@ impl super::Parser for ThriftParser {
         self.include_dirs = dirs;
     }
 
-    fn parse(self) -> super::ParseResult {
+    fn is_include_only(&self, path: &std::path::Path) -> bool {
+        self.db
+            .parse(path.to_path_buf())
+            .is_ok_and(|f| f.items.is_empty())
+    }
+
+    fn parse(self) -> Result<super::ParseResult, ParseError> {
+        super::check_include_cycles(&self.files, |path| {
+            self.db
+                .parse(path.to_path_buf())?
+                .includes
+                .iter()
+                .map(|i| resolve_include(path, &i.path.0, &self.include_dirs))
+                .collect()
+        })?;
+
         let mut lower = ThriftLower::new(self.db.snapshot(), self.include_dirs.clone());
 
-        self.files.iter().for_each(|f| {
+        self.files.iter().try_for_each(|f| {
             println!("cargo:rerun-if-changed={}", f.display());
-            lower.lower(self.db.parse(f.to_path_buf()));
-        });
+            lower.lower(self.db.parse(f.to_path_buf())?).map(|_| ())
+        })?;
 
         let result = lower.finish();
 
-        super::ParseResult {
-            files: result.files,
-        }
+        Ok(super::ParseResult::new(result.files))
     }
 }
@@ -0,0 +1,179 @@
+use std::{collections::HashSet, sync::Arc};
+
+use syn::{parse_quote, punctuated::Punctuated, Token};
+
+use super::PathCollector;
+use crate::{
+    db::RirDatabase,
+    rir::Item,
+    tags::{thrift::RustCollection, Derives, OrdById},
+    ty::{self, CollectionKind, Visitor},
+    Context, DefId,
+};
+
+/// The traits every generated type derives already.
+const BUILTIN_DERIVES: &[&str] = &["Clone", "PartialEq"];
+
+/// The traits which are not implemented for `f32` and `f64`.
+const FLOAT_UNSUPPORTED: &[&str] = &["Hash", "Eq", "Ord"];
+
+/// The traits which are not implemented for the hash and index maps and sets, only the
+/// `BTreeMap` and `BTreeSet` implement them.
+const UNORDERED_UNSUPPORTED: &[&str] = &["Hash", "PartialOrd", "Ord"];
+
+/// Derives extra traits for the messages, enums and newtypes selected by the predicate, which
+/// gets the id and the name of the type. The traits in the [`Derives`] tag of a type are
+/// derived too.
+pub struct DerivePlugin<F> {
+    derives: Vec<syn::Path>,
+    predicate: F,
+}
+
+impl<F> DerivePlugin<F>
+where
+    F: Fn(DefId, &str) -> bool,
+{
+    pub fn new(derives: &[&str], predicate: F) -> Self {
+        Self {
+            derives: derives.iter().map(|d| parse_derive(d)).collect(),
+            predicate,
+        }
+    }
+}
+
+fn parse_derive(derive: &str) -> syn::Path {
+    syn::parse_str(derive).unwrap_or_else(|_| panic!("invalid derive `{derive}`"))
+}
+
+fn trait_name(path: &syn::Path) -> String {
+    path.segments.last().unwrap().ident.to_string()
+}
+
+/// The names of the traits derived by the attributes already added to the type, including
+/// the ones derived through `derivative` and the ones implemented for the [`OrdById`] types.
+pub(crate) fn existing_derives(cx: &Context, def_id: DefId, item: &Item) -> HashSet<String> {
+    let copy = matches!(item, Item::Enum(e) if e.repr.is_some()).then(|| "Copy".to_string());
+    let ord = cx
+        .node_contains_tag::<OrdById>(def_id)
+        .then(|| ["PartialOrd".to_string(), "Ord".to_string()])
+        .into_iter()
+        .flatten();
+    cx.adjust(def_id)
+        .into_iter()
+        .flat_map(|adj| adj.attrs())
+        .filter(|attr| attr.path.is_ident("derive") || attr.path.is_ident("derivative"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .map(|path| trait_name(&path))
+        .chain(BUILTIN_DERIVES.iter().map(|d| d.to_string()))
+        .chain(copy)
+        .chain(ord)
+        .collect()
+}
+
+/// Why a field of type `ty` can not derive the trait named `derive`, if it can not. The maps
+/// and sets of the field are generated as `collection`, and the ones in their elements as
+/// `elements`.
+fn unsupported(
+    ty: &ty::Ty,
+    collection: CollectionKind,
+    elements: CollectionKind,
+    derive: &str,
+) -> Option<&'static str> {
+    let unordered = collection != CollectionKind::BTree && UNORDERED_UNSUPPORTED.contains(&derive);
+    match &ty.kind {
+        ty::F32 | ty::F64 if FLOAT_UNSUPPORTED.contains(&derive) => Some("a float field"),
+        ty::Map(..) if unordered => Some("a map field"),
+        ty::Set(_) if unordered => Some("a set field"),
+        ty::Map(k, v) => unsupported(k, elements, elements, derive)
+            .or_else(|| unsupported(v, elements, elements, derive)),
+        ty::Vec(el) | ty::Set(el) | ty::Arc(el) => unsupported(el, elements, elements, derive),
+        _ => None,
+    }
+}
+
+/// Returns the name of a type reachable from `def_id` with a field which can not derive the
+/// trait named `derive`, and why.
+fn find_unsupported(
+    cx: &Context,
+    def_id: DefId,
+    derive: &str,
+    visited: &mut HashSet<DefId>,
+) -> Option<(String, &'static str)> {
+    if !visited.insert(def_id) {
+        return None;
+    }
+    let item = cx.expect_item(def_id);
+    let tys = match &*item {
+        Item::Message(s) => s.fields.iter().map(|f| &f.ty).collect::<Vec<_>>(),
+        Item::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+        Item::NewType(t) => vec![&t.ty],
+        _ => return None,
+    };
+
+    // the collection of a field can be chosen by `rust.collection`, but not of its elements
+    let collection = |ty: &ty::Ty| {
+        cx.tags(ty.tags_id)
+            .and_then(|tags| tags.get::<RustCollection>().map(|c| c.0))
+            .unwrap_or_else(|| cx.collection_kind())
+    };
+    if let Some(reason) = tys
+        .iter()
+        .find_map(|ty| unsupported(ty, collection(ty), cx.collection_kind(), derive))
+    {
+        return Some((item.symbol_name().to_string(), reason));
+    }
+
+    let mut collector = PathCollector::default();
+    tys.iter().for_each(|ty| collector.visit(ty));
+    collector
+        .paths
+        .iter()
+        .find_map(|p| find_unsupported(cx, p.did, derive, visited))
+}
+
+impl<F> super::Plugin for DerivePlugin<F>
+where
+    F: Fn(DefId, &str) -> bool,
+{
+    fn on_item(&mut self, cx: &mut Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Message(_) | Item::Enum(_) | Item::NewType(_) = &*item {
+            let name = item.symbol_name();
+            let tagged = cx
+                .node_tags(def_id)
+                .get::<Derives>()
+                .map(|d| d.0.iter().map(|d| parse_derive(d)).collect::<Vec<_>>())
+                .unwrap_or_default();
+            let selected = if (self.predicate)(def_id, &name) {
+                self.derives.clone()
+            } else {
+                Vec::new()
+            };
+
+            let mut existing = existing_derives(cx, def_id, &item);
+            let derives = selected
+                .into_iter()
+                .chain(tagged)
+                .filter(|d| existing.insert(trait_name(d)))
+                .collect::<Vec<_>>();
+
+            derives.iter().map(trait_name).for_each(|d| {
+                if let Some((ty, reason)) =
+                    find_unsupported(cx, def_id, &d, &mut HashSet::default())
+                {
+                    panic!("can not derive `{d}` for `{name}`: `{ty}` has {reason}")
+                }
+            });
+
+            if !derives.is_empty() {
+                cx.with_adjust(def_id, |adj| {
+                    adj.add_attrs(&[parse_quote!(#[derive(#(#derives),*)])])
+                });
+            }
+        }
+        super::walk_item(self, cx, def_id, item)
+    }
+}
@@ -1,19 +1,25 @@
 use std::{collections::HashSet, ops::DerefMut, sync::Arc};
 
 use fxhash::FxHashMap;
-use syn::{parse_quote, Attribute};
+use syn::{parse_quote, punctuated::Punctuated, Attribute, Token};
 
 use crate::{
     db::RirDatabase,
-    rir::{Field, Item},
+    rir::{Enum, EnumVariant, Field, Item, Message, Node},
     symbol::DefId,
+    tags::{thrift::DefaultVariant, OrdById, Tags},
     ty::{self, Ty, Visitor},
     Context,
 };
 
+mod derive;
 mod serde;
+mod validator;
 
-pub use serde::SerdePlugin;
+pub(crate) use derive::existing_derives;
+pub use derive::DerivePlugin;
+pub use serde::{SerdeEnumRepr, SerdePlugin};
+pub use validator::ValidatorPlugin;
 
 pub trait Plugin {
     fn on_item(&mut self, cx: &mut Context, def_id: DefId, item: Arc<Item>) {
@@ -27,6 +33,22 @@ pub trait Plugin {
     fn on_emit(&mut self, _cx: &mut Context) {}
 }
 
+/// Runs over the resolved nodes before the types are generated, it can change the nodes and
+/// attach tags to them. The fields and variants are visited as their own nodes as well as
+/// inside the items owning them.
+pub trait NodePlugin {
+    fn on_node(&mut self, def_id: DefId, node: &mut Node, tags: &mut Tags);
+}
+
+impl<F> NodePlugin for F
+where
+    F: FnMut(DefId, &mut Node, &mut Tags),
+{
+    fn on_node(&mut self, def_id: DefId, node: &mut Node, tags: &mut Tags) {
+        self(def_id, node, tags)
+    }
+}
+
 pub trait ClonePlugin: Plugin {
     fn clone_box(&self) -> Box<dyn ClonePlugin>;
 }
@@ -104,6 +126,10 @@ pub fn walk_filed<P: Plugin + ?Sized>(
 ) {
 }
 
+/// Boxes the fields of a message whose type leads back to the message. The resolver already
+/// wraps the fields closing a cycle in an `Arc`, this handles the cycles it leaves: the ones
+/// closed by a typedef, such as `Tree` in `struct Tree { 1: optional Forest children }` with
+/// `typedef Tree Forest`, and the ones a `NodePlugin` creates.
 pub struct BoxedPlugin;
 
 impl Plugin for BoxedPlugin {
@@ -154,7 +180,7 @@ pub enum PredicateResult {
 
 #[derive(Default)]
 pub struct PathCollector {
-    paths: Vec<crate::rir::Path>,
+    pub(crate) paths: Vec<crate::rir::Path>,
 }
 
 impl super::ty::Visitor for PathCollector {
@@ -248,12 +274,37 @@ where
     fn on_emit(&mut self, cx: &mut Context) {
         self.can_derive.iter().for_each(|(def_id, can_derive)| {
             if !matches!(can_derive, CanDerive::No) {
-                cx.with_adjust(*def_id, |adj| adj.add_attrs(&self.attrs));
+                // the types ordered by field id implement `PartialOrd` and `Ord` themselves
+                let attrs = if cx.node_contains_tag::<OrdById>(*def_id) {
+                    without_ord(&self.attrs)
+                } else {
+                    self.attrs.clone()
+                };
+                cx.with_adjust(*def_id, |adj| adj.add_attrs(&attrs));
             }
         })
     }
 }
 
+/// Removes `PartialOrd` and `Ord` from the derive attributes, and the attributes left empty.
+fn without_ord(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter_map(|attr| {
+            if !attr.path.is_ident("derive") {
+                return Some(attr.clone());
+            }
+            let derives = attr
+                .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()?
+                .into_iter()
+                .filter(|d| !d.is_ident("PartialOrd") && !d.is_ident("Ord"))
+                .collect::<Vec<_>>();
+            (!derives.is_empty()).then(|| parse_quote!(#[derive(#(#derives),*)]))
+        })
+        .collect()
+}
+
 impl<T> Plugin for Box<T>
 where
     T: Plugin + ?Sized,
@@ -285,28 +336,63 @@ impl Plugin for WithAttrsPlugin {
     }
 }
 
+/// How `Default` is implemented for messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DefaultImpl {
+    /// Implement it by hand when any field has a default value in the IDL, derive it otherwise.
+    #[default]
+    Auto,
+    /// Always derive it, the default values in the IDL are ignored.
+    Derive,
+    /// Always implement it by hand with the default values in the IDL.
+    Manual,
+}
+
+impl DefaultImpl {
+    pub(crate) fn is_manual(self, s: &Message) -> bool {
+        match self {
+            DefaultImpl::Auto => s.fields.iter().any(|f| f.default.is_some()),
+            DefaultImpl::Derive => false,
+            DefaultImpl::Manual => true,
+        }
+    }
+}
+
+/// The variant tagged as the default one, or the first one.
+pub(crate) fn default_variant<'a>(cx: &Context, e: &'a Enum) -> Option<&'a Arc<EnumVariant>> {
+    e.variants
+        .iter()
+        .find(|v| cx.contains_tag::<DefaultVariant>(cx.node(v.did).unwrap().tags))
+        .or_else(|| e.variants.first())
+}
+
 pub struct ImplDefaultPlugin;
 
 impl Plugin for ImplDefaultPlugin {
     fn on_item(&mut self, cx: &mut Context, def_id: DefId, item: Arc<Item>) {
         match &*item {
+            // the manual `Default` is generated along with the message
+            Item::Message(s) if cx.default_impl().is_manual(s) => {}
             Item::Message(_) | Item::NewType(_) => cx.with_adjust(def_id, |adj| {
                 adj.add_attrs(&[parse_quote!(#[derive(Default)])])
             }),
-            Item::Enum(e) => {
-                cx.with_adjust(def_id, |adj| {
-                    adj.add_attrs(&[
-                        parse_quote!(#[derive(::pilota::derivative::Derivative)]),
-                        parse_quote!(#[derivative(Default)]),
-                    ]);
-                });
-
-                if let Some(v) = e.variants.first() {
+            Item::Enum(e) => match default_variant(cx, e) {
+                // the `Default` with the default value is generated along with the union
+                Some(v) if v.default.is_some() => {}
+                Some(v) => {
+                    cx.with_adjust(def_id, |adj| {
+                        adj.add_attrs(&[
+                            parse_quote!(#[derive(::pilota::derivative::Derivative)]),
+                            parse_quote!(#[derivative(Default)]),
+                        ]);
+                    });
                     cx.with_adjust(v.did, |adj| {
                         adj.add_attrs(&[parse_quote!(#[derivative(Default)])]);
                     })
                 }
-            }
+                // an empty union has no value at all
+                None => {}
+            },
             _ => {}
         }
         walk_item(self, cx, def_id, item)
@@ -318,11 +404,11 @@ pub struct EnumNumPlugin;
 impl Plugin for EnumNumPlugin {
     fn on_item(&mut self, cx: &mut Context, def_id: DefId, item: Arc<Item>) {
         match &*item {
-            Item::Enum(e) if e.repr.is_some() =>  {
+            Item::Enum(e) if e.repr.is_some() && !cx.is_open_enum(e) => {
                 cx.with_adjust(def_id, |adj| {
                     adj.add_attrs(&[parse_quote!(#[derive(:: pilota :: num_enum :: IntoPrimitive, :: pilota :: num_enum :: TryFromPrimitive)])])
                 })
-            },
+            }
             _ => {}
         }
         walk_item(self, cx, def_id, item)
@@ -1,5 +1,17 @@
 use syn::parse_quote;
 
+use crate::{db::RirDatabase, rir::Item, tags::thrift::Internal, ty::TyKind, Context};
+
+/// How serde represents the variants of enums which have discriminants.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SerdeEnumRepr {
+    /// The name of the variant in the IDL.
+    #[default]
+    Name,
+    /// The discriminant of the variant as an `i32`.
+    Discriminant,
+}
+
 #[derive(Clone, Copy)]
 pub struct SerdePlugin;
 
@@ -18,6 +30,60 @@ impl crate::Plugin for SerdePlugin {
             }),
             _ => {}
         };
+        if let crate::rir::Item::Enum(e) = &*item {
+            if e.repr.is_some() && cx.serde_enum_repr() == SerdeEnumRepr::Discriminant {
+                cx.with_adjust(def_id, |adj| {
+                    adj.add_attrs(&[parse_quote!(#[serde(into = "i32", try_from = "i32")])])
+                })
+            } else {
+                e.variants.iter().for_each(|v| {
+                    let name = v.name.to_variant_case(cx.naming());
+                    if **name != *v.original_name || name.has_keyword_suffix() {
+                        let name = &*v.original_name;
+                        cx.with_adjust(v.did, |adj| {
+                            adj.add_attrs(&[parse_quote!(#[serde(rename = #name)])])
+                        })
+                    }
+                })
+            }
+        }
         crate::plugin::walk_item(self, cx, def_id, item)
     }
+
+    fn on_field(
+        &mut self,
+        cx: &mut crate::Context,
+        def_id: crate::DefId,
+        f: std::sync::Arc<crate::rir::Field>,
+    ) {
+        if f.name.sym != f.original_name || f.name.has_keyword_suffix() {
+            let name = &*f.original_name;
+            cx.with_adjust(def_id, |adj| {
+                adj.add_attrs(&[parse_quote!(#[serde(rename = #name)])])
+            })
+        }
+        if cx.node_contains_tag::<Internal>(def_id) {
+            if !f.is_optional() && !is_default(cx, &f.ty.kind) {
+                panic!(
+                    "internal field `{}` is skipped by serde, so its type must implement `Default`",
+                    f.name
+                )
+            }
+            cx.with_adjust(def_id, |adj| adj.add_attrs(&[parse_quote!(#[serde(skip)])]))
+        }
+        crate::plugin::walk_filed(self, cx, def_id, f)
+    }
+}
+
+fn is_default(cx: &Context, ty: &TyKind) -> bool {
+    match ty {
+        TyKind::Arc(inner) => is_default(cx, &inner.kind),
+        TyKind::Path(p) => match &*cx.expect_item(p.did) {
+            // the default of an enum is one of its variants
+            Item::Enum(e) => !e.variants.is_empty(),
+            Item::NewType(t) => is_default(cx, &t.ty.kind),
+            _ => true,
+        },
+        _ => true,
+    }
 }
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use quote::quote;
+use syn::parse_quote;
+
+use crate::{
+    rir::{Field, Item},
+    tags::validate::{Max, Min, NonEmpty},
+    ty::TyKind,
+    Context, DefId,
+};
+
+/// Derives `validator::Validate` for the messages which have constraint annotations, the
+/// annotations are translated into `#[validate(length(..))]` or `#[validate(range(..))]`.
+#[derive(Clone, Copy)]
+pub struct ValidatorPlugin;
+
+fn is_constrained(cx: &Context, def_id: DefId) -> bool {
+    let tags = cx.node_tags(def_id);
+    tags.contains::<Min>() || tags.contains::<Max>() || tags.contains::<NonEmpty>()
+}
+
+impl crate::Plugin for ValidatorPlugin {
+    fn on_item(&mut self, cx: &mut Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Message(s) = &*item {
+            if s.fields.iter().any(|f| is_constrained(cx, f.did)) {
+                cx.with_adjust(def_id, |adj| {
+                    adj.add_attrs(&[parse_quote!(#[derive(::validator::Validate)])])
+                })
+            }
+        }
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+
+    fn on_field(&mut self, cx: &mut Context, def_id: DefId, f: Arc<Field>) {
+        let tags = cx.node_tags(def_id);
+        let min = tags.get::<Min>().map(|min| min.0);
+        let max = tags.get::<Max>().map(|max| max.0);
+        let non_empty = tags.contains::<NonEmpty>();
+
+        let args = |min: Option<i64>| {
+            let min = min.map(|min| {
+                let min = proc_macro2::Literal::i64_unsuffixed(min);
+                quote!(min = #min)
+            });
+            let max = max.map(|max| {
+                let max = proc_macro2::Literal::i64_unsuffixed(max);
+                quote!(max = #max)
+            });
+            min.into_iter().chain(max).collect::<Vec<_>>()
+        };
+
+        let attr = match &f.ty.kind {
+            _ if min.is_none() && max.is_none() && !non_empty => None,
+            TyKind::String | TyKind::Vec(_) | TyKind::Set(_) | TyKind::Map(..) => {
+                let min = if non_empty {
+                    Some(min.unwrap_or(1).max(1))
+                } else {
+                    min
+                };
+                let args = args(min);
+                Some(parse_quote!(#[validate(length(#(#args),*))]))
+            }
+            TyKind::I8
+            | TyKind::I16
+            | TyKind::I32
+            | TyKind::I64
+            | TyKind::UInt32
+            | TyKind::UInt64
+            | TyKind::U8
+                if !non_empty =>
+            {
+                let args = args(min);
+                Some(parse_quote!(#[validate(range(#(#args),*))]))
+            }
+            _ => {
+                tracing::warn!(
+                    "constraint annotations on field {} can not be translated for validator",
+                    f.name
+                );
+                None
+            }
+        };
+
+        if let Some(attr) = attr {
+            cx.with_adjust(def_id, |adj| adj.add_attrs(&[attr]))
+        }
+        crate::plugin::walk_filed(self, cx, def_id, f)
+    }
+}
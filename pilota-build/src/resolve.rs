@@ -1,6 +1,7 @@
-use std::{ptr::NonNull, sync::Arc};
+use std::{cell::RefCell, fmt, path::PathBuf, ptr::NonNull, sync::Arc};
 
-use fxhash::FxHashMap;
+use fxhash::{FxHashMap, FxHashSet};
+use petgraph::{algo::tarjan_scc, graph::NodeIndex, visit::EdgeRef, Graph};
 
 use crate::{
     index::Idx,
@@ -11,11 +12,16 @@ use crate::{
             Arg, Const, DefKind, Enum, EnumVariant, Field, FieldKind, File, Item, ItemPath,
             Literal, Message, Method, MethodSource, NewType, Node, NodeKind, Path, Service,
         },
-        ty::{self, Ty},
+        ty::{self, Ty, Visitor as _},
     },
+    plugin::PathCollector,
     rir::Mod,
-    symbol::{DefId, FileId, Symbol},
-    tags::{TagId, Tags},
+    symbol::{DefId, EnumRepr, FileId, Ident, Naming, Span, Symbol, TypeNameAffixes},
+    tags::{
+        protobuf::{MessageMod, OneOf},
+        thrift::{EmptyByDefault, MethodArgs, RustType},
+        ArcField, Deprecated, TagId, Tags,
+    },
 };
 
 #[derive(Default)]
@@ -38,7 +44,7 @@ impl ModuleId {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Namespace {
     Value,
     Ty,
@@ -47,6 +53,10 @@ enum Namespace {
 pub struct CollectDef<'a> {
     resolver: &'a mut Resolver,
     parent: Option<ModuleId>,
+    file: Option<FileId>,
+    // the names of the items enclosing the current one in its file
+    path: Vec<Symbol>,
+    def_spans: FxHashMap<DefId, Option<Span>>,
 }
 
 impl<'a> CollectDef<'a> {
@@ -54,39 +64,52 @@ impl<'a> CollectDef<'a> {
         CollectDef {
             resolver,
             parent: None,
+            file: None,
+            path: Default::default(),
+            def_spans: Default::default(),
         }
     }
 }
 
 impl CollectDef<'_> {
-    fn def_item(&mut self, item: &ir::Item, ns: Namespace) -> DefId {
-        let parent = self.parent.as_ref().unwrap();
-        let did = self.resolver.did_counter.inc_one();
-        let table = match parent {
-            ModuleId::File(file_id) => self.resolver.file_sym_map.entry(*file_id).or_default(),
+    fn parent_table<'r>(resolver: &'r mut Resolver, parent: &ModuleId) -> &'r mut SymbolTable {
+        match parent {
+            ModuleId::File(file_id) => resolver.file_sym_map.entry(*file_id).or_default(),
             ModuleId::Node(def_id) => {
-                &mut self
-                    .resolver
-                    .def_modules
-                    .entry(*def_id)
-                    .or_default()
-                    .resolutions
+                &mut resolver.def_modules.entry(*def_id).or_default().resolutions
             }
-        };
+        }
+    }
+
+    fn def_item(&mut self, item: &ir::Item, ns: Namespace) -> DefId {
+        let did = self.resolver.did_counter.inc_one();
+        let table = Self::parent_table(self.resolver, self.parent.as_ref().unwrap());
 
         let name = item.name();
 
         tracing::debug!("def {} with DefId({:?})", name, did);
 
-        if match ns {
-            Namespace::Value => table.value.insert(name.clone(), did),
-            Namespace::Ty => table.ty.insert(name.clone(), did),
-        }
-        .is_some()
-        {
-            tracing::error!("{} is already defined", name);
+        let defs = match ns {
+            Namespace::Value => &mut table.value,
+            Namespace::Ty => &mut table.ty,
         };
 
+        match defs.get(&name) {
+            Some(prev) => {
+                let prev_span = self.def_spans[prev];
+                let path = self.path.iter().cloned().chain([name]).collect::<Vec<_>>();
+                self.resolver
+                    .duplicate_defs
+                    .entry((self.file.unwrap(), ns, path))
+                    .or_insert_with(|| vec![prev_span])
+                    .push(item.span);
+            }
+            None => {
+                defs.insert(name, did);
+            }
+        }
+        self.def_spans.insert(did, item.span);
+
         did
     }
 }
@@ -94,8 +117,10 @@ impl CollectDef<'_> {
 impl ir::visit::Visitor for CollectDef<'_> {
     fn visit_file(&mut self, file: Arc<ir::File>) {
         self.parent = Some(ModuleId::File(file.id));
+        self.file = Some(file.id);
         ir::visit::walk_file(self, file);
         self.parent = None;
+        self.file = None;
     }
 
     fn visit_item(&mut self, item: Arc<ir::Item>) {
@@ -108,9 +133,39 @@ impl ir::visit::Visitor for CollectDef<'_> {
             ir::ItemKind::Mod(_) => Some(self.def_item(&item, Namespace::Ty)),
             ir::ItemKind::Use(_) => None,
         } {
+            // variants are defined along with their enum, so that paths to them resolve
+            // no matter in which order the files and items are lowered
+            if let ir::ItemKind::Enum(e) = &item.kind {
+                e.variants.iter().for_each(|v| {
+                    let variant_did = self.resolver.did_counter.inc_one();
+                    self.resolver
+                        .def_modules
+                        .entry(did)
+                        .or_default()
+                        .resolutions
+                        .value
+                        .insert(v.name.sym.clone(), variant_did);
+                });
+            }
+
             let prev_parent = self.parent.replace(ModuleId::Node(did));
-            ir::visit::walk_item(self, item);
+            self.path.push(item.name());
+            ir::visit::walk_item(self, item.clone());
+            self.path.pop();
             self.parent = prev_parent;
+
+            if let Some(MessageMod(name)) = item.tags.get::<MessageMod>() {
+                let message = self
+                    .resolver
+                    .def_modules
+                    .get(&did)
+                    .and_then(|m| m.resolutions.ty.get(name).copied());
+                if let Some(message) = message {
+                    Self::parent_table(self.resolver, self.parent.as_ref().unwrap())
+                        .message_mods
+                        .insert(name.clone(), message);
+                }
+            }
         }
     }
 }
@@ -119,6 +174,25 @@ impl ir::visit::Visitor for CollectDef<'_> {
 pub struct SymbolTable {
     pub(crate) value: FxHashMap<Symbol, DefId>,
     pub(crate) ty: FxHashMap<Symbol, DefId>,
+    /// The messages moved into the modules of this table which are named after them.
+    pub(crate) message_mods: FxHashMap<Symbol, DefId>,
+}
+
+impl SymbolTable {
+    fn namespace(&self, ns: Namespace) -> &FxHashMap<Symbol, DefId> {
+        match ns {
+            Namespace::Value => &self.value,
+            Namespace::Ty => &self.ty,
+        }
+    }
+
+    /// The definition named `sym`, or the message moved into the module named after it.
+    fn get_exact(&self, ns: Namespace, sym: &Symbol) -> Option<&DefId> {
+        self.namespace(ns).get(sym).or_else(|| match ns {
+            Namespace::Value => None,
+            Namespace::Ty => self.message_mods.get(sym),
+        })
+    }
 }
 
 pub struct Resolver {
@@ -133,6 +207,15 @@ pub struct Resolver {
     tags: FxHashMap<TagId, Arc<Tags>>,
     cur_file: Option<FileId>,
     ir_files: FxHashMap<FileId, Arc<ir::File>>,
+    errors: RefCell<Vec<ResolveError>>,
+    // keyed by the path of the items in their file, in which they are defined multiple times
+    duplicate_defs: FxHashMap<(FileId, Namespace, Vec<Symbol>), Vec<Option<Span>>>,
+    naming: Naming,
+    type_name_affixes: TypeNameAffixes,
+    transparent_typedefs: bool,
+    empty_optional_collections: bool,
+    disambiguate_names: bool,
+    strict_resolution: bool,
 }
 
 impl Default for Resolver {
@@ -148,83 +231,328 @@ impl Default for Resolver {
             ir_files: Default::default(),
             cur_file: None,
             parent_node: None,
+            errors: Default::default(),
+            duplicate_defs: Default::default(),
+            naming: Default::default(),
+            type_name_affixes: Default::default(),
+            transparent_typedefs: false,
+            empty_optional_collections: false,
+            disambiguate_names: false,
+            strict_resolution: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolveError {
+    pub path: Arc<PathBuf>,
+    pub span: Option<Span>,
+    pub message: String,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span {
+            Some(span) => write!(f, "{}:{}: {}", self.path.display(), span, self.message),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
         }
     }
 }
 
+impl std::error::Error for ResolveError {}
+
+#[derive(Clone)]
 pub struct ResolveResult {
     pub files: FxHashMap<FileId, Arc<File>>,
     pub nodes: FxHashMap<DefId, Node>,
     pub tags: FxHashMap<TagId, Arc<Tags>>,
 }
 
+impl ResolveResult {
+    /// Returns the items whose types refer to `did`, including the services whose methods
+    /// take or return it and the consts of its type.
+    pub fn references_to(&self, did: DefId) -> Vec<DefId> {
+        let mut referrers = referrers(&self.nodes).remove(&did).unwrap_or_default();
+        referrers.sort();
+        referrers.dedup();
+        referrers
+    }
+}
+
 impl Resolver {
+    pub(crate) fn with_naming(mut self, naming: Naming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    pub(crate) fn with_type_name_affixes(mut self, type_name_affixes: TypeNameAffixes) -> Self {
+        self.type_name_affixes = type_name_affixes;
+        self
+    }
+
+    pub(crate) fn with_transparent_typedefs(mut self, transparent_typedefs: bool) -> Self {
+        self.transparent_typedefs = transparent_typedefs;
+        self
+    }
+
+    pub(crate) fn with_empty_optional_collections(
+        mut self,
+        empty_optional_collections: bool,
+    ) -> Self {
+        self.empty_optional_collections = empty_optional_collections;
+        self
+    }
+
+    pub(crate) fn with_disambiguate_names(mut self, disambiguate_names: bool) -> Self {
+        self.disambiguate_names = disambiguate_names;
+        self
+    }
+
+    pub(crate) fn with_strict_resolution(mut self, strict_resolution: bool) -> Self {
+        self.strict_resolution = strict_resolution;
+        self
+    }
+
     fn resolve_sym(&self, ns: Namespace, sym: Symbol) -> Option<ModuleId> {
-        let def_id = self
+        if self.strict_resolution {
+            return self
+                .blocks
+                .iter()
+                .rev()
+                .map(|b| unsafe { b.as_ref() })
+                .find_map(|t| t.get_exact(ns, &sym))
+                .copied()
+                .map(ModuleId::Node)
+                .or_else(|| self.resolve_use(&sym));
+        }
+
+        let mut blocks = self
             .blocks
             .iter()
             .rev()
-            .find_map(|b| {
-                let b = unsafe { b.as_ref() };
-                match ns {
-                    Namespace::Value => b.value.get(&sym),
-                    Namespace::Ty => b.ty.get(&sym),
-                }
-                .or_else(|| {
-                    // fuzzy find for protobuf
-                    match ns {
-                        Namespace::Value => b.value.get(&sym.to_snake_case()).and_then(|def_id| {
-                            self.def_modules[def_id].resolutions.value.get(&sym)
-                        }),
-                        Namespace::Ty => b
-                            .ty
-                            .get(&sym.to_snake_case())
-                            .and_then(|def_id| self.def_modules[def_id].resolutions.ty.get(&sym)),
-                    }
+            .map(|b| unsafe { b.as_ref() }.namespace(ns));
+
+        // the symbol is converted for the fuzzy lookup once, and only when the innermost block
+        // has no exact match
+        let innermost = blocks.next();
+        let def_id = match innermost.and_then(|t| t.get(&sym)) {
+            Some(def_id) => Some(def_id),
+            None => {
+                let snake_sym = sym.to_snake_case(&self.naming);
+                innermost.into_iter().chain(blocks).find_map(|t| {
+                    t.get(&sym).or_else(|| {
+                        // fuzzy find for protobuf
+                        t.get(&snake_sym).and_then(|def_id| {
+                            self.def_modules
+                                .get(def_id)?
+                                .resolutions
+                                .namespace(ns)
+                                .get(&sym)
+                        })
+                    })
                 })
-            })
-            .copied();
+            }
+        }
+        .copied();
 
-        def_id.map(ModuleId::Node).or_else(|| {
-            let cur_file = self.ir_files.get(self.cur_file.as_ref().unwrap()).unwrap();
-            cur_file.uses.get(&sym).map(|id| ModuleId::File(*id))
-        })
+        def_id
+            .map(ModuleId::Node)
+            .or_else(|| self.resolve_use(&sym))
+    }
+
+    fn resolve_use(&self, sym: &Symbol) -> Option<ModuleId> {
+        let cur_file = self.ir_files.get(self.cur_file.as_ref().unwrap()).unwrap();
+        cur_file.uses.get(sym).map(|id| ModuleId::File(*id))
     }
 
-    pub fn resolve_files(mut self, files: &[Arc<ir::File>]) -> ResolveResult {
+    pub fn resolve_files(
+        mut self,
+        files: &[Arc<ir::File>],
+    ) -> Result<ResolveResult, Vec<ResolveError>> {
         files.iter().for_each(|f| {
             let mut collect = CollectDef::new(&mut self);
             collect.visit_file(f.clone());
             self.ir_files.insert(f.id, f.clone());
         });
 
+        if !self.duplicate_defs.is_empty() {
+            return Err(self.duplicate_def_errors());
+        }
+
         let files = files
             .iter()
             .map(|f| (f.id, Arc::from(self.lower_file(f))))
             .collect::<FxHashMap<_, _>>();
 
-        ResolveResult {
+        let errors = self.errors.take();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        orphaned_nested_items(&self.nodes, &self.type_name_affixes, &self.naming)
+            .into_iter()
+            .for_each(|def_id| {
+                tracing::warn!(
+                    "nested item {} is never referenced",
+                    self.nodes[&def_id].expect_item().symbol_name()
+                )
+            });
+
+        self.inherit_methods();
+
+        if self.transparent_typedefs {
+            unwrap_typedefs(&mut self.nodes);
+        }
+
+        break_recursive_cycles(&mut self.nodes, &self.tags);
+
+        Ok(ResolveResult {
             tags: self.tags,
             files,
             nodes: self.nodes,
+        })
+    }
+
+    /// Gives the methods a service inherits through `extends` nodes of their own, whose tags
+    /// are merged by `method_tags` like the ones of the own methods of the service. They come
+    /// before the own methods, in the order of the bases.
+    fn inherit_methods(&mut self) {
+        let mut services = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| {
+                matches!(&node.kind, NodeKind::Item(item) if matches!(&**item, Item::Service(_)))
+            })
+            .map(|(def_id, _)| *def_id)
+            .collect::<Vec<_>>();
+        services.sort();
+
+        let mut done = FxHashSet::default();
+        services
+            .into_iter()
+            .for_each(|def_id| self.inherit_service_methods(def_id, &mut done));
+    }
+
+    fn inherit_service_methods(&mut self, def_id: DefId, done: &mut FxHashSet<DefId>) {
+        if !done.insert(def_id) {
+            return;
+        }
+        let node = &self.nodes[&def_id];
+        let NodeKind::Item(item) = &node.kind else {
+            return;
+        };
+        let Item::Service(service) = &**item else {
+            return;
+        };
+        if service.extend.is_empty() {
+            return;
+        }
+        let service = service.clone();
+        let service_tags = self.tags[&node.tags].clone();
+        let file_id = node.file_id;
+
+        let mut methods = Vec::new();
+        for base in &service.extend {
+            // the bases get their inherited methods first, so they are passed on as well
+            self.inherit_service_methods(base.did, done);
+            let NodeKind::Item(item) = &self.nodes[&base.did].kind else {
+                continue;
+            };
+            let Item::Service(base_service) = &**item else {
+                continue;
+            };
+            for m in base_service.methods.clone() {
+                let did = self.did_counter.inc_one();
+                let tags_id = self.tags_id_counter.inc_one();
+                let tags = method_tags(&self.tags[&self.nodes[&m.def_id].tags], &service_tags);
+                self.tags.insert(tags_id, tags);
+
+                let method = Arc::new(Method {
+                    def_id: did,
+                    source: match m.source {
+                        MethodSource::Extend(source) => MethodSource::Extend(source),
+                        MethodSource::Own => MethodSource::Extend(base.did),
+                    },
+                    ..(*m).clone()
+                });
+                self.nodes.insert(
+                    did,
+                    Node {
+                        tags: tags_id,
+                        parent: Some(def_id),
+                        file_id,
+                        kind: NodeKind::Method(method.clone()),
+                    },
+                );
+                methods.push(method);
+            }
         }
+        methods.extend(service.methods.iter().cloned());
+
+        let NodeKind::Item(item) = &mut self.nodes.get_mut(&def_id).unwrap().kind else {
+            unreachable!()
+        };
+        *item = Arc::new(Item::Service(Service { methods, ..service }));
+    }
+
+    fn duplicate_def_errors(&self) -> Vec<ResolveError> {
+        let mut errors = self
+            .duplicate_defs
+            .iter()
+            .map(|((file_id, _, path), spans)| {
+                let sites = spans
+                    .iter()
+                    .map(|span| match span {
+                        Some(span) => span.to_string(),
+                        None => "<unknown>".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                ResolveError {
+                    path: self.ir_files[file_id].path.clone(),
+                    span: spans[1],
+                    message: format!(
+                        "`{}` is defined multiple times, at {sites}",
+                        path.iter().map(|s| &**s).collect::<Vec<_>>().join(".")
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+        errors.sort_by(|a, b| (&a.path, a.span).cmp(&(&b.path, b.span)));
+        errors
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(name = &**f.name))]
-    fn lower_field(&mut self, f: &ir::Field) -> Arc<Field> {
+    fn lower_field(&mut self, f: &ir::Field, name: Symbol) -> Arc<Field> {
         tracing::info!("lower filed {}, ty: {:?}", f.name, f.ty.kind);
         let did = self.did_counter.inc_one();
         let tag_id = self.tags_id_counter.inc_one();
-        self.tags.insert(tag_id, f.tags.clone());
+        let empty_by_default = self.empty_optional_collections
+            && matches!(f.kind, ir::FieldKind::Optional)
+            && matches!(
+                f.ty.kind,
+                ir::TyKind::Vec(_) | ir::TyKind::Set(_) | ir::TyKind::Map(_, _)
+            );
+        if empty_by_default {
+            let mut tags = (*f.tags).clone();
+            tags.insert(EmptyByDefault);
+            self.tags.insert(tag_id, Arc::new(tags));
+        } else {
+            self.tags.insert(tag_id, f.tags.clone());
+        }
         let f = Arc::from(Field {
             did,
             id: f.id,
             kind: match f.kind {
                 ir::FieldKind::Required => FieldKind::Required,
+                ir::FieldKind::Optional if empty_by_default => FieldKind::Required,
                 ir::FieldKind::Optional => FieldKind::Optional,
+                ir::FieldKind::Implicit => FieldKind::Implicit,
             },
-            name: f.name.to_snake_case(),
+            name: Ident::new(name),
+            original_name: f.name.sym.clone(),
             ty: self.lower_type(&f.ty),
+            default: f.default.as_ref().map(|l| self.lower_lit(l)),
         });
 
         self.nodes
@@ -253,6 +581,9 @@ impl Resolver {
             ir::TyKind::I16 => ty::I16,
             ir::TyKind::I32 => ty::I32,
             ir::TyKind::I64 => ty::I64,
+            ir::TyKind::UInt32 => ty::UInt32,
+            ir::TyKind::UInt64 => ty::UInt64,
+            ir::TyKind::F32 => ty::F32,
             ir::TyKind::F64 => ty::F64,
             ir::TyKind::Vec(ty) => ty::Vec(Arc::from(self.lower_type(ty))),
             ir::TyKind::Set(ty) => ty::Set(Arc::from(self.lower_type(ty))),
@@ -260,9 +591,6 @@ impl Resolver {
                 ty::Map(Arc::from(self.lower_type(k)), Arc::from(self.lower_type(v)))
             }
             ir::TyKind::Path(p) => ty::Path(self.lower_path(p, Namespace::Ty)),
-            ir::TyKind::UInt64 => todo!(),
-            ir::TyKind::UInt32 => todo!(),
-            ir::TyKind::F32 => todo!(),
         };
         let tags_id = self.tags_id_counter.inc_one();
 
@@ -272,79 +600,219 @@ impl Resolver {
     }
 
     fn lower_path(&self, p: &ir::Path, ns: Namespace) -> Path {
+        self.try_lower_path(p, ns).unwrap_or_else(|message| {
+            let file = &self.ir_files[self.cur_file.as_ref().unwrap()];
+            self.errors.borrow_mut().push(ResolveError {
+                path: file.path.clone(),
+                span: p.span,
+                message,
+            });
+            // `resolve_files` fails when there is any error, so the path is never used
+            Path {
+                kind: DefKind::Type,
+                did: DefId::from_usize(0),
+            }
+        })
+    }
+
+    fn try_lower_path(&self, p: &ir::Path, ns: Namespace) -> Result<Path, String> {
         let mut module_id = match ns {
             Namespace::Value => &[Namespace::Value, Namespace::Ty] as &[_],
             Namespace::Ty => &[Namespace::Ty],
         }
         .iter()
         .find_map(|ns| self.resolve_sym(*ns, p.segments[0].sym.clone()))
-        .unwrap_or_else(|| panic!("undefined ident {}", p.segments[0].sym));
+        .ok_or_else(|| format!("undefined ident {}", p.segments[0].sym))?;
 
-        p.segments[1..].iter().for_each(|ident| {
+        for (idx, ident) in p.segments.iter().enumerate().skip(1) {
             module_id = match module_id {
                 ModuleId::File(file_id) => {
                     let file = self.ir_files.get(&file_id).unwrap();
                     let table = self.file_sym_map.get(&file_id).unwrap();
-                    let table = match ns {
-                        Namespace::Value => &table.value,
-                        Namespace::Ty => &table.ty,
+                    let def_id = match ns {
+                        // values like `b.Kind.X` are reached through the type `Kind`
+                        Namespace::Value if idx + 1 < p.segments.len() => table.ty.get(ident),
+                        Namespace::Value => table.value.get(ident),
+                        Namespace::Ty => table.ty.get(ident),
                     };
-                    ModuleId::Node(*table.get(ident).unwrap_or_else(|| {
-                        panic!("can not find {} in file {:?}", ident, file.package)
-                    }))
+                    ModuleId::Node(*def_id.ok_or_else(|| {
+                        format!("can not find {} in file {}", ident, file.package)
+                    })?)
                 }
-                ModuleId::Node(def_id) => match &self.nodes[&def_id].kind {
-                    NodeKind::Item(item) => match &**item {
-                        Item::Enum(e) => ModuleId::Node(
-                            e.variants.iter().find(|v| &v.name == ident).unwrap().did,
-                        ),
-                        Item::Mod(_) => {
-                            let table = match ns {
-                                Namespace::Value => &self.def_modules[&def_id].resolutions.value,
-                                Namespace::Ty => &self.def_modules[&def_id].resolutions.ty,
-                            };
-
-                            ModuleId::Node(
-                                *table
-                                    .get(ident)
-                                    .unwrap_or_else(|| panic!("can not find {}", ident)),
-                            )
+                // the mod or enum may not be lowered yet, so look up its symbol table directly
+                ModuleId::Node(def_id) if self.def_modules.contains_key(&def_id) => {
+                    let table = &self.def_modules[&def_id].resolutions;
+                    fn get<'a>(
+                        table: &'a SymbolTable,
+                        ns: Namespace,
+                        sym: &Symbol,
+                    ) -> Option<&'a DefId> {
+                        match ns {
+                            Namespace::Value => table.value.get(sym),
+                            Namespace::Ty => table.ty.get(sym),
                         }
-                        _ => panic!("invalid item"),
-                    },
-                    _ => panic!("invalid node"),
-                },
+                    }
+
+                    let def_id = if self.strict_resolution {
+                        table.get_exact(ns, ident)
+                    } else {
+                        get(table, ns, ident).or_else(|| {
+                            // fuzzy find for protobuf
+                            table
+                                .ty
+                                .get(&ident.to_snake_case(&self.naming))
+                                .and_then(|def_id| {
+                                    get(&self.def_modules.get(def_id)?.resolutions, ns, ident)
+                                })
+                        })
+                    };
+                    ModuleId::Node(
+                        *def_id.ok_or_else(|| format!("can not find {} in {}", ident, p))?,
+                    )
+                }
+                ModuleId::Node(_) => return Err(format!("invalid path {}", p)),
             }
-        });
+        }
 
         let (kind, did) = match module_id {
-            ModuleId::File(_) => panic!(""),
+            ModuleId::File(_) => return Err(format!("{} is a file rather than an item", p)),
             ModuleId::Node(def_id) => match ns {
                 Namespace::Value => (DefKind::Value, def_id),
                 Namespace::Ty => (DefKind::Type, def_id),
             },
         };
 
-        Path { kind, did }
+        Ok(Path { kind, did })
     }
 
     #[tracing::instrument(level = "debug", skip(self, s), fields(name = &**s.name))]
-    fn lower_message(&mut self, s: &ir::Message) -> Message {
+    fn lower_message(&mut self, s: &ir::Message, span: Option<Span>) -> Message {
+        let names = self.dedup_names(
+            &format!(" of `{}`", s.name),
+            s.fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.name.sym.clone(),
+                        f.name.to_field_case(&self.naming).sym,
+                        span,
+                    )
+                })
+                .collect(),
+            |name, n| format!("{name}_{n}"),
+        );
         Message {
-            name: s.name.clone(),
-            fields: s.fields.iter().map(|f| self.lower_field(f)).collect(),
+            name: self.type_name_affixes.apply(&s.name, &self.naming),
+            fields: s
+                .fields
+                .iter()
+                .zip(names)
+                .map(|(f, name)| self.lower_field(f, name))
+                .collect(),
+        }
+    }
+
+    /// The names colliding with an earlier one once converted to the Rust case get the smallest
+    /// free suffix from 2 under `disambiguate_names`, such as `foo_bar_2`, and are reported
+    /// otherwise. `names` are the IDL name, the Rust name and the span of every definition.
+    fn dedup_names(
+        &self,
+        owner: &str,
+        names: Vec<(Symbol, Symbol, Option<Span>)>,
+        suffix: impl Fn(&str, usize) -> String,
+    ) -> Vec<Symbol> {
+        let mut taken = names
+            .iter()
+            .map(|(_, name, _)| name.clone())
+            .collect::<FxHashSet<_>>();
+        let mut seen = FxHashMap::default();
+        names
+            .into_iter()
+            .map(|(idl_name, name, span)| match seen.get(&name) {
+                None => {
+                    seen.insert(name.clone(), idl_name);
+                    name
+                }
+                Some(_) if self.disambiguate_names => {
+                    let name = (2..)
+                        .map(|n| Symbol::from(suffix(&name, n)))
+                        .find(|name| !taken.contains(name))
+                        .unwrap();
+                    taken.insert(name.clone());
+                    name
+                }
+                Some(prev) => {
+                    self.errors.borrow_mut().push(ResolveError {
+                        path: self.ir_files[self.cur_file.as_ref().unwrap()].path.clone(),
+                        span,
+                        message: format!(
+                            "`{prev}` and `{idl_name}`{owner} are both named `{name}` in Rust"
+                        ),
+                    });
+                    name
+                }
+            })
+            .collect()
+    }
+
+    /// Renames the lowered `items` whose Rust names collide, see `dedup_names`.
+    fn dedup_item_names(&mut self, owner: &str, items: &[(&ir::Item, DefId)]) {
+        // the consts are in another namespace than the types and the modules
+        for consts in [true, false] {
+            let items = items
+                .iter()
+                .filter(|(_, did)| {
+                    matches!(&**self.nodes[did].expect_item(), Item::Const(_)) == consts
+                })
+                .collect::<Vec<_>>();
+            let names = items
+                .iter()
+                .map(|(ir_item, did)| {
+                    let item = self.nodes[did].expect_item();
+                    let name = match &**item {
+                        Item::Mod(_) => item.symbol_name().to_snake_case(&self.naming),
+                        Item::Const(_) => item.symbol_name().to_shouty_snake_case(&self.naming),
+                        _ => item.symbol_name().to_upper_camel_case(&self.naming),
+                    };
+                    (ir_item.name(), name, ir_item.span)
+                })
+                .collect::<Vec<_>>();
+            let suffix = |name: &str, n: usize| match consts {
+                true => format!("{name}_{n}"),
+                false if name.contains('_') || name.starts_with(char::is_lowercase) => {
+                    format!("{name}_{n}")
+                }
+                false => format!("{name}{n}"),
+            };
+            let renamed = names
+                .iter()
+                .map(|(_, name, _)| name.clone())
+                .collect::<Vec<_>>();
+            let deduped = self.dedup_names(owner, names, suffix);
+            items
+                .iter()
+                .zip(renamed.iter().zip(deduped))
+                .filter(|(_, (old, new))| *old != new)
+                .for_each(|((_, did), (_, name))| {
+                    let node = self.nodes.get_mut(did).unwrap();
+                    if let NodeKind::Item(item) = &mut node.kind {
+                        *item = Arc::new(item.renamed(name));
+                    }
+                });
         }
     }
 
     fn lower_enum(&mut self, e: &ir::Enum) -> Enum {
         Enum {
-            name: e.name.clone(),
+            name: self.type_name_affixes.apply(&e.name, &self.naming),
             variants: {
                 e.variants
                     .iter()
                     .map(|v| {
                         let tag_id = self.tags_id_counter.inc_one();
-                        let did = self.did_counter.inc_one();
+                        let did = self.def_modules[&self.parent_node.unwrap()]
+                            .resolutions
+                            .value[&v.name.sym];
                         if !v.tags.is_empty() {
                             self.tags.insert(tag_id, v.tags.clone());
                         }
@@ -352,8 +820,10 @@ impl Resolver {
                             id: v.id,
                             did,
                             name: v.name.clone(),
+                            original_name: v.name.sym.clone(),
                             discr: v.discr,
                             fields: v.fields.iter().map(|p| self.lower_type(p)).collect(),
+                            default: v.default.as_ref().map(|l| self.lower_lit(l)),
                         });
                         self.nodes
                             .insert(did, self.mk_node(NodeKind::Variant(e.clone()), tag_id));
@@ -365,16 +835,53 @@ impl Resolver {
         }
     }
 
-    fn lower_service(&mut self, s: &ir::Service) -> Service {
+    /// Fails on two variants with the same discriminant and on the discriminants out of the
+    /// range of the repr. A variant without a discriminant takes the one after the previous
+    /// variant, starting from 0, like in thrift and Rust.
+    fn check_discriminants(&self, e: &ir::Enum, span: Option<Span>) {
+        let Some(repr) = e.repr else {
+            return;
+        };
+        let (repr_name, range) = match repr {
+            EnumRepr::I32 => ("i32", i32::MIN as i128..=i32::MAX as i128),
+        };
+        let mut seen = FxHashMap::default();
+        let mut next = 0;
+        for v in &e.variants {
+            let discr = v.discr.map_or(next, i128::from);
+            next = discr + 1;
+            let message = if !range.contains(&discr) {
+                format!(
+                    "the discriminant {discr} of `{}::{}` is out of the range of {repr_name}",
+                    e.name.sym, v.name.sym
+                )
+            } else if let Some(prev) = seen.insert(discr, &v.name.sym) {
+                format!(
+                    "`{}::{prev}` and `{}::{}` have the same discriminant {discr}",
+                    e.name.sym, e.name.sym, v.name.sym
+                )
+            } else {
+                continue;
+            };
+            self.errors.borrow_mut().push(ResolveError {
+                path: self.ir_files[self.cur_file.as_ref().unwrap()].path.clone(),
+                span,
+                message,
+            });
+        }
+    }
+
+    /// The inherited methods are added by `inherit_methods` once all the services are lowered.
+    fn lower_service(&mut self, s: &ir::Service, tags: &Tags) -> Service {
         Service {
-            name: s.name.clone(),
+            name: self.type_name_affixes.apply(&s.name, &self.naming),
             methods: s
                 .methods
                 .iter()
                 .map(|m| {
                     let def_id = self.did_counter.inc_one();
                     let tags_id = self.tags_id_counter.inc_one();
-                    self.tags.insert(tags_id, m.tags.clone());
+                    self.tags.insert(tags_id, method_tags(&m.tags, tags));
                     let method = Arc::from(Method {
                         def_id,
                         source: MethodSource::Own,
@@ -385,6 +892,7 @@ impl Resolver {
                             .map(|a| Arg {
                                 ty: self.lower_type(&a.ty),
                                 name: a.name.clone(),
+                                original_name: a.name.sym.clone(),
                                 id: a.id,
                             })
                             .collect(),
@@ -413,7 +921,7 @@ impl Resolver {
 
     fn lower_type_alias(&mut self, t: &ir::NewType) -> NewType {
         NewType {
-            name: t.name.clone(),
+            name: self.type_name_affixes.apply(&t.name, &self.naming),
             ty: self.lower_type(&t.ty),
         }
     }
@@ -449,14 +957,15 @@ impl Resolver {
         let items = m
             .items
             .iter()
-            .filter_map(|i| self.lower_item(i))
+            .filter_map(|i| Some((&**i, self.lower_item(i)?)))
             .collect::<Vec<_>>();
+        self.dedup_item_names(&format!(" in `{}`", m.name), &items);
 
         self.blocks.pop();
 
         Mod {
             name: m.name.clone(),
-            items,
+            items: items.into_iter().map(|(_, did)| did).collect(),
         }
     }
 
@@ -482,9 +991,13 @@ impl Resolver {
         let old_parent = self.parent_node.replace(def_id);
 
         let item = Arc::new(match &item.kind {
-            ir::ItemKind::Message(s) => Item::Message(self.lower_message(s)),
-            ir::ItemKind::Enum(e) => Item::Enum(self.lower_enum(e)),
-            ir::ItemKind::Service(s) => Item::Service(self.lower_service(s)),
+            ir::ItemKind::Message(s) => Item::Message(self.lower_message(s, item.span)),
+            ir::ItemKind::Enum(e) => {
+                let lowered = self.lower_enum(e);
+                self.check_discriminants(e, item.span);
+                Item::Enum(lowered)
+            }
+            ir::ItemKind::Service(s) => Item::Service(self.lower_service(s, tags)),
             ir::ItemKind::NewType(t) => Item::NewType(self.lower_type_alias(t)),
             ir::ItemKind::Const(c) => Item::Const(self.lower_const(c)),
             ir::ItemKind::Mod(m) => Item::Mod(self.lower_mod(m, def_id)),
@@ -510,14 +1023,18 @@ impl Resolver {
             .map(|block| self.blocks.push(NonNull::from(block)))
             .is_some();
 
+        let items = file
+            .items
+            .iter()
+            .filter_map(|item| Some((&**item, self.lower_item(item)?)))
+            .collect::<Vec<_>>();
+        self.dedup_item_names("", &items);
+
         let f = File {
-            items: file
-                .items
-                .iter()
-                .filter_map(|item| self.lower_item(item))
-                .collect(),
+            items: items.into_iter().map(|(_, did)| did).collect(),
 
             file_id: file.id,
+            uses: file.uses.clone(),
             package: ItemPath::from(
                 file.package
                     .segments
@@ -535,3 +1052,436 @@ impl Resolver {
         f
     }
 }
+
+/// Maps every referenced item to the items whose types refer to it, the fields,
+/// variants and methods are attributed to the items owning them.
+fn referrers(nodes: &FxHashMap<DefId, Node>) -> FxHashMap<DefId, Vec<DefId>> {
+    let mut referenced = FxHashMap::<DefId, Vec<DefId>>::default();
+    nodes.iter().for_each(|(def_id, node)| {
+        let mut collector = PathCollector::default();
+        let referrer = match &node.kind {
+            NodeKind::Item(item) => {
+                match &**item {
+                    Item::NewType(t) => collector.visit(&t.ty),
+                    Item::Const(c) => collector.visit(&c.ty),
+                    Item::Service(s) => collector.paths.extend(s.extend.iter().cloned()),
+                    _ => {}
+                }
+                *def_id
+            }
+            NodeKind::Variant(v) => {
+                v.fields.iter().for_each(|ty| collector.visit(ty));
+                node.parent.unwrap()
+            }
+            NodeKind::Field(f) => {
+                collector.visit(&f.ty);
+                node.parent.unwrap()
+            }
+            NodeKind::Method(m) => {
+                m.args.iter().for_each(|a| collector.visit(&a.ty));
+                collector.visit(&m.ret);
+                collector.paths.extend(m.exceptions.iter().cloned());
+                node.parent.unwrap()
+            }
+        };
+        collector.paths.into_iter().for_each(|p| {
+            referenced.entry(p.did).or_default().push(referrer);
+        });
+    });
+    referenced
+}
+
+/// Returns the nested items which are not referenced by any other item.
+///
+/// A message with nested items is lowered into a mod containing both the
+/// message itself and its nested items, so the message which owns a mod is
+/// only nested when the mod is.
+pub(crate) fn orphaned_nested_items(
+    nodes: &FxHashMap<DefId, Node>,
+    type_name_affixes: &TypeNameAffixes,
+    naming: &Naming,
+) -> Vec<DefId> {
+    let item_of = |def_id: DefId| match &nodes[&def_id].kind {
+        NodeKind::Item(item) => Some(item.clone()),
+        _ => None,
+    };
+
+    let owner_of = |m: &Mod| {
+        m.items.iter().copied().find(|def_id| {
+            let item = item_of(*def_id).unwrap();
+            !matches!(&*item, Item::Mod(_))
+                && Symbol::from(type_name_affixes.strip(&item.symbol_name())).to_snake_case(naming)
+                    == *m.name
+        })
+    };
+
+    let is_nested = |def_id: DefId| {
+        let parent = match nodes[&def_id].parent {
+            Some(parent) => parent,
+            None => return false,
+        };
+        match item_of(parent).as_deref() {
+            Some(Item::Mod(m)) => owner_of(m) != Some(def_id) || nodes[&parent].parent.is_some(),
+            _ => false,
+        }
+    };
+
+    let referenced = referrers(nodes);
+
+    let mut orphans = nodes
+        .iter()
+        .filter(|(def_id, node)| {
+            matches!(&node.kind, NodeKind::Item(item) if !matches!(&**item, Item::Mod(_)))
+                && is_nested(**def_id)
+                && !referenced
+                    .get(def_id)
+                    .is_some_and(|referrers| referrers.iter().any(|r| r != *def_id))
+        })
+        .map(|(def_id, _)| *def_id)
+        .collect::<Vec<_>>();
+    orphans.sort();
+    orphans
+}
+
+#[derive(Clone, Copy)]
+enum RefSite {
+    Field(DefId),
+    Variant(DefId, usize),
+    NewType,
+}
+
+/// Wraps the type of every field which closes a reference cycle between items
+/// in an `Arc`, so that the generated types have a finite size. The protobuf
+/// codegen prints it as a `Box`.
+pub(crate) fn break_recursive_cycles(
+    nodes: &mut FxHashMap<DefId, Node>,
+    tags: &FxHashMap<TagId, Arc<Tags>>,
+) {
+    let mut graph = Graph::<DefId, RefSite>::new();
+    let mut node_map = FxHashMap::default();
+    let mut item_ids = nodes
+        .iter()
+        .filter(|(_, node)| matches!(node.kind, NodeKind::Item(_)))
+        .map(|(def_id, _)| *def_id)
+        .collect::<Vec<_>>();
+    item_ids.sort();
+    item_ids.iter().for_each(|def_id| {
+        node_map.insert(*def_id, graph.add_node(*def_id));
+    });
+
+    let mut add_edge = |from: DefId, ty: &Ty, site: RefSite| {
+        if let ty::Path(p) = &ty.kind {
+            if let Some(to) = node_map.get(&p.did) {
+                graph.add_edge(node_map[&from], *to, site);
+            }
+        }
+    };
+
+    let mut def_ids = nodes.keys().copied().collect::<Vec<_>>();
+    def_ids.sort();
+    def_ids.iter().for_each(|def_id| {
+        let node = &nodes[def_id];
+        match &node.kind {
+            NodeKind::Field(f) => add_edge(node.parent.unwrap(), &f.ty, RefSite::Field(*def_id)),
+            NodeKind::Variant(v) => v.fields.iter().enumerate().for_each(|(idx, ty)| {
+                add_edge(node.parent.unwrap(), ty, RefSite::Variant(*def_id, idx))
+            }),
+            NodeKind::Item(item) => {
+                if let Item::NewType(t) = &**item {
+                    add_edge(*def_id, &t.ty, RefSite::NewType)
+                }
+            }
+            NodeKind::Method(_) => {}
+        }
+    });
+
+    let mut back_edges = Vec::new();
+    tarjan_scc(&graph).into_iter().for_each(|scc| {
+        let is_cyclic = scc.len() > 1 || graph.contains_edge(scc[0], scc[0]);
+        if !is_cyclic {
+            return;
+        }
+        let scc = scc.into_iter().collect::<FxHashSet<_>>();
+        // a oneof is only referenced by its message, starting from the message keeps the
+        // oneof field unwrapped and breaks the cycle at a variant instead
+        let root = scc
+            .iter()
+            .copied()
+            .min_by_key(|idx| {
+                let is_oneof = tags
+                    .get(&nodes[&graph[*idx]].tags)
+                    .is_some_and(|tags| tags.contains::<OneOf>());
+                (is_oneof, graph[*idx])
+            })
+            .unwrap();
+        collect_back_edges(
+            &graph,
+            &scc,
+            root,
+            &mut Vec::new(),
+            &mut FxHashSet::default(),
+            &mut back_edges,
+        );
+    });
+
+    back_edges.into_iter().for_each(|site| match site {
+        RefSite::Field(def_id) => {
+            let node = nodes.get_mut(&def_id).unwrap();
+            let parent = node.parent.unwrap();
+            let f = match &mut node.kind {
+                NodeKind::Field(f) => {
+                    let mut field = (**f).clone();
+                    field.ty = arc_ty(&field.ty);
+                    *f = Arc::from(field);
+                    f.clone()
+                }
+                _ => unreachable!(),
+            };
+            let NodeKind::Item(item) = &mut nodes.get_mut(&parent).unwrap().kind else {
+                unreachable!()
+            };
+            let mut new_item = (**item).clone();
+            if let Item::Message(s) = &mut new_item {
+                s.fields
+                    .iter_mut()
+                    .filter(|field| field.did == def_id)
+                    .for_each(|field| *field = f.clone());
+            }
+            *item = Arc::from(new_item);
+        }
+        RefSite::Variant(def_id, idx) => {
+            let node = nodes.get_mut(&def_id).unwrap();
+            let parent = node.parent.unwrap();
+            let v = match &mut node.kind {
+                NodeKind::Variant(v) => {
+                    let mut variant = (**v).clone();
+                    variant.fields[idx] = arc_ty(&variant.fields[idx]);
+                    *v = Arc::from(variant);
+                    v.clone()
+                }
+                _ => unreachable!(),
+            };
+            let NodeKind::Item(item) = &mut nodes.get_mut(&parent).unwrap().kind else {
+                unreachable!()
+            };
+            let mut new_item = (**item).clone();
+            if let Item::Enum(e) = &mut new_item {
+                e.variants
+                    .iter_mut()
+                    .filter(|variant| variant.did == def_id)
+                    .for_each(|variant| *variant = v.clone());
+            }
+            *item = Arc::from(new_item);
+        }
+        // a typedef can not be wrapped, the cycle is left to the `BoxedPlugin`
+        RefSite::NewType => {}
+    });
+}
+
+/// Wraps the types of the fields of the messages selected by `predicate`, which gets the id and
+/// the name of the message, in an `Arc` and tags the fields [`ArcField`]. The fields already
+/// wrapped to break a cycle are only tagged, the arguments of the methods and the fields with
+/// a `rust.type` are left as they are.
+pub(crate) fn wrap_fields_in_arc(
+    nodes: &mut FxHashMap<DefId, Node>,
+    tags: &mut FxHashMap<TagId, Arc<Tags>>,
+    predicate: &dyn Fn(DefId, &str) -> bool,
+) {
+    let has_tag = |tags: &FxHashMap<TagId, Arc<Tags>>, tags_id: TagId, f: fn(&Tags) -> bool| {
+        tags.get(&tags_id).is_some_and(|tags| f(tags))
+    };
+    let mut def_ids = nodes.keys().copied().collect::<Vec<_>>();
+    def_ids.sort();
+    for def_id in def_ids {
+        let node = nodes.get_mut(&def_id).unwrap();
+        let NodeKind::Item(item) = &mut node.kind else {
+            continue;
+        };
+        let Item::Message(s) = &**item else {
+            continue;
+        };
+        if has_tag(tags, node.tags, |t| t.contains::<MethodArgs>()) || !predicate(def_id, &s.name) {
+            continue;
+        }
+
+        let mut s = s.clone();
+        for f in &mut s.fields {
+            if has_tag(tags, f.ty.tags_id, |t| t.contains::<RustType>()) {
+                continue;
+            }
+            if !matches!(f.ty.kind, ty::Arc(_)) {
+                let mut field = (**f).clone();
+                field.ty = arc_ty(&field.ty);
+                *f = Arc::from(field);
+            }
+            let node = nodes.get_mut(&f.did).unwrap();
+            node.kind = NodeKind::Field(f.clone());
+            Arc::make_mut(tags.entry(node.tags).or_default()).insert(ArcField);
+        }
+        let NodeKind::Item(item) = &mut nodes.get_mut(&def_id).unwrap().kind else {
+            unreachable!()
+        };
+        *item = Arc::new(Item::Message(s));
+    }
+}
+
+/// Replaces the paths to typedefs with the types they alias, so a chain of typedefs
+/// collapses into the type at its end.
+/// The tags of a method of the service tagged `service`, a deprecated service deprecates the
+/// methods without a `Deprecated` tag of their own.
+fn method_tags(method: &Tags, service: &Tags) -> Arc<Tags> {
+    let mut tags = method.clone();
+    if let Some(deprecated) = service.get::<Deprecated>() {
+        tags.merge(crate::tags!(deprecated.clone()));
+    }
+    Arc::new(tags)
+}
+
+pub(crate) fn unwrap_typedefs(nodes: &mut FxHashMap<DefId, Node>) {
+    let aliases = nodes
+        .iter()
+        .filter_map(|(def_id, node)| match &node.kind {
+            NodeKind::Item(item) => match &**item {
+                Item::NewType(t) => Some((*def_id, t.ty.clone())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    fn unwrap(aliases: &FxHashMap<DefId, Ty>, ty: &Ty, seen: &mut Vec<DefId>) -> Ty {
+        let kind = match &ty.kind {
+            ty::Path(p) if aliases.contains_key(&p.did) && !seen.contains(&p.did) => {
+                seen.push(p.did);
+                let ty = unwrap(aliases, &aliases[&p.did], seen);
+                seen.pop();
+                return ty;
+            }
+            ty::Vec(el) => ty::Vec(Arc::new(unwrap(aliases, el, seen))),
+            ty::Set(el) => ty::Set(Arc::new(unwrap(aliases, el, seen))),
+            ty::Map(k, v) => ty::Map(
+                Arc::new(unwrap(aliases, k, seen)),
+                Arc::new(unwrap(aliases, v, seen)),
+            ),
+            ty::Arc(el) => ty::Arc(Arc::new(unwrap(aliases, el, seen))),
+            kind => kind.clone(),
+        };
+        Ty {
+            kind,
+            tags_id: ty.tags_id,
+        }
+    }
+    let unwrap = |ty: &Ty| unwrap(&aliases, ty, &mut Vec::new());
+
+    // fields, variants and methods are shared with their items, so they are rebuilt first
+    let mut fields = FxHashMap::default();
+    let mut variants = FxHashMap::default();
+    let mut methods = FxHashMap::default();
+    nodes
+        .iter_mut()
+        .for_each(|(def_id, node)| match &mut node.kind {
+            NodeKind::Field(f) => {
+                *f = Arc::new(Field {
+                    ty: unwrap(&f.ty),
+                    ..(**f).clone()
+                });
+                fields.insert(*def_id, f.clone());
+            }
+            NodeKind::Variant(v) => {
+                *v = Arc::new(EnumVariant {
+                    fields: v.fields.iter().map(unwrap).collect(),
+                    ..(**v).clone()
+                });
+                variants.insert(*def_id, v.clone());
+            }
+            NodeKind::Method(m) => {
+                *m = Arc::new(Method {
+                    args: m
+                        .args
+                        .iter()
+                        .map(|a| Arg {
+                            ty: unwrap(&a.ty),
+                            ..a.clone()
+                        })
+                        .collect(),
+                    ret: unwrap(&m.ret),
+                    ..(**m).clone()
+                });
+                methods.insert(*def_id, m.clone());
+            }
+            NodeKind::Item(_) => {}
+        });
+
+    nodes.values_mut().for_each(|node| {
+        let NodeKind::Item(item) = &mut node.kind else {
+            return;
+        };
+        let new_item = match &**item {
+            Item::Message(s) => Item::Message(Message {
+                fields: s.fields.iter().map(|f| fields[&f.did].clone()).collect(),
+                ..s.clone()
+            }),
+            Item::Enum(e) => Item::Enum(Enum {
+                variants: e
+                    .variants
+                    .iter()
+                    .map(|v| variants[&v.did].clone())
+                    .collect(),
+                ..e.clone()
+            }),
+            Item::Service(s) => Item::Service(Service {
+                methods: s
+                    .methods
+                    .iter()
+                    .map(|m| methods[&m.def_id].clone())
+                    .collect(),
+                ..s.clone()
+            }),
+            Item::NewType(t) => Item::NewType(NewType {
+                ty: unwrap(&t.ty),
+                ..t.clone()
+            }),
+            Item::Const(c) => Item::Const(Const {
+                ty: unwrap(&c.ty),
+                ..c.clone()
+            }),
+            Item::Mod(_) => return,
+        };
+        *item = Arc::new(new_item);
+    });
+}
+
+fn collect_back_edges(
+    graph: &Graph<DefId, RefSite>,
+    scc: &FxHashSet<NodeIndex>,
+    node: NodeIndex,
+    stack: &mut Vec<NodeIndex>,
+    visited: &mut FxHashSet<NodeIndex>,
+    back_edges: &mut Vec<RefSite>,
+) {
+    visited.insert(node);
+    stack.push(node);
+
+    let mut edges = graph.edges(node).collect::<Vec<_>>();
+    edges.sort_by_key(|e| graph[e.target()]);
+    edges.into_iter().for_each(|e| {
+        let target = e.target();
+        if !scc.contains(&target) {
+            return;
+        }
+        if stack.contains(&target) {
+            back_edges.push(*e.weight());
+        } else if !visited.contains(&target) {
+            collect_back_edges(graph, scc, target, stack, visited, back_edges);
+        }
+    });
+
+    stack.pop();
+}
+
+fn arc_ty(ty: &Ty) -> Ty {
+    Ty {
+        kind: ty::Arc(Arc::new(ty.clone())),
+        tags_id: ty.tags_id,
+    }
+}
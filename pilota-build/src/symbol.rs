@@ -4,6 +4,8 @@ use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use phf::phf_set;
 use quote::IdentFragment;
 
+use crate::middle::context::tls::CONTEXT;
+
 crate::newtype_index! {
     pub struct FileId { .. }
 }
@@ -73,22 +75,146 @@ lazy_static::lazy_static! {
     ];
 }
 
+/// The keywords which can not be raw identifiers.
+const NON_RAW_KEYWORDS: [&str; 4] = ["crate", "self", "super", "Self"];
+
+/// How the leading underscores of an IDL name are handled when converting its case.
+#[derive(Hash, PartialEq, Eq, Clone, Debug, Copy, Default)]
+pub enum LeadingUnderscore {
+    /// `_Foo` stays `_Foo`.
+    Preserve,
+    /// `_Foo` becomes `Foo`.
+    #[default]
+    Strip,
+    /// `_Foo` becomes `Foo_`.
+    Escape,
+}
+
+/// The case of the names of the generated fields and method arguments.
+#[derive(Hash, PartialEq, Eq, Clone, Debug, Copy, Default)]
+pub enum FieldCase {
+    /// `fooBar` becomes `foo_bar`.
+    #[default]
+    SnakeCase,
+    /// `fooBar` stays `fooBar`, the enum variants keep the names of the IDL too.
+    Preserve,
+    /// `foo_bar` becomes `fooBar`.
+    CamelCase,
+}
+
+/// How the names of the IDL are converted to the names of the generated code, it is built
+/// from the options of the `Builder` and kept in the `Context` of the build.
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct Naming {
+    pub leading_underscore: LeadingUnderscore,
+    pub field_case: FieldCase,
+    /// The suffix of the keywords which can not be raw identifiers, such as `self_`.
+    pub keyword_suffix: Arc<str>,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Naming {
+            leading_underscore: Default::default(),
+            field_case: Default::default(),
+            keyword_suffix: Arc::from("_"),
+        }
+    }
+}
+
+/// The prefix and suffix added to the names of all generated types.
+#[derive(Clone, Debug, Default, Hash)]
+pub(crate) struct TypeNameAffixes {
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl TypeNameAffixes {
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_empty() && self.suffix.is_empty()
+    }
+
+    pub fn apply(&self, name: &Ident, naming: &Naming) -> Ident {
+        if self.is_empty() {
+            return name.clone();
+        }
+        Ident::new(Symbol::from(format!(
+            "{}{}{}",
+            self.prefix,
+            name.to_upper_camel_case(naming),
+            self.suffix
+        )))
+    }
+
+    /// Returns the name without the prefix and suffix, the reverse of [`TypeNameAffixes::apply`].
+    pub fn strip<'a>(&self, name: &'a str) -> &'a str {
+        let name = name.strip_prefix(&*self.prefix).unwrap_or(name);
+        name.strip_suffix(&*self.suffix).unwrap_or(name)
+    }
+
+    /// Names a type generated for the type `name`, such as the client of a service.
+    pub fn derive(&self, name: &str, f: impl FnOnce(Symbol) -> String) -> String {
+        let derived = f(Symbol::from(self.strip(name)));
+        if self.is_empty() {
+            return derived;
+        }
+        format!("{}{}{}", self.prefix, derived, self.suffix).to_upper_camel_case()
+    }
+}
+
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub struct Symbol(pub Arc<str>);
 
 macro_rules! to_case {
     ($m: ident) => {
-        pub fn $m(&self) -> Symbol {
-            Symbol(Arc::from(self.0.$m()))
+        pub fn $m(&self, naming: &Naming) -> Symbol {
+            let name = self.0.trim_start_matches('_');
+            let underscores = &self.0[..self.0.len() - name.len()];
+            let name = match naming.leading_underscore {
+                LeadingUnderscore::Preserve => format!("{}{}", underscores, name.$m()),
+                LeadingUnderscore::Strip => name.$m(),
+                // keep the trailing underscores so that converting an escaped name is a no-op
+                LeadingUnderscore::Escape => {
+                    let trimmed = name.trim_end_matches('_');
+                    match &name[trimmed.len()..] {
+                        "" if underscores.is_empty() => trimmed.$m(),
+                        "" => format!("{}_", trimmed.$m()),
+                        trailing => format!("{}{}", trimmed.$m(), trailing),
+                    }
+                }
+            };
+            Symbol(Arc::from(name))
         }
     };
 }
 
 impl Symbol {
+    /// Whether the name is written with the keyword suffix, which changes its serde name.
+    pub(crate) fn has_keyword_suffix(&self) -> bool {
+        NON_RAW_KEYWORDS.contains(&&**self)
+    }
+
     to_case!(to_shouty_snake_case);
     to_case!(to_snake_case);
     to_case!(to_lower_camel_case);
     to_case!(to_upper_camel_case);
+
+    /// The name of a field or a method argument in the [`FieldCase`] of the build.
+    pub fn to_field_case(&self, naming: &Naming) -> Symbol {
+        match naming.field_case {
+            FieldCase::SnakeCase => self.to_snake_case(naming),
+            FieldCase::Preserve => self.clone(),
+            FieldCase::CamelCase => self.to_lower_camel_case(naming),
+        }
+    }
+
+    /// The name of an enum variant, which is only kept as is by [`FieldCase::Preserve`].
+    pub fn to_variant_case(&self, naming: &Naming) -> Symbol {
+        match naming.field_case {
+            FieldCase::Preserve => self.clone(),
+            _ => self.to_upper_camel_case(naming),
+        }
+    }
 }
 
 impl Deref for Symbol {
@@ -110,7 +236,15 @@ where
 
 impl IdentFragment for Symbol {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        if KEYWORDS_SET.contains(self) {
+        if self.has_keyword_suffix() {
+            // the identifiers are formatted by the codegen, under the context of the build
+            let suffix = if CONTEXT.is_set() {
+                CONTEXT.with(|cx| cx.naming().keyword_suffix.clone())
+            } else {
+                Naming::default().keyword_suffix
+            };
+            write!(f, "{}{}", self, suffix)
+        } else if KEYWORDS_SET.contains(self) {
             write!(f, "r#{}", self)
         } else {
             write!(f, "{}", self)
@@ -124,6 +258,19 @@ impl Display for Symbol {
     }
 }
 
+/// A position in an IDL file, both the line and the column start from 1.
+#[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Copy)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 #[derive(Hash, PartialEq, Eq, Clone, Debug, Copy)]
 pub enum EnumRepr {
     I32,
@@ -142,8 +289,10 @@ impl Ident {
 
 macro_rules! ident_to_case {
     ($m: ident) => {
-        pub fn $m(&self) -> Ident {
-            Ident { sym: self.sym.$m() }
+        pub fn $m(&self, naming: &Naming) -> Ident {
+            Ident {
+                sym: self.sym.$m(naming),
+            }
         }
     };
 }
@@ -153,6 +302,8 @@ impl Ident {
     ident_to_case!(to_snake_case);
     ident_to_case!(to_lower_camel_case);
     ident_to_case!(to_upper_camel_case);
+    ident_to_case!(to_field_case);
+    ident_to_case!(to_variant_case);
 }
 
 impl Deref for Ident {
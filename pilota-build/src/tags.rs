@@ -1,23 +1,126 @@
 use std::{
     any::{Any, TypeId},
-    collections::HashMap,
-    fmt::Debug,
+    collections::{hash_map::Entry, HashMap},
+    fmt::{self, Debug},
     hash::Hash,
     ops::{Deref, DerefMut},
+    sync::{Arc, RwLock},
 };
 
-#[derive(Default, Debug)]
-pub struct TypeMap(HashMap<TypeId, Box<dyn Any + Sync + Send>>);
+type DebugFn = fn(&(dyn Any + Sync + Send), &mut fmt::Formatter<'_>) -> fmt::Result;
+
+type CloneFn = fn(&(dyn Any + Sync + Send)) -> Box<dyn Any + Sync + Send>;
+
+type MergeFn = Arc<
+    dyn Fn(&(dyn Any + Sync + Send), &(dyn Any + Sync + Send)) -> Box<dyn Any + Sync + Send>
+        + Sync
+        + Send,
+>;
+
+lazy_static::lazy_static! {
+    static ref DEBUG_FORMATTERS: RwLock<HashMap<TypeId, DebugFn>> = Default::default();
+    static ref MERGERS: RwLock<HashMap<TypeId, MergeFn>> = RwLock::new(HashMap::from([
+        (TypeId::of::<Derives>(), merger(|a: &Derives, b| Derives(concat(&a.0, &b.0)))),
+        (TypeId::of::<RustAttrs>(), merger(|a: &RustAttrs, b| RustAttrs(concat(&a.0, &b.0)))),
+    ]));
+}
+
+/// Renders the values of `T` in the `Debug` output of the maps holding them, instead of only the
+/// name of their type.
+pub fn register_debug<T: Debug + 'static>() {
+    fn fmt<T: Debug + 'static>(
+        v: &(dyn Any + Sync + Send),
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        v.downcast_ref::<T>().unwrap().fmt(f)
+    }
+
+    DEBUG_FORMATTERS
+        .write()
+        .unwrap()
+        .insert(TypeId::of::<T>(), fmt::<T>);
+}
+
+fn merger<T: Sync + Send + 'static>(merge: fn(&T, &T) -> T) -> MergeFn {
+    Arc::new(move |a, b| Box::new(merge(a.downcast_ref().unwrap(), b.downcast_ref().unwrap())))
+}
+
+/// The values of both lists in order, without the ones of `b` which are in `a` already.
+fn concat(a: &[Arc<str>], b: &[Arc<str>]) -> Vec<Arc<str>> {
+    let mut values = a.to_vec();
+    values.extend(b.iter().filter(|v| !a.contains(v)).cloned());
+    values
+}
+
+/// Combines the values of `T` with `merge` when merging two maps holding them, the value of the
+/// map merged into is the first argument. `Derives` and `RustAttrs` are combined by
+/// concatenating their lists.
+pub fn register_merge<T: Sync + Send + 'static>(merge: fn(&T, &T) -> T) {
+    MERGERS
+        .write()
+        .unwrap()
+        .insert(TypeId::of::<T>(), merger(merge));
+}
+
+fn clone_value<T: Clone + Sync + Send + 'static>(
+    v: &(dyn Any + Sync + Send),
+) -> Box<dyn Any + Sync + Send> {
+    Box::new(v.downcast_ref::<T>().unwrap().clone())
+}
+
+/// A map keyed by the type of its values, a clone owns copies of the values.
+///
+/// A value is always stored under the `TypeId` of its own type, so looking it up never finds
+/// a value of another type, the downcasts below can not fail.
+#[derive(Default)]
+pub struct TypeMap(HashMap<TypeId, (&'static str, CloneFn, Box<dyn Any + Sync + Send>)>);
 
 impl TypeMap {
-    pub fn insert<T: 'static + Sync + Send>(&mut self, v: T) {
-        self.0.insert(TypeId::of::<T>(), Box::new(v));
+    pub fn insert<T: Clone + 'static + Sync + Send>(&mut self, v: T) {
+        self.0.insert(
+            TypeId::of::<T>(),
+            (std::any::type_name::<T>(), clone_value::<T>, Box::new(v)),
+        );
     }
 
     pub fn get<T: 'static>(&self) -> Option<&T> {
         self.0
             .get(&TypeId::of::<T>())
-            .map(|v| v.downcast_ref().unwrap())
+            .map(|(_, _, v)| v.downcast_ref().expect("a value is keyed by its type"))
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.0
+            .get_mut(&TypeId::of::<T>())
+            .map(|(_, _, v)| v.downcast_mut().expect("a value is keyed by its type"))
+    }
+
+    /// Removes the value of type `T` and returns it, `None` when there is none.
+    pub fn remove<T: 'static + Sync + Send>(&mut self) -> Option<T> {
+        self.0
+            .remove(&TypeId::of::<T>())
+            .map(|(_, _, v)| *v.downcast().expect("a value is keyed by its type"))
+    }
+
+    /// Moves the entries of `other` into the map. The values of a type registered by
+    /// `register_merge` are combined with the existing ones, for the other types the existing
+    /// entries win over the ones of `other`.
+    pub fn extend_from(&mut self, other: TypeMap) {
+        other
+            .0
+            .into_iter()
+            .for_each(|(k, (name, clone, v))| match self.0.entry(k) {
+                Entry::Occupied(mut e) => {
+                    // the lock is released before merging, as the values may hold maps as well
+                    let merge = MERGERS.read().unwrap().get(&k).cloned();
+                    if let Some(merge) = merge {
+                        e.get_mut().2 = merge(&*e.get().2, &*v);
+                    }
+                }
+                Entry::Vacant(e) => {
+                    e.insert((name, clone, v));
+                }
+            })
     }
 
     pub fn is_empty(&self) -> bool {
@@ -31,13 +134,87 @@ impl TypeMap {
     pub fn contains<T: 'static>(&self) -> bool {
         self.0.contains_key(&TypeId::of::<T>())
     }
+
+    /// The types of the values in the map, in no particular order.
+    pub fn type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// The names of the types of the values in the map as given by `std::any::type_name`, in no
+    /// particular order.
+    pub fn type_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.values().map(|(name, _, _)| *name)
+    }
+}
+
+impl Clone for TypeMap {
+    fn clone(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|(k, (name, clone, v))| (*k, (*name, *clone, clone(&**v))))
+                .collect(),
+        )
+    }
+}
+
+/// Lists the values sorted by the names of their types, such as `{OneOf, Packed(true)}` when
+/// `Packed` is registered by `register_debug`.
+impl Debug for TypeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Entry<'a>(String, &'a (dyn Any + Sync + Send));
+
+        impl Debug for Entry<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // the lock is released before formatting, as the value may hold a map as well
+                let debug = DEBUG_FORMATTERS
+                    .read()
+                    .unwrap()
+                    .get(&(*self.1).type_id())
+                    .copied();
+                match debug {
+                    Some(debug) => debug(self.1, f),
+                    None => f.write_str(&self.0),
+                }
+            }
+        }
+
+        let mut entries = self
+            .0
+            .values()
+            .map(|(name, _, v)| {
+                // strips the path of the type but not the ones of its generic arguments
+                let (path, args) = name.split_at(name.find('<').unwrap_or(name.len()));
+                Entry(
+                    format!("{}{}", path.rsplit("::").next().unwrap(), args),
+                    &**v,
+                )
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        f.debug_set().entries(entries).finish()
+    }
 }
 
 crate::newtype_index!(pub struct TagId { .. });
 
-#[derive(Default, Debug)]
+#[derive(Default, Clone)]
 pub struct Tags(TypeMap);
 
+impl Tags {
+    /// Merges the tags of `other` like `TypeMap::extend_from`, the lists of `Derives` and
+    /// `RustAttrs` are concatenated, the other existing tags win over the ones of `other`.
+    pub fn merge(&mut self, other: Tags) {
+        self.0.extend_from(other.0)
+    }
+}
+
+impl Debug for Tags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 impl Deref for Tags {
     type Target = TypeMap;
 
@@ -101,28 +278,156 @@ macro_rules! new_type {
     {} => {};
 }
 
+/// Extra traits derived for the generated type, such as `Hash` or `serde::Serialize`.
+#[derive(Clone)]
+pub struct Derives(pub Vec<Arc<str>>);
+
+/// Fields whose types are wrapped in an `Arc` by `Builder::arc_fields_if`, they get `get_`
+/// accessors returning a reference to the shared value.
+#[derive(Clone)]
+pub struct ArcField;
+
+/// Attributes such as `#[serde(skip)]` added as they are to the generated field, the strings
+/// are parsed when the field is generated. `#[cfg]` is not supported, as the generated impls
+/// of the message still use the field.
+#[derive(Clone)]
+pub struct RustAttrs(pub Vec<Arc<str>>);
+
+/// Fields, methods and enum variants marked as deprecated, by the `deprecated` option in
+/// protobuf and the `deprecated` annotation in thrift, with the note of the annotation if any.
+#[derive(Clone)]
+pub struct Deprecated(pub Option<Arc<str>>);
+
+/// The lines of the doc comments of a definition in the IDL, emitted as `#[doc]` attributes.
+#[derive(Clone)]
+pub struct Docs(pub Vec<Arc<str>>);
+
+/// Orders the message by its fields in the ascending order of their ids instead of the order
+/// they are declared in, comparing each field like `Ord` does and a missing optional field
+/// before a present one. Only the fields of the IDL are compared.
+#[derive(Clone)]
+pub struct OrdById;
+
 pub mod thrift {
+    #[derive(Clone)]
     pub struct EntryMessage;
+
+    #[derive(Clone)]
+    pub struct Union;
+
+    #[derive(Clone)]
+    pub struct DefaultVariant;
+
+    /// The argument structs generated for the methods of a service.
+    #[derive(Clone)]
+    pub struct MethodArgs;
+
+    /// The enums of the exceptions thrown by the methods of a service, which can be built from
+    /// each of them with `From`.
+    #[derive(Clone)]
+    pub struct MethodException;
+
+    /// String fields generated as `Box<str>` instead of `String`, by the `pilota.boxed_str`
+    /// annotation.
+    #[derive(Clone)]
+    pub struct BoxedStr;
+
+    /// Optional collection fields generated as bare collections, which are left empty when
+    /// absent from the wire.
+    #[derive(Clone)]
+    pub struct EmptyByDefault;
+
+    /// Fields which are only meaningful inside the process, such as
+    /// computed or cached values.
+    #[derive(Clone)]
+    pub struct Internal;
+
+    /// The type of a string, binary or container field set by the `rust.type` annotation,
+    /// which is built from the decoded value with `From` or `FromIterator`.
+    #[derive(Clone)]
+    pub struct RustType(pub std::sync::Arc<str>);
+
+    /// The collection of a map or set field set by the `rust.collection` annotation.
+    #[derive(Clone)]
+    pub struct RustCollection(pub crate::ty::CollectionKind);
+
+    /// The name of the trait shared by the versions of a message, which has
+    /// accessors for the fields common to all of them.
+    #[derive(Clone)]
+    pub struct SharedTrait(pub std::sync::Arc<str>);
+
+    /// Methods marked with the `streaming.mode` annotation, which the generated clients
+    /// can not call.
+    #[derive(Clone)]
+    pub struct Streaming;
+}
+
+pub mod validate {
+    new_type! {
+        #[derive(Clone)]
+        pub struct Min(pub i64);
+        #[derive(Clone)]
+        pub struct Max(pub i64);
+
+        #[derive(Clone)]
+        pub struct NonEmpty;
+    }
 }
 
 pub mod protobuf {
 
     new_type! {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         pub struct OneOf;
 
+        #[derive(Clone)]
         pub struct Repeated;
 
+        #[derive(Clone)]
         pub struct ClientStreaming;
+        #[derive(Clone)]
         pub struct ServerStreaming;
 
+        #[derive(Clone)]
         pub struct SInt32;
+        #[derive(Clone)]
         pub struct SInt64;
 
+        #[derive(Clone)]
         pub struct Fixed32;
+        #[derive(Clone)]
         pub struct Fixed64;
 
+        #[derive(Clone)]
         pub struct SFixed32;
+        #[derive(Clone)]
         pub struct SFixed64;
+
+        /// Whether a repeated scalar field is packed, set by the `packed` option, or to `false`
+        /// for the proto2 fields without it.
+        #[derive(Clone)]
+        pub struct Packed(pub bool);
     }
+
+    /// The custom options of a field keyed by their full name, such as `my.option`, with the
+    /// values rendered like in the `.proto` file.
+    #[derive(Clone)]
+    pub struct CustomOptions(pub Vec<(std::sync::Arc<str>, std::sync::Arc<str>)>);
+
+    /// The fully qualified name of a message, such as `google.protobuf.Any`.
+    #[derive(Clone)]
+    pub struct FullName(pub std::sync::Arc<str>);
+
+    /// The `default` option of a proto2 field, such as `7` or `FANCY`, the bytes are C escaped.
+    #[derive(Clone)]
+    pub struct DefaultValue(pub std::sync::Arc<str>);
+
+    /// The enum of an enum field, whose type is generated as `i32`.
+    #[derive(Clone)]
+    pub struct Enumeration(pub crate::DefId);
+
+    /// The name of the message moved along with its nested items into this module, which is
+    /// named after the message in snake case.
+    #[derive(Clone)]
+    pub struct MessageMod(pub crate::symbol::Symbol);
 }
@@ -74,22 +74,5211 @@ fn test_thrift_gen() {
 }
 
 #[test]
-fn test_protobuf_gen() {
+fn test_thrift_include_gen() {
     let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("test_data")
-        .join("protobuf");
+        .join("thrift_include");
 
-    test_data_dir.read_dir().unwrap().for_each(|f| {
-        let f = f.unwrap();
+    test_thrift(test_data_dir.join("a.thrift"), test_data_dir.join("a.rs"));
+}
 
-        let path = f.path();
+#[test]
+fn test_thrift_include_const_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_include_const");
 
-        if let Some(ext) = path.extension() {
-            if ext == "proto" {
-                let mut rs_path = path.clone();
-                rs_path.set_extension("rs");
-                test_protobuf(path, rs_path);
-            }
+    test_thrift(
+        test_data_dir.join("item.thrift"),
+        test_data_dir.join("item.rs"),
+    );
+}
+
+#[test]
+fn test_thrift_client_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_client");
+
+    ["service", "streaming"].into_iter().for_each(|name| {
+        test_with_builder(
+            test_data_dir.join(format!("{name}.thrift")),
+            test_data_dir.join(format!("{name}.rs")),
+            |source, target| {
+                crate::Builder::thrift()
+                    .gen_client(true)
+                    .compile(&[source], target)
+            },
+        );
+    });
+}
+
+#[test]
+fn test_module_doc() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_include");
+
+    test_with_builder(
+        test_data_dir.join("a.thrift"),
+        test_data_dir.join("a_module_doc.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .module_doc(|path| {
+                    (path.iter().map(|s| &**s).eq(["bar", "b"]))
+                        .then(|| "Generated from b.thrift.\nDo not edit.".to_string())
+                })
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_validate_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_validate");
+
+    test_thrift(
+        test_data_dir.join("validate.thrift"),
+        test_data_dir.join("validate.rs"),
+    );
+
+    test_with_builder(
+        test_data_dir.join("validate.thrift"),
+        test_data_dir.join("validate_validator.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .with_validator(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_serde_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_serde");
+
+    test_with_builder(
+        test_data_dir.join("internal.thrift"),
+        test_data_dir.join("internal.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .plugin(crate::plugin::SerdePlugin)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("paint.thrift"),
+        test_data_dir.join("paint.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .with_serde(true)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("paint.thrift"),
+        test_data_dir.join("paint_discriminant.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .with_serde(true)
+                .serde_enum_repr(crate::SerdeEnumRepr::Discriminant)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_ty_transformer_gen() {
+    use std::sync::Arc;
+
+    use crate::ty::{CodegenTy, Ty, TyTransformer};
+
+    struct CustomTyTransformer;
+
+    impl TyTransformer for CustomTyTransformer {
+        fn bytes(&self) -> CodegenTy {
+            CodegenTy::Custom("::pilota::bytes::Bytes".into(), Arc::from(Vec::new()))
+        }
+
+        fn map(&self, key: &Ty, value: &Ty) -> CodegenTy {
+            let key = self.codegen_item_ty(&key.kind);
+            let value = self.codegen_item_ty(&value.kind);
+            CodegenTy::Custom(
+                "::std::collections::BTreeMap".into(),
+                Arc::from(vec![key, value]),
+            )
         }
+    }
+
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_ty_transformer");
+
+    test_with_builder(
+        test_data_dir.join("custom.thrift"),
+        test_data_dir.join("custom.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ty_transformer(CustomTyTransformer)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_bytes_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir.join("thrift_bytes").join("bytes.thrift"),
+        test_data_dir.join("thrift_bytes").join("bytes.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .use_bytes(true)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("protobuf_bytes").join("bytes.proto"),
+        test_data_dir.join("protobuf_bytes").join("bytes.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .use_bytes(true)
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_shared_trait_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_shared_trait");
+
+    test_thrift(
+        test_data_dir.join("user.thrift"),
+        test_data_dir.join("user.rs"),
+    );
+}
+
+#[test]
+fn test_thrift_dirty_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_dirty");
+
+    test_with_builder(
+        test_data_dir.join("user.thrift"),
+        test_data_dir.join("user.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .track_dirty_fields(true)
+                .gen_client(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_json_value_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_json");
+
+    test_with_builder(
+        test_data_dir.join("nested.thrift"),
+        test_data_dir.join("nested.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_to_json_value(true)
+                .gen_encoded_len(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_type_name_prefix() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_prefix");
+
+    test_with_builder(
+        test_data_dir.join("account.thrift"),
+        test_data_dir.join("account.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .type_name_prefix("Pb")
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_any_message() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir.join("thrift_any").join("envelope.thrift"),
+        test_data_dir.join("thrift_any").join("envelope.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_any_message(true)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("protobuf_any").join("envelope.proto"),
+        test_data_dir.join("protobuf_any").join("envelope.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_any_message(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_field_wire_types() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir.join("thrift_wire").join("sample.thrift"),
+        test_data_dir.join("thrift_wire").join("sample.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_field_wire_types(true)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("protobuf_wire").join("sample.proto"),
+        test_data_dir.join("protobuf_wire").join("sample.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_field_wire_types(true)
+                .compile(&[source], target)
+        },
+    );
+
+    // the repeated scalars of proto2 are packed by the `packed` option only
+    test_with_builder(
+        test_data_dir.join("protobuf_wire").join("legacy.proto"),
+        test_data_dir.join("protobuf_wire").join("legacy.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_field_wire_types(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_protobuf_presence() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_presence");
+
+    test_with_builder(
+        test_data_dir.join("presence.proto"),
+        test_data_dir.join("presence.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_has_accessors(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_protobuf_field_options() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{rir::NodeKind, tags::protobuf::CustomOptions};
+
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_options");
+
+    let options = Arc::new(Mutex::new(Vec::new()));
+    let seen = options.clone();
+    test_with_builder(
+        test_data_dir.join("options.proto"),
+        test_data_dir.join("options.rs"),
+        move |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .node_plugin(
+                    move |_, node: &mut crate::rir::Node, tags: &mut crate::tags::Tags| {
+                        if let (NodeKind::Field(f), Some(o)) =
+                            (&node.kind, tags.get::<CustomOptions>())
+                        {
+                            seen.lock().unwrap().push((f.name.to_string(), o.0.clone()));
+                        }
+                    },
+                )
+                .compile(&[source], target)
+        },
+    );
+
+    assert_eq!(
+        *options.lock().unwrap(),
+        vec![(
+            "secret".to_string(),
+            vec![
+                (Arc::from("options.label"), Arc::from("secret")),
+                (Arc::from("options.sensitive"), Arc::from("true")),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn test_empty_optional_collections() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_empty_collections");
+
+    test_with_builder(
+        test_data_dir.join("profile.thrift"),
+        test_data_dir.join("profile.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .empty_optional_collections(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_enum_string_conversions() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_enum_str");
+
+    test_with_builder(
+        test_data_dir.join("level.thrift"),
+        test_data_dir.join("level.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .enum_string_conversions(true)
+                .compile(&[source], target)
+        },
+    );
+
+    test_with_builder(
+        test_data_dir.join("level.thrift"),
+        test_data_dir.join("level_open.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .enum_string_conversions(true)
+                .open_enums(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "more than one variant of `Level` is written as `INFO`")]
+fn test_enum_string_conversions_duplicate() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("level.thrift");
+    std::fs::write(&path, "enum Level {\n    INFO = 1,\n    INFO = 2,\n}\n").unwrap();
+
+    crate::Builder::thrift()
+        .enum_string_conversions(true)
+        .compile(&[&path], dir.path().join("level.rs"));
+}
+
+#[test]
+fn test_changed_from() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_changed");
+
+    test_with_builder(
+        test_data_dir.join("audit.thrift"),
+        test_data_dir.join("audit.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_changed_from(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_keyword_suffix() {
+    let source = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("keywords.thrift");
+    let dir = tempdir().unwrap();
+    let target = dir.path().join("keywords.rs");
+
+    crate::Builder::thrift()
+        .with_serde(true)
+        .keyword_suffix("_kw")
+        .compile(&[source], &target);
+
+    let code = std::fs::read_to_string(target).unwrap();
+    assert!(code.contains("pub r#type: ::std::string::String"));
+    assert!(code.contains("#[serde(rename = \"self\")]\n            pub self_kw:"));
+    assert!(code.contains("pub struct Self_kw"));
+    assert!(!code.contains("self_:"));
+}
+
+#[test]
+fn test_visibility() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_visibility");
+
+    for (visibility, target) in [
+        (crate::Visibility::Crate, "inventory.rs"),
+        (crate::Visibility::Inherited, "inventory_inherited.rs"),
+    ] {
+        test_with_builder(
+            test_data_dir.join("inventory.thrift"),
+            test_data_dir.join(target),
+            |source, target| {
+                crate::Builder::thrift()
+                    .visibility(visibility)
+                    .compile(&[source], target)
+            },
+        );
+    }
+}
+
+#[test]
+fn test_rename_modules() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_rename");
+
+    test_with_builder(
+        test_data_dir.join("api.thrift"),
+        test_data_dir.join("api.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .rename_modules(|path| {
+                    path[path.len() - 2..]
+                        .iter()
+                        .map(|s| &**s)
+                        .collect::<Vec<_>>()
+                        .join("_")
+                        .into()
+                })
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "the packages `com.example.api.v1` and `com.example.common.types` are both renamed to `example`"
+)]
+fn test_rename_modules_collision() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_rename");
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .rename_modules(|path| path[1].clone())
+        .compile(
+            &[test_data_dir.join("api.thrift")],
+            dir.path().join("api.rs"),
+        );
+}
+
+#[test]
+fn test_extern_package() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_extern");
+
+    test_protobuf(
+        test_data_dir.join("common.proto"),
+        test_data_dir.join("common.rs"),
+    );
+    test_with_builder(
+        test_data_dir.join("order.proto"),
+        test_data_dir.join("order.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .extern_package(
+                    "common",
+                    quote::quote!(crate::test::extern_package::common::common::common),
+                )
+                .compile(&[source], target)
+        },
+    );
+    // the prefix is not added to the types of the other crate
+    test_with_builder(
+        test_data_dir.join("order.proto"),
+        test_data_dir.join("order_prefixed.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .type_name_prefix("Pb")
+                .extern_package(
+                    "common",
+                    quote::quote!(crate::test::extern_package::common::common::common),
+                )
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_well_known_types() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_wkt");
+
+    ["wrappers", "event"].into_iter().for_each(|name| {
+        test_with_builder(
+            test_data_dir.join(format!("{name}.proto")),
+            test_data_dir.join(format!("{name}.rs")),
+            |source, target| {
+                crate::Builder::protobuf()
+                    .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                    .with_well_known_types(true)
+                    .gen_service_methods(true)
+                    .compile(&[source], target)
+            },
+        );
     });
 }
+
+#[test]
+fn test_with_builders() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_builder");
+
+    test_with_builder(
+        test_data_dir.join("account.thrift"),
+        test_data_dir.join("account.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .with_builders(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_gen_constructors() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_constructor");
+
+    test_with_builder(
+        test_data_dir.join("user.thrift"),
+        test_data_dir.join("user.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_constructors(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_deprecated() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_deprecated");
+
+    test_with_builder(
+        test_data_dir.join("legacy.thrift"),
+        test_data_dir.join("legacy.rs"),
+        |source, target| crate::Builder::thrift().compile(&[source], target),
+    );
+}
+
+#[test]
+fn test_ord_by_id() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_ord");
+
+    test_with_builder(
+        test_data_dir.join("version.thrift"),
+        test_data_dir.join("version.rs"),
+        |source, target| crate::Builder::thrift().compile(&[source], target),
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "can not order `Score` by field id: the type of the field `values` does not implement `Ord`"
+)]
+fn test_ord_by_id_with_hash_map() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("score.thrift");
+    std::fs::write(
+        &path,
+        "struct Score {\n    1: required i32 id,\n    2: required map<string, i32> values,\n} (ord_by_id = \"true\")\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift().compile(&[&path], dir.path().join("score.rs"));
+}
+
+#[test]
+fn test_collection_kind() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_collections");
+
+    test_with_builder(
+        test_data_dir.join("ordered.thrift"),
+        test_data_dir.join("ordered.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .collection_kind(crate::CollectionKind::BTree)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_flatten_single_package() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_flatten");
+
+    test_with_builder(
+        test_data_dir.join("api.thrift"),
+        test_data_dir.join("api.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .flatten_single_package(true)
+                .compile(&[source], target)
+        },
+    );
+
+    // only the prefix shared by all the packages is stripped
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("common.thrift"),
+        "namespace rs shop.common\n\nstruct Money {\n    1: required i64 cents,\n}\n",
+    )
+    .unwrap();
+    let path = dir.path().join("order.thrift");
+    std::fs::write(
+        &path,
+        "namespace rs shop.order\n\ninclude \"common.thrift\"\n\nstruct Order {\n    1: required common.Money total,\n}\n",
+    )
+    .unwrap();
+    let out = dir.path().join("out.rs");
+    crate::Builder::thrift()
+        .flatten_single_package(true)
+        .formatter(crate::Formatter::None)
+        .compile(&[&path], &out);
+    let generated = std::fs::read_to_string(&out).unwrap();
+    assert!(generated.starts_with("pub mod out { # ! [allow"));
+    assert!(generated.contains("pub mod common {"));
+    assert!(generated.contains("pub use super :: common as common ;"));
+    assert!(!generated.contains("pub mod shop"));
+}
+
+#[test]
+fn test_dry_run() {
+    let source = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_flatten")
+        .join("api.thrift");
+    let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+    let layout = crate::Builder::thrift().dry_run(&[&source]);
+    assert_eq!(
+        layout.modules.into_iter().collect::<Vec<_>>(),
+        [(
+            "api".to_string(),
+            names(&[
+                "Account",
+                "AccountService",
+                "AccountServiceGetResult",
+                "AccountServiceGetArgs",
+                "Status",
+                "Money",
+            ])
+        )]
+    );
+
+    let layout = crate::Builder::thrift()
+        .flatten_single_package(true)
+        .dry_run(&[&source]);
+    assert_eq!(layout.modules.keys().collect::<Vec<_>>(), [""]);
+
+    // the packages between the root and the ones with items are listed too
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("order.thrift");
+    std::fs::write(
+        &path,
+        "namespace rs shop.order\n\nconst i32 MAX_ITEMS = 10\n\nstruct Order {\n    1: required i64 id,\n}\n",
+    )
+    .unwrap();
+    let layout = crate::Builder::thrift().dry_run(&[&path]);
+    assert_eq!(
+        layout.modules.into_iter().collect::<Vec<_>>(),
+        [
+            ("shop".to_string(), vec![]),
+            ("shop::order".to_string(), names(&["MAX_ITEMS", "Order"])),
+        ]
+    );
+}
+
+#[test]
+fn test_gen_view_types() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_view");
+
+    test_with_builder(
+        test_data_dir.join("order.thrift"),
+        test_data_dir.join("order.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_view_types(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_preserve_unknown_fields() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_unknown");
+
+    test_with_builder(
+        test_data_dir.join("user.thrift"),
+        test_data_dir.join("user.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .preserve_unknown_fields(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_preserve_unknown_proto_fields() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_unknown");
+
+    test_with_builder(
+        test_data_dir.join("user.proto"),
+        test_data_dir.join("user.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .preserve_unknown_fields(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_preserve_unknown_proto2_fields() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_unknown");
+
+    for (preserve_unknown_fields, target) in [(true, "legacy.rs"), (false, "legacy_derive.rs")] {
+        test_with_builder(
+            test_data_dir.join("legacy.proto"),
+            test_data_dir.join(target),
+            |source, target| {
+                crate::Builder::protobuf()
+                    .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                    .preserve_unknown_fields(preserve_unknown_fields)
+                    .compile(&[source], target)
+            },
+        );
+    }
+}
+
+#[test]
+fn test_default_impl() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_default_impl");
+
+    for (default_impl, target) in [
+        (crate::DefaultImpl::Derive, "settings_derive.rs"),
+        (crate::DefaultImpl::Manual, "settings_manual.rs"),
+    ] {
+        test_with_builder(
+            test_data_dir.join("settings.thrift"),
+            test_data_dir.join(target),
+            |source, target| {
+                crate::Builder::thrift()
+                    .default_impl(default_impl)
+                    .compile(&[source], target)
+            },
+        );
+    }
+}
+
+#[test]
+fn test_transparent_typedefs() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_typedef");
+
+    test_with_builder(
+        test_data_dir.join("event.thrift"),
+        test_data_dir.join("event.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .transparent_typedefs(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_union_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_union");
+
+    test_with_builder(
+        test_data_dir.join("union.thrift"),
+        test_data_dir.join("union.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .unknown_union_variant(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_open_enums() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_open_enum");
+
+    test_with_builder(
+        test_data_dir.join("status.thrift"),
+        test_data_dir.join("status.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .open_enums(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_derives() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_derive");
+
+    test_with_builder(
+        test_data_dir.join("derive.thrift"),
+        test_data_dir.join("derive.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .derive(&["Hash", "Default"])
+                .derive_if(&["Copy"], |_, name| name == "Point" || name == "Color")
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "can not derive `Hash` for `Line`: `Point` has a float field")]
+fn test_derive_hash_with_float() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("float.thrift");
+    std::fs::write(
+        &path,
+        "struct Point {\n    1: required double x,\n}\n\nstruct Line {\n    1: required Point from,\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .derive_if(&["Hash"], |_, name| name == "Line")
+        .compile(&[&path], dir.path().join("float.rs"));
+}
+
+#[test]
+#[should_panic(expected = "can not derive `Hash` for `Index`: `Index` has a map field")]
+fn test_derive_hash_with_map() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("map.thrift");
+    std::fs::write(
+        &path,
+        "struct Index {\n    1: required map<string, i32> ids,\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .derive(&["Hash"])
+        .compile(&[&path], dir.path().join("map.rs"));
+}
+
+#[test]
+fn test_derive_hash_with_btree_map() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("map.thrift");
+    let out = dir.path().join("map.rs");
+    std::fs::write(
+        &path,
+        "struct Index {\n    1: required map<string, i32> ids (rust.collection = \"btree\"),\n    2: required set<i32> tags (rust.collection = \"btree\"),\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .derive(&["Hash"])
+        .compile(&[&path], &out);
+    assert!(std::fs::read_to_string(&out)
+        .unwrap()
+        .contains("#[derive(Debug, Default, Hash, Clone, PartialEq)]"));
+}
+
+#[test]
+fn test_doc_stubs() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_docs");
+
+    test_with_builder(
+        test_data_dir.join("docs.thrift"),
+        test_data_dir.join("docs.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_doc_stubs(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_leading_underscore() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_underscore");
+
+    [
+        (crate::LeadingUnderscore::Preserve, "preserve"),
+        (crate::LeadingUnderscore::Strip, "strip"),
+        (crate::LeadingUnderscore::Escape, "escape"),
+    ]
+    .into_iter()
+    .for_each(|(mode, name)| {
+        test_with_builder(
+            test_data_dir.join("underscore.thrift"),
+            test_data_dir.join(format!("underscore_{}.rs", name)),
+            |source, target| {
+                crate::Builder::thrift()
+                    .leading_underscore(mode)
+                    .compile(&[source], target)
+            },
+        );
+    });
+}
+
+#[test]
+fn test_field_case() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_field_case");
+
+    // the cases are built at the same time, each build keeps its own in its context
+    std::thread::scope(|scope| {
+        [
+            (crate::FieldCase::SnakeCase, "snake"),
+            (crate::FieldCase::Preserve, "preserve"),
+            (crate::FieldCase::CamelCase, "camel"),
+        ]
+        .into_iter()
+        .map(|(case, name)| {
+            let test_data_dir = &test_data_dir;
+            scope.spawn(move || {
+                test_with_builder(
+                    test_data_dir.join("field_case.thrift"),
+                    test_data_dir.join(format!("field_case_{}.rs", name)),
+                    |source, target| {
+                        crate::Builder::thrift()
+                            .with_serde(true)
+                            .field_case(case)
+                            .compile(&[source], target)
+                    },
+                )
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+    });
+}
+
+#[test]
+fn test_protobuf_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf");
+
+    test_data_dir.read_dir().unwrap().for_each(|f| {
+        let f = f.unwrap();
+
+        let path = f.path();
+
+        if let Some(ext) = path.extension() {
+            if ext == "proto" {
+                let mut rs_path = path.clone();
+                rs_path.set_extension("rs");
+                test_protobuf(path, rs_path);
+            }
+        }
+    });
+}
+
+#[test]
+fn test_protobuf_service_methods() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_service");
+
+    test_with_builder(
+        test_data_dir.join("streaming.proto"),
+        test_data_dir.join("streaming.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_service_methods(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_protobuf_wire_names() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_any");
+
+    test_with_builder(
+        test_data_dir.join("well_known.proto"),
+        test_data_dir.join("well_known.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_wire_names(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_protobuf_oneof_reexport_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_reexport");
+
+    test_with_builder(
+        test_data_dir.join("oneof.proto"),
+        test_data_dir.join("oneof.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .reexport_oneof_payloads(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_oneof_from_impls_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_oneof_from");
+
+    test_with_builder(
+        test_data_dir.join("size.proto"),
+        test_data_dir.join("size.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_oneof_from_impls(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_oneof_dispatcher_gen() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_dispatch");
+
+    test_with_builder(
+        test_data_dir.join("shape.proto"),
+        test_data_dir.join("shape.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_oneof_dispatcher(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_thrift_json() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir.join("thrift_tjson").join("tjson.thrift"),
+        test_data_dir.join("thrift_tjson").join("tjson.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_thrift_json(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_small_int_width() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_small_ints");
+
+    test_with_builder(
+        test_data_dir.join("ints.thrift"),
+        test_data_dir.join("ints.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .small_int_width(crate::SmallIntWidth::I32)
+                .gen_thrift_json(true)
+                .gen_encoded_len(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_arc_fields() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir
+            .join("thrift_arc_fields")
+            .join("arc_fields.thrift"),
+        test_data_dir
+            .join("thrift_arc_fields")
+            .join("arc_fields.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .arc_fields_if(|_, name| name != "Inner")
+                .with_builders(true)
+                .gen_encoded_len(true)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_box_large_variants() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    test_with_builder(
+        test_data_dir
+            .join("thrift_box_variants")
+            .join("payload.thrift"),
+        test_data_dir.join("thrift_box_variants").join("payload.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .gen_to_json_value(true)
+                .box_large_variants(32)
+                .gen_encoded_len(true)
+                .compile(&[source], target)
+        },
+    );
+    test_with_builder(
+        test_data_dir
+            .join("protobuf_box_variants")
+            .join("event.proto"),
+        test_data_dir.join("protobuf_box_variants").join("event.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .gen_oneof_dispatcher(true)
+                .gen_oneof_from_impls(true)
+                .box_large_variants(16)
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_input_glob() {
+    use crate::parser::{glob, Parser, ProtobufParser, ThriftParser};
+
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("sub/deep")).unwrap();
+    std::fs::write(root.join("common.thrift"), "namespace rs common\n").unwrap();
+    std::fs::write(
+        root.join("sub/a.thrift"),
+        "include \"../common.thrift\"\nstruct A {}",
+    )
+    .unwrap();
+    std::fs::write(root.join("sub/deep/b.thrift"), "struct B {}").unwrap();
+    std::fs::write(root.join("sub/deep/b.thrift.bak"), "").unwrap();
+    std::fs::write(root.join("c.proto"), "syntax = \"proto3\";\nmessage C {}").unwrap();
+    std::fs::write(
+        root.join("d.proto"),
+        "syntax = \"proto3\";\n// message D {}",
+    )
+    .unwrap();
+
+    let names = |paths: Vec<std::path::PathBuf>| {
+        paths
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().display().to_string())
+            .collect::<Vec<_>>()
+    };
+    let pattern = |p: &str| format!("{}/{p}", root.display());
+    assert_eq!(
+        names(glob(&pattern("**/*.thrift"))),
+        ["common.thrift", "sub/a.thrift", "sub/deep/b.thrift"]
+    );
+    assert_eq!(
+        names(glob(&pattern("sub/**/?.thrift"))),
+        ["sub/a.thrift", "sub/deep/b.thrift"]
+    );
+    assert_eq!(
+        names(glob(&pattern("**/**/*.proto"))),
+        ["c.proto", "d.proto"]
+    );
+    assert!(glob(&pattern("missing/*.thrift")).is_empty());
+
+    let parser = ThriftParser::default();
+    assert!(parser.is_include_only(&root.join("common.thrift")));
+    assert!(!parser.is_include_only(&root.join("sub/a.thrift")));
+
+    let mut parser = ThriftParser::default();
+    parser.input_glob(&pattern("**/*.thrift"), true);
+    let result = parser.parse().unwrap();
+    // `common.thrift` is parsed as the include of `a.thrift`
+    assert_eq!(result.files.len(), 3);
+
+    // the files are parsed, not read line by line
+    std::fs::write(
+        root.join("e.proto"),
+        "syntax = \"proto3\";\n/*\nmessage E {}\n*/\n",
+    )
+    .unwrap();
+    std::fs::write(root.join("f.proto"), "syntax = \"proto3\"; message F {}\n").unwrap();
+    let parser = ProtobufParser::default();
+    assert!(!parser.is_include_only(&root.join("c.proto")));
+    assert!(parser.is_include_only(&root.join("d.proto")));
+    assert!(parser.is_include_only(&root.join("e.proto")));
+    assert!(!parser.is_include_only(&root.join("f.proto")));
+
+    let out = root.join("glob.rs");
+    crate::Builder::thrift()
+        .input_glob(pattern("sub/**/*.thrift"), true)
+        .compile(&[root.join("sub/a.thrift")], &out);
+    let generated = std::fs::read_to_string(out).unwrap();
+    assert_eq!(generated.matches("pub struct A {").count(), 1);
+    assert_eq!(generated.matches("pub struct B {").count(), 1);
+}
+
+#[test]
+fn test_convert() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_convert");
+
+    test_with_builder(
+        test_data_dir.join("convert.thrift"),
+        test_data_dir.join("convert.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .convert("convert.AddressV1", "convert.AddressV2")
+                .convert("convert.UserV1", "convert.UserV2")
+                .compile(&[source], target)
+        },
+    );
+}
+
+#[test]
+fn test_method_exceptions() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_exceptions");
+
+    test_with_builder(
+        test_data_dir.join("exceptions.thrift"),
+        test_data_dir.join("exceptions.rs"),
+        |source, target| crate::Builder::thrift().compile(&[source], target),
+    );
+}
+
+#[test]
+#[should_panic(expected = "the field `nothing` of `Canvas` has no value")]
+fn test_required_empty_union() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("empty.thrift");
+    std::fs::write(
+        &path,
+        "union Empty {\n}\nstruct Canvas {\n    1: required Empty nothing,\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift().compile(&[&path], dir.path().join("empty.rs"));
+}
+
+#[test]
+#[should_panic(expected = "the field `id` of `A` can't be converted to the one of `B`")]
+fn test_convert_mismatch() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("mismatch.thrift");
+    std::fs::write(
+        &path,
+        "struct A {\n    1: required i64 id,\n}\nstruct B {\n    1: required string id,\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .convert("mismatch.A", "mismatch.B")
+        .compile(&[&path], dir.path().join("mismatch.rs"));
+}
+
+#[test]
+fn test_protobuf_convert() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_convert");
+
+    test_with_builder(
+        test_data_dir.join("v2.proto"),
+        test_data_dir.join("convert.rs"),
+        |source, target| {
+            let dir = source.parent().unwrap();
+            crate::Builder::protobuf()
+                .include_dirs(vec![dir.to_path_buf()])
+                .convert("v1.Item", "v2.Item")
+                .convert("v1.Order", "v2.Order")
+                .convert("v1.Order.Line", "v2.Order.Line")
+                .compile(&[&dir.join("v1.proto"), source], target)
+        },
+    );
+}
+
+#[test]
+fn test_resolve_conversions() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+    let pairs = |from: &str, to: &str| vec![(from.to_string(), to.to_string())];
+
+    // the thrift names are the ones of the IDL, whatever the prefix of the types is
+    let thrift = test_data_dir.join("thrift_convert").join("convert.thrift");
+    let resolved = crate::Builder::thrift()
+        .type_name_prefix("Pb")
+        .generate(&[&thrift], |cx| {
+            cx.resolve_conversions(&pairs("convert.UserV1", "convert.UserV2"))
+        });
+    assert_eq!(resolved.unwrap().len(), 1);
+
+    // and the packages of the IDL, whatever the modules are renamed to
+    let resolved = crate::Builder::thrift()
+        .rename_modules(|_| "renamed".into())
+        .generate(&[&thrift], |cx| {
+            cx.resolve_conversions(&pairs("convert.UserV1", "convert.UserV2"))
+        });
+    assert_eq!(resolved.unwrap().len(), 1);
+
+    // the oneofs of the converted messages are converted along with them
+    let protobuf = test_data_dir.join("protobuf_convert");
+    let resolved = crate::Builder::protobuf()
+        .include_dirs(vec![protobuf.clone()])
+        .generate(
+            &[protobuf.join("v1.proto"), protobuf.join("v2.proto")],
+            |cx| cx.resolve_conversions(&pairs("v1.Order", "v2.Order")),
+        );
+    assert_eq!(resolved.unwrap().len(), 2);
+
+    let resolved = crate::Builder::thrift().generate(&[&thrift], |cx| {
+        cx.resolve_conversions(&pairs("convert.UserV1", "convert.Missing"))
+    });
+    assert_eq!(
+        resolved.unwrap_err(),
+        "no message named `convert.Missing` to convert"
+    );
+}
+
+#[test]
+fn test_parse_deps() {
+    use crate::parser::{ParseResult, Parser, ProtobufParser, ThriftParser};
+
+    // the edges between the file names
+    fn edges(result: ParseResult) -> Vec<(String, String)> {
+        let name = |id| {
+            let f = result.files.iter().find(|f| f.id == id).unwrap();
+            f.path.file_name().unwrap().to_str().unwrap().to_string()
+        };
+        let mut edges = result
+            .deps
+            .iter()
+            .flat_map(|(from, deps)| deps.iter().map(|to| (name(*from), name(*to))))
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges
+    }
+
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("c.thrift"), "struct C {}").unwrap();
+    std::fs::write(
+        dir.path().join("b.thrift"),
+        "include \"c.thrift\"\nstruct B { 1: required c.C c }",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("a.thrift"),
+        "include \"b.thrift\"\ninclude \"c.thrift\"\nstruct A { 1: required b.B b }",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(dir.path().join("a.thrift"));
+    let result = parser.parse().unwrap();
+    assert_eq!(result.deps.len(), 3);
+    assert_eq!(
+        edges(result),
+        vec![
+            ("a.thrift".into(), "b.thrift".into()),
+            ("a.thrift".into(), "c.thrift".into()),
+            ("b.thrift".into(), "c.thrift".into()),
+        ]
+    );
+
+    std::fs::write(
+        dir.path().join("c.proto"),
+        "syntax = \"proto3\";\nmessage C {}",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("b.proto"),
+        "syntax = \"proto3\";\nimport \"c.proto\";\nmessage B { C c = 1; }",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("a.proto"),
+        "syntax = \"proto3\";\nimport \"b.proto\";\nmessage A { B b = 1; }",
+    )
+    .unwrap();
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf()]);
+    parser.input(dir.path().join("a.proto"));
+    let result = parser.parse().unwrap();
+    assert_eq!(result.deps.len(), 3);
+    assert_eq!(
+        edges(result),
+        vec![
+            ("a.proto".into(), "b.proto".into()),
+            ("b.proto".into(), "c.proto".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_orphaned_nested_items() {
+    use crate::{
+        parser::{ParseResult, Parser, ProtobufParser},
+        resolve::{orphaned_nested_items, ResolveResult, Resolver},
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("orphan.proto");
+    std::fs::write(
+        &path,
+        r#"
+        syntax = "proto3";
+
+        message A {
+            message Used {}
+            message Unused {
+                Unused next = 1;
+            }
+
+            Used used = 1;
+        }
+
+        message B {}
+        "#,
+    )
+    .unwrap();
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf()]);
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let ResolveResult { nodes, .. } = Resolver::default().resolve_files(&files).unwrap();
+
+    let orphans = orphaned_nested_items(&nodes, &Default::default(), &Default::default())
+        .into_iter()
+        .map(|def_id| nodes[&def_id].expect_item().symbol_name())
+        .collect::<Vec<_>>();
+
+    assert_eq!(orphans, vec!["Unused".into()]);
+}
+
+#[test]
+fn test_references_to() {
+    use crate::{
+        parser::{ParseResult, Parser, ThriftParser},
+        resolve::Resolver,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("refs.thrift");
+    std::fs::write(
+        &path,
+        r#"
+        enum Role {
+            ADMIN = 1,
+            GUEST = 2,
+        }
+
+        typedef Role RoleAlias
+
+        const Role DEFAULT_ROLE = Role.GUEST
+
+        struct User {
+            1: required Role role,
+        }
+
+        struct Group {
+            1: required map<string, set<Role>> roles,
+            2: required list<User> users,
+        }
+
+        union Grant {
+            1: Role role,
+        }
+
+        struct Unrelated {
+            1: required i32 x,
+        }
+
+        exception Denied {
+            1: required Role required_role,
+        }
+
+        service RoleService {
+            Role get_role(1: User user) throws (1: Denied denied),
+            void set_role(1: RoleAlias role),
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let result = Resolver::default().resolve_files(&files).unwrap();
+
+    let def_id_of = |name: &str| {
+        *result
+            .nodes
+            .iter()
+            .find(|(_, node)| {
+                matches!(&node.kind, crate::rir::NodeKind::Item(item) if &*item.symbol_name() == name)
+            })
+            .unwrap()
+            .0
+    };
+    let names = |def_ids: Vec<crate::DefId>| {
+        let mut names = def_ids
+            .into_iter()
+            .map(|def_id| {
+                result.nodes[&def_id]
+                    .expect_item()
+                    .symbol_name()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    };
+
+    assert_eq!(
+        names(result.references_to(def_id_of("Role"))),
+        vec![
+            "DEFAULT_ROLE",
+            "Denied",
+            "Grant",
+            "Group",
+            "RoleAlias",
+            "RoleService",
+            "RoleServiceGetRoleResult",
+            "User"
+        ]
+    );
+    assert_eq!(
+        names(result.references_to(def_id_of("User"))),
+        vec!["Group", "RoleService", "RoleServiceGetRoleArgs"]
+    );
+    assert!(result.references_to(def_id_of("Unrelated")).is_empty());
+}
+
+#[test]
+fn test_tags_get_mut_and_remove() {
+    use crate::tags::Tags;
+
+    #[derive(Debug, PartialEq, Clone)]
+    struct Derives(Vec<&'static str>);
+
+    let mut tags = Tags::default();
+    assert!(tags.get_mut::<Derives>().is_none());
+
+    tags.insert(Derives(vec!["Debug"]));
+    tags.get_mut::<Derives>().unwrap().0.push("Clone");
+    assert_eq!(
+        tags.get::<Derives>(),
+        Some(&Derives(vec!["Debug", "Clone"]))
+    );
+
+    assert_eq!(
+        tags.remove::<Derives>(),
+        Some(Derives(vec!["Debug", "Clone"]))
+    );
+    assert!(!tags.contains::<Derives>());
+    assert!(tags.remove::<Derives>().is_none());
+
+    // a clone owns its values, changing them leaves the original map untouched
+    tags.insert(Derives(vec!["Debug"]));
+    let mut clone = tags.clone();
+    clone.get_mut::<Derives>().unwrap().0.push("Clone");
+    assert_eq!(tags.get::<Derives>(), Some(&Derives(vec!["Debug"])));
+    assert_eq!(
+        clone.remove::<Derives>(),
+        Some(Derives(vec!["Debug", "Clone"]))
+    );
+    assert_eq!(tags.remove::<Derives>(), Some(Derives(vec!["Debug"])));
+}
+
+#[test]
+fn test_tags_merge() {
+    use crate::tags::{Derives, Tags};
+
+    #[derive(Debug, PartialEq, Clone)]
+    struct Name(&'static str);
+    #[derive(Debug, PartialEq, Clone)]
+    struct Deprecated;
+
+    let mut tags = crate::tags!(Name("local"));
+    tags.merge(crate::tags!(Name("inherited"), Deprecated));
+
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags.get::<Name>(), Some(&Name("local")));
+    assert_eq!(tags.get::<Deprecated>(), Some(&Deprecated));
+
+    let mut tags = crate::tags!(Derives(vec!["Hash".into()]));
+    tags.merge(crate::tags!(Derives(vec!["Eq".into(), "Hash".into()])));
+    assert_eq!(
+        tags.get::<Derives>().unwrap().0,
+        ["Hash".into(), "Eq".into()] as [std::sync::Arc<str>; 2]
+    );
+
+    #[derive(Debug, PartialEq, Clone)]
+    struct Count(u32);
+    crate::tags::register_merge(|a: &Count, b| Count(a.0 + b.0));
+    let mut tags = crate::tags!(Count(1));
+    tags.merge(crate::tags!(Count(2)));
+    assert_eq!(tags.get::<Count>(), Some(&Count(3)));
+
+    let mut empty = Tags::default();
+    empty.merge(Tags::default());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_tags_debug() {
+    use std::any::TypeId;
+
+    use crate::tags::{protobuf::OneOf, register_debug, RustAttrs, Tags};
+
+    #[derive(Debug, Clone)]
+    struct Packed(bool);
+    #[derive(Clone)]
+    struct Wrapper<T>(#[allow(dead_code)] T);
+
+    let tags = crate::tags!(RustAttrs(vec![]), OneOf, Packed(true), Wrapper(OneOf));
+
+    let mut ids = tags.type_ids().collect::<Vec<_>>();
+    ids.sort();
+    let mut expected = vec![
+        TypeId::of::<RustAttrs>(),
+        TypeId::of::<OneOf>(),
+        TypeId::of::<Packed>(),
+        TypeId::of::<Wrapper<OneOf>>(),
+    ];
+    expected.sort();
+    assert_eq!(ids, expected);
+    assert!(tags
+        .type_names()
+        .any(|name| name == "pilota_build::tags::RustAttrs"));
+
+    assert!(tags.get::<Packed>().unwrap().0);
+    register_debug::<Packed>();
+    assert_eq!(
+        format!("{:?}", tags),
+        "{OneOf, Packed(true), RustAttrs, Wrapper<pilota_build::tags::protobuf::OneOf>}"
+    );
+    assert_eq!(format!("{:?}", Tags::default()), "{}");
+}
+
+#[test]
+fn test_protobuf_missing_import() {
+    use crate::parser::{Parser, ProtobufParser};
+
+    let dir = tempdir().unwrap();
+    let other = tempdir().unwrap();
+    let main = dir.path().join("main.proto");
+    std::fs::write(
+        &main,
+        "syntax = \"proto3\";\nimport \"missing.proto\";\nmessage A {}\n",
+    )
+    .unwrap();
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf(), other.path().to_path_buf()]);
+    parser.input(&main);
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, main);
+    assert_eq!(
+        err.message,
+        format!(
+            "include file `missing.proto` not found, searched in: {}, {}",
+            dir.path().display(),
+            other.path().display()
+        )
+    );
+
+    // the error names the file importing the missing one, the embedded well-known types are
+    // found anyway
+    let nested = other.path().join("nested.proto");
+    std::fs::write(
+        &nested,
+        "syntax = \"proto3\";\nimport \"google/protobuf/empty.proto\";\nimport \"gone.proto\";\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &main,
+        "syntax = \"proto3\";\nimport \"nested.proto\";\nmessage A {}\n",
+    )
+    .unwrap();
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf(), other.path().to_path_buf()]);
+    parser.input(&main);
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, nested);
+    assert!(err
+        .message
+        .starts_with("include file `gone.proto` not found, searched in: "));
+}
+
+#[test]
+fn test_resolve_cache() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{cache::CachedResolve, MemoryResolveCache, ResolveCache};
+
+    // counts the results stored after parsing, a cache hit skips the parser and stores none
+    #[derive(Default)]
+    struct CountingCache {
+        inner: MemoryResolveCache,
+        inserts: AtomicUsize,
+    }
+
+    impl ResolveCache for CountingCache {
+        fn get(&self, key: u64) -> Option<Arc<CachedResolve>> {
+            self.inner.get(key)
+        }
+
+        fn insert(&self, key: u64, resolve: Arc<CachedResolve>) {
+            self.inserts.fetch_add(1, Ordering::SeqCst);
+            self.inner.insert(key, resolve)
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let a = dir.path().join("a.thrift");
+    let b = dir.path().join("b.thrift");
+    let out = dir.path().join("out.rs");
+    std::fs::write(
+        &a,
+        "include \"b.thrift\"\nstruct A { 1: required b.B b, }\n",
+    )
+    .unwrap();
+    std::fs::write(&b, "struct B { 1: required i32 x, }\n").unwrap();
+
+    let cache = Arc::new(CountingCache::default());
+    let compile = || {
+        crate::Builder::thrift()
+            .resolve_cache(cache.clone())
+            .compile(&[&a], &out);
+        std::fs::read_to_string(&out).unwrap()
+    };
+    let entry = || {
+        cache
+            .inner
+            .0
+            .lock()
+            .unwrap()
+            .values()
+            .next()
+            .cloned()
+            .unwrap()
+    };
+    let parses = || cache.inserts.load(Ordering::SeqCst);
+
+    let first = compile();
+    assert_eq!(parses(), 1);
+    assert!(entry().is_fresh());
+    assert_eq!(entry().deps().count(), 2);
+    assert_eq!(compile(), first);
+    assert_eq!(parses(), 1);
+
+    // only the included file changes
+    std::fs::write(&b, "struct B { 1: required i32 x, 2: required i32 y, }\n").unwrap();
+    assert!(!entry().is_fresh());
+    let second = compile();
+    assert_eq!(parses(), 2);
+    assert!(second.contains("pub y: i32"));
+    assert!(entry().is_fresh());
+    assert_eq!(compile(), second);
+    assert_eq!(parses(), 2);
+    assert_eq!(cache.inner.0.lock().unwrap().len(), 1);
+    assert!(cache.get(0).is_none());
+}
+
+#[test]
+fn test_node_plugin() {
+    use std::sync::Arc;
+
+    use crate::{
+        rir::{Item, NodeKind},
+        tags::Derives,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("nodes.thrift");
+    let out = dir.path().join("nodes.rs");
+    std::fs::write(
+        &path,
+        "struct Point {\n    1: required i32 x,\n}\n\nstruct Legacy {\n    1: required Point p,\n}\n",
+    )
+    .unwrap();
+
+    let visited = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = visited.clone();
+    crate::Builder::thrift()
+        .node_plugin(
+            |_, node: &mut crate::rir::Node, tags: &mut crate::tags::Tags| {
+                let NodeKind::Item(item) = &mut node.kind else {
+                    return;
+                };
+                if let Item::Message(s) = &**item {
+                    match &*s.name.sym {
+                        "Point" => tags.insert(Derives(vec![Arc::from("Copy")])),
+                        "Legacy" => {
+                            let mut s = s.clone();
+                            s.name = "Modern".into();
+                            *item = Arc::new(Item::Message(s));
+                        }
+                        _ => {}
+                    }
+                }
+            },
+        )
+        .node_plugin(
+            move |_, node: &mut crate::rir::Node, _: &mut crate::tags::Tags| {
+                if let NodeKind::Item(item) = &node.kind {
+                    seen.lock().unwrap().push(item.symbol_name().to_string());
+                }
+            },
+        )
+        .compile(&[&path], &out);
+
+    let generated = std::fs::read_to_string(&out).unwrap();
+    assert!(generated.contains("Copy, Clone, PartialEq)]\n        pub struct Point"));
+    assert!(generated.contains("pub struct Modern"));
+    assert!(!generated.contains("Legacy"));
+    // the plugins run in order, the second one sees the renamed item
+    assert!(visited.lock().unwrap().contains(&"Modern".to_string()));
+}
+
+#[test]
+fn test_rust_attrs() {
+    use std::sync::Arc;
+
+    use crate::{rir::NodeKind, tags::RustAttrs};
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("attrs.thrift");
+    let out = dir.path().join("attrs.rs");
+    std::fs::write(
+        &path,
+        "struct User {\n    1: required string name,\n    2: required string token,\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .node_plugin(
+            |_, node: &mut crate::rir::Node, tags: &mut crate::tags::Tags| {
+                if matches!(&node.kind, NodeKind::Field(f) if &**f.name == "token") {
+                    tags.insert(RustAttrs(vec![
+                        Arc::from("#[serde(skip)]"),
+                        Arc::from("#[doc(alias = \"secret\")]"),
+                    ]));
+                }
+            },
+        )
+        .compile(&[&path], &out);
+
+    // the attributes of the fields of `User`, compared as token streams
+    let file = syn::parse_file(&std::fs::read_to_string(&out).unwrap()).unwrap();
+    fn find_struct<'a>(items: &'a [syn::Item], name: &str) -> Option<&'a syn::ItemStruct> {
+        items.iter().find_map(|item| match item {
+            syn::Item::Struct(s) if s.ident == name => Some(s),
+            syn::Item::Mod(m) => find_struct(&m.content.as_ref()?.1, name),
+            _ => None,
+        })
+    }
+    let user = find_struct(&file.items, "User").unwrap();
+    let attrs = |name: &str| {
+        user.fields
+            .iter()
+            .find(|f| f.ident.as_ref().unwrap() == name)
+            .unwrap()
+            .attrs
+            .iter()
+            .map(|attr| quote::quote!(#attr).to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        attrs("token"),
+        [
+            quote::quote!(#[serde(skip)]).to_string(),
+            quote::quote!(#[doc(alias = "secret")]).to_string(),
+        ]
+    );
+    assert!(attrs("name").is_empty());
+}
+
+#[test]
+fn test_pkg_tree_interleaved() {
+    use itertools::Itertools;
+
+    use crate::{codegen::pkg_tree::PkgNode, rir::ItemPath, symbol::Symbol};
+
+    fn path(p: &str) -> ItemPath {
+        ItemPath::from(p.split('.').map(Symbol::from).collect::<Vec<_>>())
+    }
+
+    fn tree(nodes: &[PkgNode]) -> Vec<(String, Vec<String>)> {
+        nodes
+            .iter()
+            .map(|n| {
+                (
+                    n.path.iter().join("."),
+                    n.children.iter().map(|c| c.path.iter().join(".")).collect(),
+                )
+            })
+            .collect()
+    }
+
+    let expected = vec![
+        ("a".to_string(), vec!["a.b".to_string(), "a.d".to_string()]),
+        ("c".to_string(), vec![]),
+    ];
+    for pkgs in [
+        ["a.b", "c", "a.d"],
+        ["c", "a.d", "a.b"],
+        ["a.d", "c", "a.b"],
+    ] {
+        let pkgs = pkgs.map(path);
+        assert_eq!(tree(&PkgNode::from_pkgs(&pkgs)), expected);
+    }
+}
+
+#[test]
+fn test_pkg_rust_path_to() {
+    use quote::ToTokens;
+
+    use crate::{codegen::pkg_tree::PkgNode, rir::ItemPath, symbol::Symbol};
+
+    fn path(p: &str) -> ItemPath {
+        ItemPath::from(p.split('.').map(Symbol::from).collect::<Vec<_>>())
+    }
+
+    let pkgs = PkgNode::from_pkgs(&[path("a.b"), path("a.d"), path("c")]);
+    let a_b = &pkgs[0].children[0];
+    let rust_path = |target: &str| {
+        a_b.rust_path_to(&path(target))
+            .to_token_stream()
+            .to_string()
+    };
+
+    assert_eq!(rust_path("a.d.E"), "super :: d :: E");
+    assert_eq!(rust_path("a.b.C"), "C");
+    assert_eq!(rust_path("a.b.c.D"), "c :: D");
+    assert_eq!(rust_path("c.F"), "super :: super :: c :: F");
+}
+
+#[test]
+fn test_path_base() {
+    use crate::PathBase;
+
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("common.thrift"),
+        "namespace rs common\n\nstruct Money {\n    1: required i64 cents,\n}\n",
+    )
+    .unwrap();
+    let path = dir.path().join("order.thrift");
+    std::fs::write(
+        &path,
+        "namespace rs shop.order\n\ninclude \"common.thrift\"\n\nstruct Order {\n    1: required common.Money total,\n}\n",
+    )
+    .unwrap();
+    let proto = dir.path().join("shape.proto");
+    std::fs::write(
+        &proto,
+        "syntax = \"proto3\";\n\npackage shape;\n\nmessage Point {\n    int32 x = 1;\n}\n\nmessage Line {\n    message Segment {\n        Point from = 1;\n    }\n    Segment segment = 1;\n}\n",
+    )
+    .unwrap();
+
+    let generate = |base: PathBase| {
+        let out = dir.path().join("out.rs");
+        crate::Builder::thrift()
+            .path_base(base.clone())
+            .compile(&[&path], &out);
+        let thrift = std::fs::read_to_string(&out).unwrap();
+        crate::Builder::protobuf()
+            .include_dirs(vec![dir.path().to_path_buf()])
+            .path_base(base)
+            .compile(&[&proto], &out);
+        let protobuf = std::fs::read_to_string(&out).unwrap();
+        (thrift, protobuf)
+    };
+
+    for (base, root) in [
+        (PathBase::Relative, None),
+        (PathBase::Crate, Some("crate::out")),
+        (
+            PathBase::Module("crate::proto".into()),
+            Some("crate::proto::out"),
+        ),
+    ] {
+        let (thrift, protobuf) = generate(base);
+        let (common, point) = match root {
+            None => (
+                "pub use super::super::common;".to_string(),
+                "super::Point>".to_string(),
+            ),
+            Some(root) => (
+                format!("pub use {root}::common;"),
+                format!("{root}::shape::Point>"),
+            ),
+        };
+        assert!(thrift.contains(&common), "{thrift}");
+        assert!(protobuf.contains(&point), "{protobuf}");
+        // the items of the same module are still referred to by their names
+        assert!(protobuf.contains("Option<Segment>"), "{protobuf}");
+    }
+}
+
+#[test]
+fn test_formatter() {
+    use crate::Formatter;
+
+    let source = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf")
+        .join("scalars.proto");
+    let dir = tempdir().unwrap();
+
+    let compile = |formatter| {
+        let out = dir.path().join("scalars.rs");
+        crate::Builder::protobuf()
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .formatter(formatter)
+            .compile(&[&source], &out);
+        std::fs::read_to_string(out).unwrap()
+    };
+    let raw = compile(Formatter::None);
+    #[allow(unused_mut)]
+    let mut formatted = vec![compile(Formatter::Rustfmt)];
+    #[cfg(feature = "prettyplease")]
+    formatted.push(compile(Formatter::PrettyPlease));
+
+    for code in formatted {
+        assert_ne!(code, raw);
+        assert!(code.lines().count() > raw.lines().count());
+        assert!(code.parse::<proc_macro2::TokenStream>().is_ok());
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    use crate::parser::{Parser, ThriftParser};
+
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.thrift");
+    std::fs::write(&main, "include \"missing.thrift\"\n\nstruct A {}\n").unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&main);
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, main);
+    assert_eq!(
+        err.message,
+        format!(
+            "include file `missing.thrift` not found, searched in: {}",
+            dir.path().display()
+        )
+    );
+
+    let other = tempdir().unwrap();
+    let mut parser = ThriftParser::default();
+    parser.include_dirs(vec![other.path().to_path_buf()]);
+    parser.input(&main);
+    let err = parser.parse().err().unwrap();
+    assert_eq!(
+        err.message,
+        format!(
+            "include file `missing.thrift` not found, searched in: {}, {}",
+            dir.path().display(),
+            other.path().display()
+        )
+    );
+
+    let broken = dir.path().join("broken.thrift");
+    std::fs::write(&broken, "struct A {}\n\nstruct B {\n").unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&broken);
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, broken);
+    assert_eq!(err.message, "syntax error at line 3");
+}
+
+#[test]
+fn test_include_cycle() {
+    use crate::parser::{Parser, ProtobufParser, ThriftParser};
+
+    let dir = tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    std::fs::write(
+        root.join("a.thrift"),
+        "include \"b.thrift\"\n\nstruct A {}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("b.thrift"),
+        "include \"c.thrift\"\n\nstruct B {}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("c.thrift"),
+        "include \"a.thrift\"\n\nstruct C {}\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(root.join("a.thrift"));
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, root.join("c.thrift"));
+    assert_eq!(
+        err.message,
+        format!(
+            "include cycle: {} -> {} -> {} -> {}",
+            root.join("a.thrift").display(),
+            root.join("b.thrift").display(),
+            root.join("c.thrift").display(),
+            root.join("a.thrift").display(),
+        )
+    );
+
+    for (name, import) in [("a", "b"), ("b", "c"), ("c", "a")] {
+        std::fs::write(
+            root.join(format!("{name}.proto")),
+            format!("syntax = \"proto3\";\nimport \"{import}.proto\";\n"),
+        )
+        .unwrap();
+    }
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![root.clone()]);
+    parser.input(root.join("a.proto"));
+    let err = parser.parse().err().unwrap();
+    assert_eq!(err.path, root.join("c.proto"));
+    assert!(err.message.starts_with("include cycle: "));
+
+    // a file included twice without a cycle is fine
+    std::fs::write(root.join("top.thrift"), "include \"left.thrift\"\ninclude \"right.thrift\"\n\nstruct Top {\n    1: required left.Left l,\n    2: required right.Right r,\n}\n").unwrap();
+    std::fs::write(
+        root.join("left.thrift"),
+        "include \"base.thrift\"\n\nstruct Left {\n    1: required base.Base b,\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("right.thrift"),
+        "include \"base.thrift\"\n\nstruct Right {\n    1: required base.Base b,\n}\n",
+    )
+    .unwrap();
+    std::fs::write(root.join("base.thrift"), "struct Base {}\n").unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(root.join("top.thrift"));
+    assert!(parser.parse().is_ok());
+
+    // an import in a comment is not one, the base file is parsed once
+    let proto = |name: &str, body: &str| {
+        std::fs::write(
+            root.join(format!("{name}.proto")),
+            format!("syntax = \"proto3\";\n{body}"),
+        )
+        .unwrap()
+    };
+    proto(
+        "top",
+        "import \"left.proto\";\nimport \"right.proto\";\nmessage Top {\n  Left l = 1;\n  Right r = 2;\n}\n",
+    );
+    proto(
+        "left",
+        "import \"base.proto\";\nmessage Left {\n  Base b = 1;\n}\n",
+    );
+    proto(
+        "right",
+        "import \"base.proto\";\nmessage Right {\n  Base b = 1;\n}\n",
+    );
+    proto("base", "/*\nimport \"top.proto\";\n*/\nmessage Base {}\n");
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![root.clone()]);
+    parser.input(root.join("top.proto"));
+    let result = parser.parse().unwrap();
+    assert_eq!(
+        result
+            .files
+            .iter()
+            .filter(|f| f.path.file_name().unwrap() == "base.proto")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_resolve_errors() {
+    use crate::{
+        parser::{ParseResult, Parser, ThriftParser},
+        resolve::Resolver,
+        symbol::Span,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("typo.thrift");
+    std::fs::write(
+        &path,
+        "struct A {\n    1: required Bb b,\n    2: optional list<Cc> c,\n}\n\nconst i32 X = Y\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    let errors = errors
+        .iter()
+        .map(|e| {
+            assert_eq!(*e.path, path);
+            (e.span, e.message.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (
+                Some(Span {
+                    line: 2,
+                    column: 17
+                }),
+                "undefined ident Bb"
+            ),
+            (
+                Some(Span {
+                    line: 3,
+                    column: 22
+                }),
+                "undefined ident Cc"
+            ),
+            (
+                Some(Span {
+                    line: 6,
+                    column: 15
+                }),
+                "undefined ident Y"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_sym() {
+    use crate::{
+        middle::rir::NodeKind,
+        parser::{ParseResult, Parser, ProtobufParser},
+        resolve::Resolver,
+        ty::TyKind,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("scope.proto");
+    std::fs::write(
+        &path,
+        r#"syntax = "proto3";
+
+message Outer {
+    message Inner {
+        int32 a = 1;
+    }
+
+    message Other {
+        Inner inner = 1;
+    }
+
+    Inner inner = 1;
+    Other other = 2;
+    Outer.Inner qualified = 3;
+}
+
+message Other {
+    Outer.Other nested = 1;
+}
+"#,
+    )
+    .unwrap();
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf()]);
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let nodes = Resolver::default()
+        .resolve_files(&files)
+        .ok()
+        .unwrap()
+        .nodes;
+
+    let item_path = |mut def_id| {
+        let mut segs = vec![];
+        while let Some(node) = nodes.get(&def_id) {
+            segs.push(node.expect_item().symbol_name().to_string());
+            match node.parent {
+                Some(parent) => def_id = parent,
+                None => break,
+            }
+        }
+        segs.reverse();
+        segs.join(".")
+    };
+
+    let mut resolved = nodes
+        .values()
+        .filter_map(|node| match &node.kind {
+            NodeKind::Field(f) => match &f.ty.kind {
+                TyKind::Path(p) => Some((
+                    format!("{}.{}", item_path(node.parent.unwrap()), f.name),
+                    item_path(p.did),
+                )),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    resolved.sort();
+
+    let expected = [
+        ("Other.nested", "outer.Other"),
+        ("outer.Other.inner", "outer.Inner"),
+        ("outer.Outer.inner", "outer.Inner"),
+        ("outer.Outer.other", "outer.Other"),
+        ("outer.Outer.qualified", "outer.Inner"),
+    ];
+    assert_eq!(
+        resolved,
+        expected
+            .iter()
+            .map(|(f, t)| (f.to_string(), t.to_string()))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_duplicate_defs() {
+    use crate::{
+        parser::{ParseResult, Parser, ThriftParser},
+        resolve::Resolver,
+        symbol::Span,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("dup.thrift");
+    std::fs::write(
+        &path,
+        "struct A {}\n\nconst i32 A = 1\n\nenum A {\n    B = 1,\n}\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(*errors[0].path, path);
+    assert_eq!(errors[0].span, Some(Span { line: 5, column: 1 }));
+    assert_eq!(
+        errors[0].message,
+        "`A` is defined multiple times, at 1:1, 5:1"
+    );
+
+    // the duplicates of the same name in another namespace are reported on their own
+    let path = dir.path().join("namespaces.thrift");
+    std::fs::write(
+        &path,
+        "struct A {}\nconst i32 A = 1\nenum A {}\nconst i32 A = 2\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    assert_eq!(
+        errors.iter().map(|e| &*e.message).collect::<Vec<_>>(),
+        [
+            "`A` is defined multiple times, at 1:1, 3:1",
+            "`A` is defined multiple times, at 2:1, 4:1",
+        ]
+    );
+}
+
+#[test]
+fn test_name_collisions() {
+    use crate::{
+        parser::{ParseResult, Parser, ThriftParser},
+        resolve::Resolver,
+        symbol::Span,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("names.thrift");
+    std::fs::write(
+        &path,
+        "struct user_info {\n    1: required i32 fooBar,\n    2: required i32 foo_bar,\n}\n\nstruct UserInfo {}\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.span, e.message.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                Some(Span { line: 1, column: 1 }),
+                "`fooBar` and `foo_bar` of `user_info` are both named `foo_bar` in Rust"
+            ),
+            (
+                Some(Span { line: 6, column: 1 }),
+                "`user_info` and `UserInfo` are both named `UserInfo` in Rust"
+            ),
+        ]
+    );
+
+    let out = dir.path().join("names.rs");
+    crate::Builder::thrift()
+        .disambiguate_names(true)
+        .compile(&[&path], &out);
+    let generated = std::fs::read_to_string(&out).unwrap();
+    assert!(generated.contains("pub struct UserInfo {"));
+    assert!(generated.contains("pub struct UserInfo2 {"));
+    assert!(generated.contains("pub foo_bar: i32,"));
+    assert!(generated.contains("pub foo_bar_2: i32,"));
+}
+
+#[test]
+fn test_strict_resolution() {
+    use std::{path::PathBuf, sync::Arc};
+
+    use crate::{ir, resolve::Resolver, symbol::FileId, tags::protobuf::MessageMod};
+
+    let item = |kind, tags| ir::Item {
+        kind,
+        tags: Arc::new(tags),
+        span: None,
+    };
+    let message = |name: &str, fields| {
+        item(
+            ir::ItemKind::Message(ir::Message {
+                name: name.into(),
+                fields,
+            }),
+            Default::default(),
+        )
+    };
+    let file = |tags| {
+        // `Outer` is in the module `outer` named after it, as the protobuf parser moves the
+        // messages with nested items
+        let outer = item(
+            ir::ItemKind::Mod(ir::Mod {
+                name: "outer".into(),
+                items: vec![Arc::new(message("Outer", vec![]))],
+            }),
+            tags,
+        );
+        let user = message(
+            "User",
+            vec![ir::Field {
+                name: "outer".into(),
+                id: 1,
+                ty: ir::Ty {
+                    kind: ir::TyKind::Path(ir::Path {
+                        segments: Arc::from(["Outer".into()]),
+                        span: None,
+                    }),
+                    tags: Default::default(),
+                },
+                kind: ir::FieldKind::Optional,
+                tags: Default::default(),
+                default: None,
+            }],
+        );
+        Arc::new(ir::File {
+            path: Arc::new(PathBuf::from("outer.proto")),
+            package: ir::Path {
+                segments: Arc::from([]),
+                span: None,
+            },
+            items: vec![Arc::new(outer), Arc::new(user)],
+            id: FileId::from_u32(0),
+            uses: Default::default(),
+        })
+    };
+    let strict = || Resolver::default().with_strict_resolution(true);
+
+    let moved = file(crate::tags!(MessageMod("Outer".into())));
+    assert!(Resolver::default()
+        .resolve_files(std::slice::from_ref(&moved))
+        .is_ok());
+    assert!(strict().resolve_files(&[moved]).is_ok());
+
+    // a module which only happens to be named like the message is not looked into
+    let unrelated = file(Default::default());
+    assert!(Resolver::default()
+        .resolve_files(std::slice::from_ref(&unrelated))
+        .is_ok());
+    let errors = strict().resolve_files(&[unrelated]).err().unwrap();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["undefined ident Outer"]
+    );
+}
+
+#[test]
+fn test_enum_discriminants() {
+    use crate::{
+        parser::{ParseResult, Parser, ProtobufParser, ThriftParser},
+        resolve::Resolver,
+    };
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("discrs.thrift");
+    std::fs::write(
+        &path,
+        "enum Status {\n    OK = 1,\n    FAILED = 1,\n}\n\nenum Auto {\n    A,\n    B = 0,\n}\n\nenum Large {\n    BIG = 2147483647,\n    NEXT,\n}\n\nenum Fine {\n    X = 2,\n    Y = 0,\n    Z,\n}\n",
+    )
+    .unwrap();
+
+    let mut parser = ThriftParser::default();
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "`Status::OK` and `Status::FAILED` have the same discriminant 1",
+            "`Auto::A` and `Auto::B` have the same discriminant 0",
+            "the discriminant 2147483648 of `Large::NEXT` is out of the range of i32",
+        ]
+    );
+
+    let path = dir.path().join("discrs.proto");
+    std::fs::write(
+        &path,
+        "syntax = \"proto3\";\n\nenum Kind {\n    KIND_UNSPECIFIED = 0;\n    KIND_DEFAULT = 0;\n}\n",
+    )
+    .unwrap();
+
+    let mut parser = ProtobufParser::default();
+    parser.include_dirs(vec![dir.path().to_path_buf()]);
+    parser.input(&path);
+    let ParseResult { files, .. } = parser.parse().unwrap();
+    let errors = Resolver::default().resolve_files(&files).err().unwrap();
+
+    assert_eq!(
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            "discrs.proto: `Kind::KIND_UNSPECIFIED` and `Kind::KIND_DEFAULT` have the same discriminant 0"
+        ]
+    );
+}
+
+#[test]
+fn test_generate() {
+    use quote::ToTokens;
+
+    use crate::{db::RirDatabase, rir::Item, Context};
+
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    // the fields of the messages and their types, relative to the messages
+    fn fields(cx: &Context) -> Vec<String> {
+        let mut def_ids = cx.nodes().keys().copied().collect::<Vec<_>>();
+        def_ids.sort();
+        def_ids
+            .into_iter()
+            .filter(|def_id| matches!(cx.nodes()[def_id].kind, crate::rir::NodeKind::Item(_)))
+            .flat_map(|def_id| match &*cx.expect_item(def_id) {
+                Item::Message(s) => cx.in_item(def_id, || {
+                    s.fields
+                        .iter()
+                        .map(|f| {
+                            let ty = cx.codegen_item_ty(f.ty.kind.clone());
+                            format!("{}.{}: {}", s.name, f.name, ty.to_token_stream())
+                        })
+                        .collect::<Vec<_>>()
+                }),
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    let thrift = crate::Builder::thrift().generate(
+        &[test_data_dir.join("thrift").join("normal.thrift")],
+        fields,
+    );
+    assert_eq!(thrift, ["A.a: i32", "b.a: A"]);
+
+    let protobuf = crate::Builder::protobuf()
+        .include_dirs(vec![test_data_dir.join("protobuf")])
+        .generate(
+            &[test_data_dir.join("protobuf").join("nested_message.proto")],
+            fields,
+        );
+    assert_eq!(protobuf, ["TT3.a: i32", "T2.t3: Tt3", "TT1.t2: t2 :: T2"]);
+}
+
+#[test]
+fn test_output_size_limit() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("large.thrift");
+    let structs = (0..200)
+        .map(|i| format!("struct S{i} {{\n    1: required i64 a,\n    2: optional string b,\n}}\n"))
+        .collect::<String>();
+    std::fs::write(&path, structs).unwrap();
+
+    let out = dir.path().join("large.rs");
+    crate::Builder::thrift()
+        .output_size_limit(1024)
+        .compile(&[&path], &out);
+    let size = std::fs::metadata(&out).unwrap().len();
+    assert!(size > 100 * 1024);
+
+    assert_eq!(crate::output_size_warning(&out, size), None);
+    let warning = crate::output_size_warning(&out, size - 1).unwrap();
+    assert!(warning.contains(&format!(
+        "is {size} bytes, more than the limit of {} bytes",
+        size - 1
+    )));
+}
+
+mod validate {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_validate/validate.rs");
+    }
+
+    use generated::validate::validate::User;
+    use pilota::validate::{ValidationError, ValidationErrorKind};
+
+    #[test]
+    fn test_validate() {
+        let mut user = User {
+            name: "pilota".to_string(),
+            age: Some(18),
+            tags: None,
+            id: 1,
+            score: None,
+        };
+        assert!(user.validate().is_ok());
+
+        // The `pilota.max` bound of score is not an integer and is skipped.
+        user.score = Some(1000);
+        assert!(user.validate().is_ok());
+
+        user.age = Some(200);
+        assert_eq!(
+            user.validate(),
+            Err(ValidationError {
+                field: "age",
+                kind: ValidationErrorKind::GreaterThanMax {
+                    max: 150,
+                    actual: 200
+                },
+            })
+        );
+
+        user.name = String::new();
+        assert_eq!(
+            user.validate(),
+            Err(ValidationError {
+                field: "name",
+                kind: ValidationErrorKind::Empty,
+            })
+        );
+    }
+}
+
+mod validate_validator {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_validate/validate_validator.rs");
+    }
+
+    use generated::validate_validator::validate::User;
+    use validator::Validate;
+
+    #[test]
+    fn test_validate_derive() {
+        let mut user = User {
+            name: "pilota".to_string(),
+            age: Some(18),
+            tags: None,
+            id: 1,
+            score: None,
+        };
+        assert!(user.validate().is_ok());
+
+        user.age = Some(200);
+        user.name = String::new();
+        let errors = user.validate().unwrap_err();
+        let mut fields = errors.field_errors().into_keys().collect::<Vec<_>>();
+        fields.sort();
+        assert_eq!(fields, ["age", "name"]);
+    }
+}
+
+mod union {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_union/union.rs");
+    }
+
+    use generated::union::union::{Shape, ShapeV2};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_union() {
+        assert_eq!(Shape::default(), Shape::Square(0));
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        ShapeV2::Triangle(3)
+            .encode(&mut TBinaryProtocol::new(&mut buf))
+            .unwrap();
+        let shape = Shape::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(shape, Shape::Unknown(3));
+    }
+}
+
+mod serde {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_serde/internal.rs");
+        include!("../../test_data/thrift_serde/paint.rs");
+        include!("../../test_data/thrift_serde/paint_discriminant.rs");
+    }
+
+    use generated::internal::internal::{Item, Meta};
+
+    #[test]
+    fn test_internal_fields_skipped() {
+        let item = Item {
+            name: "pilota".to_string(),
+            price: 42,
+            cached_label: Some("pilota: 42".to_string()),
+            history: vec!["41".to_string()],
+            meta: Meta {
+                source: Some("cache".to_string()),
+            },
+        };
+
+        let json = serde_json::to_string(&item).unwrap();
+        assert_eq!(json, r#"{"name":"pilota","price":42}"#);
+
+        let item: Item = serde_json::from_str(&json).unwrap();
+        assert_eq!(item.cached_label, None);
+        assert!(item.history.is_empty());
+        assert_eq!(item.meta.source, None);
+    }
+
+    #[test]
+    fn test_with_serde() {
+        use generated::paint::paint::{Color, Paint, Shade};
+
+        let paint = Paint {
+            color_name: "red".to_string(),
+            color: Color::DarkRed,
+            mixed_with: Some(vec![Color::Blue]),
+            shade: Some(Shade::LightName("pale".to_string())),
+        };
+
+        let json = serde_json::to_value(&paint).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "colorName": "red",
+                "color": "DARK_RED",
+                "mixedWith": ["Blue"],
+                "shade": {"lightName": "pale"},
+            })
+        );
+        assert_eq!(serde_json::from_value::<Paint>(json).unwrap(), paint);
+    }
+
+    #[test]
+    fn test_serde_enum_discriminant() {
+        use generated::paint_discriminant::paint::{Color, Paint};
+
+        let paint = Paint {
+            color_name: "red".to_string(),
+            color: Color::DarkRed,
+            mixed_with: Some(vec![Color::Blue]),
+            shade: None,
+        };
+
+        let json = serde_json::to_value(&paint).unwrap();
+        assert_eq!(json["color"], 1);
+        assert_eq!(json["mixedWith"], serde_json::json!([2]));
+        assert_eq!(serde_json::from_value::<Paint>(json).unwrap(), paint);
+
+        let err = serde_json::from_value::<Color>(serde_json::json!(3)).unwrap_err();
+        assert!(err.to_string().contains('3'), "{err}");
+    }
+}
+
+mod ty_transformer {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_ty_transformer/custom.rs");
+    }
+
+    use generated::custom::custom::Blob;
+    use pilota::{
+        bytes::Bytes,
+        thrift::{Message, TBinaryProtocol},
+    };
+
+    #[test]
+    fn test_custom_types_roundtrip() {
+        let blob = Blob {
+            data: Bytes::from_static(b"pilota"),
+            counts: [("a".to_string(), 1), ("b".to_string(), 2)]
+                .into_iter()
+                .collect(),
+            chunks: Some(vec![Bytes::from_static(b"pi"), Bytes::from_static(b"lota")]),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        blob.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = Blob::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, blob);
+    }
+}
+
+mod bytes {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_bytes/bytes.rs");
+    }
+
+    use generated::bytes::bytes::{Packet, MAGIC};
+    use pilota::{
+        bytes::Bytes,
+        thrift::{Message, TBinaryProtocol},
+    };
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        assert_eq!(&*MAGIC, &b"pilota"[..]);
+
+        let packet = Packet {
+            payload: MAGIC.clone(),
+            frames: Some(vec![Bytes::from_static(b"pi"), Bytes::from_static(b"lota")]),
+            headers: Some(
+                [("k".to_string(), Bytes::from_static(b"v"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        packet.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = Packet::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, packet);
+    }
+}
+
+mod shared_trait {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_shared_trait/user.rs");
+    }
+
+    use generated::user::user::{UserLike, UserV1, UserV2};
+
+    fn describe(user: &impl UserLike) -> String {
+        format!(
+            "{}:{}:{}",
+            user.id(),
+            user.name(),
+            user.email().map_or("-", |e| e.as_str())
+        )
+    }
+
+    #[test]
+    fn test_shared_trait() {
+        let v1 = UserV1 {
+            id: 1,
+            name: "pilota".to_string(),
+            email: None,
+        };
+        let v2 = UserV2 {
+            id: 2,
+            name: "volo".to_string(),
+            email: Some("volo@cloudwego.io".to_string()),
+            age: Some(2),
+            roles: vec!["admin".to_string()],
+        };
+
+        assert_eq!(describe(&v1), "1:pilota:-");
+        assert_eq!(describe(&v2), "2:volo:volo@cloudwego.io");
+    }
+}
+
+mod nested_message {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf/nested_message.rs");
+    }
+
+    use generated::nested_message::nested_message::tt1::{t2, Tt1};
+    use prost::Message;
+
+    #[test]
+    fn test_nested_message_paths() {
+        let tt1 = Tt1 {
+            t2: Some(t2::T2 {
+                t3: Some(t2::Tt3 { a: Some(1) }),
+            }),
+        };
+        assert_eq!(Tt1::decode(&*tt1.encode_to_vec()).unwrap(), tt1);
+    }
+}
+
+mod zigzag {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf/zigzag.rs");
+    }
+
+    use generated::zigzag::zigzag::delta::{Delta, Hint};
+    use prost::Message;
+
+    #[test]
+    fn test_zigzag_roundtrip() {
+        let delta = Delta {
+            dx: -1,
+            dy: i64::MIN,
+            steps: vec![-2, 0, 2],
+            offsets: [(-3, -4)].into_iter().collect(),
+            hint: Some(Hint::Small(-5)),
+        };
+
+        let buf = delta.encode_to_vec();
+        // zigzag encodes -1 as 1, a single byte, where int32 would take ten.
+        assert_eq!(&buf[..2], &[0x08, 0x01]);
+        assert_eq!(Delta::decode(&*buf).unwrap(), delta);
+    }
+}
+
+mod oneof {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf/oneof.rs");
+    }
+
+    use generated::oneof::oneof::tree::{Node, Tree};
+    use prost::Message;
+
+    #[test]
+    fn test_oneof_roundtrip() {
+        let leaf = Tree {
+            name: "leaf".into(),
+            node: Some(Node::Leaf(1)),
+        };
+        let variants = [
+            Some(Node::Leaf(-1)),
+            Some(Node::Label("label".into())),
+            Some(Node::Child(Box::new(leaf))),
+            None,
+        ];
+
+        for node in variants {
+            let tree = Tree {
+                name: "root".into(),
+                node,
+            };
+            let buf = tree.encode_to_vec();
+            assert_eq!(Tree::decode(&*buf).unwrap(), tree);
+        }
+    }
+
+    #[test]
+    fn test_oneof_tag() {
+        let tree = Tree {
+            node: Some(Node::Label("a".into())),
+            ..Default::default()
+        };
+        // the variant is written with the number of its member field.
+        assert_eq!(tree.encode_to_vec(), [0x1a, 0x01, b'a']);
+    }
+}
+
+mod fixed {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf/fixed.rs");
+    }
+
+    use generated::fixed::fixed::Fixed;
+    use prost::Message;
+
+    #[test]
+    fn test_fixed_width() {
+        let fixed = Fixed {
+            b: 1,
+            ..Default::default()
+        };
+
+        let buf = fixed.encode_to_vec();
+        // tag 2 with the 64-bit wire type, followed by 8 little-endian bytes.
+        assert_eq!(buf, [0x11, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Fixed::decode(&*buf).unwrap(), fixed);
+
+        let fixed = Fixed {
+            a: 1,
+            c: -1,
+            d: -2,
+            e: vec![-3, 4],
+            f: [(5, -6)].into_iter().collect(),
+            ..fixed
+        };
+        assert_eq!(Fixed::decode(&*fixed.encode_to_vec()).unwrap(), fixed);
+
+        // the same field sent as a varint has the wrong wire type.
+        assert!(Fixed::decode(&[0x10, 0x01][..]).is_err());
+    }
+}
+
+mod well_known {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_any/well_known.rs");
+    }
+
+    use generated::well_known::events::{any::Any, Event, Login, Logout};
+    use pilota::protobuf::WireName;
+    use prost::Message;
+
+    #[test]
+    fn test_any_pack_unpack() {
+        let login = Login {
+            user: "pilota".to_string(),
+        };
+        let event = Event {
+            name: "login".to_string(),
+            payload: Some(Any::pack(&login)),
+        };
+
+        let decoded = Event::decode(&*event.encode_to_vec()).unwrap();
+        let payload = decoded.payload.unwrap();
+        assert_eq!(payload.type_url, "type.googleapis.com/events.Login");
+        assert_eq!(payload.unpack::<Login>().unwrap(), Some(login));
+        assert_eq!(payload.unpack::<Logout>().unwrap(), None);
+
+        let unknown = Any {
+            type_url: "example.com/events.Unknown".to_string(),
+            value: vec![0xff],
+        };
+        assert_eq!(unknown.unpack::<Login>().unwrap(), None);
+        assert_eq!(Any::WIRE_NAME, "google.protobuf.Any");
+    }
+}
+
+mod streaming {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_service/streaming.rs");
+    }
+
+    use futures::{executor::block_on, stream, StreamExt, TryStreamExt};
+    use generated::streaming::chat::{Chat, Msg};
+    use pilota::{AnyhowError, BoxStream};
+
+    struct Echo;
+
+    #[async_trait::async_trait]
+    impl Chat for Echo {
+        async fn send(&self, req: Msg) -> Result<Msg, AnyhowError> {
+            Ok(req)
+        }
+
+        async fn touch(&self, req: Msg) -> Result<Msg, AnyhowError> {
+            Ok(req)
+        }
+
+        async fn upload(&self, req: BoxStream<Msg>) -> Result<Msg, AnyhowError> {
+            let texts: Vec<_> = req.map_ok(|m| m.text).try_collect().await?;
+            Ok(Msg {
+                text: texts.join(" "),
+            })
+        }
+
+        async fn subscribe(&self, req: Msg) -> Result<BoxStream<Msg>, AnyhowError> {
+            Ok(stream::repeat(req).take(2).map(Ok).boxed())
+        }
+
+        async fn chat(&self, req: BoxStream<Msg>) -> Result<BoxStream<Msg>, AnyhowError> {
+            Ok(req
+                .map_ok(|m| Msg {
+                    text: m.text.to_uppercase(),
+                })
+                .boxed())
+        }
+    }
+
+    fn msgs(texts: &[&str]) -> Vec<Msg> {
+        texts
+            .iter()
+            .map(|t| Msg {
+                text: t.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bidi_streaming() {
+        block_on(async {
+            let req = stream::iter(msgs(&["hello", "pilota"])).map(Ok).boxed();
+            let resp: Vec<_> = Echo.chat(req).await.unwrap().try_collect().await.unwrap();
+            assert_eq!(resp, msgs(&["HELLO", "PILOTA"]));
+
+            let req = stream::iter(msgs(&["hello", "pilota"])).map(Ok).boxed();
+            assert_eq!(Echo.upload(req).await.unwrap(), msgs(&["hello pilota"])[0]);
+
+            let resp: Vec<_> = Echo
+                .subscribe(msgs(&["hi"]).remove(0))
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            assert_eq!(resp, msgs(&["hi", "hi"]));
+        })
+    }
+}
+
+mod dirty {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_dirty/user.rs");
+    }
+
+    use generated::user::user::{User, ANONYMOUS};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_dirty_fields() {
+        let mut user = ANONYMOUS.clone();
+        assert_eq!(user.dirty_fields().count(), 0);
+
+        user.set_tags(Some(vec!["admin".to_string()]));
+        user.set_id(1);
+        user.set_id(2);
+        assert_eq!(user.dirty_fields().collect::<Vec<_>>(), [1, 5]);
+        // the setter of a keyword field drops the raw identifier
+        user.set_type(Some("bot".into()));
+        assert_eq!(user.dirty_fields().collect::<Vec<_>>(), [1, 3, 5]);
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        user.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = User::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, user);
+        assert_eq!(decoded.dirty_fields().count(), 0);
+
+        user.clear_dirty_fields();
+        assert_eq!(user.dirty_fields().count(), 0);
+    }
+}
+
+mod json_value {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_json/nested.rs");
+    }
+
+    use generated::nested::nested::{Address, Contact, Person, Status};
+    use serde_json::json;
+
+    #[test]
+    fn test_to_json_value() {
+        let home = Address {
+            city: "Beijing".to_string(),
+            zip_code: Some("100000".to_string()),
+        };
+        let person = Person {
+            name: "pilota".to_string(),
+            status: Status::Active,
+            addresses: vec![home.clone()],
+            labels: [(1, ["rust".to_string()].into_iter().collect())]
+                .into_iter()
+                .collect(),
+            contact: Some(Contact::Address(home)),
+            avatar: Some(vec![1, 2]),
+            created_at: Some(42.into()),
+            office: None,
+        };
+
+        let home = json!({"city": "Beijing", "zipCode": "100000"});
+        assert_eq!(
+            person.to_json_value(),
+            json!({
+                "name": "pilota",
+                "status": "ACTIVE",
+                "addresses": [home],
+                "labels": {"1": ["rust"]},
+                "contact": {"address": home},
+                "avatar": [1, 2],
+                "createdAt": 42,
+            })
+        );
+    }
+}
+
+mod type_name_prefix {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_prefix/account.rs");
+    }
+
+    use generated::account::account::{PbLookup, PbRole, PbUser, PbUserId};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_prefixed_types() {
+        let user = PbUser {
+            id: PbUserId::from(1),
+            role: PbRole::Admin,
+            friends: Some(vec![PbUser {
+                id: 2.into(),
+                role: PbRole::Guest,
+                friends: None,
+            }]),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        user.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = PbUser::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, user);
+
+        assert_eq!(PbLookup::Id(1.into()), PbLookup::Id(PbUserId::from(1)));
+    }
+}
+
+mod any_message {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_any/envelope.rs");
+    }
+
+    use generated::envelope::envelope::{AnyMessage, Notice, Ping};
+    use pilota::thrift::TBinaryProtocol;
+
+    #[test]
+    fn test_decode_by_tag() {
+        let messages = vec![
+            AnyMessage::from(Ping { seq: 7 }),
+            AnyMessage::from(Notice {
+                text: "hello".into(),
+                level: Some(2),
+            }),
+        ];
+
+        for message in messages {
+            let mut buf = pilota::bytes::BytesMut::new();
+            message.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+            let decoded =
+                AnyMessage::decode_by_tag(message.tag(), &mut TBinaryProtocol::new(&mut buf))
+                    .unwrap();
+            assert_eq!(decoded, message);
+        }
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        assert!(AnyMessage::decode_by_tag(3, &mut TBinaryProtocol::new(&mut buf)).is_err());
+    }
+}
+
+mod oneof_dispatcher {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_dispatch/shape.rs");
+    }
+
+    use generated::shape::shape::{shape::Kind, Circle};
+
+    fn describe(kind: Kind) -> String {
+        kind.map_variant(
+            |circle| format!("circle of radius {}", circle.radius),
+            |side| format!("square of side {}", side),
+            |name| format!("shape named {}", name),
+        )
+    }
+
+    #[test]
+    fn test_map_variant() {
+        assert_eq!(
+            describe(Kind::Circle(Circle { radius: 1.5 })),
+            "circle of radius 1.5"
+        );
+        assert_eq!(describe(Kind::Square(2)), "square of side 2");
+        assert_eq!(describe(Kind::Named("hex".to_string())), "shape named hex");
+    }
+}
+
+mod oneof_from {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_oneof_from/size.rs");
+    }
+
+    use generated::size::size::{size::Value, Pixels};
+
+    #[test]
+    fn test_from_payload() {
+        assert_eq!(
+            Value::from(Pixels { count: 2 }),
+            Value::Pixels(Pixels { count: 2 })
+        );
+        assert_eq!(
+            Value::from("wide".to_string()),
+            Value::Label("wide".to_string())
+        );
+    }
+}
+
+mod default_value {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/default_value.rs");
+    }
+
+    use generated::default_value::default_value::{Level, Options};
+
+    #[test]
+    fn test_field_defaults() {
+        let options = Options::default();
+        assert_eq!(options.retries, 5);
+        assert_eq!(options.host, "localhost");
+        assert_eq!(options.timeout, Some(30));
+        assert_eq!(options.ratio, 1.0);
+        assert_eq!(options.level, Level::High);
+        assert_eq!(options.comment, None);
+        assert_eq!(options.name.as_deref(), Some("anon"));
+        assert_eq!(options.weights, vec![1, 2]);
+        assert_eq!(options.limits.unwrap()["a"], 1);
+        assert!(options.tags.contains("x"));
+        assert_eq!(options.timestamp, 0);
+        assert_eq!(
+            *generated::default_value::default_value::PRIMES,
+            vec![2, 3, 5]
+        );
+    }
+}
+
+mod const_ref {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/const_ref.rs");
+    }
+
+    use generated::const_ref::const_ref::{Config, Level, Server, CONFIG, LEVELS, LIMITS};
+
+    #[test]
+    fn test_const_refs() {
+        assert_eq!(*LEVELS, vec![Level::Low, Level::High]);
+        assert_eq!(LIMITS["hello"], 100);
+        assert_eq!(CONFIG.timeout, 100);
+        assert_eq!(CONFIG.greeting.as_deref(), Some("hello"));
+
+        let config = Config::default();
+        assert_eq!(config.level, Some(Level::High));
+        assert_eq!(config.levels, Some(vec![Level::Low, Level::High]));
+        assert_eq!(Server::default().config, *CONFIG);
+    }
+}
+
+mod include_const {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_include_const/item.rs");
+    }
+
+    use generated::item::{
+        consts::Kind,
+        item::{Item, KIND},
+    };
+
+    #[test]
+    fn test_included_const_defaults() {
+        let item = Item::default();
+        assert_eq!(item.kind, Kind::Y);
+        assert_eq!(item.fallback, Kind::X);
+        assert_eq!(item.size, Some(10));
+        assert_eq!(item.kinds, Some(vec![Kind::X, Kind::Y]));
+        assert_eq!(KIND, Kind::Y);
+    }
+}
+
+mod unknown_fields {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_unknown/user.rs");
+    }
+
+    use generated::user::user::{Address, UserV1, UserV2};
+    use pilota::thrift::{Message, TAsyncBinaryProtocol, TBinaryProtocol};
+
+    fn encode<M: Message>(m: &M) -> pilota::bytes::BytesMut {
+        let mut buf = pilota::bytes::BytesMut::new();
+        m.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_reencode_unknown_fields() {
+        let user = UserV2 {
+            id: 1,
+            name: Some("alice".to_string()),
+            addresses: vec![Address {
+                city: "paris".to_string(),
+                _unknown_fields: Default::default(),
+            }],
+            scores: [("math".to_string(), 9.5)].into_iter().collect(),
+            avatar: Some(vec![0xff, 0x00]),
+            _unknown_fields: Default::default(),
+        };
+        let bytes = encode(&user);
+
+        let old = UserV1::decode(&mut TBinaryProtocol::new(&mut bytes.clone())).unwrap();
+        assert_eq!(old.id, 1);
+        assert_eq!(
+            old._unknown_fields
+                .iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 4, 5]
+        );
+        assert_eq!(encode(&old), bytes);
+        assert_eq!(
+            pilota::thrift::Size::size(&old, &TBinaryProtocol::new(())),
+            bytes.len()
+        );
+
+        let decoded = UserV2::decode(&mut TBinaryProtocol::new(&mut encode(&old))).unwrap();
+        assert_eq!(decoded, user);
+        assert!(decoded._unknown_fields.is_empty());
+
+        let old = futures::executor::block_on(UserV1::decode_async(
+            &mut TAsyncBinaryProtocol::new(&bytes[..]),
+        ))
+        .unwrap();
+        assert_eq!(encode(&old), bytes);
+    }
+}
+
+mod unknown_proto_fields {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_unknown/user.rs");
+    }
+
+    use generated::user::user::{
+        user_v2::{Contact, UserV2},
+        AddressV2, Role, UserV1,
+    };
+    use prost::Message;
+
+    #[test]
+    fn test_reencode_unknown_proto_fields() {
+        let user = UserV2 {
+            id: 1,
+            name: Some("alice".to_string()),
+            scores: vec![-1, 2],
+            addresses: vec![AddressV2 {
+                city: "paris".to_string(),
+                zip: 75001,
+                ..Default::default()
+            }],
+            weights: [("math".to_string(), 9.5)].into_iter().collect(),
+            role: Role::Admin as i32,
+            stamp: 42,
+            tags: vec!["a".to_string(), "b".to_string()],
+            contact: Some(Contact::Phone(vec![0xff, 0x00])),
+            ..Default::default()
+        };
+        let bytes = user.encode_to_vec();
+
+        let old = UserV1::decode(&*bytes).unwrap();
+        assert_eq!(old.id, 1);
+        assert_eq!(old.addresses[0].city, "paris");
+        assert_eq!(
+            old._unknown_fields
+                .iter()
+                .map(|(tag, wire_type, _)| (tag, wire_type))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, 2),
+                (3, 2),
+                (5, 2),
+                (6, 0),
+                (7, 1),
+                (8, 2),
+                (8, 2),
+                (10, 2)
+            ]
+        );
+        assert_eq!(old.addresses[0]._unknown_fields.iter().count(), 1);
+
+        // the unknown fields are written after the known ones
+        let reencoded = old.encode_to_vec();
+        assert_eq!(reencoded.len(), bytes.len());
+        assert_eq!(old.encoded_len(), bytes.len());
+
+        let decoded = UserV2::decode(&*reencoded).unwrap();
+        assert_eq!(decoded, user);
+        // the getters prost derives
+        assert_eq!(decoded.name(), "alice");
+        assert_eq!(UserV2::default().name(), "");
+        assert_eq!(decoded.role(), Role::Admin);
+        assert!(decoded._unknown_fields.is_empty());
+        assert!(decoded.addresses[0]._unknown_fields.is_empty());
+
+        let mut old = old;
+        old.clear();
+        assert!(old._unknown_fields.is_empty());
+        assert!(old.encode_to_vec().is_empty());
+    }
+
+    #[test]
+    fn test_reencode_unknown_proto_groups() {
+        let mut bytes = UserV1 {
+            id: 1,
+            ..Default::default()
+        }
+        .encode_to_vec();
+        // the group 20 of proto2, with the field 1 in it
+        bytes.extend([0xa3, 0x01, 0x08, 0x05, 0xa4, 0x01]);
+
+        let old = UserV1::decode(&*bytes).unwrap();
+        assert_eq!(old.id, 1);
+        assert_eq!(
+            old._unknown_fields.iter().collect::<Vec<_>>(),
+            vec![(20, 3, &[0x08, 0x05, 0xa4, 0x01][..])]
+        );
+        assert_eq!(old.encode_to_vec(), bytes);
+    }
+}
+
+mod unknown_proto2_fields {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_unknown/legacy.rs");
+    }
+    #[allow(clippy::all)]
+    mod derived {
+        include!("../../test_data/protobuf_unknown/legacy_derive.rs");
+    }
+
+    use derived::legacy_derive::legacy as prost_legacy;
+    use generated::legacy::legacy::{Item, Kind};
+    use prost::Message;
+
+    #[test]
+    fn test_same_as_prost_derive() {
+        // the default values of the IDL
+        let item = Item::default();
+        let prost_item = prost_legacy::Item::default();
+        assert_eq!(item.id, 7);
+        assert_eq!(item.id, prost_item.id);
+        assert_eq!(item.main, prost_item.main);
+        assert_eq!(item.name(), "anon");
+        assert_eq!(item.name(), prost_item.name());
+        assert_eq!(item.kind(), Kind::Fancy);
+        assert_eq!(item.main(), Kind::Fancy);
+        assert_eq!(item.blob(), b"\x01x");
+        assert_eq!(item.blob(), prost_item.blob());
+        assert_eq!(item.ratio(), f64::NEG_INFINITY);
+        assert_eq!(item.ratio(), prost_item.ratio());
+        assert_eq!(item.count(), 0);
+        assert_eq!(item.encode_to_vec(), prost_item.encode_to_vec());
+
+        let mut item = Item {
+            name: Some("bob".to_string()),
+            codes: vec![1, 2],
+            packed_codes: vec![3, 4],
+            blob: Some(vec![]),
+            ratio: Some(0.5),
+            count: Some(3),
+            ..Default::default()
+        };
+        item.set_kind(Kind::Plain);
+        item.set_main(Kind::Plain);
+        let mut prost_item = prost_legacy::Item {
+            name: Some("bob".to_string()),
+            codes: vec![1, 2],
+            packed_codes: vec![3, 4],
+            blob: Some(vec![]),
+            ratio: Some(0.5),
+            count: Some(3),
+            ..Default::default()
+        };
+        prost_item.set_kind(prost_legacy::Kind::Plain);
+        prost_item.set_main(prost_legacy::Kind::Plain);
+
+        let bytes = item.encode_to_vec();
+        assert_eq!(bytes, prost_item.encode_to_vec());
+        assert_eq!(item.encoded_len(), prost_item.encoded_len());
+        // the codes are not packed, the packed codes are
+        assert!(bytes.windows(4).any(|w| w == [0x20, 0x01, 0x20, 0x02]));
+        assert!(bytes.windows(4).any(|w| w == [0x2a, 0x02, 0x03, 0x04]));
+
+        let decoded = Item::decode(&*prost_item.encode_to_vec()).unwrap();
+        assert_eq!(decoded, item);
+        assert_eq!(decoded.kind(), Kind::Plain);
+        assert_eq!(prost_legacy::Item::decode(&*bytes).unwrap(), prost_item);
+
+        let mut decoded = decoded;
+        decoded.clear();
+        assert_eq!(decoded, Item::default());
+    }
+}
+
+mod open_enums {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_open_enum/status.rs");
+    }
+
+    use generated::status::status::{Account, Scope, Status};
+    use pilota::thrift::{Message, TBinaryProtocol, TOutputProtocol};
+
+    #[test]
+    fn test_decode_unknown_discriminant() {
+        let mut buf = pilota::bytes::BytesMut::new();
+        TBinaryProtocol::new(&mut buf).write_i32(99).unwrap();
+        let status = Status::decode(&mut TBinaryProtocol::new(&mut buf.clone())).unwrap();
+        assert_eq!(status, Status::Unknown(99));
+
+        let mut encoded = pilota::bytes::BytesMut::new();
+        status
+            .encode(&mut TBinaryProtocol::new(&mut encoded))
+            .unwrap();
+        assert_eq!(encoded, buf);
+    }
+
+    #[test]
+    fn test_known_discriminants() {
+        assert_eq!(Status::from(10), Status::Deleted);
+        // the implicit discriminants start from 0
+        assert_eq!(Scope::from(1), Scope::Private);
+        assert_eq!(i32::from(Scope::Public), 0);
+        assert_eq!(i32::from(Status::Suspended), 2);
+
+        let account = Account {
+            id: 1,
+            status: Status::Unknown(7),
+            history: Some(vec![Status::Active, Status::Unknown(99)]),
+        };
+        let mut buf = pilota::bytes::BytesMut::new();
+        account.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = Account::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, account);
+    }
+}
+
+mod protobuf_field_options {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_options/options.rs");
+    }
+
+    use self::generated::options::options::Sample;
+
+    #[test]
+    fn test_packed_round_trip() {
+        use prost::Message;
+
+        let sample = Sample {
+            packed_ids: vec![1, 2],
+            plain_ids: vec![1, 2],
+            ..Default::default()
+        };
+        let buf = sample.encode_to_vec();
+        // the packed field is a single length delimited record, the other one a record per
+        // element
+        assert_eq!(buf, [0x0a, 0x02, 0x01, 0x02, 0x10, 0x01, 0x10, 0x02]);
+        assert_eq!(Sample::decode(&*buf).unwrap(), sample);
+    }
+}
+
+mod enum_try_from {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/color.rs");
+    }
+
+    use generated::color::color::{Color, Paint, Priority, Shade};
+    use pilota::thrift::{
+        Message, TBinaryProtocol, TFieldIdentifier, TOutputProtocol, TStructIdentifier, TType,
+    };
+
+    #[test]
+    fn test_try_from_discriminants() {
+        assert_eq!(Color::try_from(2), Ok(Color::Blue));
+        let err = Color::try_from(3).unwrap_err();
+        assert_eq!(err.number, 3);
+
+        // the gaps between the discriminants are not values
+        assert_eq!(Priority::try_from(5), Ok(Priority::Urgent));
+        assert!(Priority::try_from(3).is_err());
+        assert!(Priority::try_from(0).is_err());
+        assert_eq!(i32::from(Priority::Urgent), 5);
+
+        // the implicit discriminants follow the previous ones
+        assert_eq!(Shade::try_from(0), Ok(Shade::Light));
+        assert_eq!(Shade::try_from(6), Ok(Shade::Darker));
+        assert!(Shade::try_from(1).is_err());
+    }
+
+    #[test]
+    fn test_decode_unknown_value() {
+        let mut buf = pilota::bytes::BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf);
+        protocol
+            .write_struct_begin(&TStructIdentifier { name: "Paint" })
+            .unwrap();
+        protocol
+            .write_field_begin(&TFieldIdentifier {
+                name: None,
+                field_type: TType::I32,
+                id: Some(1),
+            })
+            .unwrap();
+        protocol.write_i32(7).unwrap();
+        protocol.write_field_end().unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+
+        let err = Paint::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("invalid enum value for Color, value: 7"));
+    }
+}
+
+mod extern_package {
+    #[allow(clippy::all)]
+    mod common {
+        include!("../../test_data/protobuf_extern/common.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod order {
+        include!("../../test_data/protobuf_extern/order.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod order_prefixed {
+        include!("../../test_data/protobuf_extern/order_prefixed.rs");
+    }
+
+    use prost::Message;
+
+    use self::{
+        common::common::common::{Money, Status},
+        order::order::order::Order,
+        order_prefixed::order_prefixed::order::PbOrder,
+    };
+
+    #[test]
+    fn test_extern_package_roundtrip() {
+        let order = Order {
+            id: "o-1".into(),
+            price: Some(Money {
+                currency: "EUR".into(),
+                units: 12,
+            }),
+            status: Status::StatusPaid as i32,
+            refunds: vec![Money::default()],
+        };
+        let buf = order.encode_to_vec();
+        assert_eq!(Order::decode(&*buf).unwrap(), order);
+
+        let prefixed = PbOrder::decode(&*buf).unwrap();
+        assert_eq!(prefixed.price, order.price);
+        assert_eq!(prefixed.refunds, order.refunds);
+    }
+}
+
+mod rename_modules {
+    #[allow(clippy::all)]
+    mod api {
+        include!("../../test_data/thrift_rename/api.rs");
+    }
+
+    use self::api::api::{
+        api_v1::{Quote, DEFAULT_CURRENCY},
+        common_types::{Currency, Money},
+    };
+
+    #[test]
+    fn test_renamed_references() {
+        let quote = Quote {
+            sku: "sku-1".into(),
+            price: Money {
+                units: 12,
+                currency: DEFAULT_CURRENCY,
+            },
+            discounts: None,
+        };
+        assert_eq!(quote.price.currency, Currency::Eur);
+    }
+}
+
+mod well_known_types {
+    #[allow(clippy::all)]
+    mod wrappers {
+        include!("../../test_data/protobuf_wkt/wrappers.rs");
+    }
+
+    use prost::Message;
+
+    #[allow(clippy::all)]
+    mod event {
+        include!("../../test_data/protobuf_wkt/event.rs");
+    }
+
+    use self::{
+        event::event::event::event::{Event, Retry},
+        wrappers::wrappers::wrappers::profile::{Profile, Settings},
+    };
+
+    #[test]
+    fn test_wrappers_roundtrip() {
+        let profile = Profile {
+            nickname: Some("ferris".into()),
+            age: Some(0),
+            verified: None,
+            score: Some(1.5),
+            avatar: Some(vec![1, 2]),
+            follower_ids: vec![0, 7],
+            settings: Some(Settings {
+                dark_mode: Some(true),
+            }),
+        };
+        let buf = profile.encode_to_vec();
+        let decoded = Profile::decode(&*buf).unwrap();
+        assert_eq!(decoded, profile);
+        // a wrapper of a default value is still present on the wire
+        assert_eq!(decoded.age, Some(0));
+
+        let empty: pilota::protobuf::Empty = ();
+        assert!(empty.encode_to_vec().is_empty());
+    }
+
+    #[test]
+    fn test_prost_types_roundtrip() {
+        let at = prost_types::Timestamp {
+            seconds: 1,
+            nanos: 2,
+        };
+        let event = Event {
+            at: Some(at.clone()),
+            took: Some(prost_types::Duration {
+                seconds: 3,
+                nanos: 0,
+            }),
+            count: Some(4),
+            history: vec![at.clone()],
+            retry: Some(Retry { next: Some(at) }),
+        };
+        let buf = event.encode_to_vec();
+        assert_eq!(Event::decode(&*buf).unwrap(), event);
+    }
+}
+
+mod field_wire_types {
+    #[allow(clippy::all)]
+    mod thrift {
+        include!("../../test_data/thrift_wire/sample.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod protobuf {
+        include!("../../test_data/protobuf_wire/sample.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod protobuf2 {
+        include!("../../test_data/protobuf_wire/legacy.rs");
+    }
+
+    #[test]
+    fn test_thrift_field_wire_types() {
+        use pilota::thrift::TType;
+
+        assert_eq!(
+            self::thrift::sample::sample::Sample::FIELD_WIRE_TYPES,
+            &[
+                (1, TType::Bool),
+                (2, TType::I16),
+                (4, TType::I64),
+                (5, TType::Double),
+                (6, TType::String),
+                (7, TType::String),
+                (8, TType::I32),
+                (9, TType::Struct),
+                (10, TType::List),
+                (11, TType::Set),
+                (12, TType::Map),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_protobuf_field_wire_types() {
+        use prost::encoding::WireType::*;
+
+        assert_eq!(
+            self::protobuf::sample::sample::sample::Sample::FIELD_WIRE_TYPES,
+            &[
+                (1, Varint),
+                (2, Varint),
+                (3, ThirtyTwoBit),
+                (4, SixtyFourBit),
+                (5, ThirtyTwoBit),
+                (6, SixtyFourBit),
+                (7, Varint),
+                (8, LengthDelimited),
+                (9, LengthDelimited),
+                (10, Varint),
+                (11, LengthDelimited),
+                (12, LengthDelimited),
+                (13, LengthDelimited),
+                (16, Varint),
+                (14, Varint),
+                (15, LengthDelimited),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_protobuf2_packed_fields() {
+        use prost::{encoding::WireType::*, Message};
+
+        use self::protobuf2::legacy::legacy::Legacy;
+
+        assert_eq!(
+            Legacy::FIELD_WIRE_TYPES,
+            &[
+                (1, Varint),
+                (2, LengthDelimited),
+                (3, Varint),
+                (4, LengthDelimited)
+            ]
+        );
+
+        let legacy = Legacy {
+            plain: vec![1, 2],
+            packed: vec![3, 4],
+            unpacked: vec![-1],
+            names: vec![],
+        };
+        let bytes = legacy.encode_to_vec();
+        assert_eq!(
+            bytes,
+            [0x08, 0x01, 0x08, 0x02, 0x12, 0x02, 0x03, 0x04, 0x18, 0x01]
+        );
+        assert_eq!(Legacy::decode(&*bytes).unwrap(), legacy);
+    }
+}
+
+mod empty_optional_collections {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_empty_collections/profile.rs");
+    }
+
+    use generated::profile::profile::{Profile, ProfileV1};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_absent_collections_are_empty() {
+        let v1 = ProfileV1 {
+            name: "alice".into(),
+        };
+        let mut buf = pilota::bytes::BytesMut::new();
+        v1.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+
+        let decoded = Profile::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert!(decoded.tags.is_empty());
+        assert!(decoded.ids.is_empty());
+        assert!(decoded.attrs.is_empty());
+        assert_eq!(decoded.nickname, None);
+    }
+
+    #[test]
+    fn test_empty_collections_roundtrip() {
+        let profile = Profile {
+            name: "alice".into(),
+            tags: vec!["admin".into()],
+            ..Default::default()
+        };
+        let mut buf = pilota::bytes::BytesMut::new();
+        profile.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(
+            pilota::thrift::Size::size(&profile, &TBinaryProtocol::new(())),
+            buf.len()
+        );
+        let decoded = Profile::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, profile);
+    }
+}
+
+mod enum_string_conversions {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_enum_str/level.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod open {
+        include!("../../test_data/thrift_enum_str/level_open.rs");
+    }
+
+    #[test]
+    fn test_enum_display_from_str() {
+        use self::generated::level::level::Level;
+
+        assert_eq!(Level::WarnLevel.to_string(), "WARN_LEVEL");
+        assert_eq!("INFO".parse::<Level>(), Ok(Level::Info));
+        assert_eq!(
+            "info".parse::<Level>(),
+            Err(pilota::ParseEnumError {
+                enum_name: "Level",
+                name: "info".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_open_enum_display_from_str() {
+        use self::open::level_open::level::Level;
+
+        assert_eq!(Level::Unknown(7).to_string(), "7");
+        assert_eq!("7".parse::<Level>(), Ok(Level::Unknown(7)));
+        assert_eq!("1".parse::<Level>(), Ok(Level::Debug));
+        assert!("TRACE".parse::<Level>().is_err());
+    }
+}
+
+mod changed_from {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_changed/audit.rs");
+    }
+
+    use generated::audit::audit::{Account, Address};
+
+    #[test]
+    fn test_changed_field_ids() {
+        let before = Account {
+            id: 1,
+            name: "alice".into(),
+            balance: Some(10.0),
+            address: Some(Address {
+                city: "paris".into(),
+            }),
+            tags: vec!["admin".into()],
+        };
+        assert!(before.changed_from(&before).is_empty());
+
+        let mut after = before.clone();
+        after.name = "bob".into();
+        after.address.as_mut().unwrap().city = "rome".into();
+        assert_eq!(after.changed_from(&before), vec![2, 4]);
+
+        // floats are compared with exact equality, so NaN always differs
+        after.balance = Some(f64::NAN);
+        assert_eq!(after.changed_from(&after), vec![3]);
+    }
+}
+
+mod gen_constructors {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_constructor/user.rs");
+    }
+
+    use generated::user::user::{Filter, Role, User};
+
+    #[test]
+    fn test_new_with_required_fields() {
+        let user = User::new(7, "alice".into(), Filter::default());
+        assert_eq!(user.id, 7);
+        assert_eq!(user.name, "alice");
+        assert_eq!(user.email, None);
+        assert_eq!(user.role, Some(Role::Guest));
+        assert_eq!(user.tags, Some(vec!["new".to_string()]));
+        assert_eq!(user.filter.limit, Some(10));
+    }
+}
+
+mod with_builders {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_builder/account.rs");
+    }
+
+    use generated::account::account::{Account, Quota};
+
+    #[test]
+    fn test_builder_matches_literal() {
+        let account = Account::builder()
+            .owner("alice".into())
+            .id(7)
+            .email("alice@example.com".into())
+            .quota(Quota::builder().limit(5).build().unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            account,
+            Account {
+                id: 7,
+                owner: "alice".into(),
+                level: 1,
+                email: Some("alice@example.com".into()),
+                tags: Some(vec!["new".into()]),
+                quota: Some(Quota { limit: Some(5) }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_missing_required() {
+        let err = Account::builder().id(7).build().unwrap_err();
+        assert_eq!(err.message, "Account");
+        assert_eq!(err.field, "owner");
+        assert_eq!(Quota::builder().build().unwrap().limit, Some(100));
+    }
+}
+
+mod rust_type {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/rust_type.rs");
+    }
+
+    use generated::rust_type::rust_type::Inventory;
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_rust_type_roundtrip() {
+        let inventory = Inventory {
+            owner: "alice".into(),
+            history: [3, 1, 2].into_iter().collect(),
+            stock: Some([("apple".to_string(), 4)].into_iter().collect()),
+            note: "fresh".to_string(),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        inventory
+            .encode(&mut TBinaryProtocol::new(&mut buf))
+            .unwrap();
+        assert_eq!(
+            pilota::thrift::Size::size(&inventory, &TBinaryProtocol::new(())),
+            buf.len()
+        );
+        let decoded = Inventory::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, inventory);
+        assert_eq!(decoded.history.front(), Some(&3));
+    }
+}
+
+mod union_members {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/union_members.rs");
+    }
+
+    use generated::union_members::union_members::{Canvas, Empty, Point, Shape};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_union_roundtrip() {
+        let shapes = [
+            Shape::Label("square".into()),
+            Shape::Point(Point { x: 1, y: 2 }),
+            Shape::Path(vec![Point { x: 0, y: 0 }, Point { x: 3, y: 4 }]),
+            Shape::Radius(7),
+        ];
+        for shape in shapes {
+            let mut buf = pilota::bytes::BytesMut::new();
+            shape.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+            assert_eq!(
+                pilota::thrift::Size::size(&shape, &TBinaryProtocol::new(())),
+                buf.len()
+            );
+            let decoded = Shape::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+            assert_eq!(decoded, shape);
+        }
+    }
+
+    #[test]
+    fn test_union_default_member() {
+        assert_eq!(Shape::default(), Shape::Label("none".into()));
+        assert_eq!(Canvas::default().shape, Shape::Label("none".into()));
+    }
+
+    #[test]
+    fn test_empty_union() {
+        let canvas = Canvas {
+            shape: Shape::Radius(1),
+            nothing: None,
+        };
+        let mut buf = pilota::bytes::BytesMut::new();
+        canvas.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(
+            Canvas::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap(),
+            canvas
+        );
+
+        // the encoding of any struct without fields
+        let mut buf = pilota::bytes::BytesMut::from(&[0u8][..]);
+        assert!(Empty::decode(&mut TBinaryProtocol::new(&mut buf)).is_err());
+    }
+}
+
+mod keywords {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/keywords.rs");
+    }
+
+    use generated::keywords::keywords::{Keywords, Kind, Self_};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_keyword_fields_roundtrip() {
+        let keywords = Keywords {
+            r#type: "any".into(),
+            self_: Some(1),
+            super_: None,
+            crate_: Some(3),
+            r#match: Some(true),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        keywords
+            .encode(&mut TBinaryProtocol::new(&mut buf))
+            .unwrap();
+        let decoded = Keywords::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, keywords);
+        assert_eq!(Self_::default().kind, Kind::Self_);
+    }
+
+    #[test]
+    fn test_keyword_required_field_error() {
+        let mut buf = pilota::bytes::BytesMut::new();
+        Self_ { kind: Kind::Move }
+            .encode(&mut TBinaryProtocol::new(&mut buf))
+            .unwrap();
+        let err = Keywords::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap_err();
+        assert!(err.to_string().contains("field type is required"));
+    }
+}
+
+mod ord_by_id {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_ord/version.rs");
+    }
+
+    use generated::version::version::{Release, Version};
+
+    fn version(major: i32, minor: i32, patch: i32) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_ordered_by_field_id() {
+        // `patch` is declared first, but `major` has the smallest id
+        assert!(version(1, 0, 9) < version(2, 0, 0));
+        assert!(version(1, 2, 0) > version(1, 1, 9));
+        assert!(
+            version(1, 0, 0)
+                < Version {
+                    label: Some("rc".into()),
+                    ..version(1, 0, 0)
+                }
+        );
+
+        let releases = [
+            Release {
+                notes: [("b".to_string(), 1)].into(),
+                version: version(1, 0, 0),
+            },
+            Release {
+                notes: [("a".to_string(), 1)].into(),
+                version: version(2, 0, 0),
+            },
+        ];
+        // `notes` has the id 1 and is compared first
+        assert!(releases[1] < releases[0]);
+        let sorted = releases
+            .iter()
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>();
+        assert_eq!(sorted.first(), Some(&releases[1]));
+
+        // the equality agrees with the order
+        for (a, b) in [
+            (version(1, 0, 0), version(1, 0, 0)),
+            (version(1, 0, 0), version(1, 0, 1)),
+        ] {
+            assert_eq!(a == b, a.cmp(&b).is_eq());
+        }
+        assert_eq!(releases[0], releases[0].clone());
+    }
+}
+
+mod collection_kind {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_collections/ordered.rs");
+    }
+
+    use generated::ordered::ordered::{Inventory, LIMITS};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_ordered_collections_roundtrip() {
+        assert_eq!(LIMITS.keys().copied().collect::<Vec<_>>(), ["a", "b"]);
+
+        let inventory = Inventory {
+            counts: [("pear".to_string(), 1), ("apple".to_string(), 2)].into(),
+            tags: ["fresh".to_string(), "cheap".to_string()].into(),
+            names: Some([(2, "two".to_string()), (1, "one".to_string())].into()),
+            arrivals: [("pear".to_string(), 1), ("apple".to_string(), 2)].into(),
+            batches: vec![[("b".to_string(), 2), ("a".to_string(), 1)].into()],
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        inventory
+            .encode(&mut TBinaryProtocol::new(&mut buf))
+            .unwrap();
+        let decoded = Inventory::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, inventory);
+        assert_eq!(decoded.counts.keys().collect::<Vec<_>>(), ["apple", "pear"]);
+        assert_eq!(decoded.tags.iter().collect::<Vec<_>>(), ["cheap", "fresh"]);
+        // the field annotated with `rust.collection = "index"` keeps the wire order
+        assert_eq!(
+            decoded.arrivals.keys().collect::<Vec<_>>(),
+            ["pear", "apple"]
+        );
+        assert_eq!(
+            Inventory::default()
+                .names
+                .unwrap()
+                .into_values()
+                .collect::<Vec<_>>(),
+            ["one", "two"]
+        );
+    }
+}
+
+mod flatten_single_package {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_flatten/api.rs");
+    }
+
+    use generated::api::{Account, AccountServiceGetArgs, Money, Status};
+
+    #[test]
+    fn test_items_at_the_root() {
+        let account = Account {
+            name: "alice".into(),
+            status: Status::Active,
+            balance: Some(Money { cents: 100 }),
+        };
+        let args = AccountServiceGetArgs {
+            name: account.name.clone(),
+        };
+        assert_eq!(args.name, "alice");
+    }
+}
+
+mod view_types {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_view/order.rs");
+    }
+
+    use generated::order::order::{Address, Order, OrderRef, Point, Status};
+
+    #[test]
+    fn test_owned_view_roundtrip() {
+        let order = Order {
+            id: 1,
+            customer: "alice".into(),
+            note: None,
+            payload: vec![1, 2, 3],
+            items: vec!["apple".into()],
+            quantities: [("apple".to_string(), 2)].into(),
+            address: Address {
+                city: "paris".into(),
+                location: Some(Point { x: 1, y: 2 }),
+            },
+            billing: Some(Address {
+                city: "rome".into(),
+                location: None,
+            }),
+            status: Some(Status::Open),
+        };
+
+        let view = order.as_ref_view();
+        assert_eq!(view.customer, "alice");
+        assert_eq!(view.payload, &[1, 2, 3]);
+        assert_eq!(view.address.city, "paris");
+        assert_eq!(view.address.location.unwrap().x, 1);
+        assert_eq!(view.billing.unwrap().city, "rome");
+        assert!(std::ptr::eq(
+            view.customer.as_ptr(),
+            order.customer.as_ptr()
+        ));
+
+        let view: OrderRef<'_> = view;
+        assert_eq!(Order::from(view), order);
+    }
+}
+
+mod protobuf_presence {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_presence/presence.rs");
+    }
+
+    use generated::presence::presence::Counter;
+    use prost::Message;
+
+    #[test]
+    fn test_explicit_presence() {
+        let counter = Counter {
+            count: Some(0),
+            ..Default::default()
+        };
+        assert!(counter.has_count());
+        assert!(!counter.has_label());
+        // a keyword field keeps the raw identifier and the accessor drops it.
+        assert!(!counter.has_type());
+
+        let buf = counter.encode_to_vec();
+        // an explicit zero is written, the implicit `total` is not.
+        assert_eq!(buf, [0x08, 0x00]);
+        let decoded = Counter::decode(&*buf).unwrap();
+        assert_eq!(decoded.count, Some(0));
+        assert_eq!(decoded.total, 0);
+
+        assert!(Counter::default().encode_to_vec().is_empty());
+    }
+}
+
+mod boxed_str {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift/boxed_str.rs");
+    }
+
+    use generated::boxed_str::boxed_str::Profile;
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_boxed_str_fields() {
+        let profile = Profile::default();
+        assert_eq!(&*profile.city, "unknown");
+        assert_eq!(profile.motto.as_deref(), Some("carpe diem"));
+
+        let profile = Profile {
+            name: "alice".into(),
+            bio: Some(Box::from("hello")),
+            email: "alice@example.com".to_string(),
+            ..profile
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        profile.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = Profile::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, profile);
+        assert_eq!(&*decoded.name, "alice");
+    }
+}
+
+mod default_impl {
+    #[allow(clippy::all)]
+    mod derive {
+        include!("../../test_data/thrift_default_impl/settings_derive.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod manual {
+        include!("../../test_data/thrift_default_impl/settings_manual.rs");
+    }
+
+    #[test]
+    fn test_derived_default() {
+        use self::derive::settings_derive::settings::{Mode, Settings};
+
+        let settings = Settings::default();
+        assert_eq!(settings.mode, Mode::Fast);
+        assert_eq!(settings.limit, None);
+        assert_eq!(settings.retries, 0);
+    }
+
+    #[test]
+    fn test_manual_default() {
+        use self::manual::settings_manual::settings::{Mode, Plain, Settings};
+
+        let settings = Settings::default();
+        assert_eq!(settings.mode, Mode::Fast);
+        assert_eq!(settings.limit, None);
+        assert_eq!(settings.retries, 3);
+
+        let plain = Plain::default();
+        assert_eq!(plain.mode, Mode::Fast);
+        assert_eq!(plain.note, None);
+    }
+}
+
+mod transparent_typedefs {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_typedef/event.rs");
+    }
+
+    use generated::event::event::{Event, EPOCHS};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_unwrapped_fields() {
+        let event = Event {
+            created_at: 42i64,
+            history: Some(EPOCHS.clone()),
+            deadlines: [("review".to_string(), 7i64)].into_iter().collect(),
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        event.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        let decoded = Event::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, event);
+        assert_eq!(decoded.history, Some(vec![0, 1]));
+    }
+}
+
+mod encoded_len {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_json/nested.rs");
+    }
+
+    use generated::nested::nested::{Address, Contact, Person, Status};
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_encoded_len() {
+        let home = Address {
+            city: "Beijing".to_string(),
+            zip_code: Some("100000".to_string()),
+        };
+        let person = Person {
+            name: "pilota".to_string(),
+            status: Status::Active,
+            addresses: vec![home.clone(), home.clone()],
+            labels: [
+                (
+                    1,
+                    ["rust".to_string(), "thrift".to_string()]
+                        .into_iter()
+                        .collect(),
+                ),
+                (2, Default::default()),
+            ]
+            .into_iter()
+            .collect(),
+            contact: Some(Contact::Address(home)),
+            avatar: Some(vec![1, 2, 3]),
+            created_at: Some(42.into()),
+            office: None,
+        };
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        person.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(person.encoded_len(), buf.len());
+
+        let contact = Contact::Email("pilota@example.com".to_string());
+        let mut buf = pilota::bytes::BytesMut::new();
+        contact.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(contact.encoded_len(), buf.len());
+    }
+}
+
+mod box_large_variants {
+    #[allow(clippy::all)]
+    mod payload {
+        include!("../../test_data/thrift_box_variants/payload.rs");
+    }
+
+    #[allow(clippy::all)]
+    mod event {
+        include!("../../test_data/protobuf_box_variants/event.rs");
+    }
+
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    #[test]
+    fn test_boxed_union_roundtrip() {
+        use self::payload::payload::payload::{Header, Payload};
+
+        let payload = Payload::Nested(::std::sync::Arc::new(Payload::Header(Box::new(Header {
+            id: 1,
+            name: "pilota".to_string(),
+            owner: "cloudwego".to_string(),
+            tags: vec!["rust".to_string()],
+        }))));
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        payload.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(payload.encoded_len(), buf.len());
+        let decoded = Payload::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_boxed_oneof_roundtrip() {
+        use self::event::event::event::{
+            event::{Event, Payload},
+            Header,
+        };
+
+        let header = Header {
+            id: 1,
+            name: "pilota".to_string(),
+            ..Default::default()
+        };
+        let event = Event {
+            payload: Some(Payload::from(header.clone())),
+        };
+
+        let buf = prost::Message::encode_to_vec(&event);
+        let decoded = <Event as prost::Message>::decode(&*buf).unwrap();
+        assert_eq!(decoded, event);
+
+        let name =
+            decoded
+                .payload
+                .unwrap()
+                .map_variant(|_| None, |h| Some(h.name), |_| None, |_| None);
+        assert_eq!(name.as_deref(), Some("pilota"));
+
+        // the strings are larger than the threshold but prost cannot box them
+        let event = Event {
+            payload: Some(Payload::Note("large".to_string())),
+        };
+        let buf = prost::Message::encode_to_vec(&event);
+        assert_eq!(<Event as prost::Message>::decode(&*buf).unwrap(), event);
+    }
+}
+
+mod thrift_json {
+    #[allow(clippy::all)]
+    mod tjson {
+        include!("../../test_data/thrift_tjson/tjson.rs");
+    }
+
+    use self::tjson::tjson::tjson::{Canvas, Color, Point, Shape};
+
+    // the document written by `TJSONProtocol` for a `Canvas`
+    const CANVAS: &str = r#"{
+        "1": {"tf": 1},
+        "2": {"i8": -1},
+        "3": {"i16": 640},
+        "4": {"i64": 1700000000000},
+        "5": {"dbl": "Infinity"},
+        "6": {"str": "pilota"},
+        "7": {"str": "AAEC/w"},
+        "8": {"i32": 2},
+        "9": {"rec": {"1": {"i32": 1}, "2": {"i32": 2}}},
+        "10": {"lst": ["rec", 2, {"1": {"i32": 0}, "2": {"i32": 0}}, {"1": {"i32": 3}, "2": {"i32": 4}}]},
+        "11": {"set": ["str", 1, "rust"]},
+        "12": {"map": ["str", "lst", 1, {"even": ["i32", 2, 2, 4]}]},
+        "13": {"map": ["i32", "rec", 1, {"7": {"1": {"i32": 0}, "2": {"i32": 7}}}]},
+        "14": {"rec": {"2": {"lst": ["rec", 1, {"1": {"i32": 5}, "2": {"i32": 6}}]}}}
+    }"#;
+
+    #[test]
+    fn test_thrift_json_roundtrip() {
+        let document: serde_json::Value = serde_json::from_str(CANVAS).unwrap();
+        let canvas = Canvas::from_thrift_json(&document).unwrap();
+
+        assert!(canvas.visible);
+        assert_eq!(canvas.layer as i8, -1);
+        assert_eq!(canvas.scale, f64::INFINITY);
+        assert_eq!(canvas.thumbnail, vec![0, 1, 2, 255]);
+        assert_eq!(canvas.background, Color::Green);
+        assert_eq!(canvas.origin, Some(Point { x: 1, y: 2 }));
+        assert_eq!(canvas.points[1], Point { x: 3, y: 4 });
+        assert!(canvas.labels.contains("rust"));
+        assert_eq!(canvas.groups["even"], vec![2, 4]);
+        assert_eq!(canvas.anchors[&7], Point { x: 0, y: 7 });
+        assert_eq!(canvas.shape, Shape::Polygon(vec![Point { x: 5, y: 6 }]));
+        assert_eq!(canvas.comment, None);
+
+        assert_eq!(canvas.to_thrift_json().unwrap(), document);
+    }
+
+    #[test]
+    fn test_thrift_json_errors() {
+        let mut document: serde_json::Value = serde_json::from_str(CANVAS).unwrap();
+        document["7"]["str"] = serde_json::Value::from("AAEC/w==");
+        let canvas = Canvas::from_thrift_json(&document).unwrap();
+        assert_eq!(canvas.thumbnail, vec![0, 1, 2, 255]);
+
+        // a field of another type is skipped
+        document["9"] = serde_json::json!({"i32": 1});
+        assert_eq!(Canvas::from_thrift_json(&document).unwrap().origin, None);
+
+        document["11"]["set"][1] = serde_json::Value::from(2);
+        assert!(Canvas::from_thrift_json(&document).is_err());
+
+        document.as_object_mut().unwrap().remove("6");
+        assert!(Canvas::from_thrift_json(&document).is_err());
+    }
+}
+
+mod arc_fields {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_arc_fields/arc_fields.rs");
+    }
+
+    use std::{collections::HashMap, sync::Arc};
+
+    use pilota::thrift::{Message, TBinaryProtocol};
+
+    use self::generated::arc_fields::arc_fields::{Color, Hot, Inner, Node};
+
+    #[test]
+    fn test_arc_fields() {
+        let hot = Hot {
+            meta: Some(Arc::new(HashMap::from([(
+                "k".to_string(),
+                Inner {
+                    label: "v".to_string(),
+                },
+            )]))),
+            payload: Arc::new(vec![1, 2]),
+            ..Default::default()
+        };
+        assert_eq!(hot.get_name(), "hot");
+        assert_eq!(hot.get_ids(), &vec![1, 2]);
+        assert_eq!(*hot.get_count(), 3);
+        assert_eq!(*hot.get_color(), Color::Red);
+        assert_eq!(hot.get_meta().unwrap()["k"].label, "v");
+        assert_eq!(hot.get_inner(), None);
+        assert_eq!(hot.get_encoded_len(), None);
+
+        // the clones share the values of the fields
+        let cloned = hot.clone();
+        assert!(Arc::ptr_eq(&hot.ids, &cloned.ids));
+
+        let mut buf = pilota::bytes::BytesMut::new();
+        hot.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(hot.encoded_len(), buf.len());
+        let decoded = Hot::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(decoded, hot);
+
+        let built = Hot::builder()
+            .payload(Arc::new(vec![]))
+            .inner(Arc::new(Inner {
+                label: "built".to_string(),
+            }))
+            .build()
+            .unwrap();
+        assert_eq!(built.get_inner().unwrap().label, "built");
+        assert_eq!(*built.get_count(), 3);
+
+        let node = Node {
+            value: Arc::new(1),
+            next: Some(Arc::new(Node {
+                value: Arc::new(2),
+                next: None,
+            })),
+        };
+        assert_eq!(*node.get_next().unwrap().get_value(), 2);
+    }
+}
+
+mod inherited_visibility {
+    #[allow(clippy::all, dead_code)]
+    mod generated {
+        include!("../../test_data/thrift_visibility/inventory_inherited.rs");
+    }
+
+    use self::generated::inventory_inherited::inventory::{Item, State, MAX_ITEMS};
+
+    #[test]
+    fn test_inherited_visibility() {
+        // the items are usable out of their modules, but not the fields
+        let item = Item::default();
+        assert_eq!(item, Item::default());
+        assert_eq!(MAX_ITEMS, 100);
+        assert_eq!(State::InStock as i32, 1);
+    }
+}
+
+mod convert {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_convert/convert.rs");
+    }
+
+    use self::generated::convert::convert::{AddressV1, UserV1, UserV2};
+
+    #[test]
+    fn test_convert() {
+        let address = |city: &str| AddressV1 {
+            city: city.to_string(),
+            street: Some("main".to_string()),
+        };
+        let user = UserV2::from(UserV1 {
+            id: 1,
+            name: "alice".to_string(),
+            home: Some(address("paris")),
+            addresses: vec![address("lyon"), address("nice")],
+            named: [("work".to_string(), address("lille"))]
+                .into_iter()
+                .collect(),
+            legacy_flags: Some(3),
+            tags: vec!["a".to_string()],
+        });
+
+        assert_eq!(user.id, 1);
+        assert_eq!(user.name.as_deref(), Some("alice"));
+        let home = user.home.unwrap();
+        assert_eq!(home.city, "paris");
+        assert_eq!(home.street.as_deref(), Some("main"));
+        assert_eq!(home.zip, None);
+        assert_eq!(
+            user.addresses.iter().map(|a| &*a.city).collect::<Vec<_>>(),
+            ["lyon", "nice"]
+        );
+        assert_eq!(user.named["work"].city, "lille");
+        assert_eq!(user.tags, ["a"]);
+        assert_eq!(user.email, None);
+    }
+}
+
+mod protobuf_convert {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/protobuf_convert/convert.rs");
+    }
+
+    use self::generated::convert::{v1, v2};
+
+    #[test]
+    fn test_convert() {
+        let order = v2::order::Order::from(v1::order::Order {
+            id: 1,
+            lines: vec![v1::order::Line {
+                sku: "a".to_string(),
+                count: 2,
+            }],
+            payment: Some(v1::order::Payment::Gift(v1::Item {
+                name: "card".to_string(),
+            })),
+        });
+
+        assert_eq!(order.id, 1);
+        assert_eq!(order.lines[0].sku, "a");
+        assert_eq!(order.lines[0].count, 2);
+        assert_eq!(order.comment, "");
+        match order.payment {
+            Some(v2::order::Payment::Gift(item)) => {
+                assert_eq!(item.name, "card");
+                assert_eq!(item.note, "");
+            }
+            _ => panic!("the gift is not converted"),
+        }
+
+        let order = v2::order::Order::from(v1::order::Order {
+            payment: Some(v1::order::Payment::Voucher(3)),
+            ..Default::default()
+        });
+        assert!(matches!(
+            order.payment,
+            Some(v2::order::Payment::Voucher(3))
+        ));
+    }
+}
+
+mod method_exceptions {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_exceptions/exceptions.rs");
+    }
+
+    use pilota::thrift::UserError;
+
+    use self::generated::exceptions::exceptions::{
+        Denied, Item, NotFound, Store, StoreGetException, StoreMoveException, StoreRemoveException,
+    };
+
+    struct Empty;
+
+    #[async_trait::async_trait]
+    impl Store for Empty {
+        async fn get(&self, key: String) -> Result<Item, UserError<StoreGetException>> {
+            if key.is_empty() {
+                Err(Denied {
+                    reason: "empty key".into(),
+                })?;
+            }
+            Err(NotFound { key })?
+        }
+
+        async fn remove(&self, key: String) -> Result<(), UserError<StoreRemoveException>> {
+            Err(NotFound { key }.into())
+        }
+
+        async fn r#move(
+            &self,
+            _from: String,
+            _to: String,
+        ) -> Result<(), UserError<StoreMoveException>> {
+            // `NotFound` is thrown by two variants, so only `Denied` converts
+            Err(Denied {
+                reason: "read only".into(),
+            })?
+        }
+    }
+
+    #[test]
+    fn test_method_exceptions() {
+        futures::executor::block_on(async {
+            assert!(matches!(
+                Empty.get("a".into()).await,
+                Err(UserError::UserException(StoreGetException::NotFound(NotFound { key }))) if key == "a"
+            ));
+            assert!(matches!(
+                Empty.get("".into()).await,
+                Err(UserError::UserException(StoreGetException::Denied(_)))
+            ));
+            assert!(matches!(
+                Empty.remove("a".into()).await,
+                Err(UserError::UserException(StoreRemoveException::NotFound(_)))
+            ));
+            assert!(matches!(
+                Empty.r#move("a".into(), "b".into()).await,
+                Err(UserError::UserException(StoreMoveException::Denied(_)))
+            ));
+        });
+
+        assert!(matches!(
+            StoreMoveException::from(Denied {
+                reason: "read only".into()
+            }),
+            StoreMoveException::Denied(_)
+        ));
+    }
+}
+
+mod small_int_width {
+    #[allow(clippy::all)]
+    mod generated {
+        include!("../../test_data/thrift_small_ints/ints.rs");
+    }
+
+    use pilota::thrift::{
+        Message, TBinaryProtocol, TFieldIdentifier, TOutputProtocol, TStructIdentifier, TType,
+    };
+
+    use self::generated::ints::ints::{Level, Limits, Port, MAX_RETRIES};
+
+    #[test]
+    fn test_small_int_width() {
+        // a `Level` whose value is a one byte `i8` on the wire
+        let mut wire = pilota::bytes::BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut wire);
+        protocol
+            .write_struct_begin(&TStructIdentifier { name: "Level" })
+            .unwrap();
+        protocol
+            .write_field_begin(&TFieldIdentifier {
+                name: Some("value"),
+                field_type: TType::I08,
+                id: Some(1),
+            })
+            .unwrap();
+        protocol.write_i8(-5).unwrap();
+        protocol.write_field_end().unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+
+        let level = Level::decode(&mut TBinaryProtocol::new(&mut wire.clone())).unwrap();
+        assert_eq!(level.value, -5i32);
+        let mut buf = pilota::bytes::BytesMut::new();
+        level.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(buf, wire);
+        assert_eq!(level.encoded_len(), wire.len());
+
+        let err = Level { value: 300 }
+            .encode(&mut TBinaryProtocol::new(
+                &mut pilota::bytes::BytesMut::new(),
+            ))
+            .unwrap_err();
+        assert!(err.to_string().contains("300 is out of the range of i8"));
+
+        let limits = Limits {
+            retries: MAX_RETRIES,
+            port: 443,
+            priority: Some(-128),
+            steps: vec![1, 127],
+            weights: [(-32768, 1)].into_iter().collect(),
+            fallback: Port::from(8080),
+        };
+        let mut buf = pilota::bytes::BytesMut::new();
+        limits.encode(&mut TBinaryProtocol::new(&mut buf)).unwrap();
+        assert_eq!(limits.encoded_len(), buf.len());
+        assert_eq!(
+            Limits::decode(&mut TBinaryProtocol::new(&mut buf)).unwrap(),
+            limits
+        );
+
+        let json = limits.to_thrift_json().unwrap();
+        assert_eq!(Limits::from_thrift_json(&json).unwrap(), limits);
+
+        let overflow = Limits {
+            port: 65536,
+            ..limits
+        };
+        assert!(overflow
+            .encode(&mut TBinaryProtocol::new(
+                &mut pilota::bytes::BytesMut::new()
+            ))
+            .is_err());
+        let err = overflow.to_thrift_json().unwrap_err();
+        assert!(err.to_string().contains("65536 is out of the range of i16"));
+        let mut json = json;
+        json["2"]["i16"] = 65536.into();
+        assert!(Limits::from_thrift_json(&json).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+pub mod doc_comments {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    #![allow(deprecated)]
+    pub mod doc_comments {
+        pub mod user {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Contact {
+                #[doc = " An email address."]
+                #[prost(string, tag = "3")]
+                Email(::std::string::String),
+                #[prost(int64, tag = "4")]
+                Phone(i64),
+            }
+            #[doc = " Where the user lives."]
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Address {
+                #[doc = " The street and number."]
+                #[prost(string, tag = "1")]
+                #[deprecated]
+                pub street: ::std::string::String,
+            }
+            #[doc = " A user, written to the \"users\" table."]
+            #[doc = ""]
+            #[doc = " Paths look like `C:\\Users\\name`."]
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct User {
+                #[doc = " The id, never reused."]
+                #[prost(int64, tag = "1")]
+                pub id: i64,
+                #[prost(string, tag = "2")]
+                pub name: ::std::string::String,
+                #[prost(message, repeated, tag = "5")]
+                pub addresses: ::std::vec::Vec<Address>,
+                #[doc = " How to reach the user."]
+                #[prost(oneof = "Contact", tags = "3,4")]
+                pub contact: ::std::option::Option<Contact>,
+            }
+        }
+        #[doc = " The state of an account."]
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum State {
+            #[doc = " Can log in."]
+            StateActive = 0i32,
+            StateBanned = 1i32,
+        }
+        #[doc = " The users."]
+        #[::async_trait::async_trait]
+        pub trait UserService {}
+    }
+}
@@ -0,0 +1,27 @@
+pub mod fixed {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod fixed {
+        #[derive(:: prost :: Message, Clone, PartialEq)]
+        pub struct Fixed {
+            #[prost(fixed32, tag = "1")]
+            pub a: u32,
+            #[prost(fixed64, tag = "2")]
+            pub b: u64,
+            #[prost(sfixed32, tag = "3")]
+            pub c: i32,
+            #[prost(sfixed64, tag = "4")]
+            pub d: i64,
+            #[prost(sfixed32, repeated, tag = "5")]
+            pub e: ::std::vec::Vec<i32>,
+            #[prost(map = "fixed32 , sfixed64", tag = "6")]
+            pub f: ::std::collections::HashMap<u32, i64>,
+        }
+    }
+}
@@ -12,8 +12,8 @@ pub mod nested_message {
             pub mod t2 {
                 #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
                 pub struct Tt3 {
-                    #[prost(int32, tag = "1")]
-                    pub a: i32,
+                    #[prost(int32, tag = "1", optional)]
+                    pub a: ::std::option::Option<i32>,
                 }
                 #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
                 pub struct T2 {
@@ -24,7 +24,7 @@ pub mod nested_message {
             #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
             pub struct Tt1 {
                 #[prost(message, tag = "1", optional)]
-                pub t2: ::std::option::Option<T2::T2>,
+                pub t2: ::std::option::Option<t2::T2>,
             }
         }
     }
@@ -0,0 +1,30 @@
+pub mod oneof {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod oneof {
+        pub mod tree {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Node {
+                #[prost(int32, tag = "2")]
+                Leaf(i32),
+                #[prost(string, tag = "3")]
+                Label(::std::string::String),
+                #[prost(message, tag = "4")]
+                Child(::std::boxed::Box<Tree>),
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Tree {
+                #[prost(string, tag = "1")]
+                pub name: ::std::string::String,
+                #[prost(oneof = "Node", tags = "2,3,4")]
+                pub node: ::std::option::Option<Node>,
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+pub mod scalars {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod scalars {
+        #[derive(PartialOrd, :: prost :: Message, Clone, PartialEq)]
+        pub struct Scalars {
+            #[prost(uint64, tag = "1")]
+            pub id: u64,
+            #[prost(uint32, tag = "2")]
+            pub flags: u32,
+            #[prost(float, tag = "3")]
+            pub ratio: f32,
+            #[prost(fixed32, tag = "4")]
+            pub checksum: u32,
+            #[prost(fixed64, tag = "5")]
+            pub offset: u64,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+pub mod zigzag {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod zigzag {
+        pub mod delta {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Hint {
+                #[prost(sint32, tag = "5")]
+                Small(i32),
+                #[prost(sint64, tag = "6")]
+                Large(i64),
+            }
+            #[derive(:: prost :: Message, Clone, PartialEq)]
+            pub struct Delta {
+                #[prost(sint32, tag = "1")]
+                pub dx: i32,
+                #[prost(sint64, tag = "2")]
+                pub dy: i64,
+                #[prost(sint32, repeated, tag = "3")]
+                pub steps: ::std::vec::Vec<i32>,
+                #[prost(map = "sint32 , sint64", tag = "4")]
+                pub offsets: ::std::collections::HashMap<i32, i64>,
+                #[prost(oneof = "Hint", tags = "5,6")]
+                pub hint: ::std::option::Option<Hint>,
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+pub mod envelope {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod envelope {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Ping {
+            #[prost(int64, tag = "1")]
+            pub seq: i64,
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Notice {
+            #[prost(string, tag = "1")]
+            pub text: ::std::string::String,
+            #[prost(int32, tag = "2")]
+            pub level: i32,
+        }
+        #[doc = r" All the messages of the package, tagged by a hash of their full names."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum AnyMessage {
+            Ping(Ping),
+            Notice(Notice),
+        }
+        impl AnyMessage {
+            pub fn tag(&self) -> i32 {
+                match self {
+                    AnyMessage::Ping(_) => 2133532469,
+                    AnyMessage::Notice(_) => 549668109,
+                }
+            }
+        }
+        impl ::std::convert::From<Ping> for AnyMessage {
+            fn from(v: Ping) -> Self {
+                AnyMessage::Ping(v)
+            }
+        }
+        impl ::std::convert::From<Notice> for AnyMessage {
+            fn from(v: Notice) -> Self {
+                AnyMessage::Notice(v)
+            }
+        }
+        impl AnyMessage {
+            pub fn decode_by_tag(
+                tag: i32,
+                buf: impl ::prost::bytes::Buf,
+            ) -> ::std::result::Result<Self, ::prost::DecodeError> {
+                match tag {
+                    2133532469 => Ok(AnyMessage::Ping(::prost::Message::decode(buf)?)),
+                    549668109 => Ok(AnyMessage::Notice(::prost::Message::decode(buf)?)),
+                    _ => Err(::prost::DecodeError::new(format!(
+                        "unknown message tag {}",
+                        tag
+                    ))),
+                }
+            }
+            pub fn encode(
+                &self,
+                buf: &mut impl ::prost::bytes::BufMut,
+            ) -> ::std::result::Result<(), ::prost::EncodeError> {
+                match self {
+                    AnyMessage::Ping(v) => ::prost::Message::encode(v, buf),
+                    AnyMessage::Notice(v) => ::prost::Message::encode(v, buf),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+pub mod well_known {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod events {
+        pub use super::google::protobuf as any;
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Event {
+            #[prost(string, tag = "1")]
+            pub name: ::std::string::String,
+            #[prost(message, tag = "2", optional)]
+            pub payload: ::std::option::Option<any::Any>,
+        }
+        impl ::pilota::protobuf::WireName for Event {
+            const WIRE_NAME: &'static str = "events.Event";
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Login {
+            #[prost(string, tag = "1")]
+            pub user: ::std::string::String,
+        }
+        impl ::pilota::protobuf::WireName for Login {
+            const WIRE_NAME: &'static str = "events.Login";
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Logout {
+            #[prost(string, tag = "1")]
+            pub user: ::std::string::String,
+        }
+        impl ::pilota::protobuf::WireName for Logout {
+            const WIRE_NAME: &'static str = "events.Logout";
+        }
+    }
+    pub mod google {
+        pub mod protobuf {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Any {
+                #[prost(string, tag = "1")]
+                pub type_url: ::std::string::String,
+                #[prost(bytes, tag = "2")]
+                pub value: ::std::vec::Vec<u8>,
+            }
+            impl ::pilota::protobuf::WireName for Any {
+                const WIRE_NAME: &'static str = "google.protobuf.Any";
+            }
+            impl Any {
+                pub fn pack<T: ::prost::Message + ::pilota::protobuf::WireName>(msg: &T) -> Self {
+                    Any {
+                        type_url: T::type_url(),
+                        value: msg.encode_to_vec().into(),
+                    }
+                }
+                #[doc = r" Returns `None` if the packed message is not a `T`."]
+                pub fn unpack<T: ::prost::Message + ::pilota::protobuf::WireName + Default>(
+                    &self,
+                ) -> ::std::result::Result<::std::option::Option<T>, ::prost::DecodeError>
+                {
+                    if self.type_url.rsplit('/').next() != Some(T::WIRE_NAME) {
+                        return Ok(None);
+                    }
+                    T::decode(&*self.value).map(Some)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+pub mod event {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod event {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Header {
+            #[prost(int64, tag = "1")]
+            pub id: i64,
+            #[prost(string, tag = "2")]
+            pub name: ::std::string::String,
+            #[prost(string, tag = "3")]
+            pub owner: ::std::string::String,
+            #[prost(string, repeated, tag = "4")]
+            pub tags: ::std::vec::Vec<::std::string::String>,
+        }
+        pub mod event {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Payload {
+                #[prost(int64, tag = "1")]
+                Code(i64),
+                #[prost(message, tag = "2")]
+                Header(::std::boxed::Box<super::Header>),
+                #[prost(message, tag = "3")]
+                Nested(::std::boxed::Box<Event>),
+                #[prost(string, tag = "4")]
+                Note(::std::string::String),
+            }
+            impl ::std::convert::From<i64> for Payload {
+                fn from(v: i64) -> Self {
+                    Payload::Code(v)
+                }
+            }
+            impl ::std::convert::From<super::Header> for Payload {
+                fn from(v: super::Header) -> Self {
+                    Payload::Header(::std::boxed::Box::new(v))
+                }
+            }
+            impl ::std::convert::From<::std::boxed::Box<Event>> for Payload {
+                fn from(v: ::std::boxed::Box<Event>) -> Self {
+                    Payload::Nested(v)
+                }
+            }
+            impl ::std::convert::From<::std::string::String> for Payload {
+                fn from(v: ::std::string::String) -> Self {
+                    Payload::Note(v)
+                }
+            }
+            impl Payload {
+                #[doc = r" Calls the handler of the variant, every variant must be handled."]
+                pub fn map_variant<R>(
+                    self,
+                    on_code: impl FnOnce(i64) -> R,
+                    on_header: impl FnOnce(super::Header) -> R,
+                    on_nested: impl FnOnce(::std::boxed::Box<Event>) -> R,
+                    on_note: impl FnOnce(::std::string::String) -> R,
+                ) -> R {
+                    match self {
+                        Payload::Code(v0) => on_code(v0),
+                        Payload::Header(v0) => on_header(*v0),
+                        Payload::Nested(v0) => on_nested(v0),
+                        Payload::Note(v0) => on_note(v0),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Event {
+                #[prost(oneof = "Payload", tags = "1,2,3,4")]
+                pub payload: ::std::option::Option<Payload>,
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod bytes {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod bytes {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Packet {
+            #[prost(bytes = "bytes", tag = "1")]
+            pub payload: ::pilota::bytes::Bytes,
+            #[prost(bytes = "bytes", repeated, tag = "2")]
+            pub frames: ::std::vec::Vec<::pilota::bytes::Bytes>,
+        }
+    }
+}
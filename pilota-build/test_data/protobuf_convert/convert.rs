@@ -0,0 +1,126 @@
+pub mod convert {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod v1 {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Item {
+            #[prost(string, tag = "1")]
+            pub name: ::std::string::String,
+        }
+        pub mod order {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Payment {
+                #[prost(string, tag = "3")]
+                Card(::std::string::String),
+                #[prost(int64, tag = "4")]
+                Voucher(i64),
+                #[prost(message, tag = "5")]
+                Gift(super::Item),
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Line {
+                #[prost(string, tag = "1")]
+                pub sku: ::std::string::String,
+                #[prost(int32, tag = "2")]
+                pub count: i32,
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Order {
+                #[prost(int64, tag = "1")]
+                pub id: i64,
+                #[prost(message, repeated, tag = "2")]
+                pub lines: ::std::vec::Vec<Line>,
+                #[prost(oneof = "Payment", tags = "3,4,5")]
+                pub payment: ::std::option::Option<Payment>,
+            }
+        }
+    }
+    pub mod v2 {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Item {
+            #[prost(string, tag = "1")]
+            pub name: ::std::string::String,
+            #[prost(string, tag = "2")]
+            pub note: ::std::string::String,
+        }
+        impl ::std::convert::From<super::v1::Item> for Item {
+            #[allow(unused_variables)]
+            fn from(value: super::v1::Item) -> Self {
+                Item {
+                    name: value.name,
+                    note: ::std::default::Default::default(),
+                }
+            }
+        }
+        pub mod order {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Payment {
+                #[prost(string, tag = "3")]
+                Card(::std::string::String),
+                #[prost(int64, tag = "4")]
+                Voucher(i64),
+                #[prost(message, tag = "5")]
+                Gift(super::Item),
+            }
+            impl ::std::convert::From<super::super::v1::order::Payment> for Payment {
+                fn from(value: super::super::v1::order::Payment) -> Self {
+                    match value {
+                        super::super::v1::order::Payment::Card(v) => Payment::Card(v),
+                        super::super::v1::order::Payment::Voucher(v) => Payment::Voucher(v),
+                        super::super::v1::order::Payment::Gift(v) => {
+                            Payment::Gift(::std::convert::Into::into(v))
+                        }
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Line {
+                #[prost(string, tag = "1")]
+                pub sku: ::std::string::String,
+                #[prost(int32, tag = "2")]
+                pub count: i32,
+            }
+            impl ::std::convert::From<super::super::v1::order::Line> for Line {
+                #[allow(unused_variables)]
+                fn from(value: super::super::v1::order::Line) -> Self {
+                    Line {
+                        sku: value.sku,
+                        count: value.count,
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Order {
+                #[prost(int64, tag = "1")]
+                pub id: i64,
+                #[prost(message, repeated, tag = "2")]
+                pub lines: ::std::vec::Vec<Line>,
+                #[prost(string, tag = "6")]
+                pub comment: ::std::string::String,
+                #[prost(oneof = "Payment", tags = "3,4,5")]
+                pub payment: ::std::option::Option<Payment>,
+            }
+            impl ::std::convert::From<super::super::v1::order::Order> for Order {
+                #[allow(unused_variables)]
+                fn from(value: super::super::v1::order::Order) -> Self {
+                    Order {
+                        id: value.id,
+                        lines: value
+                            .lines
+                            .into_iter()
+                            .map(|v| ::std::convert::Into::into(v))
+                            .collect(),
+                        comment: ::std::default::Default::default(),
+                        payment: value.payment.map(|v| ::std::convert::Into::into(v)),
+                    }
+                }
+            }
+        }
+    }
+}
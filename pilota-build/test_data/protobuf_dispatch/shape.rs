@@ -0,0 +1,48 @@
+pub mod shape {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod shape {
+        #[derive(PartialOrd, :: prost :: Message, Clone, PartialEq)]
+        pub struct Circle {
+            #[prost(double, tag = "1")]
+            pub radius: f64,
+        }
+        pub mod shape {
+            #[derive(PartialOrd, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Kind {
+                #[prost(message, tag = "1")]
+                Circle(super::Circle),
+                #[prost(int32, tag = "2")]
+                Square(i32),
+                #[prost(string, tag = "3")]
+                Named(::std::string::String),
+            }
+            impl Kind {
+                #[doc = r" Calls the handler of the variant, every variant must be handled."]
+                pub fn map_variant<R>(
+                    self,
+                    on_circle: impl FnOnce(super::Circle) -> R,
+                    on_square: impl FnOnce(i32) -> R,
+                    on_named: impl FnOnce(::std::string::String) -> R,
+                ) -> R {
+                    match self {
+                        Kind::Circle(v0) => on_circle(v0),
+                        Kind::Square(v0) => on_square(v0),
+                        Kind::Named(v0) => on_named(v0),
+                    }
+                }
+            }
+            #[derive(PartialOrd, :: prost :: Message, Clone, PartialEq)]
+            pub struct Shape {
+                #[prost(oneof = "Kind", tags = "1,2,3")]
+                pub kind: ::std::option::Option<Kind>,
+            }
+        }
+    }
+}
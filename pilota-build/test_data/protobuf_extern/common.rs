@@ -0,0 +1,26 @@
+pub mod common {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod common {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Money {
+            #[prost(string, tag = "1")]
+            pub currency: ::std::string::String,
+            #[prost(int64, tag = "2")]
+            pub units: i64,
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Status {
+            StatusUnspecified = 0i32,
+            StatusPaid = 1i32,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+pub mod order_prefixed {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod order {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct PbOrder {
+            #[prost(string, tag = "1")]
+            pub id: ::std::string::String,
+            #[prost(message, tag = "2", optional)]
+            pub price:
+                ::std::option::Option<crate::test::extern_package::common::common::common::Money>,
+            #[prost(
+                enumeration = "crate :: test :: extern_package :: common :: common :: common :: Status",
+                tag = "3"
+            )]
+            pub status: i32,
+            #[prost(message, repeated, tag = "4")]
+            pub refunds:
+                ::std::vec::Vec<crate::test::extern_package::common::common::common::Money>,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+pub mod size {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod size {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Pixels {
+            #[prost(int32, tag = "1")]
+            pub count: i32,
+        }
+        pub mod size {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Value {
+                #[prost(message, tag = "1")]
+                Pixels(super::Pixels),
+                #[prost(int32, tag = "2")]
+                Width(i32),
+                #[prost(int32, tag = "3")]
+                Height(i32),
+                #[prost(string, tag = "4")]
+                Label(::std::string::String),
+            }
+            impl ::std::convert::From<super::Pixels> for Value {
+                fn from(v: super::Pixels) -> Self {
+                    Value::Pixels(v)
+                }
+            }
+            impl ::std::convert::From<::std::string::String> for Value {
+                fn from(v: ::std::string::String) -> Self {
+                    Value::Label(v)
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Size {
+                #[prost(oneof = "Value", tags = "1,2,3,4")]
+                pub value: ::std::option::Option<Value>,
+            }
+        }
+    }
+}
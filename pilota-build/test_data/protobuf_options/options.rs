@@ -0,0 +1,36 @@
+pub mod options {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    #![allow(deprecated)]
+    pub mod google {
+        pub mod protobuf {}
+    }
+    pub mod options {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Sample {
+            #[prost(int32, repeated, tag = "1", packed = "true")]
+            pub packed_ids: ::std::vec::Vec<i32>,
+            #[prost(int32, repeated, tag = "2", packed = "false")]
+            pub plain_ids: ::std::vec::Vec<i32>,
+            #[prost(string, tag = "3")]
+            #[deprecated]
+            pub old_name: ::std::string::String,
+            #[prost(string, tag = "4")]
+            pub secret: ::std::string::String,
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Mode {
+            ModeUnspecified = 0i32,
+            #[deprecated]
+            ModeLegacy = 1i32,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+pub mod presence {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod presence {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Inner {
+            #[prost(int32, tag = "1")]
+            pub value: i32,
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Counter {
+            #[prost(int32, tag = "1", optional)]
+            pub count: ::std::option::Option<i32>,
+            #[prost(int32, tag = "2")]
+            pub total: i32,
+            #[prost(string, tag = "3", optional)]
+            pub label: ::std::option::Option<::std::string::String>,
+            #[prost(string, tag = "4")]
+            pub name: ::std::string::String,
+            #[prost(message, tag = "5", optional)]
+            pub inner: ::std::option::Option<Inner>,
+            #[prost(int32, repeated, tag = "6")]
+            pub history: ::std::vec::Vec<i32>,
+            #[prost(string, tag = "7", optional)]
+            pub r#type: ::std::option::Option<::std::string::String>,
+        }
+        impl Counter {
+            pub fn has_count(&self) -> bool {
+                self.count.is_some()
+            }
+            pub fn has_label(&self) -> bool {
+                self.label.is_some()
+            }
+            pub fn has_inner(&self) -> bool {
+                self.inner.is_some()
+            }
+            pub fn has_type(&self) -> bool {
+                self.r#type.is_some()
+            }
+        }
+    }
+}
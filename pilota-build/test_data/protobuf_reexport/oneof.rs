@@ -0,0 +1,46 @@
+pub mod oneof {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod oneof {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Value {}
+        pub mod outer {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Value {
+                #[prost(message, tag = "1")]
+                Deep(inner::Deep),
+                #[prost(message, tag = "2")]
+                Inner(inner::Inner),
+                #[prost(message, tag = "3")]
+                Other(super::Value),
+                #[prost(string, tag = "4")]
+                Name(::std::string::String),
+            }
+            pub use inner::Deep;
+            pub use inner::Inner;
+            pub mod inner {
+                #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+                pub struct Deep {
+                    #[prost(int32, tag = "1")]
+                    pub a: i32,
+                }
+                #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+                pub struct Inner {
+                    #[prost(int32, tag = "1")]
+                    pub b: i32,
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Outer {
+                #[prost(oneof = "Value", tags = "1,2,3,4")]
+                pub value: ::std::option::Option<Value>,
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+pub mod streaming {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    #![allow(deprecated)]
+    pub mod chat {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Msg {
+            #[prost(string, tag = "1")]
+            pub text: ::std::string::String,
+        }
+        #[::async_trait::async_trait]
+        pub trait Chat {
+            #[doc = " Replies with the message."]
+            async fn send(&self, req: Msg) -> ::core::result::Result<Msg, ::pilota::AnyhowError>;
+            #[deprecated]
+            async fn touch(&self, req: Msg) -> ::core::result::Result<Msg, ::pilota::AnyhowError>;
+            async fn upload(
+                &self,
+                req: ::pilota::BoxStream<Msg>,
+            ) -> ::core::result::Result<Msg, ::pilota::AnyhowError>;
+            async fn subscribe(
+                &self,
+                req: Msg,
+            ) -> ::core::result::Result<::pilota::BoxStream<Msg>, ::pilota::AnyhowError>;
+            async fn chat(
+                &self,
+                req: ::pilota::BoxStream<Msg>,
+            ) -> ::core::result::Result<::pilota::BoxStream<Msg>, ::pilota::AnyhowError>;
+        }
+    }
+}
@@ -0,0 +1,259 @@
+pub mod legacy {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod legacy {
+        #[derive(PartialOrd, Debug, Clone, PartialEq)]
+        pub struct Item {
+            pub id: i32,
+            pub name: ::std::option::Option<::std::string::String>,
+            pub kind: ::std::option::Option<i32>,
+            pub codes: ::std::vec::Vec<i32>,
+            pub packed_codes: ::std::vec::Vec<i32>,
+            pub main: i32,
+            pub blob: ::std::option::Option<::std::vec::Vec<u8>>,
+            pub ratio: ::std::option::Option<f64>,
+            pub count: ::std::option::Option<u64>,
+            pub _unknown_fields: ::pilota::protobuf::UnknownFields,
+        }
+        impl ::prost::Message for Item {
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::prost::bytes::BufMut,
+            {
+                ::prost::encoding::int32::encode(1u32, &self.id, buf);
+                if let ::std::option::Option::Some(value) = &self.name {
+                    ::prost::encoding::string::encode(2u32, value, buf);
+                }
+                if let ::std::option::Option::Some(value) = &self.kind {
+                    ::prost::encoding::int32::encode(3u32, value, buf);
+                }
+                ::prost::encoding::int32::encode_repeated(4u32, &self.codes, buf);
+                ::prost::encoding::int32::encode_packed(5u32, &self.packed_codes, buf);
+                ::prost::encoding::int32::encode(6u32, &self.main, buf);
+                if let ::std::option::Option::Some(value) = &self.blob {
+                    ::prost::encoding::bytes::encode(7u32, value, buf);
+                }
+                if let ::std::option::Option::Some(value) = &self.ratio {
+                    ::prost::encoding::double::encode(8u32, value, buf);
+                }
+                if let ::std::option::Option::Some(value) = &self.count {
+                    ::prost::encoding::uint64::encode(9u32, value, buf);
+                }
+                self._unknown_fields.encode_raw(buf);
+            }
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::prost::encoding::DecodeContext,
+            ) -> ::std::result::Result<(), ::prost::DecodeError>
+            where
+                B: ::prost::bytes::Buf,
+            {
+                match tag {
+                    1u32 => ::prost::encoding::int32::merge(wire_type, &mut self.id, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("Item", "id");
+                            error
+                        }),
+                    2u32 => ::prost::encoding::string::merge(
+                        wire_type,
+                        self.name
+                            .get_or_insert_with(::std::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "name");
+                        error
+                    }),
+                    3u32 => ::prost::encoding::int32::merge(
+                        wire_type,
+                        self.kind
+                            .get_or_insert_with(::std::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "kind");
+                        error
+                    }),
+                    4u32 => ::prost::encoding::int32::merge_repeated(
+                        wire_type,
+                        &mut self.codes,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "codes");
+                        error
+                    }),
+                    5u32 => ::prost::encoding::int32::merge_repeated(
+                        wire_type,
+                        &mut self.packed_codes,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "packed_codes");
+                        error
+                    }),
+                    6u32 => ::prost::encoding::int32::merge(wire_type, &mut self.main, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("Item", "main");
+                            error
+                        }),
+                    7u32 => ::prost::encoding::bytes::merge(
+                        wire_type,
+                        self.blob
+                            .get_or_insert_with(::std::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "blob");
+                        error
+                    }),
+                    8u32 => ::prost::encoding::double::merge(
+                        wire_type,
+                        self.ratio
+                            .get_or_insert_with(::std::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "ratio");
+                        error
+                    }),
+                    9u32 => ::prost::encoding::uint64::merge(
+                        wire_type,
+                        self.count
+                            .get_or_insert_with(::std::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("Item", "count");
+                        error
+                    }),
+                    _ => self
+                        ._unknown_fields
+                        .merge_field(tag, wire_type as u8, buf)
+                        .map_err(::prost::DecodeError::new),
+                }
+            }
+            fn encoded_len(&self) -> usize {
+                0 + ::prost::encoding::int32::encoded_len(1u32, &self.id)
+                    + self.name.as_ref().map_or(0, |value| {
+                        ::prost::encoding::string::encoded_len(2u32, value)
+                    })
+                    + self.kind.as_ref().map_or(0, |value| {
+                        ::prost::encoding::int32::encoded_len(3u32, value)
+                    })
+                    + ::prost::encoding::int32::encoded_len_repeated(4u32, &self.codes)
+                    + ::prost::encoding::int32::encoded_len_packed(5u32, &self.packed_codes)
+                    + ::prost::encoding::int32::encoded_len(6u32, &self.main)
+                    + self.blob.as_ref().map_or(0, |value| {
+                        ::prost::encoding::bytes::encoded_len(7u32, value)
+                    })
+                    + self.ratio.as_ref().map_or(0, |value| {
+                        ::prost::encoding::double::encoded_len(8u32, value)
+                    })
+                    + self.count.as_ref().map_or(0, |value| {
+                        ::prost::encoding::uint64::encoded_len(9u32, value)
+                    })
+                    + self._unknown_fields.encoded_len()
+            }
+            fn clear(&mut self) {
+                self.id = 7;
+                self.name = ::std::default::Default::default();
+                self.kind = ::std::default::Default::default();
+                self.codes = ::std::default::Default::default();
+                self.packed_codes = ::std::default::Default::default();
+                self.main = Kind::Fancy as i32;
+                self.blob = ::std::default::Default::default();
+                self.ratio = ::std::default::Default::default();
+                self.count = ::std::default::Default::default();
+                self._unknown_fields.clear();
+            }
+        }
+        impl ::std::default::Default for Item {
+            fn default() -> Self {
+                Item {
+                    id: 7,
+                    name: ::std::default::Default::default(),
+                    kind: ::std::default::Default::default(),
+                    codes: ::std::default::Default::default(),
+                    packed_codes: ::std::default::Default::default(),
+                    main: Kind::Fancy as i32,
+                    blob: ::std::default::Default::default(),
+                    ratio: ::std::default::Default::default(),
+                    count: ::std::default::Default::default(),
+                    _unknown_fields: ::std::default::Default::default(),
+                }
+            }
+        }
+        impl Item {
+            #[doc = "Returns the value of `name`, or the default value if `name` is unset."]
+            pub fn name(&self) -> &str {
+                match &self.name {
+                    ::std::option::Option::Some(value) => &value[..],
+                    ::std::option::Option::None => "anon",
+                }
+            }
+            #[doc = "Returns the enum value of `kind`, or the default if the field is unset or set to an invalid enum value."]
+            pub fn kind(&self) -> Kind {
+                self.kind.and_then(Kind::from_i32).unwrap_or(Kind::Fancy)
+            }
+            #[doc = "Sets `kind` to the provided enum value."]
+            pub fn set_kind(&mut self, value: Kind) {
+                self.kind = ::std::option::Option::Some(value as i32);
+            }
+            #[doc = "Returns the enum value of `main`, or the default if the field is set to an invalid enum value."]
+            pub fn main(&self) -> Kind {
+                Kind::from_i32(self.main).unwrap_or(Kind::Fancy)
+            }
+            #[doc = "Sets `main` to the provided enum value."]
+            pub fn set_main(&mut self, value: Kind) {
+                self.main = value as i32;
+            }
+            #[doc = "Returns the value of `blob`, or the default value if `blob` is unset."]
+            pub fn blob(&self) -> &[u8] {
+                match &self.blob {
+                    ::std::option::Option::Some(value) => &value[..],
+                    ::std::option::Option::None => &b"\x01x"[..],
+                }
+            }
+            #[doc = "Returns the value of `ratio`, or the default value if `ratio` is unset."]
+            pub fn ratio(&self) -> f64 {
+                match &self.ratio {
+                    ::std::option::Option::Some(value) => *value,
+                    ::std::option::Option::None => f64::NEG_INFINITY,
+                }
+            }
+            #[doc = "Returns the value of `count`, or the default value if `count` is unset."]
+            pub fn count(&self) -> u64 {
+                match &self.count {
+                    ::std::option::Option::Some(value) => *value,
+                    ::std::option::Option::None => ::std::default::Default::default(),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Kind {
+            Plain = 0i32,
+            Fancy = 1i32,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+pub mod legacy_derive {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod legacy {
+        #[derive(PartialOrd, :: prost :: Message, Clone, PartialEq)]
+        pub struct Item {
+            #[prost(int32, tag = "1", required, default = "7")]
+            pub id: i32,
+            #[prost(string, tag = "2", optional, default = "anon")]
+            pub name: ::std::option::Option<::std::string::String>,
+            #[prost(enumeration = "Kind", tag = "3", optional, default = "Fancy")]
+            pub kind: ::std::option::Option<i32>,
+            #[prost(int32, repeated, tag = "4", packed = "false")]
+            pub codes: ::std::vec::Vec<i32>,
+            #[prost(int32, repeated, tag = "5", packed = "true")]
+            pub packed_codes: ::std::vec::Vec<i32>,
+            #[prost(enumeration = "Kind", tag = "6", required, default = "Fancy")]
+            pub main: i32,
+            #[prost(bytes, tag = "7", optional, default = "b\"\\x01x\"")]
+            pub blob: ::std::option::Option<::std::vec::Vec<u8>>,
+            #[prost(double, tag = "8", optional, default = "-inf")]
+            pub ratio: ::std::option::Option<f64>,
+            #[prost(uint64, tag = "9", optional)]
+            pub count: ::std::option::Option<u64>,
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Kind {
+            Plain = 0i32,
+            Fancy = 1i32,
+        }
+    }
+}
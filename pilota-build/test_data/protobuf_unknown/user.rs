@@ -0,0 +1,413 @@
+pub mod user {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod user {
+        #[derive(PartialOrd, Hash, Eq, Ord, Default, Debug, Clone, PartialEq)]
+        pub struct AddressV1 {
+            pub city: ::std::string::String,
+            pub _unknown_fields: ::pilota::protobuf::UnknownFields,
+        }
+        impl ::prost::Message for AddressV1 {
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::prost::bytes::BufMut,
+            {
+                if !self.city.is_empty() {
+                    ::prost::encoding::string::encode(1u32, &self.city, buf);
+                }
+                self._unknown_fields.encode_raw(buf);
+            }
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::prost::encoding::DecodeContext,
+            ) -> ::std::result::Result<(), ::prost::DecodeError>
+            where
+                B: ::prost::bytes::Buf,
+            {
+                match tag {
+                    1u32 => ::prost::encoding::string::merge(wire_type, &mut self.city, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("AddressV1", "city");
+                            error
+                        }),
+                    _ => self
+                        ._unknown_fields
+                        .merge_field(tag, wire_type as u8, buf)
+                        .map_err(::prost::DecodeError::new),
+                }
+            }
+            fn encoded_len(&self) -> usize {
+                0 + if !self.city.is_empty() {
+                    ::prost::encoding::string::encoded_len(1u32, &self.city)
+                } else {
+                    0
+                } + self._unknown_fields.encoded_len()
+            }
+            fn clear(&mut self) {
+                self.city = ::std::default::Default::default();
+                self._unknown_fields.clear();
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Default, Debug, Clone, PartialEq)]
+        pub struct AddressV2 {
+            pub city: ::std::string::String,
+            pub zip: u32,
+            pub _unknown_fields: ::pilota::protobuf::UnknownFields,
+        }
+        impl ::prost::Message for AddressV2 {
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::prost::bytes::BufMut,
+            {
+                if !self.city.is_empty() {
+                    ::prost::encoding::string::encode(1u32, &self.city, buf);
+                }
+                if self.zip != 0 {
+                    ::prost::encoding::uint32::encode(2u32, &self.zip, buf);
+                }
+                self._unknown_fields.encode_raw(buf);
+            }
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::prost::encoding::DecodeContext,
+            ) -> ::std::result::Result<(), ::prost::DecodeError>
+            where
+                B: ::prost::bytes::Buf,
+            {
+                match tag {
+                    1u32 => ::prost::encoding::string::merge(wire_type, &mut self.city, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("AddressV2", "city");
+                            error
+                        }),
+                    2u32 => ::prost::encoding::uint32::merge(wire_type, &mut self.zip, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("AddressV2", "zip");
+                            error
+                        }),
+                    _ => self
+                        ._unknown_fields
+                        .merge_field(tag, wire_type as u8, buf)
+                        .map_err(::prost::DecodeError::new),
+                }
+            }
+            fn encoded_len(&self) -> usize {
+                0 + if !self.city.is_empty() {
+                    ::prost::encoding::string::encoded_len(1u32, &self.city)
+                } else {
+                    0
+                } + if self.zip != 0 {
+                    ::prost::encoding::uint32::encoded_len(2u32, &self.zip)
+                } else {
+                    0
+                } + self._unknown_fields.encoded_len()
+            }
+            fn clear(&mut self) {
+                self.city = ::std::default::Default::default();
+                self.zip = ::std::default::Default::default();
+                self._unknown_fields.clear();
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Default, Debug, Clone, PartialEq)]
+        pub struct UserV1 {
+            pub id: i64,
+            pub addresses: ::std::vec::Vec<AddressV1>,
+            pub _unknown_fields: ::pilota::protobuf::UnknownFields,
+        }
+        impl ::prost::Message for UserV1 {
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::prost::bytes::BufMut,
+            {
+                if self.id != 0 {
+                    ::prost::encoding::int64::encode(1u32, &self.id, buf);
+                }
+                ::prost::encoding::message::encode_repeated(4u32, &self.addresses, buf);
+                self._unknown_fields.encode_raw(buf);
+            }
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::prost::encoding::DecodeContext,
+            ) -> ::std::result::Result<(), ::prost::DecodeError>
+            where
+                B: ::prost::bytes::Buf,
+            {
+                match tag {
+                    1u32 => ::prost::encoding::int64::merge(wire_type, &mut self.id, buf, ctx)
+                        .map_err(|mut error| {
+                            error.push("UserV1", "id");
+                            error
+                        }),
+                    4u32 => ::prost::encoding::message::merge_repeated(
+                        wire_type,
+                        &mut self.addresses,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push("UserV1", "addresses");
+                        error
+                    }),
+                    _ => self
+                        ._unknown_fields
+                        .merge_field(tag, wire_type as u8, buf)
+                        .map_err(::prost::DecodeError::new),
+                }
+            }
+            fn encoded_len(&self) -> usize {
+                0 + if self.id != 0 {
+                    ::prost::encoding::int64::encoded_len(1u32, &self.id)
+                } else {
+                    0
+                } + ::prost::encoding::message::encoded_len_repeated(4u32, &self.addresses)
+                    + self._unknown_fields.encoded_len()
+            }
+            fn clear(&mut self) {
+                self.id = ::std::default::Default::default();
+                self.addresses = ::std::default::Default::default();
+                self._unknown_fields.clear();
+            }
+        }
+        pub mod user_v2 {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Contact {
+                #[prost(string, tag = "9")]
+                Email(::std::string::String),
+                #[prost(bytes, tag = "10")]
+                Phone(::std::vec::Vec<u8>),
+            }
+            #[derive(Default, Debug, Clone, PartialEq)]
+            pub struct UserV2 {
+                pub id: i64,
+                pub name: ::std::option::Option<::std::string::String>,
+                pub scores: ::std::vec::Vec<i32>,
+                pub addresses: ::std::vec::Vec<super::AddressV2>,
+                pub weights: ::std::collections::HashMap<::std::string::String, f64>,
+                pub role: i32,
+                pub stamp: u64,
+                pub tags: ::std::vec::Vec<::std::string::String>,
+                pub contact: ::std::option::Option<Contact>,
+                pub _unknown_fields: ::pilota::protobuf::UnknownFields,
+            }
+            impl ::prost::Message for UserV2 {
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::prost::bytes::BufMut,
+                {
+                    if self.id != 0 {
+                        ::prost::encoding::int64::encode(1u32, &self.id, buf);
+                    }
+                    if let ::std::option::Option::Some(value) = &self.name {
+                        ::prost::encoding::string::encode(2u32, value, buf);
+                    }
+                    ::prost::encoding::sint32::encode_packed(3u32, &self.scores, buf);
+                    ::prost::encoding::message::encode_repeated(4u32, &self.addresses, buf);
+                    ::prost::encoding::hash_map::encode(
+                        ::prost::encoding::string::encode,
+                        ::prost::encoding::string::encoded_len,
+                        ::prost::encoding::double::encode,
+                        ::prost::encoding::double::encoded_len,
+                        5u32,
+                        &self.weights,
+                        buf,
+                    );
+                    if self.role != 0 {
+                        ::prost::encoding::int32::encode(6u32, &self.role, buf);
+                    }
+                    if self.stamp != 0 {
+                        ::prost::encoding::fixed64::encode(7u32, &self.stamp, buf);
+                    }
+                    ::prost::encoding::string::encode_repeated(8u32, &self.tags, buf);
+                    if let ::std::option::Option::Some(oneof) = &self.contact {
+                        oneof.encode(buf);
+                    }
+                    self._unknown_fields.encode_raw(buf);
+                }
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::prost::encoding::DecodeContext,
+                ) -> ::std::result::Result<(), ::prost::DecodeError>
+                where
+                    B: ::prost::bytes::Buf,
+                {
+                    match tag {
+                        1u32 => ::prost::encoding::int64::merge(wire_type, &mut self.id, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push("UserV2", "id");
+                                error
+                            }),
+                        2u32 => ::prost::encoding::string::merge(
+                            wire_type,
+                            self.name
+                                .get_or_insert_with(::std::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push("UserV2", "name");
+                            error
+                        }),
+                        3u32 => ::prost::encoding::sint32::merge_repeated(
+                            wire_type,
+                            &mut self.scores,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push("UserV2", "scores");
+                            error
+                        }),
+                        4u32 => ::prost::encoding::message::merge_repeated(
+                            wire_type,
+                            &mut self.addresses,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push("UserV2", "addresses");
+                            error
+                        }),
+                        5u32 => ::prost::encoding::hash_map::merge(
+                            ::prost::encoding::string::merge,
+                            ::prost::encoding::double::merge,
+                            &mut self.weights,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push("UserV2", "weights");
+                            error
+                        }),
+                        6u32 => {
+                            ::prost::encoding::int32::merge(wire_type, &mut self.role, buf, ctx)
+                                .map_err(|mut error| {
+                                    error.push("UserV2", "role");
+                                    error
+                                })
+                        }
+                        7u32 => {
+                            ::prost::encoding::fixed64::merge(wire_type, &mut self.stamp, buf, ctx)
+                                .map_err(|mut error| {
+                                    error.push("UserV2", "stamp");
+                                    error
+                                })
+                        }
+                        8u32 => ::prost::encoding::string::merge_repeated(
+                            wire_type,
+                            &mut self.tags,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push("UserV2", "tags");
+                            error
+                        }),
+                        9u32 | 10u32 => {
+                            <Contact>::merge(&mut self.contact, tag, wire_type, buf, ctx).map_err(
+                                |mut error| {
+                                    error.push("UserV2", "contact");
+                                    error
+                                },
+                            )
+                        }
+                        _ => self
+                            ._unknown_fields
+                            .merge_field(tag, wire_type as u8, buf)
+                            .map_err(::prost::DecodeError::new),
+                    }
+                }
+                fn encoded_len(&self) -> usize {
+                    0 + if self.id != 0 {
+                        ::prost::encoding::int64::encoded_len(1u32, &self.id)
+                    } else {
+                        0
+                    } + self.name.as_ref().map_or(0, |value| {
+                        ::prost::encoding::string::encoded_len(2u32, value)
+                    }) + ::prost::encoding::sint32::encoded_len_packed(3u32, &self.scores)
+                        + ::prost::encoding::message::encoded_len_repeated(4u32, &self.addresses)
+                        + ::prost::encoding::hash_map::encoded_len(
+                            ::prost::encoding::string::encoded_len,
+                            ::prost::encoding::double::encoded_len,
+                            5u32,
+                            &self.weights,
+                        )
+                        + if self.role != 0 {
+                            ::prost::encoding::int32::encoded_len(6u32, &self.role)
+                        } else {
+                            0
+                        }
+                        + if self.stamp != 0 {
+                            ::prost::encoding::fixed64::encoded_len(7u32, &self.stamp)
+                        } else {
+                            0
+                        }
+                        + ::prost::encoding::string::encoded_len_repeated(8u32, &self.tags)
+                        + self.contact.as_ref().map_or(0, |oneof| oneof.encoded_len())
+                        + self._unknown_fields.encoded_len()
+                }
+                fn clear(&mut self) {
+                    self.id = ::std::default::Default::default();
+                    self.name = ::std::default::Default::default();
+                    self.scores = ::std::default::Default::default();
+                    self.addresses = ::std::default::Default::default();
+                    self.weights = ::std::default::Default::default();
+                    self.role = ::std::default::Default::default();
+                    self.stamp = ::std::default::Default::default();
+                    self.tags = ::std::default::Default::default();
+                    self.contact = ::std::default::Default::default();
+                    self._unknown_fields.clear();
+                }
+            }
+            impl UserV2 {
+                #[doc = "Returns the value of `name`, or the default value if `name` is unset."]
+                pub fn name(&self) -> &str {
+                    match &self.name {
+                        ::std::option::Option::Some(value) => &value[..],
+                        ::std::option::Option::None => "",
+                    }
+                }
+                #[doc = "Returns the enum value of `role`, or the default if the field is set to an invalid enum value."]
+                pub fn role(&self) -> super::Role {
+                    super::Role::from_i32(self.role).unwrap_or(super::Role::default())
+                }
+                #[doc = "Sets `role` to the provided enum value."]
+                pub fn set_role(&mut self, value: super::Role) {
+                    self.role = value as i32;
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Role {
+            Guest = 0i32,
+            Admin = 1i32,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+pub mod legacy {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod legacy {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Legacy {
+            #[prost(int32, repeated, tag = "1", packed = "false")]
+            pub plain: ::std::vec::Vec<i32>,
+            #[prost(int32, repeated, tag = "2", packed = "true")]
+            pub packed: ::std::vec::Vec<i32>,
+            #[prost(sint64, repeated, tag = "3", packed = "false")]
+            pub unpacked: ::std::vec::Vec<i64>,
+            #[prost(string, repeated, tag = "4")]
+            pub names: ::std::vec::Vec<::std::string::String>,
+        }
+        impl Legacy {
+            pub const FIELD_WIRE_TYPES: &'static [(u32, ::prost::encoding::WireType)] = &[
+                (1, ::prost::encoding::WireType::Varint),
+                (2, ::prost::encoding::WireType::LengthDelimited),
+                (3, ::prost::encoding::WireType::Varint),
+                (4, ::prost::encoding::WireType::LengthDelimited),
+            ];
+        }
+    }
+}
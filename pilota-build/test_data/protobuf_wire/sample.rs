@@ -0,0 +1,90 @@
+pub mod sample {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod sample {
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+        pub struct Inner {
+            #[prost(int32, tag = "1")]
+            pub x: i32,
+        }
+        impl Inner {
+            pub const FIELD_WIRE_TYPES: &'static [(u32, ::prost::encoding::WireType)] =
+                &[(1, ::prost::encoding::WireType::Varint)];
+        }
+        pub mod sample {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Oneof, Clone, PartialEq)]
+            pub enum Choice {
+                #[prost(uint64, tag = "14")]
+                Number(u64),
+                #[prost(string, tag = "15")]
+                Text(::std::string::String),
+            }
+            #[derive(:: prost :: Message, Clone, PartialEq)]
+            pub struct Sample {
+                #[prost(int32, tag = "1")]
+                pub plain: i32,
+                #[prost(sint64, tag = "2")]
+                pub zigzag: i64,
+                #[prost(fixed32, tag = "3")]
+                pub fixed: u32,
+                #[prost(sfixed64, tag = "4")]
+                pub sfixed: i64,
+                #[prost(float, tag = "5")]
+                pub ratio: f32,
+                #[prost(double, tag = "6")]
+                pub precise: f64,
+                #[prost(bool, tag = "7")]
+                pub flag: bool,
+                #[prost(string, tag = "8")]
+                pub name: ::std::string::String,
+                #[prost(bytes, tag = "9")]
+                pub payload: ::std::vec::Vec<u8>,
+                #[prost(enumeration = "super :: Kind", tag = "10")]
+                pub kind: i32,
+                #[prost(message, tag = "11", optional)]
+                pub inner: ::std::option::Option<super::Inner>,
+                #[prost(int32, repeated, tag = "12")]
+                pub ids: ::std::vec::Vec<i32>,
+                #[prost(map = "string , int64", tag = "13")]
+                pub counts: ::std::collections::HashMap<::std::string::String, i64>,
+                #[prost(int32, repeated, tag = "16", packed = "false")]
+                pub raw_ids: ::std::vec::Vec<i32>,
+                #[prost(oneof = "Choice", tags = "14,15")]
+                pub choice: ::std::option::Option<Choice>,
+            }
+            impl Sample {
+                pub const FIELD_WIRE_TYPES: &'static [(u32, ::prost::encoding::WireType)] = &[
+                    (1, ::prost::encoding::WireType::Varint),
+                    (2, ::prost::encoding::WireType::Varint),
+                    (3, ::prost::encoding::WireType::ThirtyTwoBit),
+                    (4, ::prost::encoding::WireType::SixtyFourBit),
+                    (5, ::prost::encoding::WireType::ThirtyTwoBit),
+                    (6, ::prost::encoding::WireType::SixtyFourBit),
+                    (7, ::prost::encoding::WireType::Varint),
+                    (8, ::prost::encoding::WireType::LengthDelimited),
+                    (9, ::prost::encoding::WireType::LengthDelimited),
+                    (10, ::prost::encoding::WireType::Varint),
+                    (11, ::prost::encoding::WireType::LengthDelimited),
+                    (12, ::prost::encoding::WireType::LengthDelimited),
+                    (13, ::prost::encoding::WireType::LengthDelimited),
+                    (16, ::prost::encoding::WireType::Varint),
+                    (14, ::prost::encoding::WireType::Varint),
+                    (15, ::prost::encoding::WireType::LengthDelimited),
+                ];
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Enumeration, Debug, Clone, PartialEq)]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Kind {
+            KindUnspecified = 0i32,
+            KindA = 1i32,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+pub mod event {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod event {
+        pub mod event {
+            #[derive(:: prost :: Message, Clone, PartialEq)]
+            pub struct Retry {
+                #[prost(message, tag = "1", optional)]
+                pub next: ::std::option::Option<::prost_types::Timestamp>,
+            }
+            #[derive(:: prost :: Message, Clone, PartialEq)]
+            pub struct Event {
+                #[prost(message, tag = "1", optional)]
+                pub at: ::std::option::Option<::prost_types::Timestamp>,
+                #[prost(message, tag = "2", optional)]
+                pub took: ::std::option::Option<::prost_types::Duration>,
+                #[prost(message, tag = "3", optional)]
+                pub count: ::std::option::Option<i32>,
+                #[prost(message, repeated, tag = "4")]
+                pub history: ::std::vec::Vec<::prost_types::Timestamp>,
+                #[prost(message, tag = "5", optional)]
+                pub retry: ::std::option::Option<Retry>,
+            }
+        }
+    }
+}
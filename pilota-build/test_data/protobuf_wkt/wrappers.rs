@@ -0,0 +1,47 @@
+pub mod wrappers {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod wrappers {
+        pub mod profile {
+            #[derive(PartialOrd, Hash, Eq, Ord, :: prost :: Message, Clone, PartialEq)]
+            pub struct Settings {
+                #[prost(message, tag = "1", optional)]
+                pub dark_mode: ::std::option::Option<bool>,
+            }
+            #[derive(PartialOrd, :: prost :: Message, Clone, PartialEq)]
+            pub struct Profile {
+                #[prost(message, tag = "1", optional)]
+                pub nickname: ::std::option::Option<::std::string::String>,
+                #[prost(message, tag = "2", optional)]
+                pub age: ::std::option::Option<i32>,
+                #[prost(message, tag = "3", optional)]
+                pub verified: ::std::option::Option<bool>,
+                #[prost(message, tag = "4", optional)]
+                pub score: ::std::option::Option<f64>,
+                #[prost(message, tag = "5", optional)]
+                pub avatar: ::std::option::Option<::std::vec::Vec<u8>>,
+                #[prost(message, repeated, tag = "6")]
+                pub follower_ids: ::std::vec::Vec<u64>,
+                #[prost(message, tag = "7", optional)]
+                pub settings: ::std::option::Option<Settings>,
+            }
+        }
+        #[::async_trait::async_trait]
+        pub trait Profiles {
+            async fn get(
+                &self,
+                req: ::pilota::protobuf::Empty,
+            ) -> ::core::result::Result<profile::Profile, ::pilota::AnyhowError>;
+            async fn clear(
+                &self,
+                req: profile::Profile,
+            ) -> ::core::result::Result<::pilota::protobuf::Empty, ::pilota::AnyhowError>;
+        }
+    }
+}
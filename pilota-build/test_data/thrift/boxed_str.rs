@@ -0,0 +1,348 @@
+pub mod boxed_str {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod boxed_str {
+        pub const UNKNOWN: &'static str = "unknown";
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Profile {
+            pub name: ::std::boxed::Box<str>,
+            pub bio: ::std::option::Option<::std::boxed::Box<str>>,
+            pub city: ::std::boxed::Box<str>,
+            pub motto: ::std::option::Option<::std::boxed::Box<str>>,
+            pub email: ::std::string::String,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Profile {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Profile" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.bio.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("bio"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.city;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.motto.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("motto"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.email;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("email"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut city = None;
+                let mut email = None;
+                let mut bio = None;
+                let mut motto = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(::std::convert::From::from(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                bio = Some(::std::convert::From::from(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(::std::convert::From::from(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                motto = Some(::std::convert::From::from(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let email = if let Some(email) = email {
+                    email
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field email is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    bio,
+                    motto,
+                    name,
+                    city,
+                    email,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut city = None;
+                let mut email = None;
+                let mut bio = None;
+                let mut motto = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name =
+                                    Some(::std::convert::From::from(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                bio =
+                                    Some(::std::convert::From::from(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city =
+                                    Some(::std::convert::From::from(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                motto =
+                                    Some(::std::convert::From::from(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let email = if let Some(email) = email {
+                    email
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field email is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    bio,
+                    motto,
+                    name,
+                    city,
+                    email,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Profile {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Profile",
+                }) + {
+                    let value = &self.name;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.bio.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("bio"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.city;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(3i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.motto.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("motto"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.email;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("email"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(5i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Profile {
+            fn default() -> Self {
+                Profile {
+                    name: ::std::default::Default::default(),
+                    bio: None,
+                    city: ::std::convert::From::from(UNKNOWN),
+                    motto: Some(::std::convert::From::from("carpe diem")),
+                    email: ::std::default::Default::default(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+const string UNKNOWN = "unknown"
+
+struct Profile {
+    1: required string name (pilota.boxed_str = "true"),
+    2: optional string bio (pilota.boxed_str = "true"),
+    3: required string city = UNKNOWN (pilota.boxed_str = "true"),
+    4: optional string motto = "carpe diem" (pilota.boxed_str = "true"),
+    5: required string email,
+}
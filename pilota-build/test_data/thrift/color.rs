@@ -0,0 +1,335 @@
+pub mod color {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod color {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Color {
+            #[derivative(Default)]
+            Red = 0i32,
+            Green = 1i32,
+            Blue = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Color {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Color {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Priority {
+            #[derivative(Default)]
+            Low = 1i32,
+            High = 2i32,
+            Urgent = 5i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Priority {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Priority, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Priority, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Priority {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Shade {
+            #[derivative(Default)]
+            Light,
+            Dark = 5i32,
+            Darker,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Shade {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Shade, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Shade, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Shade {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Paint {
+            pub color: Color,
+            pub priority: ::std::option::Option<Priority>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Paint {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Paint" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.color;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("color"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.priority.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("priority"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut color = None;
+                let mut priority = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                color = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                priority = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let color = if let Some(color) = color {
+                    color
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field color is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { priority, color };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut color = None;
+                let mut priority = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                color =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                priority =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let color = if let Some(color) = color {
+                    color
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field color is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { priority, color };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Paint {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Paint" })
+                    + {
+                        let value = &self.color;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("color"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.priority.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("priority"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+enum Color {
+    RED = 0,
+    GREEN = 1,
+    BLUE = 2,
+}
+
+enum Priority {
+    LOW = 1,
+    HIGH = 2,
+    URGENT = 5,
+}
+
+enum Shade {
+    LIGHT,
+    DARK = 5,
+    DARKER,
+}
+
+struct Paint {
+    1: required Color color,
+    2: optional Priority priority,
+}
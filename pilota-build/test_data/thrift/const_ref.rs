@@ -0,0 +1,503 @@
+pub mod const_ref {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod const_ref {
+        pub const TIMEOUT: i32 = DEFAULT_TIMEOUT;
+        pub const DEFAULT_TIMEOUT: i32 = 100i32;
+        pub const GREETING: &'static str = HELLO;
+        pub const HELLO: &'static str = "hello";
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Level {
+            #[derivative(Default)]
+            Low = 1i32,
+            High = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Level {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Level, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Level, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Level {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        pub const DEFAULT_LEVEL: Level = Level::High;
+        ::pilota::lazy_static::lazy_static! { pub static ref LEVELS : :: std :: vec :: Vec < Level > = :: std :: vec ! [Level :: Low , DEFAULT_LEVEL] ; }
+        ::pilota::lazy_static::lazy_static! { pub static ref LIMITS : :: std :: collections :: HashMap < & 'static str , i32 > = { let mut map = :: std :: collections :: HashMap :: with_capacity (1usize) ; map . insert (HELLO , TIMEOUT) ; map } ; }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Config {
+            pub timeout: i32,
+            pub greeting: ::std::option::Option<::std::string::String>,
+            pub level: ::std::option::Option<Level>,
+            pub levels: ::std::option::Option<::std::vec::Vec<Level>>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Config {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Config" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.timeout;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("timeout"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.greeting.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("greeting"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.level.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("level"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.levels.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("levels"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut timeout = None;
+                let mut greeting = None;
+                let mut level = None;
+                let mut levels = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                greeting = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                levels = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let timeout = if let Some(timeout) = timeout {
+                    timeout
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field timeout is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    greeting,
+                    level,
+                    levels,
+                    timeout,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut timeout = None;
+                let mut greeting = None;
+                let mut level = None;
+                let mut levels = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                greeting = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                levels = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let timeout = if let Some(timeout) = timeout {
+                    timeout
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field timeout is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    greeting,
+                    level,
+                    levels,
+                    timeout,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Config {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Config" })
+                    + {
+                        let value = &self.timeout;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("timeout"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.greeting.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("greeting"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(2i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.level.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("level"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(3i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.levels.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("levels"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(4i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::I32,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += ::pilota::thrift::Size::size(el, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Config {
+            fn default() -> Self {
+                Config {
+                    timeout: TIMEOUT,
+                    greeting: Some(GREETING.to_string()),
+                    level: Some(DEFAULT_LEVEL),
+                    levels: Some((*LEVELS).clone()),
+                }
+            }
+        }
+        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT_CONFIG : Config = Config { timeout : DEFAULT_TIMEOUT , greeting : Some (HELLO . to_string ()) , level : Some (DEFAULT_LEVEL) , levels : Some ((* LEVELS) . clone ()) , } ; }
+        ::pilota::lazy_static::lazy_static! { pub static ref CONFIG : Config = (* DEFAULT_CONFIG) . clone () ; }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Server {
+            pub config: Config,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Server {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Server" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.config;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("config"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut config = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                config = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let config = if let Some(config) = config {
+                    config
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field config is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { config };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut config = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                config =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let config = if let Some(config) = config {
+                    config
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field config is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { config };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Server {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Server" })
+                    + {
+                        let value = &self.config;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("config"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Server {
+            fn default() -> Self {
+                Server {
+                    config: (*CONFIG).clone(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+const i32 TIMEOUT = DEFAULT_TIMEOUT
+const i32 DEFAULT_TIMEOUT = 100
+
+const string GREETING = HELLO
+const string HELLO = "hello"
+
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+const Level DEFAULT_LEVEL = Level.HIGH
+const list<Level> LEVELS = [Level.LOW, DEFAULT_LEVEL]
+const map<string, i32> LIMITS = {HELLO: TIMEOUT}
+
+struct Config {
+    1: required i32 timeout = TIMEOUT,
+    2: optional string greeting = GREETING,
+    3: optional Level level = DEFAULT_LEVEL,
+    4: optional list<Level> levels = LEVELS,
+}
+
+const Config DEFAULT_CONFIG = {"timeout": DEFAULT_TIMEOUT, "greeting": HELLO}
+const Config CONFIG = DEFAULT_CONFIG
+
+struct Server {
+    1: required Config config = CONFIG,
+}
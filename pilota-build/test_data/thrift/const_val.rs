@@ -61,5 +61,213 @@ pub mod const_val {
             }
         }
         ::pilota::lazy_static::lazy_static! { pub static ref TEST_MAP : :: std :: collections :: HashMap < Index , & 'static str > = { let mut map = :: std :: collections :: HashMap :: with_capacity (2usize) ; map . insert (Index :: A , "hello") ; map . insert (Index :: B , "world") ; map } ; }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Config {
+            pub name: ::std::string::String,
+            pub timeout: ::std::option::Option<i32>,
+            pub retries: ::std::option::Option<i64>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Config {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Config" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.timeout.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("timeout"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.retries.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("retries"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut timeout = None;
+                let mut retries = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                retries = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    timeout,
+                    retries,
+                    name,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut timeout = None;
+                let mut retries = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                retries = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    timeout,
+                    retries,
+                    name,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Config {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Config" })
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.timeout.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("timeout"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.retries.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("retries"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(3i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT_CONFIG : Config = Config { name : "default" . to_string () , timeout : Some (100i32) , retries : None , } ; }
     }
 }
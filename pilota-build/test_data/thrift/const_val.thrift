@@ -9,3 +9,14 @@ const map<Index, string> TEST_MAP = {
     Index.A: "hello",
     Index.B: "world",
 };
+
+struct Config {
+    1: required string name,
+    2: optional i32 timeout,
+    3: optional i64 retries,
+}
+
+const Config DEFAULT_CONFIG = {
+    "name": "default",
+    "timeout": 100,
+};
@@ -0,0 +1,843 @@
+pub mod default_value {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod default_value {
+        pub const DEFAULT_TIMEOUT: i32 = 30i32;
+        pub const DEFAULT_HOST: &'static str = "localhost";
+        ::pilota::lazy_static::lazy_static! { pub static ref PRIMES : :: std :: vec :: Vec < i32 > = :: std :: vec ! [2i32 , 3i32 , 5i32] ; }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Level {
+            #[derivative(Default)]
+            Low = 1i32,
+            High = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Level {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Level, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Level, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Level {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Options {
+            pub retries: i32,
+            pub host: ::std::string::String,
+            pub timeout: ::std::option::Option<i32>,
+            pub ratio: f64,
+            pub level: Level,
+            pub comment: ::std::option::Option<::std::string::String>,
+            pub name: ::std::option::Option<::std::string::String>,
+            pub weights: ::std::vec::Vec<i32>,
+            pub limits:
+                ::std::option::Option<::std::collections::HashMap<::std::string::String, i32>>,
+            pub tags: ::std::collections::HashSet<::std::string::String>,
+            pub timestamp: i64,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Options {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Options" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.retries;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("retries"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.host;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("host"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.timeout.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("timeout"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.ratio;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("ratio"),
+                        field_type: ::pilota::thrift::TType::Double,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_double(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.level;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("level"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.comment.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("comment"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.name.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.weights;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("weights"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.limits.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("limits"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(9i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.tags;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(10i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TSetIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_set_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_set_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.timestamp;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("timestamp"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(11i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut retries = None;
+                let mut host = None;
+                let mut ratio = None;
+                let mut level = None;
+                let mut weights = None;
+                let mut tags = None;
+                let mut timestamp = None;
+                let mut timeout = None;
+                let mut comment = None;
+                let mut name = None;
+                let mut limits = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                retries = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                host = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                ratio = Some(protocol.read_double()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                comment = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                weights = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i32()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                limits = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i32()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                tags = Some({
+                                    let list_ident = protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string()?);
+                                    }
+                                    protocol.read_set_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                timestamp = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let retries = if let Some(retries) = retries {
+                    retries
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field retries is required".to_string(),
+                        ),
+                    ));
+                };
+                let host = if let Some(host) = host {
+                    host
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field host is required".to_string(),
+                        ),
+                    ));
+                };
+                let ratio = if let Some(ratio) = ratio {
+                    ratio
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ratio is required".to_string(),
+                        ),
+                    ));
+                };
+                let level = if let Some(level) = level {
+                    level
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field level is required".to_string(),
+                        ),
+                    ));
+                };
+                let weights = if let Some(weights) = weights {
+                    weights
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field weights is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let timestamp = if let Some(timestamp) = timestamp {
+                    timestamp
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field timestamp is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    timeout,
+                    comment,
+                    name,
+                    limits,
+                    retries,
+                    host,
+                    ratio,
+                    level,
+                    weights,
+                    tags,
+                    timestamp,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut retries = None;
+                let mut host = None;
+                let mut ratio = None;
+                let mut level = None;
+                let mut weights = None;
+                let mut tags = None;
+                let mut timestamp = None;
+                let mut timeout = None;
+                let mut comment = None;
+                let mut name = None;
+                let mut limits = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                retries = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                host = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                timeout = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                ratio = Some(protocol.read_double().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                comment = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                weights = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i32().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                limits = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i32().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                tags = Some({
+                                    let list_ident = protocol.read_set_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string().await?);
+                                    }
+                                    protocol.read_set_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                timestamp = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let retries = if let Some(retries) = retries {
+                    retries
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field retries is required".to_string(),
+                        ),
+                    ));
+                };
+                let host = if let Some(host) = host {
+                    host
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field host is required".to_string(),
+                        ),
+                    ));
+                };
+                let ratio = if let Some(ratio) = ratio {
+                    ratio
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ratio is required".to_string(),
+                        ),
+                    ));
+                };
+                let level = if let Some(level) = level {
+                    level
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field level is required".to_string(),
+                        ),
+                    ));
+                };
+                let weights = if let Some(weights) = weights {
+                    weights
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field weights is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let timestamp = if let Some(timestamp) = timestamp {
+                    timestamp
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field timestamp is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    timeout,
+                    comment,
+                    name,
+                    limits,
+                    retries,
+                    host,
+                    ratio,
+                    level,
+                    weights,
+                    tags,
+                    timestamp,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Options {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Options",
+                }) + {
+                    let value = &self.retries;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("retries"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.host;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("host"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.timeout.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("timeout"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.ratio;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("ratio"),
+                        field_type: ::pilota::thrift::TType::Double,
+                        id: Some(4i16),
+                    }) + protocol.write_double_len(*value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.level;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("level"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(5i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.comment.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("comment"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(6i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.name.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(7i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.weights;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("weights"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(8i16),
+                    }) + {
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin_len(&list_ident)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += protocol.write_i32_len(*el);
+                                }
+                                size
+                            }
+                            + protocol.write_list_end_len()
+                    } + protocol.write_field_end_len()
+                } + if let Some(value) = self.limits.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("limits"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(9i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_string_len(&key);
+                                    size += protocol.write_i32_len(*val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.tags;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(10i16),
+                    }) + {
+                        let set_id = ::pilota::thrift::TSetIdentifier {
+                            element_type: ::pilota::thrift::TType::String,
+                            size: value.len(),
+                        };
+                        protocol.write_set_begin_len(&set_id)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += protocol.write_string_len(&el);
+                                }
+                                size
+                            }
+                            + protocol.write_set_end_len()
+                    } + protocol.write_field_end_len()
+                } + {
+                    let value = &self.timestamp;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("timestamp"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(11i16),
+                    }) + protocol.write_i64_len(*value)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Options {
+            fn default() -> Self {
+                Options {
+                    retries: 5i32,
+                    host: DEFAULT_HOST.to_string(),
+                    timeout: Some(DEFAULT_TIMEOUT),
+                    ratio: 1f64,
+                    level: Level::High,
+                    comment: None,
+                    name: Some("anon".to_string()),
+                    weights: {
+                        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT : :: std :: vec :: Vec < i32 > = :: std :: vec ! [1i32 , 2i32] ; }
+                        DEFAULT.clone()
+                    },
+                    limits: Some({
+                        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT : :: std :: collections :: HashMap < :: std :: string :: String , i32 > = { let mut map = :: std :: collections :: HashMap :: with_capacity (1usize) ; map . insert ("a" . to_string () , 1i32) ; map } ; }
+                        DEFAULT.clone()
+                    }),
+                    tags: ::std::collections::HashSet::from(["x".to_string()]),
+                    timestamp: ::std::default::Default::default(),
+                }
+            }
+        }
+    }
+}
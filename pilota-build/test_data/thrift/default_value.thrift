@@ -0,0 +1,22 @@
+const i32 DEFAULT_TIMEOUT = 30
+const string DEFAULT_HOST = "localhost"
+const list<i32> PRIMES = [2, 3, 5]
+
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+struct Options {
+    1: required i32 retries = 5,
+    2: required string host = DEFAULT_HOST,
+    3: optional i32 timeout = DEFAULT_TIMEOUT,
+    4: required double ratio = 1,
+    5: required Level level = Level.HIGH,
+    6: optional string comment,
+    7: optional string name = "anon",
+    8: required list<i32> weights = [1, 2],
+    9: optional map<string, i32> limits = {"a": 1},
+    10: required set<string> tags = ["x"],
+    11: required i64 timestamp,
+}
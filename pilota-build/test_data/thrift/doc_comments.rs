@@ -0,0 +1,1331 @@
+pub mod doc_comments {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod doc_comments {
+        #[doc = " The id of a user."]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct UserId(i64);
+        impl ::std::ops::Deref for UserId {
+            type Target = i64;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl From<i64> for UserId {
+            fn from(v: i64) -> Self {
+                Self(v)
+            }
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserId {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let value = &**self;
+                protocol.write_i64(*value)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(UserId(protocol.read_i64()?))
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(UserId(protocol.read_i64().await?))
+            }
+        }
+        impl ::pilota::thrift::Size for UserId {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                {
+                    let value = &**self;
+                    protocol.write_i64_len(*value)
+                }
+            }
+        }
+        #[doc = " The greeting sent to \"new\" users."]
+        pub const GREETING: &'static str = "hello";
+        ::pilota::lazy_static::lazy_static! { # [doc = " The default roles, a `list` is built lazily."] pub static ref DEFAULT_ROLES : :: std :: vec :: Vec < & 'static str > = :: std :: vec ! ["reader"] ; }
+        #[doc = " The state of an account."]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum State {
+            #[doc = " Can log in."]
+            #[derivative(Default)]
+            Active = 1i32,
+            Banned = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for State {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for State, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for State, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for State {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[doc = " A user, written to the \"users\" table."]
+        #[doc = ""]
+        #[doc = " Paths look like `C:\\Users\\name`, and the raw SQL is:"]
+        #[doc = ""]
+        #[doc = " ```text"]
+        #[doc = " SELECT * FROM users"]
+        #[doc = " ```"]
+        #[doc = ""]
+        #[doc = "    let indented = \"not a doc test\";"]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct User {
+            #[doc = " The id, never reused."]
+            pub id: UserId,
+            #[doc = " The display name."]
+            #[doc = " Empty until it is set."]
+            pub name: ::std::option::Option<::std::string::String>,
+            pub state: ::std::option::Option<State>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for User {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "User" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.name.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.state.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("state"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut state = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                state = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { name, state, id };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut state = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                state =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { name, state, id };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for User {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "User" })
+                    + {
+                        let value = &self.id;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("id"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.name.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(2i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.state.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("state"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(3i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for User {
+            fn default() -> Self {
+                User {
+                    id: ::std::default::Default::default(),
+                    name: None,
+                    state: Some(State::Active),
+                }
+            }
+        }
+        #[doc = " How to reach a user."]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum Contact {
+            #[doc = " An email address."]
+            #[derivative(Default)]
+            Email(::std::string::String),
+            Phone(i64),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Contact {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Contact" })?;
+                match self {
+                    Contact::Email(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("email"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        })?;
+                        protocol.write_string(value)?;
+                        protocol.write_field_end()?;
+                    }
+                    Contact::Phone(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("phone"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(2i16),
+                        })?;
+                        protocol.write_i64(*value)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Email(protocol.read_string()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Phone(protocol.read_i64()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Email(protocol.read_string().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Phone(protocol.read_i64().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Contact {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Contact",
+                }) + match self {
+                    Contact::Email(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("email"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    Contact::Phone(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("phone"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(2i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[doc = " Raised when there is no such user."]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct NotFound {
+            #[doc = " The id looked up."]
+            pub id: UserId,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for NotFound {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "NotFound" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for NotFound {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "NotFound",
+                }) + {
+                    let value = &self.id;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[doc = " The users."]
+        #[::async_trait::async_trait]
+        pub trait UserService {
+            #[doc = " Gets a user by id."]
+            async fn get(
+                &self,
+                id: UserId,
+            ) -> ::core::result::Result<User, ::pilota::thrift::UserError<UserServiceGetException>>;
+            async fn touch(&self, id: UserId) -> ::core::result::Result<(), ::pilota::AnyhowError>;
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum UserServiceGetResult {
+            #[derivative(Default)]
+            Ok(User),
+            NotFound(NotFound),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserServiceGetResult {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetResult",
+                })?;
+                match self {
+                    UserServiceGetResult::Ok(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    UserServiceGetResult::NotFound(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("not_found"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetResult::Ok(
+                                    ::pilota::thrift::Message::decode(protocol)?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetResult::NotFound(
+                                    ::pilota::thrift::Message::decode(protocol)?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetResult::Ok(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetResult::NotFound(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for UserServiceGetResult {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetResult",
+                }) + match self {
+                    UserServiceGetResult::Ok(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    UserServiceGetResult::NotFound(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("not_found"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum UserServiceGetException {
+            #[derivative(Default)]
+            NotFound(NotFound),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserServiceGetException {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetException",
+                })?;
+                match self {
+                    UserServiceGetException::NotFound(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("not_found"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetException::NotFound(
+                                    ::pilota::thrift::Message::decode(protocol)?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceGetException::NotFound(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for UserServiceGetException {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetException",
+                }) + match self {
+                    UserServiceGetException::NotFound(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("not_found"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::convert::From<NotFound> for UserServiceGetException {
+            fn from(e: NotFound) -> Self {
+                UserServiceGetException::NotFound(e)
+            }
+        }
+        impl ::std::convert::From<NotFound> for ::pilota::thrift::UserError<UserServiceGetException> {
+            fn from(e: NotFound) -> Self {
+                ::pilota::thrift::UserError::UserException(UserServiceGetException::NotFound(e))
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct UserServiceGetArgs {
+            #[doc = " The id of the user."]
+            pub id: UserId,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserServiceGetArgs {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetArgs",
+                };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for UserServiceGetArgs {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceGetArgs",
+                }) + {
+                    let value = &self.id;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum UserServiceTouchResult {
+            #[derivative(Default)]
+            Ok(()),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserServiceTouchResult {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceTouchResult",
+                })?;
+                match self {
+                    UserServiceTouchResult::Ok(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Void,
+                            id: Some(0i16),
+                        })?;
+                        protocol.write_struct_begin(&*::pilota::thrift::VOID_IDENT)?;
+                        protocol.write_struct_end()?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceTouchResult::Ok({
+                                    protocol.read_struct_begin()?;
+                                    protocol.read_struct_end()?;
+                                    ()
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(UserServiceTouchResult::Ok({
+                                    protocol.read_struct_begin().await?;
+                                    protocol.read_struct_end().await?;
+                                    ()
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for UserServiceTouchResult {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceTouchResult",
+                }) + match self {
+                    UserServiceTouchResult::Ok(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Void,
+                            id: Some(0i16),
+                        }) + protocol.write_struct_begin_len(&*::pilota::thrift::VOID_IDENT)
+                            + protocol.write_struct_end_len()
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct UserServiceTouchArgs {
+            pub id: UserId,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserServiceTouchArgs {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceTouchArgs",
+                };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { id };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for UserServiceTouchArgs {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UserServiceTouchArgs",
+                }) + {
+                    let value = &self.id;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+/// The id of a user.
+typedef i64 UserId
+
+/** The greeting sent to "new" users. */
+const string GREETING = "hello"
+
+/// The default roles, a `list` is built lazily.
+const list<string> DEFAULT_ROLES = ["reader"]
+
+/// The state of an account.
+enum State {
+    /// Can log in.
+    ACTIVE = 1,
+    // not a doc comment
+    BANNED = 2,
+}
+
+/**
+ * A user, written to the "users" table.
+ *
+ * Paths look like `C:\Users\name`, and the raw SQL is:
+ *
+ * ```
+ * SELECT * FROM users
+ * ```
+ *
+ *     let indented = "not a doc test";
+ */
+struct User {
+    /// The id, never reused.
+    1: required UserId id
+    /// The display name.
+    /// Empty until it is set.
+    2: optional string name
+    3: optional State state = State.ACTIVE,
+}
+
+/// How to reach a user.
+union Contact {
+    /// An email address.
+    1: string email,
+    2: i64 phone,
+}
+
+/// Raised when there is no such user.
+exception NotFound {
+    /// The id looked up.
+    1: required UserId id,
+}
+
+/// The users.
+service UserService {
+    /// Gets a user by id.
+    User get(
+        /// The id of the user.
+        1: UserId id,
+    ) throws (1: NotFound not_found),
+
+    oneway void touch(1: UserId id),
+}
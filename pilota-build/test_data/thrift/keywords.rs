@@ -0,0 +1,788 @@
+pub mod keywords {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod keywords {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Keywords {
+            pub r#type: ::std::string::String,
+            pub self_: ::std::option::Option<i32>,
+            pub super_: ::std::option::Option<i32>,
+            pub crate_: ::std::option::Option<i32>,
+            pub r#match: ::std::option::Option<bool>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Keywords {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Keywords" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.r#type;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("type"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.self_.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("self"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.super_.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("super"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.crate_.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("crate"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.r#match.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("match"),
+                        field_type: ::pilota::thrift::TType::Bool,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bool(*value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut r#type = None;
+                let mut self_ = None;
+                let mut super_ = None;
+                let mut crate_ = None;
+                let mut r#match = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                r#type = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                self_ = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                super_ = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                crate_ = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                r#match = Some(protocol.read_bool()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let r#type = if let Some(r#type) = r#type {
+                    r#type
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field type is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    self_,
+                    super_,
+                    crate_,
+                    r#match,
+                    r#type,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut r#type = None;
+                let mut self_ = None;
+                let mut super_ = None;
+                let mut crate_ = None;
+                let mut r#match = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                r#type = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                self_ = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                super_ = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                crate_ = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                r#match = Some(protocol.read_bool().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let r#type = if let Some(r#type) = r#type {
+                    r#type
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field type is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    self_,
+                    super_,
+                    crate_,
+                    r#match,
+                    r#type,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Keywords {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Keywords",
+                }) + {
+                    let value = &self.r#type;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("type"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.self_.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("self"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.super_.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("super"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.crate_.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("crate"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(4i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.r#match.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("match"),
+                        field_type: ::pilota::thrift::TType::Bool,
+                        id: Some(5i16),
+                    }) + protocol.write_bool_len(*value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Kind {
+            #[derivative(Default)]
+            Self_ = 0i32,
+            Move = 1i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Kind {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Kind, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Kind, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Kind {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Self_ {
+            pub kind: Kind,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Self_ {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Self" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.kind;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("kind"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut kind = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                kind = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let kind = if let Some(kind) = kind {
+                    kind
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field kind is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { kind };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut kind = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                kind =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let kind = if let Some(kind) = kind {
+                    kind
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field kind is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { kind };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Self_ {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Self" })
+                    + {
+                        let value = &self.kind;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("kind"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[::async_trait::async_trait]
+        pub trait Loop {
+            async fn r#fn(
+                &self,
+                self_: Self_,
+                r#type: i32,
+            ) -> ::core::result::Result<Keywords, ::pilota::AnyhowError>;
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum LoopFnResult {
+            #[derivative(Default)]
+            Ok(Keywords),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for LoopFnResult {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "LoopFnResult",
+                })?;
+                match self {
+                    LoopFnResult::Ok(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(LoopFnResult::Ok(::pilota::thrift::Message::decode(
+                                    protocol,
+                                )?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(LoopFnResult::Ok(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for LoopFnResult {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "LoopFnResult",
+                }) + match self {
+                    LoopFnResult::Ok(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct LoopFnArgs {
+            pub self_: Self_,
+            pub r#type: i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for LoopFnArgs {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "LoopFnArgs" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.self_;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("self"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.r#type;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("type"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut self_ = None;
+                let mut r#type = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                self_ = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                r#type = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let self_ = if let Some(self_) = self_ {
+                    self_
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field self is required".to_string(),
+                        ),
+                    ));
+                };
+                let r#type = if let Some(r#type) = r#type {
+                    r#type
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field type is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { self_, r#type };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut self_ = None;
+                let mut r#type = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                self_ =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                r#type = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let self_ = if let Some(self_) = self_ {
+                    self_
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field self is required".to_string(),
+                        ),
+                    ));
+                };
+                let r#type = if let Some(r#type) = r#type {
+                    r#type
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field type is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { self_, r#type };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for LoopFnArgs {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "LoopFnArgs",
+                }) + {
+                    let value = &self.self_;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("self"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(1i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.r#type;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("type"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+struct Keywords {
+    1: required string type,
+    2: optional i32 self,
+    3: optional i32 super,
+    4: optional i32 crate,
+    5: optional bool match,
+}
+
+enum Kind {
+    self = 0,
+    move = 1,
+}
+
+struct Self {
+    1: required Kind kind,
+}
+
+service Loop {
+    Keywords fn(1: Self self, 2: i32 type),
+}
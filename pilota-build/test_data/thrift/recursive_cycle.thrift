@@ -0,0 +1,18 @@
+struct Node {
+    1: optional Node next,
+}
+
+struct A {
+    1: optional B b,
+}
+
+struct B {
+    1: optional A a,
+    2: optional Node node,
+}
+
+struct Tree {
+    1: optional Forest children,
+}
+
+typedef Tree Forest
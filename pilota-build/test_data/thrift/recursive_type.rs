@@ -10,7 +10,7 @@ pub mod recursive_type {
     pub mod recursive_type {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
         pub struct A {
-            pub a: ::std::option::Option<::std::boxed::Box<A>>,
+            pub a: ::std::option::Option<::std::sync::Arc<A>>,
         }
         #[::async_trait::async_trait]
         impl ::pilota::thrift::Message for A {
@@ -49,9 +49,7 @@ pub mod recursive_type {
                     match field_id {
                         Some(1i16) => {
                             if ttype == ::pilota::thrift::TType::Struct {
-                                a = Some(::std::boxed::Box::new(
-                                    ::pilota::thrift::Message::decode(protocol)?,
-                                ));
+                                a = Some(::pilota::thrift::Message::decode(protocol)?);
                             } else {
                                 protocol.skip(ttype)?;
                             }
@@ -81,9 +79,7 @@ pub mod recursive_type {
                     match field_id {
                         Some(1i16) => {
                             if ttype == ::pilota::thrift::TType::Struct {
-                                a = Some(::std::boxed::Box::new(
-                                    ::pilota::thrift::Message::decode_async(protocol).await?,
-                                ));
+                                a = Some(::pilota::thrift::Message::decode_async(protocol).await?);
                             } else {
                                 protocol.skip(ttype).await?;
                             }
@@ -0,0 +1,380 @@
+pub mod rust_type {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod rust_type {
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Inventory {
+            pub owner: ::std::sync::Arc<str>,
+            pub history: ::std::collections::VecDeque<i64>,
+            pub stock:
+                ::std::option::Option<::std::collections::BTreeMap<::std::string::String, i32>>,
+            pub note: ::std::string::String,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Inventory {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inventory" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.owner;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("owner"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.history;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("history"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::I64,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_i64(*val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.stock.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("stock"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.note;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("note"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut owner = None;
+                let mut history = None;
+                let mut note = None;
+                let mut stock = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner = Some(::std::convert::From::from(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                history = Some(::std::iter::FromIterator::from_iter({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i64()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                stock = Some(::std::iter::FromIterator::from_iter({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i32()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                note = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let history = if let Some(history) = history {
+                    history
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field history is required".to_string(),
+                        ),
+                    ));
+                };
+                let note = if let Some(note) = note {
+                    note
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field note is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    stock,
+                    owner,
+                    history,
+                    note,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut owner = None;
+                let mut history = None;
+                let mut note = None;
+                let mut stock = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner =
+                                    Some(::std::convert::From::from(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                history = Some(::std::iter::FromIterator::from_iter({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i64().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                stock = Some(::std::iter::FromIterator::from_iter({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i32().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                note = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let history = if let Some(history) = history {
+                    history
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field history is required".to_string(),
+                        ),
+                    ));
+                };
+                let note = if let Some(note) = note {
+                    note
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field note is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    stock,
+                    owner,
+                    history,
+                    note,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Inventory {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Inventory",
+                }) + {
+                    let value = &self.owner;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("owner"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.history;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("history"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(2i16),
+                    }) + {
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::I64,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin_len(&list_ident)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += protocol.write_i64_len(*el);
+                                }
+                                size
+                            }
+                            + protocol.write_list_end_len()
+                    } + protocol.write_field_end_len()
+                } + if let Some(value) = self.stock.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("stock"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_string_len(&key);
+                                    size += protocol.write_i32_len(*val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.note;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("note"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
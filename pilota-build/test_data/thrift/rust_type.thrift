@@ -0,0 +1,6 @@
+struct Inventory {
+    1: required string owner (rust.type = "::std::sync::Arc<str>"),
+    2: required list<i64> history (rust.type = "::std::collections::VecDeque<i64>"),
+    3: optional map<string, i32> stock (rust.type = "::std::collections::BTreeMap<::std::string::String, i32>"),
+    4: required string note (go.tag = "json:\"note\""),
+}
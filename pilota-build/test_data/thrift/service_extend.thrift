@@ -0,0 +1,13 @@
+service Base {
+    /// Checks that the service is up.
+    void Ping(),
+    void Reset() (deprecated = "use Clear"),
+}
+
+service Store extends Base {
+    void Clear(),
+}
+
+service Legacy extends Store {
+    void Compact(),
+} (deprecated = "use Store")
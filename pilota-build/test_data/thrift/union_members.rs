@@ -0,0 +1,715 @@
+pub mod union_members {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod union_members {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Empty {}
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Empty {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                match *self {}
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Empty {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                match *self {}
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Point {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Point" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.x;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("x"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.y;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("y"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                let mut y = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                y = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let y = if let Some(y) = y {
+                    y
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x, y };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                let mut y = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                y = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let y = if let Some(y) = y {
+                    y
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x, y };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Point {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Point" })
+                    + {
+                        let value = &self.x;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("x"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.y;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("y"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Shape {
+            Label(::std::string::String),
+            Point(Point),
+            Path(::std::vec::Vec<Point>),
+            Radius(i64),
+        }
+        impl ::std::default::Default for Shape {
+            fn default() -> Self {
+                Shape::Label("none".to_string())
+            }
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Shape {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Shape" })?;
+                match self {
+                    Shape::Label(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("label"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        })?;
+                        protocol.write_string(value)?;
+                        protocol.write_field_end()?;
+                    }
+                    Shape::Point(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("point"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    Shape::Path(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("path"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(3i16),
+                        })?;
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::Struct,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin(&list_ident)?;
+                        for val in value {
+                            ::pilota::thrift::Message::encode(val, protocol)?;
+                        }
+                        protocol.write_list_end()?;
+                        protocol.write_field_end()?;
+                    }
+                    Shape::Radius(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("radius"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(4i16),
+                        })?;
+                        protocol.write_i64(*value)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Label(protocol.read_string()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Point(::pilota::thrift::Message::decode(
+                                    protocol,
+                                )?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(3i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Path({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(4i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Radius(protocol.read_i64()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Label(protocol.read_string().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Point(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(3i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Path({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(4i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Radius(protocol.read_i64().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Shape {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Shape" })
+                    + match self {
+                        Shape::Label(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("label"),
+                                field_type: ::pilota::thrift::TType::String,
+                                id: Some(1i16),
+                            }) + protocol.write_string_len(&value)
+                                + protocol.write_field_end_len()
+                        }
+                        Shape::Point(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("point"),
+                                field_type: ::pilota::thrift::TType::Struct,
+                                id: Some(2i16),
+                            }) + ::pilota::thrift::Size::size(value, protocol)
+                                + protocol.write_field_end_len()
+                        }
+                        Shape::Path(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("path"),
+                                field_type: ::pilota::thrift::TType::List,
+                                id: Some(3i16),
+                            }) + {
+                                let list_ident = ::pilota::thrift::TListIdentifier {
+                                    element_type: ::pilota::thrift::TType::Struct,
+                                    size: value.len(),
+                                };
+                                protocol.write_list_begin_len(&list_ident)
+                                    + {
+                                        let mut size = 0;
+                                        for el in value {
+                                            size += ::pilota::thrift::Size::size(el, protocol);
+                                        }
+                                        size
+                                    }
+                                    + protocol.write_list_end_len()
+                            } + protocol.write_field_end_len()
+                        }
+                        Shape::Radius(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("radius"),
+                                field_type: ::pilota::thrift::TType::I64,
+                                id: Some(4i16),
+                            }) + protocol.write_i64_len(*value)
+                                + protocol.write_field_end_len()
+                        }
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Canvas {
+            pub shape: Shape,
+            pub nothing: ::std::option::Option<Empty>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Canvas {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Canvas" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.shape;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("shape"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.nothing.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("nothing"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut shape = None;
+                let mut nothing = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                shape = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                nothing = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let shape = if let Some(shape) = shape {
+                    shape
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field shape is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { nothing, shape };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut shape = None;
+                let mut nothing = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                shape =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                nothing =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let shape = if let Some(shape) = shape {
+                    shape
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field shape is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { nothing, shape };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Canvas {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Canvas" })
+                    + {
+                        let value = &self.shape;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("shape"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.nothing.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("nothing"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
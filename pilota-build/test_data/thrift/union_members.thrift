@@ -0,0 +1,19 @@
+union Empty {
+}
+
+struct Point {
+    1: required i32 x,
+    2: required i32 y,
+}
+
+union Shape {
+    1: string label = "none",
+    2: Point point,
+    3: list<Point> path,
+    4: i64 radius,
+}
+
+struct Canvas {
+    1: required Shape shape,
+    2: optional Empty nothing,
+}
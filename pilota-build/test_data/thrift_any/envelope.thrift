@@ -0,0 +1,17 @@
+struct Ping {
+    1: required i64 seq,
+}
+
+struct Notice {
+    1: required string text,
+    2: optional i32 level,
+}
+
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+service Relay {
+    Ping forward(1: Notice notice),
+}
@@ -0,0 +1,1472 @@
+pub mod arc_fields {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod arc_fields {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Color {
+            #[derivative(Default)]
+            Red = 1i32,
+            Blue = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Color {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Color {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        impl Color {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Inner {
+            pub label: ::std::string::String,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Inner {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.label;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("label"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut label = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                label = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let label = if let Some(label) = label {
+                    label
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field label is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { label };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut label = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                label = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let label = if let Some(label) = label {
+                    label
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field label is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { label };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Inner {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + {
+                        let value = &self.label;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("label"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Inner {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct InnerBuilder {
+            label: ::std::option::Option<::std::string::String>,
+        }
+        impl InnerBuilder {
+            pub fn label(mut self, label: ::std::string::String) -> Self {
+                self.label = Some(label);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<Inner, ::pilota::MissingField> {
+                let label = self.label.ok_or(::pilota::MissingField {
+                    message: "Inner",
+                    field: "label",
+                })?;
+                Ok(Inner { label })
+            }
+        }
+        impl Inner {
+            pub fn builder() -> InnerBuilder {
+                InnerBuilder::default()
+            }
+        }
+        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT_IDS : :: std :: vec :: Vec < i64 > = :: std :: vec ! [1i64 , 2i64] ; }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Hot {
+            pub name: ::std::sync::Arc<::std::string::String>,
+            pub ids: ::std::sync::Arc<::std::vec::Vec<i64>>,
+            pub meta: ::std::option::Option<
+                ::std::sync::Arc<::std::collections::HashMap<::std::string::String, Inner>>,
+            >,
+            pub inner: ::std::option::Option<::std::sync::Arc<Inner>>,
+            pub count: ::std::sync::Arc<i32>,
+            pub color: ::std::sync::Arc<Color>,
+            pub payload: ::std::sync::Arc<::std::vec::Vec<u8>>,
+            pub encoded_len: ::std::option::Option<::std::sync::Arc<i64>>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Hot {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Hot" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        protocol.write_string(value)?;
+                    }
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.ids;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("ids"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::I64,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin(&list_ident)?;
+                        for val in value {
+                            protocol.write_i64(*val)?;
+                        }
+                        protocol.write_list_end()?;
+                    }
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.meta.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("meta"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        let map_ident = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::Struct,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin(&map_ident)?;
+                        for (key, val) in value.iter() {
+                            protocol.write_string(key)?;
+                            ::pilota::thrift::Message::encode(val, protocol)?;
+                        }
+                        protocol.write_map_end()?;
+                    }
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.inner.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("inner"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.count;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("count"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        protocol.write_i32(*value)?;
+                    }
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.color;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("color"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.payload;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("payload"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        protocol.write_bytes(&value)?;
+                    }
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.encoded_len.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("encoded_len"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        protocol.write_i64(*value)?;
+                    }
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut ids = None;
+                let mut count = None;
+                let mut color = None;
+                let mut payload = None;
+                let mut meta = None;
+                let mut inner = None;
+                let mut encoded_len = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(::std::sync::Arc::new(protocol.read_string()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                ids = Some(::std::sync::Arc::new({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i64()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                meta = Some(::std::sync::Arc::new({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = ::pilota::thrift::Message::decode(protocol)?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                inner = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                count = Some(::std::sync::Arc::new(protocol.read_i32()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                color = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload = Some(::std::sync::Arc::new(protocol.read_bytes()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                encoded_len = Some(::std::sync::Arc::new(protocol.read_i64()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let ids = if let Some(ids) = ids {
+                    ids
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ids is required".to_string(),
+                        ),
+                    ));
+                };
+                let count = if let Some(count) = count {
+                    count
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field count is required".to_string(),
+                        ),
+                    ));
+                };
+                let color = if let Some(color) = color {
+                    color
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field color is required".to_string(),
+                        ),
+                    ));
+                };
+                let payload = if let Some(payload) = payload {
+                    payload
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field payload is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    meta,
+                    inner,
+                    encoded_len,
+                    name,
+                    ids,
+                    count,
+                    color,
+                    payload,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut ids = None;
+                let mut count = None;
+                let mut color = None;
+                let mut payload = None;
+                let mut meta = None;
+                let mut inner = None;
+                let mut encoded_len = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(::std::sync::Arc::new(protocol.read_string().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                ids = Some(::std::sync::Arc::new({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i64().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                meta = Some(::std::sync::Arc::new({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val =
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                }));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                inner =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                count = Some(::std::sync::Arc::new(protocol.read_i32().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                color =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload = Some(::std::sync::Arc::new(protocol.read_bytes().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                encoded_len =
+                                    Some(::std::sync::Arc::new(protocol.read_i64().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let ids = if let Some(ids) = ids {
+                    ids
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ids is required".to_string(),
+                        ),
+                    ));
+                };
+                let count = if let Some(count) = count {
+                    count
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field count is required".to_string(),
+                        ),
+                    ));
+                };
+                let color = if let Some(color) = color {
+                    color
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field color is required".to_string(),
+                        ),
+                    ));
+                };
+                let payload = if let Some(payload) = payload {
+                    payload
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field payload is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    meta,
+                    inner,
+                    encoded_len,
+                    name,
+                    ids,
+                    count,
+                    color,
+                    payload,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Hot {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Hot" })
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + {
+                            let value = &**value;
+                            protocol.write_string_len(&value)
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.ids;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("ids"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(2i16),
+                        }) + {
+                            let value = &**value;
+                            {
+                                let list_ident = ::pilota::thrift::TListIdentifier {
+                                    element_type: ::pilota::thrift::TType::I64,
+                                    size: value.len(),
+                                };
+                                protocol.write_list_begin_len(&list_ident)
+                                    + {
+                                        let mut size = 0;
+                                        for el in value {
+                                            size += protocol.write_i64_len(*el);
+                                        }
+                                        size
+                                    }
+                                    + protocol.write_list_end_len()
+                            }
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.meta.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("meta"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(3i16),
+                        }) + {
+                            let value = &**value;
+                            {
+                                let map_id = ::pilota::thrift::TMapIdentifier {
+                                    key_type: ::pilota::thrift::TType::String,
+                                    value_type: ::pilota::thrift::TType::Struct,
+                                    size: value.len(),
+                                };
+                                protocol.write_map_begin_len(&map_id)
+                                    + {
+                                        let mut size = 0;
+                                        for (key, val) in value {
+                                            size += protocol.write_string_len(&key);
+                                            size += ::pilota::thrift::Size::size(val, protocol);
+                                        }
+                                        size
+                                    }
+                                    + protocol.write_map_end_len()
+                            }
+                        } + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.inner.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("inner"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(4i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.count;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("count"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(5i16),
+                        }) + {
+                            let value = &**value;
+                            protocol.write_i32_len(*value)
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.color;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("color"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(6i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.payload;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("payload"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(7i16),
+                        }) + {
+                            let value = &**value;
+                            protocol.write_bytes_len(value)
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.encoded_len.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("encoded_len"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(8i16),
+                        }) + {
+                            let value = &**value;
+                            protocol.write_i64_len(*value)
+                        } + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Hot {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl ::std::default::Default for Hot {
+            fn default() -> Self {
+                Hot {
+                    name: ::std::sync::Arc::new("hot".to_string()),
+                    ids: ::std::sync::Arc::new((*DEFAULT_IDS).clone()),
+                    meta: None,
+                    inner: None,
+                    count: ::std::sync::Arc::new(3i32),
+                    color: ::std::sync::Arc::new(Color::Red),
+                    payload: ::std::default::Default::default(),
+                    encoded_len: None,
+                }
+            }
+        }
+        impl Hot {
+            pub fn get_name(&self) -> &::std::string::String {
+                &self.name
+            }
+            pub fn get_ids(&self) -> &::std::vec::Vec<i64> {
+                &self.ids
+            }
+            pub fn get_meta(
+                &self,
+            ) -> ::std::option::Option<&::std::collections::HashMap<::std::string::String, Inner>>
+            {
+                self.meta.as_deref()
+            }
+            pub fn get_inner(&self) -> ::std::option::Option<&Inner> {
+                self.inner.as_deref()
+            }
+            pub fn get_count(&self) -> &i32 {
+                &self.count
+            }
+            pub fn get_color(&self) -> &Color {
+                &self.color
+            }
+            pub fn get_payload(&self) -> &::std::vec::Vec<u8> {
+                &self.payload
+            }
+            pub fn get_encoded_len(&self) -> ::std::option::Option<&i64> {
+                self.encoded_len.as_deref()
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct HotBuilder {
+            name: ::std::option::Option<::std::sync::Arc<::std::string::String>>,
+            ids: ::std::option::Option<::std::sync::Arc<::std::vec::Vec<i64>>>,
+            meta: ::std::option::Option<
+                ::std::sync::Arc<::std::collections::HashMap<::std::string::String, Inner>>,
+            >,
+            inner: ::std::option::Option<::std::sync::Arc<Inner>>,
+            count: ::std::option::Option<::std::sync::Arc<i32>>,
+            color: ::std::option::Option<::std::sync::Arc<Color>>,
+            payload: ::std::option::Option<::std::sync::Arc<::std::vec::Vec<u8>>>,
+            encoded_len: ::std::option::Option<::std::sync::Arc<i64>>,
+        }
+        impl HotBuilder {
+            pub fn name(mut self, name: ::std::sync::Arc<::std::string::String>) -> Self {
+                self.name = Some(name);
+                self
+            }
+            pub fn ids(mut self, ids: ::std::sync::Arc<::std::vec::Vec<i64>>) -> Self {
+                self.ids = Some(ids);
+                self
+            }
+            pub fn meta(
+                mut self,
+                meta: ::std::sync::Arc<::std::collections::HashMap<::std::string::String, Inner>>,
+            ) -> Self {
+                self.meta = Some(meta);
+                self
+            }
+            pub fn inner(mut self, inner: ::std::sync::Arc<Inner>) -> Self {
+                self.inner = Some(inner);
+                self
+            }
+            pub fn count(mut self, count: ::std::sync::Arc<i32>) -> Self {
+                self.count = Some(count);
+                self
+            }
+            pub fn color(mut self, color: ::std::sync::Arc<Color>) -> Self {
+                self.color = Some(color);
+                self
+            }
+            pub fn payload(mut self, payload: ::std::sync::Arc<::std::vec::Vec<u8>>) -> Self {
+                self.payload = Some(payload);
+                self
+            }
+            pub fn encoded_len(mut self, encoded_len: ::std::sync::Arc<i64>) -> Self {
+                self.encoded_len = Some(encoded_len);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<Hot, ::pilota::MissingField> {
+                let name = match self.name {
+                    Some(name) => name,
+                    None => ::std::sync::Arc::new("hot".to_string()),
+                };
+                let ids = match self.ids {
+                    Some(ids) => ids,
+                    None => ::std::sync::Arc::new((*DEFAULT_IDS).clone()),
+                };
+                let meta = self.meta;
+                let inner = self.inner;
+                let count = match self.count {
+                    Some(count) => count,
+                    None => ::std::sync::Arc::new(3i32),
+                };
+                let color = match self.color {
+                    Some(color) => color,
+                    None => ::std::sync::Arc::new(Color::Red),
+                };
+                let payload = self.payload.ok_or(::pilota::MissingField {
+                    message: "Hot",
+                    field: "payload",
+                })?;
+                let encoded_len = self.encoded_len;
+                Ok(Hot {
+                    name,
+                    ids,
+                    meta,
+                    inner,
+                    count,
+                    color,
+                    payload,
+                    encoded_len,
+                })
+            }
+        }
+        impl Hot {
+            pub fn builder() -> HotBuilder {
+                HotBuilder::default()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Node {
+            pub value: ::std::sync::Arc<i32>,
+            pub next: ::std::option::Option<::std::sync::Arc<Node>>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Node {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Node" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.value;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("value"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    {
+                        let value = &**value;
+                        protocol.write_i32(*value)?;
+                    }
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.next.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("next"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut value = None;
+                let mut next = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                value = Some(::std::sync::Arc::new(protocol.read_i32()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                next = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let value = if let Some(value) = value {
+                    value
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field value is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { next, value };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut value = None;
+                let mut next = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                value = Some(::std::sync::Arc::new(protocol.read_i32().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                next =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let value = if let Some(value) = value {
+                    value
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field value is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { next, value };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Node {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Node" })
+                    + {
+                        let value = &self.value;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("value"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + {
+                            let value = &**value;
+                            protocol.write_i32_len(*value)
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.next.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("next"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Node {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Node {
+            pub fn get_value(&self) -> &i32 {
+                &self.value
+            }
+            pub fn get_next(&self) -> ::std::option::Option<&Node> {
+                self.next.as_deref()
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct NodeBuilder {
+            value: ::std::option::Option<::std::sync::Arc<i32>>,
+            next: ::std::option::Option<::std::sync::Arc<Node>>,
+        }
+        impl NodeBuilder {
+            pub fn value(mut self, value: ::std::sync::Arc<i32>) -> Self {
+                self.value = Some(value);
+                self
+            }
+            pub fn next(mut self, next: ::std::sync::Arc<Node>) -> Self {
+                self.next = Some(next);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<Node, ::pilota::MissingField> {
+                let value = self.value.ok_or(::pilota::MissingField {
+                    message: "Node",
+                    field: "value",
+                })?;
+                let next = self.next;
+                Ok(Node { value, next })
+            }
+        }
+        impl Node {
+            pub fn builder() -> NodeBuilder {
+                NodeBuilder::default()
+            }
+        }
+        #[::async_trait::async_trait]
+        pub trait HotService {
+            async fn get(&self, req: Hot) -> ::core::result::Result<Hot, ::pilota::AnyhowError>;
+        }
+        #[derive(Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum HotServiceGetResult {
+            #[derivative(Default)]
+            Ok(Hot),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for HotServiceGetResult {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "HotServiceGetResult",
+                })?;
+                match self {
+                    HotServiceGetResult::Ok(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(HotServiceGetResult::Ok(
+                                    ::pilota::thrift::Message::decode(protocol)?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(0i16) => {
+                            if ret.is_none() {
+                                ret = Some(HotServiceGetResult::Ok(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for HotServiceGetResult {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "HotServiceGetResult",
+                }) + match self {
+                    HotServiceGetResult::Ok(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("Ok"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(0i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl HotServiceGetResult {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct HotServiceGetArgs {
+            pub req: Hot,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for HotServiceGetArgs {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "HotServiceGetArgs",
+                };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.req;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("req"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut req = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                req = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let req = if let Some(req) = req {
+                    req
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field req is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { req };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut req = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                req =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let req = if let Some(req) = req {
+                    req
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field req is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { req };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for HotServiceGetArgs {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "HotServiceGetArgs",
+                }) + {
+                    let value = &self.req;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("req"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(1i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl HotServiceGetArgs {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct HotServiceGetArgsBuilder {
+            req: ::std::option::Option<Hot>,
+        }
+        impl HotServiceGetArgsBuilder {
+            pub fn req(mut self, req: Hot) -> Self {
+                self.req = Some(req);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<HotServiceGetArgs, ::pilota::MissingField> {
+                let req = self.req.ok_or(::pilota::MissingField {
+                    message: "HotServiceGetArgs",
+                    field: "req",
+                })?;
+                Ok(HotServiceGetArgs { req })
+            }
+        }
+        impl HotServiceGetArgs {
+            pub fn builder() -> HotServiceGetArgsBuilder {
+                HotServiceGetArgsBuilder::default()
+            }
+        }
+    }
+}
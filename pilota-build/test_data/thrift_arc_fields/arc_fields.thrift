@@ -0,0 +1,30 @@
+enum Color {
+    RED = 1,
+    BLUE = 2,
+}
+
+struct Inner {
+    1: required string label,
+}
+
+const list<i64> DEFAULT_IDS = [1, 2]
+
+struct Hot {
+    1: required string name = "hot",
+    2: required list<i64> ids = DEFAULT_IDS,
+    3: optional map<string, Inner> meta,
+    4: optional Inner inner,
+    5: required i32 count = 3,
+    6: required Color color = Color.RED,
+    7: required binary payload,
+    8: optional i64 encoded_len,
+}
+
+struct Node {
+    1: required i32 value,
+    2: optional Node next,
+}
+
+service HotService {
+    Hot get(1: Hot req),
+}
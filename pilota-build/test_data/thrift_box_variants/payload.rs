@@ -0,0 +1,671 @@
+pub mod payload {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod payload {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Header {
+            pub id: i64,
+            pub name: ::std::string::String,
+            pub owner: ::std::string::String,
+            pub tags: ::std::vec::Vec<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Header {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Header" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.owner;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("owner"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.tags;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut owner = None;
+                let mut tags = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    id,
+                    name,
+                    owner,
+                    tags,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut owner = None;
+                let mut tags = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    id,
+                    name,
+                    owner,
+                    tags,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Header {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Header" })
+                    + {
+                        let value = &self.id;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("id"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(2i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.owner;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("owner"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(3i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.tags;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("tags"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(4i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_string_len(&el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Header {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Header {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                let mut map = ::serde_json::Map::new();
+                map.insert("id".to_string(), ::serde_json::Value::from(*(&self.id)));
+                map.insert(
+                    "name".to_string(),
+                    ::serde_json::Value::String((&self.name).to_string()),
+                );
+                map.insert(
+                    "owner".to_string(),
+                    ::serde_json::Value::String((&self.owner).to_string()),
+                );
+                map.insert(
+                    "tags".to_string(),
+                    ::serde_json::Value::Array(
+                        (&self.tags)
+                            .iter()
+                            .map(|v| ::serde_json::Value::String(v.to_string()))
+                            .collect(),
+                    ),
+                );
+                ::serde_json::Value::Object(map)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Payload {
+            Code(i64),
+            Header(::std::boxed::Box<Header>),
+            Nested(::std::sync::Arc<Payload>),
+            Text(::std::string::String),
+        }
+        impl ::std::default::Default for Payload {
+            fn default() -> Self {
+                Payload::Code(0i64)
+            }
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Payload {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Payload" })?;
+                match self {
+                    Payload::Code(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("code"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        })?;
+                        protocol.write_i64(*value)?;
+                        protocol.write_field_end()?;
+                    }
+                    Payload::Header(ref value) => {
+                        let value = &**value;
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("header"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    Payload::Nested(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("nested"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(3i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    Payload::Text(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("text"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(4i16),
+                        })?;
+                        protocol.write_string(value)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Code(protocol.read_i64()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Header(::std::boxed::Box::new(
+                                    ::pilota::thrift::Message::decode(protocol)?,
+                                )));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(3i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Nested(::pilota::thrift::Message::decode(
+                                    protocol,
+                                )?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(4i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Text(protocol.read_string()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Code(protocol.read_i64().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Header(::std::boxed::Box::new(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                )));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(3i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Nested(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(4i16) => {
+                            if ret.is_none() {
+                                ret = Some(Payload::Text(protocol.read_string().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Payload {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Payload",
+                }) + match self {
+                    Payload::Code(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("code"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    Payload::Header(ref value) => {
+                        let value = &**value;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("header"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    Payload::Nested(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("nested"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(3i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    Payload::Text(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("text"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(4i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Payload {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Payload {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                match self {
+                    Self::Code(v) => {
+                        let mut map = ::serde_json::Map::new();
+                        map.insert("code".to_string(), ::serde_json::Value::from(*v));
+                        ::serde_json::Value::Object(map)
+                    }
+                    Self::Header(v) => {
+                        let v = &**v;
+                        let mut map = ::serde_json::Map::new();
+                        map.insert("header".to_string(), v.to_json_value());
+                        ::serde_json::Value::Object(map)
+                    }
+                    Self::Nested(v) => {
+                        let mut map = ::serde_json::Map::new();
+                        map.insert("nested".to_string(), (&**v).to_json_value());
+                        ::serde_json::Value::Object(map)
+                    }
+                    Self::Text(v) => {
+                        let mut map = ::serde_json::Map::new();
+                        map.insert(
+                            "text".to_string(),
+                            ::serde_json::Value::String(v.to_string()),
+                        );
+                        ::serde_json::Value::Object(map)
+                    }
+                }
+            }
+        }
+    }
+}
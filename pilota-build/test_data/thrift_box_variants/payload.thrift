@@ -0,0 +1,13 @@
+struct Header {
+    1: required i64 id,
+    2: required string name,
+    3: required string owner,
+    4: required list<string> tags,
+}
+
+union Payload {
+    1: i64 code = 0,
+    2: Header header,
+    3: Payload nested,
+    4: string text,
+}
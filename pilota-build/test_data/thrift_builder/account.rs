@@ -0,0 +1,628 @@
+pub mod account {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod account {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Quota {
+            pub limit: ::std::option::Option<i32>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Quota {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Quota" };
+                protocol.write_struct_begin(&struct_ident)?;
+                if let Some(value) = self.limit.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("limit"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut limit = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                limit = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let data = Self { limit };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut limit = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                limit = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let data = Self { limit };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Quota {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Quota" })
+                    + if let Some(value) = self.limit.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("limit"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Quota {
+            fn default() -> Self {
+                Quota {
+                    limit: Some(100i32),
+                }
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct QuotaBuilder {
+            limit: ::std::option::Option<i32>,
+        }
+        impl QuotaBuilder {
+            pub fn limit(mut self, limit: i32) -> Self {
+                self.limit = Some(limit);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<Quota, ::pilota::MissingField> {
+                let limit = match self.limit {
+                    None => Some(100i32),
+                    limit => limit,
+                };
+                Ok(Quota { limit })
+            }
+        }
+        impl Quota {
+            pub fn builder() -> QuotaBuilder {
+                QuotaBuilder::default()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Account {
+            pub id: i64,
+            pub owner: ::std::string::String,
+            pub level: i32,
+            pub email: ::std::option::Option<::std::string::String>,
+            pub tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+            pub quota: ::std::option::Option<Quota>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Account {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Account" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.owner;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("owner"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.level;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("level"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.email.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("email"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.tags.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.quota.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("quota"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut owner = None;
+                let mut level = None;
+                let mut email = None;
+                let mut tags = None;
+                let mut quota = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                quota = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let level = if let Some(level) = level {
+                    level
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field level is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    email,
+                    tags,
+                    quota,
+                    id,
+                    owner,
+                    level,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut owner = None;
+                let mut level = None;
+                let mut email = None;
+                let mut tags = None;
+                let mut quota = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                owner = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                level = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                quota =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let owner = if let Some(owner) = owner {
+                    owner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field owner is required".to_string(),
+                        ),
+                    ));
+                };
+                let level = if let Some(level) = level {
+                    level
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field level is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    email,
+                    tags,
+                    quota,
+                    id,
+                    owner,
+                    level,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Account {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Account",
+                }) + {
+                    let value = &self.id;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    }) + protocol.write_i64_len(*value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.owner;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("owner"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.level;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("level"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.email.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("email"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.tags.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(5i16),
+                    }) + {
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::String,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin_len(&list_ident)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += protocol.write_string_len(&el);
+                                }
+                                size
+                            }
+                            + protocol.write_list_end_len()
+                    } + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.quota.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("quota"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(6i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Account {
+            fn default() -> Self {
+                Account {
+                    id: ::std::default::Default::default(),
+                    owner: ::std::default::Default::default(),
+                    level: 1i32,
+                    email: None,
+                    tags: Some({
+                        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT : :: std :: vec :: Vec < :: std :: string :: String > = :: std :: vec ! ["new" . to_string ()] ; }
+                        DEFAULT.clone()
+                    }),
+                    quota: None,
+                }
+            }
+        }
+        #[derive(Clone, Default)]
+        pub struct AccountBuilder {
+            id: ::std::option::Option<i64>,
+            owner: ::std::option::Option<::std::string::String>,
+            level: ::std::option::Option<i32>,
+            email: ::std::option::Option<::std::string::String>,
+            tags: ::std::option::Option<::std::vec::Vec<::std::string::String>>,
+            quota: ::std::option::Option<Quota>,
+        }
+        impl AccountBuilder {
+            pub fn id(mut self, id: i64) -> Self {
+                self.id = Some(id);
+                self
+            }
+            pub fn owner(mut self, owner: ::std::string::String) -> Self {
+                self.owner = Some(owner);
+                self
+            }
+            pub fn level(mut self, level: i32) -> Self {
+                self.level = Some(level);
+                self
+            }
+            pub fn email(mut self, email: ::std::string::String) -> Self {
+                self.email = Some(email);
+                self
+            }
+            pub fn tags(mut self, tags: ::std::vec::Vec<::std::string::String>) -> Self {
+                self.tags = Some(tags);
+                self
+            }
+            pub fn quota(mut self, quota: Quota) -> Self {
+                self.quota = Some(quota);
+                self
+            }
+            pub fn build(self) -> ::std::result::Result<Account, ::pilota::MissingField> {
+                let id = self.id.ok_or(::pilota::MissingField {
+                    message: "Account",
+                    field: "id",
+                })?;
+                let owner = self.owner.ok_or(::pilota::MissingField {
+                    message: "Account",
+                    field: "owner",
+                })?;
+                let level = match self.level {
+                    Some(level) => level,
+                    None => 1i32,
+                };
+                let email = self.email;
+                let tags = match self.tags {
+                    None => Some({
+                        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT : :: std :: vec :: Vec < :: std :: string :: String > = :: std :: vec ! ["new" . to_string ()] ; }
+                        DEFAULT.clone()
+                    }),
+                    tags => tags,
+                };
+                let quota = self.quota;
+                Ok(Account {
+                    id,
+                    owner,
+                    level,
+                    email,
+                    tags,
+                    quota,
+                })
+            }
+        }
+        impl Account {
+            pub fn builder() -> AccountBuilder {
+                AccountBuilder::default()
+            }
+        }
+    }
+}
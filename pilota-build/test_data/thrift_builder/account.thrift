@@ -0,0 +1,12 @@
+struct Quota {
+    1: optional i32 limit = 100,
+}
+
+struct Account {
+    1: required i64 id,
+    2: required string owner,
+    3: required i32 level = 1,
+    4: optional string email,
+    5: optional list<string> tags = ["new"],
+    6: optional Quota quota,
+}
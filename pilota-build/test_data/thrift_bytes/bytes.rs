@@ -0,0 +1,314 @@
+pub mod bytes {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod bytes {
+        ::pilota::lazy_static::lazy_static! { pub static ref MAGIC : :: pilota :: bytes :: Bytes = :: pilota :: bytes :: Bytes :: from_static (b"pilota") ; }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Packet {
+            pub payload: ::pilota::bytes::Bytes,
+            pub frames: ::std::option::Option<::std::vec::Vec<::pilota::bytes::Bytes>>,
+            pub headers: ::std::option::Option<
+                ::std::collections::HashMap<::std::string::String, ::pilota::bytes::Bytes>,
+            >,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Packet {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Packet" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.payload;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("payload"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bytes(&value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.frames.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("frames"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_bytes(&val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.headers.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("headers"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_bytes(&val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut payload = None;
+                let mut frames = None;
+                let mut headers = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload = Some(::std::convert::From::from(protocol.read_bytes()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                frames = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::std::convert::From::from(
+                                            protocol.read_bytes()?,
+                                        ));
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                headers = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val =
+                                            ::std::convert::From::from(protocol.read_bytes()?);
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let payload = if let Some(payload) = payload {
+                    payload
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field payload is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    frames,
+                    headers,
+                    payload,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut payload = None;
+                let mut frames = None;
+                let mut headers = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload =
+                                    Some(::std::convert::From::from(protocol.read_bytes().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                frames = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::std::convert::From::from(
+                                            protocol.read_bytes().await?,
+                                        ));
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                headers = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = ::std::convert::From::from(
+                                            protocol.read_bytes().await?,
+                                        );
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let payload = if let Some(payload) = payload {
+                    payload
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field payload is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    frames,
+                    headers,
+                    payload,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Packet {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Packet" })
+                    + {
+                        let value = &self.payload;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("payload"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_bytes_len(value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.frames.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("frames"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(2i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_bytes_len(el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.headers.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("headers"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(3i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::String,
+                                value_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_string_len(&key);
+                                        size += protocol.write_bytes_len(val);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+const binary MAGIC = "pilota"
+
+struct Packet {
+    1: required binary payload,
+    2: optional list<binary> frames,
+    3: optional map<string, binary> headers,
+}
@@ -0,0 +1,11 @@
+struct Address {
+    1: required string city,
+}
+
+struct Account {
+    1: required i64 id,
+    2: required string name,
+    3: optional double balance,
+    4: optional Address address,
+    5: required list<string> tags,
+}
@@ -0,0 +1,20 @@
+struct Request {
+    1: required string message,
+}
+
+struct Response {
+    1: required string message,
+}
+
+exception Error {
+    1: required i32 code,
+}
+
+service Base {
+    void Ping()
+}
+
+service Echo extends Base {
+    Response Echo(1: Request req) throws (1: Error err)
+    oneway void Notify(1: Request req, 2: i64 seq)
+}
@@ -0,0 +1,8 @@
+struct Message {
+    1: required string text,
+}
+
+service Chat {
+    Message Send(1: Message req)
+    Message Echo(1: Message req) (streaming.mode="bidirectional")
+}
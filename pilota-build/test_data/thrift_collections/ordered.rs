@@ -0,0 +1,625 @@
+pub mod ordered {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod ordered {
+        ::pilota::lazy_static::lazy_static! { pub static ref LIMITS : :: std :: collections :: BTreeMap < & 'static str , i32 > = { let mut map = :: std :: collections :: BTreeMap :: new () ; map . insert ("b" , 2i32) ; map . insert ("a" , 1i32) ; map } ; }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Inventory {
+            pub counts: ::std::collections::BTreeMap<::std::string::String, i32>,
+            pub tags: ::std::collections::BTreeSet<::std::string::String>,
+            pub names:
+                ::std::option::Option<::std::collections::BTreeMap<i32, ::std::string::String>>,
+            pub arrivals: ::indexmap::IndexMap<::std::string::String, i32>,
+            pub batches: ::std::vec::Vec<::std::collections::BTreeMap<::std::string::String, i32>>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Inventory {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inventory" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.counts;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("counts"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.tags;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TSetIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_set_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_set_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.names.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("names"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::I32,
+                        value_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_i32(*key)?;
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.arrivals;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("arrivals"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.batches;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("batches"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::Map,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        let map_ident = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: val.len(),
+                        };
+                        protocol.write_map_begin(&map_ident)?;
+                        for (key, val) in val.iter() {
+                            protocol.write_string(key)?;
+                            protocol.write_i32(*val)?;
+                        }
+                        protocol.write_map_end()?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut counts = None;
+                let mut tags = None;
+                let mut arrivals = None;
+                let mut batches = None;
+                let mut names = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                counts = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i32()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                tags = Some({
+                                    let list_ident = protocol.read_set_begin()?;
+                                    let mut val = ::std::collections::BTreeSet::new();
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string()?);
+                                    }
+                                    protocol.read_set_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                names = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32()?;
+                                        let el_val = protocol.read_string()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                arrivals = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::indexmap::IndexMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i32()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                batches = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push({
+                                            let map_ident = protocol.read_map_begin()?;
+                                            let mut val = ::std::collections::BTreeMap::new();
+                                            for _ in 0..map_ident.size {
+                                                let el_key = protocol.read_string()?;
+                                                let el_val = protocol.read_i32()?;
+                                                val.insert(el_key, el_val);
+                                            }
+                                            protocol.read_map_end()?;
+                                            val
+                                        });
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let counts = if let Some(counts) = counts {
+                    counts
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field counts is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let arrivals = if let Some(arrivals) = arrivals {
+                    arrivals
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field arrivals is required".to_string(),
+                        ),
+                    ));
+                };
+                let batches = if let Some(batches) = batches {
+                    batches
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field batches is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    names,
+                    counts,
+                    tags,
+                    arrivals,
+                    batches,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut counts = None;
+                let mut tags = None;
+                let mut arrivals = None;
+                let mut batches = None;
+                let mut names = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                counts = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i32().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                tags = Some({
+                                    let list_ident = protocol.read_set_begin().await?;
+                                    let mut val = ::std::collections::BTreeSet::new();
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string().await?);
+                                    }
+                                    protocol.read_set_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                names = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32().await?;
+                                        let el_val = protocol.read_string().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                arrivals = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::indexmap::IndexMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i32().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                batches = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push({
+                                            let map_ident = protocol.read_map_begin().await?;
+                                            let mut val = ::std::collections::BTreeMap::new();
+                                            for _ in 0..map_ident.size {
+                                                let el_key = protocol.read_string().await?;
+                                                let el_val = protocol.read_i32().await?;
+                                                val.insert(el_key, el_val);
+                                            }
+                                            protocol.read_map_end().await?;
+                                            val
+                                        });
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let counts = if let Some(counts) = counts {
+                    counts
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field counts is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let arrivals = if let Some(arrivals) = arrivals {
+                    arrivals
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field arrivals is required".to_string(),
+                        ),
+                    ));
+                };
+                let batches = if let Some(batches) = batches {
+                    batches
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field batches is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    names,
+                    counts,
+                    tags,
+                    arrivals,
+                    batches,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Inventory {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Inventory",
+                }) + {
+                    let value = &self.counts;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("counts"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(1i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_string_len(&key);
+                                    size += protocol.write_i32_len(*val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } + {
+                    let value = &self.tags;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(2i16),
+                    }) + {
+                        let set_id = ::pilota::thrift::TSetIdentifier {
+                            element_type: ::pilota::thrift::TType::String,
+                            size: value.len(),
+                        };
+                        protocol.write_set_begin_len(&set_id)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += protocol.write_string_len(&el);
+                                }
+                                size
+                            }
+                            + protocol.write_set_end_len()
+                    } + protocol.write_field_end_len()
+                } + if let Some(value) = self.names.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("names"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(3i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::I32,
+                            value_type: ::pilota::thrift::TType::String,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_i32_len(*key);
+                                    size += protocol.write_string_len(&val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } else {
+                    0
+                } + {
+                    let value = &self.arrivals;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("arrivals"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(4i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_string_len(&key);
+                                    size += protocol.write_i32_len(*val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } + {
+                    let value = &self.batches;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("batches"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(5i16),
+                    }) + {
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::Map,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin_len(&list_ident)
+                            + {
+                                let mut size = 0;
+                                for el in value {
+                                    size += {
+                                        let map_id = ::pilota::thrift::TMapIdentifier {
+                                            key_type: ::pilota::thrift::TType::String,
+                                            value_type: ::pilota::thrift::TType::I32,
+                                            size: el.len(),
+                                        };
+                                        protocol.write_map_begin_len(&map_id)
+                                            + {
+                                                let mut size = 0;
+                                                for (key, val) in el {
+                                                    size += protocol.write_string_len(&key);
+                                                    size += protocol.write_i32_len(*val);
+                                                }
+                                                size
+                                            }
+                                            + protocol.write_map_end_len()
+                                    };
+                                }
+                                size
+                            }
+                            + protocol.write_list_end_len()
+                    } + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Inventory {
+            fn default() -> Self {
+                Inventory {
+                    counts: ::std::default::Default::default(),
+                    tags: ::std::default::Default::default(),
+                    names: Some({
+                        ::pilota::lazy_static::lazy_static! { pub static ref DEFAULT : :: std :: collections :: BTreeMap < i32 , :: std :: string :: String > = { let mut map = :: std :: collections :: BTreeMap :: new () ; map . insert (2i32 , "two" . to_string ()) ; map . insert (1i32 , "one" . to_string ()) ; map } ; }
+                        DEFAULT.clone()
+                    }),
+                    arrivals: ::std::default::Default::default(),
+                    batches: ::std::default::Default::default(),
+                }
+            }
+        }
+    }
+}
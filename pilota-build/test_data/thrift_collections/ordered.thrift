@@ -0,0 +1,9 @@
+const map<string, i32> LIMITS = {"b": 2, "a": 1}
+
+struct Inventory {
+    1: required map<string, i32> counts,
+    2: required set<string> tags,
+    3: optional map<i32, string> names = {2: "two", 1: "one"},
+    4: required map<string, i32> arrivals (rust.collection = "index"),
+    5: required list<map<string, i32>> batches,
+}
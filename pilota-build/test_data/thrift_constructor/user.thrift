@@ -0,0 +1,18 @@
+enum Role {
+    GUEST = 0,
+    ADMIN = 1,
+}
+
+struct Filter {
+    1: optional string name,
+    2: optional i32 limit = 10,
+}
+
+struct User {
+    2: required string name,
+    1: required i64 id,
+    3: optional string email,
+    4: optional Role role = Role.GUEST,
+    5: optional list<string> tags = ["new"],
+    6: required Filter filter,
+}
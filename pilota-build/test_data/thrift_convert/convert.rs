@@ -0,0 +1,1520 @@
+pub mod convert {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod convert {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct AddressV1 {
+            pub city: ::std::string::String,
+            pub street: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for AddressV1 {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "AddressV1" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.city;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.street.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("street"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut street = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                street = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { street, city };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut street = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                street = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { street, city };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for AddressV1 {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "AddressV1",
+                }) + {
+                    let value = &self.city;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.street.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("street"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct AddressV2 {
+            pub city: ::std::string::String,
+            pub street: ::std::option::Option<::std::string::String>,
+            pub zip: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for AddressV2 {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "AddressV2" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.city;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.street.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("street"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.zip.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("zip"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut street = None;
+                let mut zip = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                street = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                zip = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { street, zip, city };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut street = None;
+                let mut zip = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                street = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                zip = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { street, zip, city };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for AddressV2 {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "AddressV2",
+                }) + {
+                    let value = &self.city;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.street.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("street"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + if let Some(value) = self.zip.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("zip"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(3i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::convert::From<AddressV1> for AddressV2 {
+            #[allow(unused_variables)]
+            fn from(value: AddressV1) -> Self {
+                AddressV2 {
+                    city: value.city,
+                    street: value.street,
+                    zip: ::std::option::Option::None,
+                }
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct UserV1 {
+            pub id: i64,
+            pub name: ::std::string::String,
+            pub home: ::std::option::Option<AddressV1>,
+            pub addresses: ::std::vec::Vec<AddressV1>,
+            pub named: ::std::collections::HashMap<::std::string::String, AddressV1>,
+            pub legacy_flags: ::std::option::Option<i32>,
+            pub tags: ::std::vec::Vec<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserV1 {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "UserV1" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.home.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("home"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.addresses;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("addresses"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.named;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("named"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.legacy_flags.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("legacy_flags"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.tags;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut addresses = None;
+                let mut named = None;
+                let mut tags = None;
+                let mut home = None;
+                let mut legacy_flags = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                home = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                named = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = ::pilota::thrift::Message::decode(protocol)?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                legacy_flags = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let named = if let Some(named) = named {
+                    named
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field named is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    home,
+                    legacy_flags,
+                    id,
+                    name,
+                    addresses,
+                    named,
+                    tags,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut name = None;
+                let mut addresses = None;
+                let mut named = None;
+                let mut tags = None;
+                let mut home = None;
+                let mut legacy_flags = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                home =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                named = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val =
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                legacy_flags = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let named = if let Some(named) = named {
+                    named
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field named is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    home,
+                    legacy_flags,
+                    id,
+                    name,
+                    addresses,
+                    named,
+                    tags,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for UserV1 {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "UserV1" })
+                    + {
+                        let value = &self.id;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("id"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(2i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.home.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("home"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(3i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.addresses;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("addresses"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(4i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += ::pilota::thrift::Size::size(el, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.named;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("named"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(5i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::String,
+                                value_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_string_len(&key);
+                                        size += ::pilota::thrift::Size::size(val, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.legacy_flags.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("legacy_flags"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(6i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.tags;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("tags"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(7i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_string_len(&el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct UserV2 {
+            pub id: i64,
+            pub name: ::std::option::Option<::std::string::String>,
+            pub home: ::std::option::Option<AddressV2>,
+            pub addresses: ::std::vec::Vec<AddressV2>,
+            pub named: ::std::collections::HashMap<::std::string::String, AddressV2>,
+            pub tags: ::std::vec::Vec<::std::string::String>,
+            pub email: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for UserV2 {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "UserV2" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.id;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("id"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.name.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.home.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("home"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.addresses;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("addresses"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.named;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("named"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.tags;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("tags"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.email.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("email"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut addresses = None;
+                let mut named = None;
+                let mut tags = None;
+                let mut name = None;
+                let mut home = None;
+                let mut email = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                home = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                named = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = ::pilota::thrift::Message::decode(protocol)?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let named = if let Some(named) = named {
+                    named
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field named is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    name,
+                    home,
+                    email,
+                    id,
+                    addresses,
+                    named,
+                    tags,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut id = None;
+                let mut addresses = None;
+                let mut named = None;
+                let mut tags = None;
+                let mut name = None;
+                let mut home = None;
+                let mut email = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                id = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                home =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                named = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val =
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                tags = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_string().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                email = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let id = if let Some(id) = id {
+                    id
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let named = if let Some(named) = named {
+                    named
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field named is required".to_string(),
+                        ),
+                    ));
+                };
+                let tags = if let Some(tags) = tags {
+                    tags
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field tags is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    name,
+                    home,
+                    email,
+                    id,
+                    addresses,
+                    named,
+                    tags,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for UserV2 {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "UserV2" })
+                    + {
+                        let value = &self.id;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("id"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(1i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.name.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(2i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.home.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("home"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(3i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.addresses;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("addresses"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(4i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += ::pilota::thrift::Size::size(el, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.named;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("named"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(5i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::String,
+                                value_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_string_len(&key);
+                                        size += ::pilota::thrift::Size::size(val, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.tags;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("tags"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(7i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_string_len(&el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.email.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("email"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(8i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::convert::From<UserV1> for UserV2 {
+            #[allow(unused_variables)]
+            fn from(value: UserV1) -> Self {
+                UserV2 {
+                    id: value.id,
+                    name: ::std::option::Option::Some(value.name),
+                    home: value.home.map(|v| ::std::convert::Into::into(v)),
+                    addresses: value
+                        .addresses
+                        .into_iter()
+                        .map(|v| ::std::convert::Into::into(v))
+                        .collect(),
+                    named: value
+                        .named
+                        .into_iter()
+                        .map(|(k, v)| (k, ::std::convert::Into::into(v)))
+                        .collect(),
+                    tags: value.tags,
+                    email: ::std::option::Option::None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+struct AddressV1 {
+    1: required string city,
+    2: optional string street,
+}
+
+struct AddressV2 {
+    1: required string city,
+    2: optional string street,
+    3: optional string zip,
+}
+
+struct UserV1 {
+    1: required i64 id,
+    2: required string name,
+    3: optional AddressV1 home,
+    4: required list<AddressV1> addresses,
+    5: required map<string, AddressV1> named,
+    6: optional i32 legacy_flags,
+    7: required list<string> tags,
+}
+
+struct UserV2 {
+    1: required i64 id,
+    2: optional string name,
+    3: optional AddressV2 home,
+    4: required list<AddressV2> addresses,
+    5: required map<string, AddressV2> named,
+    7: required list<string> tags,
+    8: optional string email,
+}
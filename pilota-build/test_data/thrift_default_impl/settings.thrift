@@ -0,0 +1,15 @@
+enum Mode {
+    FAST = 1,
+    SAFE = 2,
+}
+
+struct Settings {
+    1: required Mode mode,
+    2: optional i32 limit,
+    3: required i32 retries = 3,
+}
+
+struct Plain {
+    1: required Mode mode,
+    2: optional string note,
+}
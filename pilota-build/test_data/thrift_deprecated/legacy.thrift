@@ -0,0 +1,15 @@
+enum Format {
+    JSON = 1,
+    XML = 2 (deprecated = "true"),
+}
+
+struct Document {
+    1: required string body,
+    2: optional string title (deprecated = "use `body` instead"),
+    3: optional Format format,
+}
+
+service DocumentService {
+    Document get(1: string id),
+    Document fetch(1: string id) (deprecated = "use `get` instead"),
+}
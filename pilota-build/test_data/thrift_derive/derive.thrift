@@ -0,0 +1,13 @@
+struct Point {
+    1: required i32 x,
+    2: required i32 y,
+}
+
+struct Label {
+    1: required string text,
+} (derive = "Default, ::serde::Serialize")
+
+enum Color {
+    RED = 1,
+    GREEN = 2,
+}
@@ -0,0 +1,12 @@
+struct User {
+    1: required i64 id,
+    2: optional string name,
+    3: optional string type,
+    5: optional list<string> tags,
+}
+
+const User ANONYMOUS = {"id": 0, "name": "anonymous"}
+
+service UserService {
+    User get(1: i64 id),
+}
@@ -0,0 +1,28 @@
+typedef i64 UserId
+
+const string GREETING = "hello"
+const list<i32> PRIMES = [2, 3, 5]
+
+enum Status {
+    ACTIVE = 1,
+    BANNED = 2,
+}
+
+struct User {
+    1: required UserId id,
+    2: optional string name,
+    3: required Status status = Status.ACTIVE,
+}
+
+union Contact {
+    1: string email,
+    2: i64 phone,
+}
+
+exception NotFound {
+    1: required string message,
+}
+
+service UserService {
+    User get(1: UserId id) throws (1: NotFound not_found),
+}
@@ -0,0 +1,11 @@
+struct ProfileV1 {
+    1: required string name,
+}
+
+struct Profile {
+    1: required string name,
+    2: optional list<string> tags,
+    3: optional set<i32> ids,
+    4: optional map<string, string> attrs,
+    5: optional string nickname,
+}
@@ -0,0 +1,10 @@
+enum Level {
+    DEBUG = 1,
+    INFO = 2,
+    WARN_LEVEL = 3,
+}
+
+struct Record {
+    1: required Level level,
+    2: required string message,
+}
@@ -0,0 +1,18 @@
+exception NotFound {
+    1: required string key,
+}
+
+exception Denied {
+    1: required string reason,
+}
+
+struct Item {
+    1: required string key,
+    2: required string value,
+}
+
+service Store {
+    Item get(1: string key) throws (1: NotFound not_found, 2: Denied denied),
+    void remove(1: string key) throws (1: NotFound not_found),
+    void move(1: string from, 2: string to) throws (1: NotFound from_not_found, 2: NotFound to_not_found, 3: Denied denied),
+}
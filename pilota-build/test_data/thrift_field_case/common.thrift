@@ -0,0 +1,9 @@
+struct Point {
+    1: required i32 xPos,
+    2: required i32 y_pos,
+}
+
+enum Color {
+    DARK_RED = 0,
+    lightBlue = 1,
+}
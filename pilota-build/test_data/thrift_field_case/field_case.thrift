@@ -0,0 +1,18 @@
+include "common.thrift"
+
+const common.Point ORIGIN = {"xPos": 0, "y_pos": 0}
+
+struct Shape {
+    1: required common.Point topLeft,
+    2: optional common.Color fill_color = common.Color.lightBlue,
+    3: optional string type,
+}
+
+union Value {
+    1: i32 intValue,
+    2: string str_value,
+}
+
+service ShapeService {
+    Shape move(1: Shape shape, 2: i32 deltaX, 3: i32 delta_y),
+}
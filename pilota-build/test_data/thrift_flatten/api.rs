@@ -0,0 +1,676 @@
+pub mod api {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    pub struct Account {
+        pub name: ::std::string::String,
+        pub status: Status,
+        pub balance: ::std::option::Option<Money>,
+    }
+    #[::async_trait::async_trait]
+    impl ::pilota::thrift::Message for Account {
+        fn encode<T: ::pilota::thrift::TOutputProtocol>(
+            &self,
+            protocol: &mut T,
+        ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+            let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Account" };
+            protocol.write_struct_begin(&struct_ident)?;
+            {
+                let value = &self.name;
+                let field = ::pilota::thrift::TFieldIdentifier {
+                    name: Some("name"),
+                    field_type: ::pilota::thrift::TType::String,
+                    id: Some(1i16),
+                };
+                protocol.write_field_begin(&field)?;
+                protocol.write_string(value)?;
+                protocol.write_field_end()?;
+            }
+            {
+                let value = &self.status;
+                let field = ::pilota::thrift::TFieldIdentifier {
+                    name: Some("status"),
+                    field_type: ::pilota::thrift::TType::I32,
+                    id: Some(2i16),
+                };
+                protocol.write_field_begin(&field)?;
+                ::pilota::thrift::Message::encode(value, protocol)?;
+                protocol.write_field_end()?;
+            }
+            if let Some(value) = self.balance.as_ref() {
+                let field = ::pilota::thrift::TFieldIdentifier {
+                    name: Some("balance"),
+                    field_type: ::pilota::thrift::TType::Struct,
+                    id: Some(3i16),
+                };
+                protocol.write_field_begin(&field)?;
+                ::pilota::thrift::Message::encode(value, protocol)?;
+                protocol.write_field_end()?;
+            };
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()?;
+            Ok(())
+        }
+        fn decode<T: ::pilota::thrift::TInputProtocol>(
+            protocol: &mut T,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut name = None;
+            let mut status = None;
+            let mut balance = None;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::String {
+                            name = Some(protocol.read_string()?);
+                        } else {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    Some(2i16) => {
+                        if ttype == ::pilota::thrift::TType::I32 {
+                            status = Some(::pilota::thrift::Message::decode(protocol)?);
+                        } else {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    Some(3i16) => {
+                        if ttype == ::pilota::thrift::TType::Struct {
+                            balance = Some(::pilota::thrift::Message::decode(protocol)?);
+                        } else {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            let name = if let Some(name) = name {
+                name
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field name is required".to_string(),
+                    ),
+                ));
+            };
+            let status = if let Some(status) = status {
+                status
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field status is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self {
+                balance,
+                name,
+                status,
+            };
+            Ok(data)
+        }
+        async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+            protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut name = None;
+            let mut status = None;
+            let mut balance = None;
+            protocol.read_struct_begin().await?;
+            loop {
+                let field_ident = protocol.read_field_begin().await?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::String {
+                            name = Some(protocol.read_string().await?);
+                        } else {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    Some(2i16) => {
+                        if ttype == ::pilota::thrift::TType::I32 {
+                            status = Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                        } else {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    Some(3i16) => {
+                        if ttype == ::pilota::thrift::TType::Struct {
+                            balance =
+                                Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                        } else {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype).await?;
+                    }
+                }
+                protocol.read_field_end().await?;
+            }
+            protocol.read_struct_end().await?;
+            let name = if let Some(name) = name {
+                name
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field name is required".to_string(),
+                    ),
+                ));
+            };
+            let status = if let Some(status) = status {
+                status
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field status is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self {
+                balance,
+                name,
+                status,
+            };
+            Ok(data)
+        }
+    }
+    impl ::pilota::thrift::Size for Account {
+        fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+            protocol
+                .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Account" })
+                + {
+                    let value = &self.name;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                }
+                + {
+                    let value = &self.status;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("status"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                }
+                + if let Some(value) = self.balance.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("balance"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(3i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                }
+                + protocol.write_field_stop_len()
+                + protocol.write_struct_end_len()
+        }
+    }
+    #[::async_trait::async_trait]
+    pub trait AccountService {
+        async fn get(
+            &self,
+            name: ::std::string::String,
+        ) -> ::core::result::Result<Account, ::pilota::AnyhowError>;
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+    #[derivative(Default)]
+    #[derive(Clone, PartialEq)]
+    pub enum AccountServiceGetResult {
+        #[derivative(Default)]
+        Ok(Account),
+    }
+    #[::async_trait::async_trait]
+    impl ::pilota::thrift::Message for AccountServiceGetResult {
+        fn encode<T: ::pilota::thrift::TOutputProtocol>(
+            &self,
+            protocol: &mut T,
+        ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+            protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                name: "AccountServiceGetResult",
+            })?;
+            match self {
+                AccountServiceGetResult::Ok(ref value) => {
+                    protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("Ok"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(0i16),
+                    })?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+            }
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()?;
+            Ok(())
+        }
+        fn decode<T: ::pilota::thrift::TInputProtocol>(
+            protocol: &mut T,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut ret = None;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(0i16) => {
+                        if ret.is_none() {
+                            ret = Some(AccountServiceGetResult::Ok(
+                                ::pilota::thrift::Message::decode(protocol)?,
+                            ));
+                        } else {
+                            return Err(::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "received multiple fields for union from remote Message",
+                            ));
+                        }
+                    }
+                    _ => {
+                        protocol.skip(field_ident.field_type)?;
+                    }
+                }
+            }
+            protocol.read_field_end()?;
+            protocol.read_struct_end()?;
+            if let Some(ret) = ret {
+                Ok(ret)
+            } else {
+                Err(::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    "received empty union from remote Message",
+                ))
+            }
+        }
+        async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+            protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut ret = None;
+            protocol.read_struct_begin().await?;
+            loop {
+                let field_ident = protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(0i16) => {
+                        if ret.is_none() {
+                            ret = Some(AccountServiceGetResult::Ok(
+                                ::pilota::thrift::Message::decode_async(protocol).await?,
+                            ));
+                        } else {
+                            return Err(::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "received multiple fields for union from remote Message",
+                            ));
+                        }
+                    }
+                    _ => {
+                        protocol.skip(field_ident.field_type).await?;
+                    }
+                }
+            }
+            protocol.read_field_end().await?;
+            protocol.read_struct_end().await?;
+            if let Some(ret) = ret {
+                Ok(ret)
+            } else {
+                Err(::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    "received empty union from remote Message",
+                ))
+            }
+        }
+    }
+    impl ::pilota::thrift::Size for AccountServiceGetResult {
+        fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+            protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                name: "AccountServiceGetResult",
+            }) + match self {
+                AccountServiceGetResult::Ok(ref value) => {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("Ok"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(0i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                }
+            } + protocol.write_field_stop_len()
+                + protocol.write_struct_end_len()
+        }
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    pub struct AccountServiceGetArgs {
+        pub name: ::std::string::String,
+    }
+    #[::async_trait::async_trait]
+    impl ::pilota::thrift::Message for AccountServiceGetArgs {
+        fn encode<T: ::pilota::thrift::TOutputProtocol>(
+            &self,
+            protocol: &mut T,
+        ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+            let struct_ident = ::pilota::thrift::TStructIdentifier {
+                name: "AccountServiceGetArgs",
+            };
+            protocol.write_struct_begin(&struct_ident)?;
+            {
+                let value = &self.name;
+                let field = ::pilota::thrift::TFieldIdentifier {
+                    name: Some("name"),
+                    field_type: ::pilota::thrift::TType::String,
+                    id: Some(1i16),
+                };
+                protocol.write_field_begin(&field)?;
+                protocol.write_string(value)?;
+                protocol.write_field_end()?;
+            }
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()?;
+            Ok(())
+        }
+        fn decode<T: ::pilota::thrift::TInputProtocol>(
+            protocol: &mut T,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut name = None;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::String {
+                            name = Some(protocol.read_string()?);
+                        } else {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            let name = if let Some(name) = name {
+                name
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field name is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self { name };
+            Ok(data)
+        }
+        async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+            protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut name = None;
+            protocol.read_struct_begin().await?;
+            loop {
+                let field_ident = protocol.read_field_begin().await?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::String {
+                            name = Some(protocol.read_string().await?);
+                        } else {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype).await?;
+                    }
+                }
+                protocol.read_field_end().await?;
+            }
+            protocol.read_struct_end().await?;
+            let name = if let Some(name) = name {
+                name
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field name is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self { name };
+            Ok(data)
+        }
+    }
+    impl ::pilota::thrift::Size for AccountServiceGetArgs {
+        fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+            protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                name: "AccountServiceGetArgs",
+            }) + {
+                let value = &self.name;
+                protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                    name: Some("name"),
+                    field_type: ::pilota::thrift::TType::String,
+                    id: Some(1i16),
+                }) + protocol.write_string_len(&value)
+                    + protocol.write_field_end_len()
+            } + protocol.write_field_stop_len()
+                + protocol.write_struct_end_len()
+        }
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+    #[derivative(Default)]
+    #[derive(
+        :: pilota :: num_enum :: IntoPrimitive,
+        :: pilota :: num_enum :: TryFromPrimitive,
+        Clone,
+        PartialEq,
+    )]
+    #[repr(i32)]
+    #[derive(Copy)]
+    pub enum Status {
+        #[derivative(Default)]
+        Active = 1i32,
+        Banned = 2i32,
+    }
+    #[::async_trait::async_trait]
+    impl ::pilota::thrift::Message for Status {
+        fn encode<T: ::pilota::thrift::TOutputProtocol>(
+            &self,
+            protocol: &mut T,
+        ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+            protocol.write_i32(*self as i32)?;
+            Ok(())
+        }
+        fn decode<T: ::pilota::thrift::TInputProtocol>(
+            protocol: &mut T,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let value = protocol.read_i32()?;
+            Ok(Self::try_from(value).map_err(|err| {
+                ::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    format!("invalid enum value for Status, value: {}", value),
+                )
+            })?)
+        }
+        async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+            protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let value = protocol.read_i32().await?;
+            Ok(Self::try_from(value).map_err(|err| {
+                ::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    format!("invalid enum value for Status, value: {}", value),
+                )
+            })?)
+        }
+    }
+    impl ::pilota::thrift::Size for Status {
+        fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+            protocol.write_i32_len(*self as i32)
+        }
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    pub struct Money {
+        pub cents: i64,
+    }
+    #[::async_trait::async_trait]
+    impl ::pilota::thrift::Message for Money {
+        fn encode<T: ::pilota::thrift::TOutputProtocol>(
+            &self,
+            protocol: &mut T,
+        ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+            let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Money" };
+            protocol.write_struct_begin(&struct_ident)?;
+            {
+                let value = &self.cents;
+                let field = ::pilota::thrift::TFieldIdentifier {
+                    name: Some("cents"),
+                    field_type: ::pilota::thrift::TType::I64,
+                    id: Some(1i16),
+                };
+                protocol.write_field_begin(&field)?;
+                protocol.write_i64(*value)?;
+                protocol.write_field_end()?;
+            }
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()?;
+            Ok(())
+        }
+        fn decode<T: ::pilota::thrift::TInputProtocol>(
+            protocol: &mut T,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut cents = None;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::I64 {
+                            cents = Some(protocol.read_i64()?);
+                        } else {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            let cents = if let Some(cents) = cents {
+                cents
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field cents is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self { cents };
+            Ok(data)
+        }
+        async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+            protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+        ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+            let mut cents = None;
+            protocol.read_struct_begin().await?;
+            loop {
+                let field_ident = protocol.read_field_begin().await?;
+                let ttype = field_ident.field_type;
+                if ttype == ::pilota::thrift::TType::Stop {
+                    break;
+                }
+                let field_id = field_ident.id;
+                match field_id {
+                    Some(1i16) => {
+                        if ttype == ::pilota::thrift::TType::I64 {
+                            cents = Some(protocol.read_i64().await?);
+                        } else {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    _ => {
+                        protocol.skip(ttype).await?;
+                    }
+                }
+                protocol.read_field_end().await?;
+            }
+            protocol.read_struct_end().await?;
+            let cents = if let Some(cents) = cents {
+                cents
+            } else {
+                return Err(::pilota::thrift::Error::Protocol(
+                    ::pilota::thrift::ProtocolError::new(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field cents is required".to_string(),
+                    ),
+                ));
+            };
+            let data = Self { cents };
+            Ok(data)
+        }
+    }
+    impl ::pilota::thrift::Size for Money {
+        fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+            protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Money" })
+                + {
+                    let value = &self.cents;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("cents"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(1i16),
+                    }) + protocol.write_i64_len(*value)
+                        + protocol.write_field_end_len()
+                }
+                + protocol.write_field_stop_len()
+                + protocol.write_struct_end_len()
+        }
+    }
+}
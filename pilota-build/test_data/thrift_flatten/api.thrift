@@ -0,0 +1,13 @@
+namespace rs api
+
+include "types.thrift"
+
+struct Account {
+    1: required string name,
+    2: required types.Status status,
+    3: optional types.Money balance,
+}
+
+service AccountService {
+    Account get(1: string name),
+}
@@ -0,0 +1,10 @@
+namespace rs api
+
+enum Status {
+    ACTIVE = 1,
+    BANNED = 2,
+}
+
+struct Money {
+    1: required i64 cents,
+}
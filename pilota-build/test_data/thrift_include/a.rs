@@ -0,0 +1,688 @@
+pub mod a {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod bar {
+        pub mod b {
+            pub const DEFAULT_SIZE: i32 = 16i32;
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+            #[derivative(Default)]
+            #[derive(
+                :: pilota :: num_enum :: IntoPrimitive,
+                :: pilota :: num_enum :: TryFromPrimitive,
+                Clone,
+                PartialEq,
+            )]
+            #[repr(i32)]
+            #[derive(Copy)]
+            pub enum Kind {
+                #[derivative(Default)]
+                X = 1i32,
+                Y = 2i32,
+            }
+            #[::async_trait::async_trait]
+            impl ::pilota::thrift::Message for Kind {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    protocol.write_i32(*self as i32)?;
+                    Ok(())
+                }
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let value = protocol.read_i32()?;
+                    Ok(Self::try_from(value).map_err(|err| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            format!("invalid enum value for Kind, value: {}", value),
+                        )
+                    })?)
+                }
+                async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                    protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let value = protocol.read_i32().await?;
+                    Ok(Self::try_from(value).map_err(|err| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            format!("invalid enum value for Kind, value: {}", value),
+                        )
+                    })?)
+                }
+            }
+            impl ::pilota::thrift::Size for Kind {
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                    protocol.write_i32_len(*self as i32)
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct B {
+                pub kind: ::std::option::Option<Kind>,
+            }
+            #[::async_trait::async_trait]
+            impl ::pilota::thrift::Message for B {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
+                    protocol.write_struct_begin(&struct_ident)?;
+                    if let Some(value) = self.kind.as_ref() {
+                        let field = ::pilota::thrift::TFieldIdentifier {
+                            name: Some("kind"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        };
+                        protocol.write_field_begin(&field)?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    };
+                    protocol.write_field_stop()?;
+                    protocol.write_struct_end()?;
+                    Ok(())
+                }
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut kind = None;
+                    protocol.read_struct_begin()?;
+                    loop {
+                        let field_ident = protocol.read_field_begin()?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::I32 {
+                                    kind = Some(::pilota::thrift::Message::decode(protocol)?);
+                                } else {
+                                    protocol.skip(ttype)?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        protocol.read_field_end()?;
+                    }
+                    protocol.read_struct_end()?;
+                    let data = Self { kind };
+                    Ok(data)
+                }
+                async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                    protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut kind = None;
+                    protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = protocol.read_field_begin().await?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::I32 {
+                                    kind = Some(
+                                        ::pilota::thrift::Message::decode_async(protocol).await?,
+                                    );
+                                } else {
+                                    protocol.skip(ttype).await?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        protocol.read_field_end().await?;
+                    }
+                    protocol.read_struct_end().await?;
+                    let data = Self { kind };
+                    Ok(data)
+                }
+            }
+            impl ::pilota::thrift::Size for B {
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                    protocol
+                        .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
+                        + if let Some(value) = self.kind.as_ref() {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("kind"),
+                                field_type: ::pilota::thrift::TType::I32,
+                                id: Some(1i16),
+                            }) + ::pilota::thrift::Size::size(value, protocol)
+                                + protocol.write_field_end_len()
+                        } else {
+                            0
+                        }
+                        + protocol.write_field_stop_len()
+                        + protocol.write_struct_end_len()
+                }
+            }
+        }
+    }
+    pub mod foo {
+        pub mod a {
+            pub use super::super::bar::b;
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+            pub struct A {
+                pub b: b::B,
+                pub kinds: ::std::option::Option<::std::vec::Vec<b::Kind>>,
+                pub size: ::std::option::Option<i32>,
+            }
+            #[::async_trait::async_trait]
+            impl ::pilota::thrift::Message for A {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
+                    protocol.write_struct_begin(&struct_ident)?;
+                    {
+                        let value = &self.b;
+                        let field = ::pilota::thrift::TFieldIdentifier {
+                            name: Some("b"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        };
+                        protocol.write_field_begin(&field)?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    if let Some(value) = self.kinds.as_ref() {
+                        let field = ::pilota::thrift::TFieldIdentifier {
+                            name: Some("kinds"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(2i16),
+                        };
+                        protocol.write_field_begin(&field)?;
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin(&list_ident)?;
+                        for val in value {
+                            ::pilota::thrift::Message::encode(val, protocol)?;
+                        }
+                        protocol.write_list_end()?;
+                        protocol.write_field_end()?;
+                    };
+                    if let Some(value) = self.size.as_ref() {
+                        let field = ::pilota::thrift::TFieldIdentifier {
+                            name: Some("size"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(3i16),
+                        };
+                        protocol.write_field_begin(&field)?;
+                        protocol.write_i32(*value)?;
+                        protocol.write_field_end()?;
+                    };
+                    protocol.write_field_stop()?;
+                    protocol.write_struct_end()?;
+                    Ok(())
+                }
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut b = None;
+                    let mut kinds = None;
+                    let mut size = None;
+                    protocol.read_struct_begin()?;
+                    loop {
+                        let field_ident = protocol.read_field_begin()?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::Struct {
+                                    b = Some(::pilota::thrift::Message::decode(protocol)?);
+                                } else {
+                                    protocol.skip(ttype)?;
+                                }
+                            }
+                            Some(2i16) => {
+                                if ttype == ::pilota::thrift::TType::List {
+                                    kinds = Some({
+                                        let list_ident = protocol.read_list_begin()?;
+                                        let mut val = Vec::with_capacity(list_ident.size);
+                                        for _ in 0..list_ident.size {
+                                            val.push(::pilota::thrift::Message::decode(protocol)?);
+                                        }
+                                        protocol.read_list_end()?;
+                                        val
+                                    });
+                                } else {
+                                    protocol.skip(ttype)?;
+                                }
+                            }
+                            Some(3i16) => {
+                                if ttype == ::pilota::thrift::TType::I32 {
+                                    size = Some(protocol.read_i32()?);
+                                } else {
+                                    protocol.skip(ttype)?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        protocol.read_field_end()?;
+                    }
+                    protocol.read_struct_end()?;
+                    let b = if let Some(b) = b {
+                        b
+                    } else {
+                        return Err(::pilota::thrift::Error::Protocol(
+                            ::pilota::thrift::ProtocolError::new(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "field b is required".to_string(),
+                            ),
+                        ));
+                    };
+                    let data = Self { kinds, size, b };
+                    Ok(data)
+                }
+                async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                    protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut b = None;
+                    let mut kinds = None;
+                    let mut size = None;
+                    protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = protocol.read_field_begin().await?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::Struct {
+                                    b = Some(
+                                        ::pilota::thrift::Message::decode_async(protocol).await?,
+                                    );
+                                } else {
+                                    protocol.skip(ttype).await?;
+                                }
+                            }
+                            Some(2i16) => {
+                                if ttype == ::pilota::thrift::TType::List {
+                                    kinds = Some({
+                                        let list_ident = protocol.read_list_begin().await?;
+                                        let mut val = Vec::with_capacity(list_ident.size);
+                                        for _ in 0..list_ident.size {
+                                            val.push(
+                                                ::pilota::thrift::Message::decode_async(protocol)
+                                                    .await?,
+                                            );
+                                        }
+                                        protocol.read_list_end().await?;
+                                        val
+                                    });
+                                } else {
+                                    protocol.skip(ttype).await?;
+                                }
+                            }
+                            Some(3i16) => {
+                                if ttype == ::pilota::thrift::TType::I32 {
+                                    size = Some(protocol.read_i32().await?);
+                                } else {
+                                    protocol.skip(ttype).await?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        protocol.read_field_end().await?;
+                    }
+                    protocol.read_struct_end().await?;
+                    let b = if let Some(b) = b {
+                        b
+                    } else {
+                        return Err(::pilota::thrift::Error::Protocol(
+                            ::pilota::thrift::ProtocolError::new(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "field b is required".to_string(),
+                            ),
+                        ));
+                    };
+                    let data = Self { kinds, size, b };
+                    Ok(data)
+                }
+            }
+            impl ::pilota::thrift::Size for A {
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                    protocol
+                        .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
+                        + {
+                            let value = &self.b;
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("b"),
+                                field_type: ::pilota::thrift::TType::Struct,
+                                id: Some(1i16),
+                            }) + ::pilota::thrift::Size::size(value, protocol)
+                                + protocol.write_field_end_len()
+                        }
+                        + if let Some(value) = self.kinds.as_ref() {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("kinds"),
+                                field_type: ::pilota::thrift::TType::List,
+                                id: Some(2i16),
+                            }) + {
+                                let list_ident = ::pilota::thrift::TListIdentifier {
+                                    element_type: ::pilota::thrift::TType::I32,
+                                    size: value.len(),
+                                };
+                                protocol.write_list_begin_len(&list_ident)
+                                    + {
+                                        let mut size = 0;
+                                        for el in value {
+                                            size += ::pilota::thrift::Size::size(el, protocol);
+                                        }
+                                        size
+                                    }
+                                    + protocol.write_list_end_len()
+                            } + protocol.write_field_end_len()
+                        } else {
+                            0
+                        }
+                        + if let Some(value) = self.size.as_ref() {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("size"),
+                                field_type: ::pilota::thrift::TType::I32,
+                                id: Some(3i16),
+                            }) + protocol.write_i32_len(*value)
+                                + protocol.write_field_end_len()
+                        } else {
+                            0
+                        }
+                        + protocol.write_field_stop_len()
+                        + protocol.write_struct_end_len()
+                }
+            }
+            impl ::std::default::Default for A {
+                fn default() -> Self {
+                    A {
+                        b: ::std::default::Default::default(),
+                        kinds: None,
+                        size: Some(b::DEFAULT_SIZE),
+                    }
+                }
+            }
+            #[::async_trait::async_trait]
+            pub trait S {
+                async fn get(&self, req: A) -> ::core::result::Result<b::B, ::pilota::AnyhowError>;
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+            #[derivative(Default)]
+            #[derive(Clone, PartialEq)]
+            pub enum SGetResult {
+                #[derivative(Default)]
+                Ok(b::B),
+            }
+            #[::async_trait::async_trait]
+            impl ::pilota::thrift::Message for SGetResult {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                        name: "SGetResult",
+                    })?;
+                    match self {
+                        SGetResult::Ok(ref value) => {
+                            protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("Ok"),
+                                field_type: ::pilota::thrift::TType::Struct,
+                                id: Some(0i16),
+                            })?;
+                            ::pilota::thrift::Message::encode(value, protocol)?;
+                            protocol.write_field_end()?;
+                        }
+                    }
+                    protocol.write_field_stop()?;
+                    protocol.write_struct_end()?;
+                    Ok(())
+                }
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut ret = None;
+                    protocol.read_struct_begin()?;
+                    loop {
+                        let field_ident = protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(0i16) => {
+                                if ret.is_none() {
+                                    ret = Some(SGetResult::Ok(::pilota::thrift::Message::decode(
+                                        protocol,
+                                    )?));
+                                } else {
+                                    return Err(::pilota::thrift::new_protocol_error(
+                                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ));
+                                }
+                            }
+                            _ => {
+                                protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                    }
+                    protocol.read_field_end()?;
+                    protocol.read_struct_end()?;
+                    if let Some(ret) = ret {
+                        Ok(ret)
+                    } else {
+                        Err(::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                }
+                async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                    protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut ret = None;
+                    protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(0i16) => {
+                                if ret.is_none() {
+                                    ret = Some(SGetResult::Ok(
+                                        ::pilota::thrift::Message::decode_async(protocol).await?,
+                                    ));
+                                } else {
+                                    return Err(::pilota::thrift::new_protocol_error(
+                                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ));
+                                }
+                            }
+                            _ => {
+                                protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                    protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        Ok(ret)
+                    } else {
+                        Err(::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                }
+            }
+            impl ::pilota::thrift::Size for SGetResult {
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                    protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                        name: "SGetResult",
+                    }) + match self {
+                        SGetResult::Ok(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("Ok"),
+                                field_type: ::pilota::thrift::TType::Struct,
+                                id: Some(0i16),
+                            }) + ::pilota::thrift::Size::size(value, protocol)
+                                + protocol.write_field_end_len()
+                        }
+                    } + protocol.write_field_stop_len()
+                        + protocol.write_struct_end_len()
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct SGetArgs {
+                pub req: A,
+            }
+            #[::async_trait::async_trait]
+            impl ::pilota::thrift::Message for SGetArgs {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "SGetArgs" };
+                    protocol.write_struct_begin(&struct_ident)?;
+                    {
+                        let value = &self.req;
+                        let field = ::pilota::thrift::TFieldIdentifier {
+                            name: Some("req"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        };
+                        protocol.write_field_begin(&field)?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    protocol.write_field_stop()?;
+                    protocol.write_struct_end()?;
+                    Ok(())
+                }
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut req = None;
+                    protocol.read_struct_begin()?;
+                    loop {
+                        let field_ident = protocol.read_field_begin()?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::Struct {
+                                    req = Some(::pilota::thrift::Message::decode(protocol)?);
+                                } else {
+                                    protocol.skip(ttype)?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        protocol.read_field_end()?;
+                    }
+                    protocol.read_struct_end()?;
+                    let req = if let Some(req) = req {
+                        req
+                    } else {
+                        return Err(::pilota::thrift::Error::Protocol(
+                            ::pilota::thrift::ProtocolError::new(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        ));
+                    };
+                    let data = Self { req };
+                    Ok(data)
+                }
+                async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                    protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                    let mut req = None;
+                    protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = protocol.read_field_begin().await?;
+                        let ttype = field_ident.field_type;
+                        if ttype == ::pilota::thrift::TType::Stop {
+                            break;
+                        }
+                        let field_id = field_ident.id;
+                        match field_id {
+                            Some(1i16) => {
+                                if ttype == ::pilota::thrift::TType::Struct {
+                                    req = Some(
+                                        ::pilota::thrift::Message::decode_async(protocol).await?,
+                                    );
+                                } else {
+                                    protocol.skip(ttype).await?;
+                                }
+                            }
+                            _ => {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        protocol.read_field_end().await?;
+                    }
+                    protocol.read_struct_end().await?;
+                    let req = if let Some(req) = req {
+                        req
+                    } else {
+                        return Err(::pilota::thrift::Error::Protocol(
+                            ::pilota::thrift::ProtocolError::new(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        ));
+                    };
+                    let data = Self { req };
+                    Ok(data)
+                }
+            }
+            impl ::pilota::thrift::Size for SGetArgs {
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                    protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                        name: "SGetArgs",
+                    }) + {
+                        let value = &self.req;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("req"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } + protocol.write_field_stop_len()
+                        + protocol.write_struct_end_len()
+                }
+            }
+        }
+    }
+}
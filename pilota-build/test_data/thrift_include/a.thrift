@@ -0,0 +1,13 @@
+include "b.thrift"
+
+namespace rs foo.a
+
+struct A {
+    1: required b.B b,
+    2: optional list<b.Kind> kinds,
+    3: optional i32 size = b.DEFAULT_SIZE,
+}
+
+service S {
+    b.B get(1: A req),
+}
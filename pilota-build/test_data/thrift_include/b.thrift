@@ -0,0 +1,12 @@
+namespace rs bar.b
+
+const i32 DEFAULT_SIZE = 16
+
+enum Kind {
+    X = 1,
+    Y = 2,
+}
+
+struct B {
+    1: optional Kind kind,
+}
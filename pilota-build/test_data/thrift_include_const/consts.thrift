@@ -0,0 +1,8 @@
+enum Kind {
+    X = 1,
+    Y = 2,
+}
+
+const Kind DEFAULT_KIND = Kind.Y
+const i32 BASE = 10
+const list<Kind> KINDS = [Kind.X, DEFAULT_KIND]
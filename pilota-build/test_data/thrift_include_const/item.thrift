@@ -0,0 +1,10 @@
+include "consts.thrift"
+
+const consts.Kind KIND = consts.DEFAULT_KIND
+
+struct Item {
+    1: required consts.Kind kind = consts.DEFAULT_KIND,
+    2: required consts.Kind fallback = consts.Kind.X,
+    3: optional i32 size = consts.BASE,
+    4: optional list<consts.Kind> kinds = consts.KINDS,
+}
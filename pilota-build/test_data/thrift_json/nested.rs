@@ -0,0 +1,1164 @@
+pub mod nested {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod nested {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Timestamp(i64);
+        impl ::std::ops::Deref for Timestamp {
+            type Target = i64;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl From<i64> for Timestamp {
+            fn from(v: i64) -> Self {
+                Self(v)
+            }
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Timestamp {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let value = &**self;
+                protocol.write_i64(*value)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(Timestamp(protocol.read_i64()?))
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(Timestamp(protocol.read_i64().await?))
+            }
+        }
+        impl ::pilota::thrift::Size for Timestamp {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                {
+                    let value = &**self;
+                    protocol.write_i64_len(*value)
+                }
+            }
+        }
+        impl Timestamp {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Timestamp {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                ::serde_json::Value::from(*(&self.0))
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Status {
+            #[derivative(Default)]
+            Active = 1i32,
+            Disabled = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Status {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Status, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Status, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Status {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        impl Status {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Status {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                match self {
+                    Self::Active => ::serde_json::Value::String("ACTIVE".to_string()),
+                    Self::Disabled => ::serde_json::Value::String("DISABLED".to_string()),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Address {
+            pub city: ::std::string::String,
+            pub zip_code: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Address {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Address" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.city;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.zip_code.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("zip_code"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut zip_code = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                zip_code = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { zip_code, city };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut city = None;
+                let mut zip_code = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                city = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                zip_code = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let city = if let Some(city) = city {
+                    city
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field city is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { zip_code, city };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Address {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Address",
+                }) + {
+                    let value = &self.city;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("city"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.zip_code.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("zip_code"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(2i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Address {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Address {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                let mut map = ::serde_json::Map::new();
+                map.insert(
+                    "city".to_string(),
+                    ::serde_json::Value::String((&self.city).to_string()),
+                );
+                if let Some(v) = &self.zip_code {
+                    map.insert(
+                        "zipCode".to_string(),
+                        ::serde_json::Value::String(v.to_string()),
+                    );
+                }
+                ::serde_json::Value::Object(map)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum Contact {
+            #[derivative(Default)]
+            Email(::std::string::String),
+            Address(Address),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Contact {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Contact" })?;
+                match self {
+                    Contact::Email(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("email"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        })?;
+                        protocol.write_string(value)?;
+                        protocol.write_field_end()?;
+                    }
+                    Contact::Address(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("address"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Email(protocol.read_string()?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Address(::pilota::thrift::Message::decode(
+                                    protocol,
+                                )?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Email(protocol.read_string().await?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Contact::Address(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Contact {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Contact",
+                }) + match self {
+                    Contact::Email(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("email"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    Contact::Address(ref value) => {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("address"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Contact {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Contact {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                match self {
+                    Self::Email(v) => {
+                        let mut map = ::serde_json::Map::new();
+                        map.insert(
+                            "email".to_string(),
+                            ::serde_json::Value::String(v.to_string()),
+                        );
+                        ::serde_json::Value::Object(map)
+                    }
+                    Self::Address(v) => {
+                        let mut map = ::serde_json::Map::new();
+                        map.insert("address".to_string(), v.to_json_value());
+                        ::serde_json::Value::Object(map)
+                    }
+                }
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Person {
+            pub name: ::std::string::String,
+            pub status: Status,
+            pub addresses: ::std::vec::Vec<Address>,
+            pub labels: ::std::collections::HashMap<
+                i32,
+                ::std::collections::HashSet<::std::string::String>,
+            >,
+            pub contact: ::std::option::Option<Contact>,
+            pub avatar: ::std::option::Option<::std::vec::Vec<u8>>,
+            pub created_at: ::std::option::Option<Timestamp>,
+            pub office: ::std::option::Option<Address>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Person {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Person" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.status;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("status"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.addresses;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("addresses"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.labels;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("labels"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::I32,
+                        value_type: ::pilota::thrift::TType::Set,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_i32(*key)?;
+                        let list_ident = ::pilota::thrift::TSetIdentifier {
+                            element_type: ::pilota::thrift::TType::String,
+                            size: val.len(),
+                        };
+                        protocol.write_set_begin(&list_ident)?;
+                        for val in val {
+                            protocol.write_string(val)?;
+                        }
+                        protocol.write_set_end()?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.contact.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("contact"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.avatar.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("avatar"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bytes(&value)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.created_at.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("created_at"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                if let Some(value) = self.office.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("office"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut status = None;
+                let mut addresses = None;
+                let mut labels = None;
+                let mut contact = None;
+                let mut avatar = None;
+                let mut created_at = None;
+                let mut office = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                status = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                labels = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32()?;
+                                        let el_val = {
+                                            let list_ident = protocol.read_set_begin()?;
+                                            let mut val =
+                                                ::std::collections::HashSet::with_capacity(
+                                                    list_ident.size,
+                                                );
+                                            for _ in 0..list_ident.size {
+                                                val.insert(protocol.read_string()?);
+                                            }
+                                            protocol.read_set_end()?;
+                                            val
+                                        };
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                contact = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                avatar = Some(protocol.read_bytes()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                created_at = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                office = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let status = if let Some(status) = status {
+                    status
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field status is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    contact,
+                    avatar,
+                    created_at,
+                    office,
+                    name,
+                    status,
+                    addresses,
+                    labels,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut name = None;
+                let mut status = None;
+                let mut addresses = None;
+                let mut labels = None;
+                let mut contact = None;
+                let mut avatar = None;
+                let mut created_at = None;
+                let mut office = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                status =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                addresses = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                labels = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32().await?;
+                                        let el_val = {
+                                            let list_ident = protocol.read_set_begin().await?;
+                                            let mut val =
+                                                ::std::collections::HashSet::with_capacity(
+                                                    list_ident.size,
+                                                );
+                                            for _ in 0..list_ident.size {
+                                                val.insert(protocol.read_string().await?);
+                                            }
+                                            protocol.read_set_end().await?;
+                                            val
+                                        };
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                contact =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                avatar = Some(protocol.read_bytes().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                created_at =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                office =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let status = if let Some(status) = status {
+                    status
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field status is required".to_string(),
+                        ),
+                    ));
+                };
+                let addresses = if let Some(addresses) = addresses {
+                    addresses
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field addresses is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    contact,
+                    avatar,
+                    created_at,
+                    office,
+                    name,
+                    status,
+                    addresses,
+                    labels,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Person {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Person" })
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(1i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.status;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("status"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.addresses;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("addresses"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(3i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += ::pilota::thrift::Size::size(el, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.labels;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("labels"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(4i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::I32,
+                                value_type: ::pilota::thrift::TType::Set,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_i32_len(*key);
+                                        size += {
+                                            let set_id = ::pilota::thrift::TSetIdentifier {
+                                                element_type: ::pilota::thrift::TType::String,
+                                                size: val.len(),
+                                            };
+                                            protocol.write_set_begin_len(&set_id)
+                                                + {
+                                                    let mut size = 0;
+                                                    for el in val {
+                                                        size += protocol.write_string_len(&el);
+                                                    }
+                                                    size
+                                                }
+                                                + protocol.write_set_end_len()
+                                        };
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.contact.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("contact"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(5i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.avatar.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("avatar"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(6i16),
+                        }) + protocol.write_bytes_len(value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.created_at.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("created_at"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(7i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + if let Some(value) = self.office.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("office"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(8i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Person {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Person {
+            pub fn to_json_value(&self) -> ::serde_json::Value {
+                let mut map = ::serde_json::Map::new();
+                map.insert(
+                    "name".to_string(),
+                    ::serde_json::Value::String((&self.name).to_string()),
+                );
+                map.insert("status".to_string(), (&self.status).to_json_value());
+                map.insert(
+                    "addresses".to_string(),
+                    ::serde_json::Value::Array(
+                        (&self.addresses)
+                            .iter()
+                            .map(|v| v.to_json_value())
+                            .collect(),
+                    ),
+                );
+                map.insert(
+                    "labels".to_string(),
+                    ::serde_json::Value::Object(
+                        (&self.labels)
+                            .iter()
+                            .map(|(k, v)| {
+                                let key = match ::serde_json::Value::from(*k) {
+                                    ::serde_json::Value::String(s) => s,
+                                    k => k.to_string(),
+                                };
+                                (
+                                    key,
+                                    ::serde_json::Value::Array(
+                                        v.iter()
+                                            .map(|v| ::serde_json::Value::String(v.to_string()))
+                                            .collect(),
+                                    ),
+                                )
+                            })
+                            .collect(),
+                    ),
+                );
+                if let Some(v) = &self.contact {
+                    map.insert("contact".to_string(), v.to_json_value());
+                }
+                if let Some(v) = &self.avatar {
+                    map.insert("avatar".to_string(), ::serde_json::Value::from(v.to_vec()));
+                }
+                if let Some(v) = &self.created_at {
+                    map.insert("createdAt".to_string(), v.to_json_value());
+                }
+                if let Some(v) = &self.office {
+                    map.insert("office".to_string(), v.to_json_value());
+                }
+                ::serde_json::Value::Object(map)
+            }
+        }
+    }
+}
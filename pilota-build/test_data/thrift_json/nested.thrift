@@ -0,0 +1,27 @@
+typedef i64 Timestamp
+
+enum Status {
+    ACTIVE = 1,
+    DISABLED = 2,
+}
+
+struct Address {
+    1: required string city,
+    2: optional string zipCode,
+}
+
+union Contact {
+    1: string email,
+    2: Address address,
+}
+
+struct Person {
+    1: required string name,
+    2: required Status status,
+    3: required list<Address> addresses,
+    4: required map<i32, set<string>> labels,
+    5: optional Contact contact,
+    6: optional binary avatar,
+    7: optional Timestamp createdAt,
+    8: optional Address office,
+}
@@ -0,0 +1,16 @@
+enum Status {
+    ACTIVE = 1,
+    SUSPENDED = 2,
+    DELETED = 10,
+}
+
+enum Scope {
+    PUBLIC,
+    PRIVATE,
+}
+
+struct Account {
+    1: required i64 id,
+    2: required Status status = Status.ACTIVE,
+    3: optional list<Status> history,
+}
@@ -0,0 +1,557 @@
+pub mod version {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod version {
+        #[derive(Hash, Eq, Debug, Default, Clone)]
+        pub struct Version {
+            pub patch: i32,
+            pub major: i32,
+            pub minor: i32,
+            pub label: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Version {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Version" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.patch;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("patch"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.major;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("major"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.minor;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("minor"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.label.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("label"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut patch = None;
+                let mut major = None;
+                let mut minor = None;
+                let mut label = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                patch = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                major = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                minor = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                label = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let patch = if let Some(patch) = patch {
+                    patch
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field patch is required".to_string(),
+                        ),
+                    ));
+                };
+                let major = if let Some(major) = major {
+                    major
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field major is required".to_string(),
+                        ),
+                    ));
+                };
+                let minor = if let Some(minor) = minor {
+                    minor
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field minor is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    label,
+                    patch,
+                    major,
+                    minor,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut patch = None;
+                let mut major = None;
+                let mut minor = None;
+                let mut label = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                patch = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                major = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                minor = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                label = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let patch = if let Some(patch) = patch {
+                    patch
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field patch is required".to_string(),
+                        ),
+                    ));
+                };
+                let major = if let Some(major) = major {
+                    major
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field major is required".to_string(),
+                        ),
+                    ));
+                };
+                let minor = if let Some(minor) = minor {
+                    minor
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field minor is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    label,
+                    patch,
+                    major,
+                    minor,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Version {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Version",
+                }) + {
+                    let value = &self.patch;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("patch"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(3i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.major;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("major"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.minor;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("minor"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    }) + protocol.write_i32_len(*value)
+                        + protocol.write_field_end_len()
+                } + if let Some(value) = self.label.as_ref() {
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("label"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(4i16),
+                    }) + protocol.write_string_len(&value)
+                        + protocol.write_field_end_len()
+                } else {
+                    0
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::cmp::PartialEq for Version {
+            fn eq(&self, other: &Self) -> bool {
+                ::std::cmp::Ord::cmp(self, other) == ::std::cmp::Ordering::Equal
+            }
+        }
+        impl ::std::cmp::PartialOrd for Version {
+            fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+            }
+        }
+        impl ::std::cmp::Ord for Version {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                ::std::cmp::Ordering::Equal
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.major, &other.major))
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.minor, &other.minor))
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.patch, &other.patch))
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.label, &other.label))
+            }
+        }
+        #[derive(Debug, Default, Clone)]
+        pub struct Release {
+            pub version: Version,
+            pub notes: ::std::collections::BTreeMap<::std::string::String, i32>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Release {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Release" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.version;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("version"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.notes;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("notes"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut version = None;
+                let mut notes = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                version = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                notes = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i32()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let version = if let Some(version) = version {
+                    version
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field version is required".to_string(),
+                        ),
+                    ));
+                };
+                let notes = if let Some(notes) = notes {
+                    notes
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field notes is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { version, notes };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut version = None;
+                let mut notes = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                version =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                notes = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val = ::std::collections::BTreeMap::new();
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i32().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let version = if let Some(version) = version {
+                    version
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field version is required".to_string(),
+                        ),
+                    ));
+                };
+                let notes = if let Some(notes) = notes {
+                    notes
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field notes is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { version, notes };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Release {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "Release",
+                }) + {
+                    let value = &self.version;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("version"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(2i16),
+                    }) + ::pilota::thrift::Size::size(value, protocol)
+                        + protocol.write_field_end_len()
+                } + {
+                    let value = &self.notes;
+                    protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                        name: Some("notes"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(1i16),
+                    }) + {
+                        let map_id = ::pilota::thrift::TMapIdentifier {
+                            key_type: ::pilota::thrift::TType::String,
+                            value_type: ::pilota::thrift::TType::I32,
+                            size: value.len(),
+                        };
+                        protocol.write_map_begin_len(&map_id)
+                            + {
+                                let mut size = 0;
+                                for (key, val) in value {
+                                    size += protocol.write_string_len(&key);
+                                    size += protocol.write_i32_len(*val);
+                                }
+                                size
+                            }
+                            + protocol.write_map_end_len()
+                    } + protocol.write_field_end_len()
+                } + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl ::std::cmp::PartialEq for Release {
+            fn eq(&self, other: &Self) -> bool {
+                ::std::cmp::Ord::cmp(self, other) == ::std::cmp::Ordering::Equal
+            }
+        }
+        impl ::std::cmp::PartialOrd for Release {
+            fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+            }
+        }
+        impl ::std::cmp::Ord for Release {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                ::std::cmp::Ordering::Equal
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.notes, &other.notes))
+                    .then_with(|| ::std::cmp::Ord::cmp(&self.version, &other.version))
+            }
+        }
+        impl ::std::cmp::Eq for Release {}
+    }
+}
@@ -0,0 +1,11 @@
+struct Version {
+    3: required i32 patch,
+    1: required i32 major,
+    2: required i32 minor,
+    4: optional string label,
+} (ord_by_id = "true")
+
+struct Release {
+    2: required Version version,
+    1: required map<string, i32> notes (rust.collection = "btree"),
+} (ord_by_id = "true")
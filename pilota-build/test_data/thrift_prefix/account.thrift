@@ -0,0 +1,25 @@
+typedef i64 UserId
+
+enum Role {
+    ADMIN = 1,
+    GUEST = 2,
+}
+
+struct User {
+    1: required UserId id,
+    2: required Role role,
+    3: optional list<User> friends,
+}
+
+union Lookup {
+    1: UserId id,
+    2: string name,
+}
+
+exception NotFound {
+    1: required string message,
+}
+
+service AccountService {
+    User get_user(1: Lookup lookup) throws (1: NotFound not_found),
+}
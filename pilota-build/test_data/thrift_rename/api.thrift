@@ -0,0 +1,15 @@
+namespace rs com.example.api.v1
+
+include "types.thrift"
+
+const types.Currency DEFAULT_CURRENCY = types.Currency.EUR
+
+struct Quote {
+    1: required string sku,
+    2: required types.Money price,
+    3: optional list<types.Money> discounts,
+}
+
+service Pricing {
+    Quote quote(1: string sku, 2: types.Currency currency),
+}
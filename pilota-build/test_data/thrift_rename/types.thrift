@@ -0,0 +1,11 @@
+namespace rs com.example.common.types
+
+enum Currency {
+    EUR = 1,
+    USD = 2,
+}
+
+struct Money {
+    1: required i64 units,
+    2: required Currency currency,
+}
@@ -0,0 +1,11 @@
+struct Meta {
+    1: optional string source,
+}
+
+struct Item {
+    1: required string name,
+    2: required i64 price,
+    3: optional string cached_label (internal = "true"),
+    4: required list<string> history (internal = "true"),
+    5: required Meta meta (internal = "true"),
+}
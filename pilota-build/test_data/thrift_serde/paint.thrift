@@ -0,0 +1,16 @@
+enum Color {
+    DARK_RED = 1,
+    Blue = 2,
+}
+
+union Shade {
+    1: string lightName,
+    2: i32 depth,
+}
+
+struct Paint {
+    1: required string colorName,
+    2: required Color color,
+    3: optional list<Color> mixedWith,
+    4: optional Shade shade,
+}
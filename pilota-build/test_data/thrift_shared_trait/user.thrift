@@ -0,0 +1,13 @@
+struct UserV1 {
+    1: required i64 id,
+    2: required string name,
+    3: optional string email,
+} (shared_trait = "UserLike")
+
+struct UserV2 {
+    1: required i64 id,
+    2: required string name,
+    3: optional string email,
+    4: optional i32 age,
+    5: required list<string> roles,
+} (shared_trait = "UserLike")
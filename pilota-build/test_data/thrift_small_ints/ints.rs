@@ -0,0 +1,1114 @@
+pub mod ints {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod ints {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Port(i32);
+        impl ::std::ops::Deref for Port {
+            type Target = i32;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl From<i32> for Port {
+            fn from(v: i32) -> Self {
+                Self(v)
+            }
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Port {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let value = &**self;
+                protocol.write_i16(i16::try_from(*value).map_err(|_| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        ::std::format!("{} is out of the range of i16", value),
+                    )
+                })?)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(Port(i32::from(protocol.read_i16()?)))
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(Port(i32::from(protocol.read_i16().await?)))
+            }
+        }
+        impl ::pilota::thrift::Size for Port {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                {
+                    let value = &**self;
+                    protocol.write_i16_len(*value as i16)
+                }
+            }
+        }
+        impl Port {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Port {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                Ok(::serde_json::Value::from(
+                    i16::try_from(*(&self.0)).map_err(|_| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            ::std::format!("{} is out of the range of i16", (&self.0)),
+                        )
+                    })?,
+                ))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                Ok(Self(i32::from(
+                    value
+                        .as_i64()
+                        .and_then(|v| i16::try_from(v).ok())
+                        .ok_or_else(|| ::pilota::thrift::json::unexpected("i16"))?,
+                )))
+            }
+        }
+        pub const MAX_RETRIES: i32 = 3i32;
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Level {
+            pub value: i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Level {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Level" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.value;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("value"),
+                        field_type: ::pilota::thrift::TType::I08,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i8(i8::try_from(*value).map_err(|_| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            ::std::format!("{} is out of the range of i8", value),
+                        )
+                    })?)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut value = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                value = Some(i32::from(protocol.read_i8()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let value = if let Some(value) = value {
+                    value
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field value is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { value };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut value = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                value = Some(i32::from(protocol.read_i8().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let value = if let Some(value) = value {
+                    value
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field value is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { value };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Level {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Level" })
+                    + {
+                        let value = &self.value;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("value"),
+                            field_type: ::pilota::thrift::TType::I08,
+                            id: Some(1i16),
+                        }) + protocol.write_i8_len(*value as i8)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Level {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Level {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                let mut map = ::serde_json::Map::new();
+                {
+                    let v = &self.value;
+                    map.insert("1".to_string(), {
+                        let value = ::serde_json::Value::from(i8::try_from(*v).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i8", v),
+                            )
+                        })?);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i8".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                Ok(::serde_json::Value::Object(map))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                let value = map
+                    .get("1")
+                    .and_then(|v| v.get("i8"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(i32::from(
+                            v.as_i64()
+                                .and_then(|v| i8::try_from(v).ok())
+                                .ok_or_else(|| ::pilota::thrift::json::unexpected("i8"))?,
+                        ))
+                    })
+                    .transpose()?;
+                let value = value.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field value is required",
+                    )
+                })?;
+                Ok(Self { value })
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Limits {
+            pub retries: i32,
+            pub port: i32,
+            pub priority: ::std::option::Option<i32>,
+            pub steps: ::std::vec::Vec<i32>,
+            pub weights: ::std::collections::HashMap<i32, i32>,
+            pub fallback: Port,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Limits {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Limits" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.retries;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("retries"),
+                        field_type: ::pilota::thrift::TType::I08,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i8(i8::try_from(*value).map_err(|_| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            ::std::format!("{} is out of the range of i8", value),
+                        )
+                    })?)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.port;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("port"),
+                        field_type: ::pilota::thrift::TType::I16,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i16(i16::try_from(*value).map_err(|_| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            ::std::format!("{} is out of the range of i16", value),
+                        )
+                    })?)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.priority.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("priority"),
+                        field_type: ::pilota::thrift::TType::I08,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i8(i8::try_from(*value).map_err(|_| {
+                        ::pilota::thrift::new_protocol_error(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            ::std::format!("{} is out of the range of i8", value),
+                        )
+                    })?)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.steps;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("steps"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::I08,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_i8(i8::try_from(*val).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i8", val),
+                            )
+                        })?)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.weights;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("weights"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::I16,
+                        value_type: ::pilota::thrift::TType::I08,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_i16(i16::try_from(*key).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i16", key),
+                            )
+                        })?)?;
+                        protocol.write_i8(i8::try_from(*val).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i8", val),
+                            )
+                        })?)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.fallback;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("fallback"),
+                        field_type: ::pilota::thrift::TType::I16,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut retries = None;
+                let mut port = None;
+                let mut steps = None;
+                let mut weights = None;
+                let mut fallback = None;
+                let mut priority = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                retries = Some(i32::from(protocol.read_i8()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                port = Some(i32::from(protocol.read_i16()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                priority = Some(i32::from(protocol.read_i8()?));
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                steps = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(i32::from(protocol.read_i8()?));
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                weights = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = i32::from(protocol.read_i16()?);
+                                        let el_val = i32::from(protocol.read_i8()?);
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                fallback = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let retries = if let Some(retries) = retries {
+                    retries
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field retries is required".to_string(),
+                        ),
+                    ));
+                };
+                let port = if let Some(port) = port {
+                    port
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field port is required".to_string(),
+                        ),
+                    ));
+                };
+                let steps = if let Some(steps) = steps {
+                    steps
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field steps is required".to_string(),
+                        ),
+                    ));
+                };
+                let weights = if let Some(weights) = weights {
+                    weights
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field weights is required".to_string(),
+                        ),
+                    ));
+                };
+                let fallback = if let Some(fallback) = fallback {
+                    fallback
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field fallback is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    priority,
+                    retries,
+                    port,
+                    steps,
+                    weights,
+                    fallback,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut retries = None;
+                let mut port = None;
+                let mut steps = None;
+                let mut weights = None;
+                let mut fallback = None;
+                let mut priority = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                retries = Some(i32::from(protocol.read_i8().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                port = Some(i32::from(protocol.read_i16().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                priority = Some(i32::from(protocol.read_i8().await?));
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                steps = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(i32::from(protocol.read_i8().await?));
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                weights = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = i32::from(protocol.read_i16().await?);
+                                        let el_val = i32::from(protocol.read_i8().await?);
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                fallback =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let retries = if let Some(retries) = retries {
+                    retries
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field retries is required".to_string(),
+                        ),
+                    ));
+                };
+                let port = if let Some(port) = port {
+                    port
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field port is required".to_string(),
+                        ),
+                    ));
+                };
+                let steps = if let Some(steps) = steps {
+                    steps
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field steps is required".to_string(),
+                        ),
+                    ));
+                };
+                let weights = if let Some(weights) = weights {
+                    weights
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field weights is required".to_string(),
+                        ),
+                    ));
+                };
+                let fallback = if let Some(fallback) = fallback {
+                    fallback
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field fallback is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    priority,
+                    retries,
+                    port,
+                    steps,
+                    weights,
+                    fallback,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Limits {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Limits" })
+                    + {
+                        let value = &self.retries;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("retries"),
+                            field_type: ::pilota::thrift::TType::I08,
+                            id: Some(1i16),
+                        }) + protocol.write_i8_len(*value as i8)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.port;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("port"),
+                            field_type: ::pilota::thrift::TType::I16,
+                            id: Some(2i16),
+                        }) + protocol.write_i16_len(*value as i16)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.priority.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("priority"),
+                            field_type: ::pilota::thrift::TType::I08,
+                            id: Some(3i16),
+                        }) + protocol.write_i8_len(*value as i8)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.steps;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("steps"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(4i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::I08,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_i8_len(*el as i8);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.weights;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("weights"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(5i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::I16,
+                                value_type: ::pilota::thrift::TType::I08,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_i16_len(*key as i16);
+                                        size += protocol.write_i8_len(*val as i8);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.fallback;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("fallback"),
+                            field_type: ::pilota::thrift::TType::I16,
+                            id: Some(6i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Limits {
+            #[doc = r" The length of the message encoded with the binary protocol, without encoding it."]
+            pub fn encoded_len(&self) -> usize {
+                ::pilota::thrift::Size::size(self, &::pilota::thrift::TBinaryProtocol::new(()))
+            }
+        }
+        impl Limits {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                let mut map = ::serde_json::Map::new();
+                {
+                    let v = &self.retries;
+                    map.insert("1".to_string(), {
+                        let value = ::serde_json::Value::from(i8::try_from(*v).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i8", v),
+                            )
+                        })?);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i8".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.port;
+                    map.insert("2".to_string(), {
+                        let value = ::serde_json::Value::from(i16::try_from(*v).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i16", v),
+                            )
+                        })?);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i16".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                if let Some(v) = &self.priority {
+                    map.insert("3".to_string(), {
+                        let value = ::serde_json::Value::from(i8::try_from(*v).map_err(|_| {
+                            ::pilota::thrift::new_protocol_error(
+                                ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                ::std::format!("{} is out of the range of i8", v),
+                            )
+                        })?);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i8".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.steps;
+                    map.insert("4".to_string(), {
+                        let value = {
+                            let mut items = ::std::vec![
+                                ::serde_json::Value::from("i8"),
+                                ::serde_json::Value::from(v.len()),
+                            ];
+                            for v in v.iter() {
+                                items.push(::serde_json::Value::from(i8::try_from(*v).map_err(
+                                    |_| {
+                                        ::pilota::thrift::new_protocol_error(
+                                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                            ::std::format!("{} is out of the range of i8", v),
+                                        )
+                                    },
+                                )?));
+                            }
+                            ::serde_json::Value::Array(items)
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("lst".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.weights;
+                    map.insert("5".to_string(), {
+                        let value = {
+                            let mut entries = ::serde_json::Map::new();
+                            for (k, v) in v.iter() {
+                                let key =
+                                    ::serde_json::Value::from(i16::try_from(*k).map_err(|_| {
+                                        ::pilota::thrift::new_protocol_error(
+                                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                            ::std::format!("{} is out of the range of i16", k),
+                                        )
+                                    })?);
+                                let value =
+                                    ::serde_json::Value::from(i8::try_from(*v).map_err(|_| {
+                                        ::pilota::thrift::new_protocol_error(
+                                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                            ::std::format!("{} is out of the range of i8", v),
+                                        )
+                                    })?);
+                                let key = match key {
+                                    ::serde_json::Value::String(s) => s,
+                                    k => k.to_string(),
+                                };
+                                entries.insert(key, value);
+                            }
+                            ::serde_json::Value::Array(::std::vec![
+                                ::serde_json::Value::from("i16"),
+                                ::serde_json::Value::from("i8"),
+                                ::serde_json::Value::from(v.len()),
+                                ::serde_json::Value::Object(entries),
+                            ])
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("map".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.fallback;
+                    map.insert("6".to_string(), {
+                        let value = v.to_thrift_json()?;
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i16".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                Ok(::serde_json::Value::Object(map))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                let retries = map
+                    .get("1")
+                    .and_then(|v| v.get("i8"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(i32::from(
+                            v.as_i64()
+                                .and_then(|v| i8::try_from(v).ok())
+                                .ok_or_else(|| ::pilota::thrift::json::unexpected("i8"))?,
+                        ))
+                    })
+                    .transpose()?;
+                let retries = retries.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field retries is required",
+                    )
+                })?;
+                let port = map
+                    .get("2")
+                    .and_then(|v| v.get("i16"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(i32::from(
+                            v.as_i64()
+                                .and_then(|v| i16::try_from(v).ok())
+                                .ok_or_else(|| ::pilota::thrift::json::unexpected("i16"))?,
+                        ))
+                    })
+                    .transpose()?;
+                let port = port.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field port is required",
+                    )
+                })?;
+                let priority = map
+                    .get("3")
+                    .and_then(|v| v.get("i8"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(i32::from(
+                            v.as_i64()
+                                .and_then(|v| i8::try_from(v).ok())
+                                .ok_or_else(|| ::pilota::thrift::json::unexpected("i8"))?,
+                        ))
+                    })
+                    .transpose()?;
+                let steps = map
+                    .get("4")
+                    .and_then(|v| v.get("lst"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::vec::Vec<i32>,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(match v.as_array().map(|a| a.as_slice()) {
+                                Some([tag, len, items @ ..])
+                                    if *tag == "i8" && len.as_u64() == Some(items.len() as u64) =>
+                                {
+                                    items
+                                        .iter()
+                                        .map(
+                                            |v| -> ::std::result::Result<
+                                                i32,
+                                                ::pilota::thrift::Error,
+                                            > {
+                                                Ok(i32::from(
+                                                    v.as_i64()
+                                                        .and_then(|v| i8::try_from(v).ok())
+                                                        .ok_or_else(|| {
+                                                        ::pilota::thrift::json::unexpected("i8")
+                                                    })?,
+                                                ))
+                                            },
+                                        )
+                                        .collect::<::std::result::Result<::std::vec::Vec<i32>, _>>(
+                                        )?
+                                }
+                                _ => return Err(::pilota::thrift::json::unexpected("lst")),
+                            })
+                        },
+                    )
+                    .transpose()?;
+                let steps = steps.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field steps is required",
+                    )
+                })?;
+                let weights = map
+                    .get("5")
+                    .and_then(|v| v.get("map"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::collections::HashMap<i32, i32>,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(match v.as_array().map(|a| a.as_slice()) {
+                                Some([k_tag, v_tag, len, ::serde_json::Value::Object(map)])
+                                    if *k_tag == "i16"
+                                        && *v_tag == "i8"
+                                        && len.as_u64() == Some(map.len() as u64) =>
+                                {
+                                    map.iter()
+                                        .map(
+                                            |(k, v)| -> ::std::result::Result<
+                                                (i32, i32),
+                                                ::pilota::thrift::Error,
+                                            > {
+                                                let key =
+                                                    ::serde_json::from_str::<::serde_json::Value>(
+                                                        k,
+                                                    )
+                                                    .map_err(|_| {
+                                                        ::pilota::thrift::json::unexpected("i16")
+                                                    })?;
+                                                Ok((
+                                                    i32::from(
+                                                        (&key)
+                                                            .as_i64()
+                                                            .and_then(|v| i16::try_from(v).ok())
+                                                            .ok_or_else(|| {
+                                                                ::pilota::thrift::json::unexpected(
+                                                                    "i16",
+                                                                )
+                                                            })?,
+                                                    ),
+                                                    i32::from(
+                                                        v.as_i64()
+                                                            .and_then(|v| i8::try_from(v).ok())
+                                                            .ok_or_else(|| {
+                                                                ::pilota::thrift::json::unexpected(
+                                                                    "i8",
+                                                                )
+                                                            })?,
+                                                    ),
+                                                ))
+                                            },
+                                        )
+                                        .collect::<::std::result::Result<
+                                            ::std::collections::HashMap<i32, i32>,
+                                            _,
+                                        >>()?
+                                }
+                                _ => return Err(::pilota::thrift::json::unexpected("map")),
+                            })
+                        },
+                    )
+                    .transpose()?;
+                let weights = weights.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field weights is required",
+                    )
+                })?;
+                let fallback = map
+                    .get("6")
+                    .and_then(|v| v.get("i16"))
+                    .map(
+                        |v| -> ::std::result::Result<Port, ::pilota::thrift::Error> {
+                            Ok(<Port>::from_thrift_json(v)?)
+                        },
+                    )
+                    .transpose()?;
+                let fallback = fallback.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field fallback is required",
+                    )
+                })?;
+                Ok(Self {
+                    retries,
+                    port,
+                    priority,
+                    steps,
+                    weights,
+                    fallback,
+                })
+            }
+        }
+        impl ::std::default::Default for Limits {
+            fn default() -> Self {
+                Limits {
+                    retries: MAX_RETRIES,
+                    port: ::std::default::Default::default(),
+                    priority: None,
+                    steps: ::std::default::Default::default(),
+                    weights: ::std::default::Default::default(),
+                    fallback: Port(8080i32),
+                }
+            }
+        }
+    }
+}
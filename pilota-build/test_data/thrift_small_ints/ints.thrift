@@ -0,0 +1,16 @@
+typedef i16 Port
+
+const i8 MAX_RETRIES = 3
+
+struct Level {
+    1: required i8 value,
+}
+
+struct Limits {
+    1: required i8 retries = MAX_RETRIES,
+    2: required i16 port,
+    3: optional i8 priority,
+    4: required list<i8> steps,
+    5: required map<i16, i8> weights,
+    6: required Port fallback = 8080,
+}
@@ -0,0 +1,2264 @@
+pub mod tjson {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod tjson {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Color {
+            #[derivative(Default)]
+            Red = 1i32,
+            Green = 2i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Color {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Color {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        impl Color {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                Ok(::serde_json::Value::from(*self as i32))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = value
+                    .as_i64()
+                    .and_then(|v| i32::try_from(v).ok())
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("i32"))?;
+                Self::try_from(value).map_err(|_| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Color, value: {}", value),
+                    )
+                })
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Point {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Point" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.x;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("x"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.y;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("y"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                let mut y = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                y = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let y = if let Some(y) = y {
+                    y
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x, y };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                let mut y = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                y = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let y = if let Some(y) = y {
+                    y
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x, y };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Point {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Point" })
+                    + {
+                        let value = &self.x;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("x"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.y;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("y"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(2i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Point {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                let mut map = ::serde_json::Map::new();
+                {
+                    let v = &self.x;
+                    map.insert("1".to_string(), {
+                        let value = ::serde_json::Value::from(*v);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i32".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.y;
+                    map.insert("2".to_string(), {
+                        let value = ::serde_json::Value::from(*v);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i32".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                Ok(::serde_json::Value::Object(map))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                let x = map
+                    .get("1")
+                    .and_then(|v| v.get("i32"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(v.as_i64()
+                            .and_then(|v| i32::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i32"))?)
+                    })
+                    .transpose()?;
+                let x = x.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field x is required",
+                    )
+                })?;
+                let y = map
+                    .get("2")
+                    .and_then(|v| v.get("i32"))
+                    .map(|v| -> ::std::result::Result<i32, ::pilota::thrift::Error> {
+                        Ok(v.as_i64()
+                            .and_then(|v| i32::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i32"))?)
+                    })
+                    .transpose()?;
+                let y = y.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field y is required",
+                    )
+                })?;
+                Ok(Self { x, y })
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(Clone, PartialEq)]
+        pub enum Shape {
+            #[derivative(Default)]
+            Point(Point),
+            Polygon(::std::vec::Vec<Point>),
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Shape {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Shape" })?;
+                match self {
+                    Shape::Point(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("point"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(1i16),
+                        })?;
+                        ::pilota::thrift::Message::encode(value, protocol)?;
+                        protocol.write_field_end()?;
+                    }
+                    Shape::Polygon(ref value) => {
+                        protocol.write_field_begin(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("polygon"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(2i16),
+                        })?;
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::Struct,
+                            size: value.len(),
+                        };
+                        protocol.write_list_begin(&list_ident)?;
+                        for val in value {
+                            ::pilota::thrift::Message::encode(val, protocol)?;
+                        }
+                        protocol.write_list_end()?;
+                        protocol.write_field_end()?;
+                    }
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Point(::pilota::thrift::Message::decode(
+                                    protocol,
+                                )?));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Polygon({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                protocol.read_field_end()?;
+                protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut ret = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Point(
+                                    ::pilota::thrift::Message::decode_async(protocol).await?,
+                                ));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        Some(2i16) => {
+                            if ret.is_none() {
+                                ret = Some(Shape::Polygon({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                }));
+                            } else {
+                                return Err(::pilota::thrift::new_protocol_error(
+                                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                                    "received multiple fields for union from remote Message",
+                                ));
+                            }
+                        }
+                        _ => {
+                            protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+                }
+                protocol.read_field_end().await?;
+                protocol.read_struct_end().await?;
+                if let Some(ret) = ret {
+                    Ok(ret)
+                } else {
+                    Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+        }
+        impl ::pilota::thrift::Size for Shape {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Shape" })
+                    + match self {
+                        Shape::Point(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("point"),
+                                field_type: ::pilota::thrift::TType::Struct,
+                                id: Some(1i16),
+                            }) + ::pilota::thrift::Size::size(value, protocol)
+                                + protocol.write_field_end_len()
+                        }
+                        Shape::Polygon(ref value) => {
+                            protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                                name: Some("polygon"),
+                                field_type: ::pilota::thrift::TType::List,
+                                id: Some(2i16),
+                            }) + {
+                                let list_ident = ::pilota::thrift::TListIdentifier {
+                                    element_type: ::pilota::thrift::TType::Struct,
+                                    size: value.len(),
+                                };
+                                protocol.write_list_begin_len(&list_ident)
+                                    + {
+                                        let mut size = 0;
+                                        for el in value {
+                                            size += ::pilota::thrift::Size::size(el, protocol);
+                                        }
+                                        size
+                                    }
+                                    + protocol.write_list_end_len()
+                            } + protocol.write_field_end_len()
+                        }
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Shape {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                let mut map = ::serde_json::Map::new();
+                match self {
+                    Self::Point(v) => {
+                        map.insert("1".to_string(), {
+                            let value = v.to_thrift_json()?;
+                            ::serde_json::Value::Object(
+                                ::std::iter::once(("rec".to_string(), value)).collect(),
+                            )
+                        });
+                    }
+                    Self::Polygon(v) => {
+                        map.insert("2".to_string(), {
+                            let value = {
+                                let mut items = ::std::vec![
+                                    ::serde_json::Value::from("rec"),
+                                    ::serde_json::Value::from(v.len()),
+                                ];
+                                for v in v.iter() {
+                                    items.push(v.to_thrift_json()?);
+                                }
+                                ::serde_json::Value::Array(items)
+                            };
+                            ::serde_json::Value::Object(
+                                ::std::iter::once(("lst".to_string(), value)).collect(),
+                            )
+                        });
+                    }
+                }
+                Ok(::serde_json::Value::Object(map))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                if map.len() > 1 {
+                    return Err(::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "received multiple fields for union from remote Message",
+                    ));
+                }
+                if let Some(v) = map.get("1").and_then(|v| v.get("rec")) {
+                    return Ok(Self::Point(<Point>::from_thrift_json(v)?));
+                }
+                if let Some(v) = map.get("2").and_then(|v| v.get("lst")) {
+                    return Ok(Self::Polygon(match v.as_array().map(|a| a.as_slice()) {
+                        Some([tag, len, items @ ..])
+                            if *tag == "rec" && len.as_u64() == Some(items.len() as u64) =>
+                        {
+                            items
+                                .iter()
+                                .map(
+                                    |v| -> ::std::result::Result<Point, ::pilota::thrift::Error> {
+                                        Ok(<Point>::from_thrift_json(v)?)
+                                    },
+                                )
+                                .collect::<::std::result::Result<::std::vec::Vec<Point>, _>>()?
+                        }
+                        _ => return Err(::pilota::thrift::json::unexpected("lst")),
+                    }));
+                }
+                Err(::pilota::thrift::new_protocol_error(
+                    ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                    "received empty union from remote Message",
+                ))
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Canvas {
+            pub visible: bool,
+            pub layer: u8,
+            pub width: i16,
+            pub created_at: i64,
+            pub scale: f64,
+            pub title: ::std::string::String,
+            pub thumbnail: ::std::vec::Vec<u8>,
+            pub background: Color,
+            pub origin: ::std::option::Option<Point>,
+            pub points: ::std::vec::Vec<Point>,
+            pub labels: ::std::collections::HashSet<::std::string::String>,
+            pub groups: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<i32>>,
+            pub anchors: ::std::collections::HashMap<i32, Point>,
+            pub shape: Shape,
+            pub comment: ::std::option::Option<::std::string::String>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Canvas {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Canvas" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.visible;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("visible"),
+                        field_type: ::pilota::thrift::TType::Bool,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bool(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.layer;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("layer"),
+                        field_type: ::pilota::thrift::TType::I08,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_byte(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.width;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("width"),
+                        field_type: ::pilota::thrift::TType::I16,
+                        id: Some(3i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i16(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.created_at;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("created_at"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.scale;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("scale"),
+                        field_type: ::pilota::thrift::TType::Double,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_double(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.title;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("title"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.thumbnail;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("thumbnail"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bytes(&value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.background;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("background"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.origin.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("origin"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(9i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.points;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("points"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(10i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.labels;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("labels"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(11i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TSetIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_set_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_set_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.groups;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("groups"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(12i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::List,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        let list_ident = ::pilota::thrift::TListIdentifier {
+                            element_type: ::pilota::thrift::TType::I32,
+                            size: val.len(),
+                        };
+                        protocol.write_list_begin(&list_ident)?;
+                        for val in val {
+                            protocol.write_i32(*val)?;
+                        }
+                        protocol.write_list_end()?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.anchors;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("anchors"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(13i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::I32,
+                        value_type: ::pilota::thrift::TType::Struct,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_i32(*key)?;
+                        ::pilota::thrift::Message::encode(val, protocol)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.shape;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("shape"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(14i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.comment.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("comment"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(15i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                };
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut visible = None;
+                let mut layer = None;
+                let mut width = None;
+                let mut created_at = None;
+                let mut scale = None;
+                let mut title = None;
+                let mut thumbnail = None;
+                let mut background = None;
+                let mut points = None;
+                let mut labels = None;
+                let mut groups = None;
+                let mut anchors = None;
+                let mut shape = None;
+                let mut origin = None;
+                let mut comment = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                visible = Some(protocol.read_bool()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                layer = Some(protocol.read_byte()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                width = Some(protocol.read_i16()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                created_at = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                scale = Some(protocol.read_double()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                title = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                thumbnail = Some(protocol.read_bytes()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                background = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                origin = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                points = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(protocol)?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                labels = Some({
+                                    let list_ident = protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string()?);
+                                    }
+                                    protocol.read_set_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(12i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                groups = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = {
+                                            let list_ident = protocol.read_list_begin()?;
+                                            let mut val = Vec::with_capacity(list_ident.size);
+                                            for _ in 0..list_ident.size {
+                                                val.push(protocol.read_i32()?);
+                                            }
+                                            protocol.read_list_end()?;
+                                            val
+                                        };
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(13i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                anchors = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32()?;
+                                        let el_val = ::pilota::thrift::Message::decode(protocol)?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(14i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                shape = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(15i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                comment = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let visible = if let Some(visible) = visible {
+                    visible
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field visible is required".to_string(),
+                        ),
+                    ));
+                };
+                let layer = if let Some(layer) = layer {
+                    layer
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field layer is required".to_string(),
+                        ),
+                    ));
+                };
+                let width = if let Some(width) = width {
+                    width
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field width is required".to_string(),
+                        ),
+                    ));
+                };
+                let created_at = if let Some(created_at) = created_at {
+                    created_at
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field created_at is required".to_string(),
+                        ),
+                    ));
+                };
+                let scale = if let Some(scale) = scale {
+                    scale
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field scale is required".to_string(),
+                        ),
+                    ));
+                };
+                let title = if let Some(title) = title {
+                    title
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field title is required".to_string(),
+                        ),
+                    ));
+                };
+                let thumbnail = if let Some(thumbnail) = thumbnail {
+                    thumbnail
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field thumbnail is required".to_string(),
+                        ),
+                    ));
+                };
+                let background = if let Some(background) = background {
+                    background
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field background is required".to_string(),
+                        ),
+                    ));
+                };
+                let points = if let Some(points) = points {
+                    points
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field points is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let groups = if let Some(groups) = groups {
+                    groups
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field groups is required".to_string(),
+                        ),
+                    ));
+                };
+                let anchors = if let Some(anchors) = anchors {
+                    anchors
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field anchors is required".to_string(),
+                        ),
+                    ));
+                };
+                let shape = if let Some(shape) = shape {
+                    shape
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field shape is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    origin,
+                    comment,
+                    visible,
+                    layer,
+                    width,
+                    created_at,
+                    scale,
+                    title,
+                    thumbnail,
+                    background,
+                    points,
+                    labels,
+                    groups,
+                    anchors,
+                    shape,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut visible = None;
+                let mut layer = None;
+                let mut width = None;
+                let mut created_at = None;
+                let mut scale = None;
+                let mut title = None;
+                let mut thumbnail = None;
+                let mut background = None;
+                let mut points = None;
+                let mut labels = None;
+                let mut groups = None;
+                let mut anchors = None;
+                let mut shape = None;
+                let mut origin = None;
+                let mut comment = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                visible = Some(protocol.read_bool().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I08 {
+                                layer = Some(protocol.read_byte().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(3i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                width = Some(protocol.read_i16().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                created_at = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                scale = Some(protocol.read_double().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                title = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                thumbnail = Some(protocol.read_bytes().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                background =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                origin =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                points = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?,
+                                        );
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                labels = Some({
+                                    let list_ident = protocol.read_set_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string().await?);
+                                    }
+                                    protocol.read_set_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(12i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                groups = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = {
+                                            let list_ident = protocol.read_list_begin().await?;
+                                            let mut val = Vec::with_capacity(list_ident.size);
+                                            for _ in 0..list_ident.size {
+                                                val.push(protocol.read_i32().await?);
+                                            }
+                                            protocol.read_list_end().await?;
+                                            val
+                                        };
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(13i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                anchors = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_i32().await?;
+                                        let el_val =
+                                            ::pilota::thrift::Message::decode_async(protocol)
+                                                .await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(14i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                shape =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(15i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                comment = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let visible = if let Some(visible) = visible {
+                    visible
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field visible is required".to_string(),
+                        ),
+                    ));
+                };
+                let layer = if let Some(layer) = layer {
+                    layer
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field layer is required".to_string(),
+                        ),
+                    ));
+                };
+                let width = if let Some(width) = width {
+                    width
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field width is required".to_string(),
+                        ),
+                    ));
+                };
+                let created_at = if let Some(created_at) = created_at {
+                    created_at
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field created_at is required".to_string(),
+                        ),
+                    ));
+                };
+                let scale = if let Some(scale) = scale {
+                    scale
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field scale is required".to_string(),
+                        ),
+                    ));
+                };
+                let title = if let Some(title) = title {
+                    title
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field title is required".to_string(),
+                        ),
+                    ));
+                };
+                let thumbnail = if let Some(thumbnail) = thumbnail {
+                    thumbnail
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field thumbnail is required".to_string(),
+                        ),
+                    ));
+                };
+                let background = if let Some(background) = background {
+                    background
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field background is required".to_string(),
+                        ),
+                    ));
+                };
+                let points = if let Some(points) = points {
+                    points
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field points is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let groups = if let Some(groups) = groups {
+                    groups
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field groups is required".to_string(),
+                        ),
+                    ));
+                };
+                let anchors = if let Some(anchors) = anchors {
+                    anchors
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field anchors is required".to_string(),
+                        ),
+                    ));
+                };
+                let shape = if let Some(shape) = shape {
+                    shape
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field shape is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    origin,
+                    comment,
+                    visible,
+                    layer,
+                    width,
+                    created_at,
+                    scale,
+                    title,
+                    thumbnail,
+                    background,
+                    points,
+                    labels,
+                    groups,
+                    anchors,
+                    shape,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Canvas {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Canvas" })
+                    + {
+                        let value = &self.visible;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("visible"),
+                            field_type: ::pilota::thrift::TType::Bool,
+                            id: Some(1i16),
+                        }) + protocol.write_bool_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.layer;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("layer"),
+                            field_type: ::pilota::thrift::TType::I08,
+                            id: Some(2i16),
+                        }) + protocol.write_byte_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.width;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("width"),
+                            field_type: ::pilota::thrift::TType::I16,
+                            id: Some(3i16),
+                        }) + protocol.write_i16_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.created_at;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("created_at"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(4i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.scale;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("scale"),
+                            field_type: ::pilota::thrift::TType::Double,
+                            id: Some(5i16),
+                        }) + protocol.write_double_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.title;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("title"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(6i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.thumbnail;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("thumbnail"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(7i16),
+                        }) + protocol.write_bytes_len(value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.background;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("background"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(8i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.origin.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("origin"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(9i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.points;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("points"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(10i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += ::pilota::thrift::Size::size(el, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.labels;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("labels"),
+                            field_type: ::pilota::thrift::TType::Set,
+                            id: Some(11i16),
+                        }) + {
+                            let set_id = ::pilota::thrift::TSetIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_set_begin_len(&set_id)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_string_len(&el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_set_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.groups;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("groups"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(12i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::String,
+                                value_type: ::pilota::thrift::TType::List,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_string_len(&key);
+                                        size += {
+                                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                                element_type: ::pilota::thrift::TType::I32,
+                                                size: val.len(),
+                                            };
+                                            protocol.write_list_begin_len(&list_ident)
+                                                + {
+                                                    let mut size = 0;
+                                                    for el in val {
+                                                        size += protocol.write_i32_len(*el);
+                                                    }
+                                                    size
+                                                }
+                                                + protocol.write_list_end_len()
+                                        };
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.anchors;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("anchors"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(13i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::I32,
+                                value_type: ::pilota::thrift::TType::Struct,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_i32_len(*key);
+                                        size += ::pilota::thrift::Size::size(val, protocol);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.shape;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("shape"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(14i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.comment.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("comment"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(15i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Canvas {
+            pub fn to_thrift_json(
+                &self,
+            ) -> ::std::result::Result<::serde_json::Value, ::pilota::thrift::Error> {
+                let mut map = ::serde_json::Map::new();
+                {
+                    let v = &self.visible;
+                    map.insert("1".to_string(), {
+                        let value = ::serde_json::Value::from(*v as i32);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("tf".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.layer;
+                    map.insert("2".to_string(), {
+                        let value = ::serde_json::Value::from(*v as i8);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i8".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.width;
+                    map.insert("3".to_string(), {
+                        let value = ::serde_json::Value::from(*v);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i16".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.created_at;
+                    map.insert("4".to_string(), {
+                        let value = ::serde_json::Value::from(*v);
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i64".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.scale;
+                    map.insert("5".to_string(), {
+                        let value = match ::pilota::thrift::json::non_finite_double(*v) {
+                            Some(s) => ::serde_json::Value::String(s.to_string()),
+                            None => ::serde_json::Value::from(*v),
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("dbl".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.title;
+                    map.insert("6".to_string(), {
+                        let value = ::serde_json::Value::String(v.to_string());
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("str".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.thumbnail;
+                    map.insert("7".to_string(), {
+                        let value =
+                            ::serde_json::Value::String(::pilota::thrift::json::encode_base64(v));
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("str".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.background;
+                    map.insert("8".to_string(), {
+                        let value = v.to_thrift_json()?;
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("i32".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                if let Some(v) = &self.origin {
+                    map.insert("9".to_string(), {
+                        let value = v.to_thrift_json()?;
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("rec".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.points;
+                    map.insert("10".to_string(), {
+                        let value = {
+                            let mut items = ::std::vec![
+                                ::serde_json::Value::from("rec"),
+                                ::serde_json::Value::from(v.len()),
+                            ];
+                            for v in v.iter() {
+                                items.push(v.to_thrift_json()?);
+                            }
+                            ::serde_json::Value::Array(items)
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("lst".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.labels;
+                    map.insert("11".to_string(), {
+                        let value = {
+                            let mut items = ::std::vec![
+                                ::serde_json::Value::from("str"),
+                                ::serde_json::Value::from(v.len()),
+                            ];
+                            for v in v.iter() {
+                                items.push(::serde_json::Value::String(v.to_string()));
+                            }
+                            ::serde_json::Value::Array(items)
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("set".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.groups;
+                    map.insert("12".to_string(), {
+                        let value = {
+                            let mut entries = ::serde_json::Map::new();
+                            for (k, v) in v.iter() {
+                                let key = ::serde_json::Value::String(k.to_string());
+                                let value = {
+                                    let mut items = ::std::vec![
+                                        ::serde_json::Value::from("i32"),
+                                        ::serde_json::Value::from(v.len()),
+                                    ];
+                                    for v in v.iter() {
+                                        items.push(::serde_json::Value::from(*v));
+                                    }
+                                    ::serde_json::Value::Array(items)
+                                };
+                                let key = match key {
+                                    ::serde_json::Value::String(s) => s,
+                                    k => k.to_string(),
+                                };
+                                entries.insert(key, value);
+                            }
+                            ::serde_json::Value::Array(::std::vec![
+                                ::serde_json::Value::from("str"),
+                                ::serde_json::Value::from("lst"),
+                                ::serde_json::Value::from(v.len()),
+                                ::serde_json::Value::Object(entries),
+                            ])
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("map".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.anchors;
+                    map.insert("13".to_string(), {
+                        let value = {
+                            let mut entries = ::serde_json::Map::new();
+                            for (k, v) in v.iter() {
+                                let key = ::serde_json::Value::from(*k);
+                                let value = v.to_thrift_json()?;
+                                let key = match key {
+                                    ::serde_json::Value::String(s) => s,
+                                    k => k.to_string(),
+                                };
+                                entries.insert(key, value);
+                            }
+                            ::serde_json::Value::Array(::std::vec![
+                                ::serde_json::Value::from("i32"),
+                                ::serde_json::Value::from("rec"),
+                                ::serde_json::Value::from(v.len()),
+                                ::serde_json::Value::Object(entries),
+                            ])
+                        };
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("map".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                {
+                    let v = &self.shape;
+                    map.insert("14".to_string(), {
+                        let value = v.to_thrift_json()?;
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("rec".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                if let Some(v) = &self.comment {
+                    map.insert("15".to_string(), {
+                        let value = ::serde_json::Value::String(v.to_string());
+                        ::serde_json::Value::Object(
+                            ::std::iter::once(("str".to_string(), value)).collect(),
+                        )
+                    });
+                }
+                Ok(::serde_json::Value::Object(map))
+            }
+            pub fn from_thrift_json(
+                value: &::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let map = value
+                    .as_object()
+                    .ok_or_else(|| ::pilota::thrift::json::unexpected("rec"))?;
+                let visible = map
+                    .get("1")
+                    .and_then(|v| v.get("tf"))
+                    .map(
+                        |v| -> ::std::result::Result<bool, ::pilota::thrift::Error> {
+                            Ok(v.as_i64()
+                                .ok_or_else(|| ::pilota::thrift::json::unexpected("tf"))?
+                                != 0)
+                        },
+                    )
+                    .transpose()?;
+                let visible = visible.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field visible is required",
+                    )
+                })?;
+                let layer = map
+                    .get("2")
+                    .and_then(|v| v.get("i8"))
+                    .map(|v| -> ::std::result::Result<u8, ::pilota::thrift::Error> {
+                        Ok(v.as_i64()
+                            .and_then(|v| i8::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i8"))?
+                            as u8)
+                    })
+                    .transpose()?;
+                let layer = layer.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field layer is required",
+                    )
+                })?;
+                let width = map
+                    .get("3")
+                    .and_then(|v| v.get("i16"))
+                    .map(|v| -> ::std::result::Result<i16, ::pilota::thrift::Error> {
+                        Ok(v.as_i64()
+                            .and_then(|v| i16::try_from(v).ok())
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i16"))?)
+                    })
+                    .transpose()?;
+                let width = width.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field width is required",
+                    )
+                })?;
+                let created_at = map
+                    .get("4")
+                    .and_then(|v| v.get("i64"))
+                    .map(|v| -> ::std::result::Result<i64, ::pilota::thrift::Error> {
+                        Ok(v.as_i64()
+                            .ok_or_else(|| ::pilota::thrift::json::unexpected("i64"))?)
+                    })
+                    .transpose()?;
+                let created_at = created_at.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field created_at is required",
+                    )
+                })?;
+                let scale = map
+                    .get("5")
+                    .and_then(|v| v.get("dbl"))
+                    .map(|v| -> ::std::result::Result<f64, ::pilota::thrift::Error> {
+                        Ok(match v {
+                            ::serde_json::Value::String(s) => s.parse::<f64>().ok(),
+                            v => v.as_f64(),
+                        }
+                        .ok_or_else(|| ::pilota::thrift::json::unexpected("dbl"))?)
+                    })
+                    .transpose()?;
+                let scale = scale.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field scale is required",
+                    )
+                })?;
+                let title = map
+                    .get("6")
+                    .and_then(|v| v.get("str"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::string::String,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(::std::convert::From::from(
+                                v.as_str()
+                                    .ok_or_else(|| ::pilota::thrift::json::unexpected("str"))?
+                                    .to_string(),
+                            ))
+                        },
+                    )
+                    .transpose()?;
+                let title = title.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field title is required",
+                    )
+                })?;
+                let thumbnail = map
+                    .get("7")
+                    .and_then(|v| v.get("str"))
+                    .map(
+                        |v| -> ::std::result::Result<::std::vec::Vec<u8>, ::pilota::thrift::Error> {
+                            Ok(::std::convert::From::from(
+                                ::pilota::thrift::json::decode_base64(
+                                    v.as_str()
+                                        .ok_or_else(|| ::pilota::thrift::json::unexpected("str"))?,
+                                )?,
+                            ))
+                        },
+                    )
+                    .transpose()?;
+                let thumbnail = thumbnail.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field thumbnail is required",
+                    )
+                })?;
+                let background = map
+                    .get("8")
+                    .and_then(|v| v.get("i32"))
+                    .map(
+                        |v| -> ::std::result::Result<Color, ::pilota::thrift::Error> {
+                            Ok(<Color>::from_thrift_json(v)?)
+                        },
+                    )
+                    .transpose()?;
+                let background = background.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field background is required",
+                    )
+                })?;
+                let origin = map
+                    .get("9")
+                    .and_then(|v| v.get("rec"))
+                    .map(
+                        |v| -> ::std::result::Result<Point, ::pilota::thrift::Error> {
+                            Ok(<Point>::from_thrift_json(v)?)
+                        },
+                    )
+                    .transpose()?;
+                let points = map . get ("10") . and_then (| v | v . get ("lst")) . map (| v | -> :: std :: result :: Result < :: std :: vec :: Vec < Point > , :: pilota :: thrift :: Error > { Ok (match v . as_array () . map (| a | a . as_slice ()) { Some ([tag , len , items @ ..]) if * tag == "rec" && len . as_u64 () == Some (items . len () as u64) => { items . iter () . map (| v | -> :: std :: result :: Result < Point , :: pilota :: thrift :: Error > { Ok (< Point > :: from_thrift_json (v) ?) }) . collect :: < :: std :: result :: Result < :: std :: vec :: Vec < Point > , _ >> () ? } _ => return Err (:: pilota :: thrift :: json :: unexpected ("lst")) , }) }) . transpose () ? ;
+                let points = points.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field points is required",
+                    )
+                })?;
+                let labels = map
+                    .get("11")
+                    .and_then(|v| v.get("set"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::collections::HashSet<::std::string::String>,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(match v.as_array().map(|a| a.as_slice()) {
+                                Some([tag, len, items @ ..])
+                                    if *tag == "str"
+                                        && len.as_u64() == Some(items.len() as u64) =>
+                                {
+                                    items
+                                        .iter()
+                                        .map(
+                                            |v| -> ::std::result::Result<
+                                                ::std::string::String,
+                                                ::pilota::thrift::Error,
+                                            > {
+                                                Ok(::std::convert::From::from(
+                                                    v.as_str()
+                                                        .ok_or_else(|| {
+                                                            ::pilota::thrift::json::unexpected(
+                                                                "str",
+                                                            )
+                                                        })?
+                                                        .to_string(),
+                                                ))
+                                            },
+                                        )
+                                        .collect::<::std::result::Result<
+                                            ::std::collections::HashSet<::std::string::String>,
+                                            _,
+                                        >>()?
+                                }
+                                _ => return Err(::pilota::thrift::json::unexpected("set")),
+                            })
+                        },
+                    )
+                    .transpose()?;
+                let labels = labels.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field labels is required",
+                    )
+                })?;
+                let groups = map . get ("12") . and_then (| v | v . get ("map")) . map (| v | -> :: std :: result :: Result < :: std :: collections :: HashMap < :: std :: string :: String , :: std :: vec :: Vec < i32 > > , :: pilota :: thrift :: Error > { Ok (match v . as_array () . map (| a | a . as_slice ()) { Some ([k_tag , v_tag , len , :: serde_json :: Value :: Object (map)]) if * k_tag == "str" && * v_tag == "lst" && len . as_u64 () == Some (map . len () as u64) => { map . iter () . map (| (k , v) | -> :: std :: result :: Result < (:: std :: string :: String , :: std :: vec :: Vec < i32 >) , :: pilota :: thrift :: Error , > { let key = :: serde_json :: Value :: String (k . clone ()) ; Ok ((:: std :: convert :: From :: from ((& key) . as_str () . ok_or_else (|| :: pilota :: thrift :: json :: unexpected ("str")) ? . to_string ()) , match v . as_array () . map (| a | a . as_slice ()) { Some ([tag , len , items @ ..]) if * tag == "i32" && len . as_u64 () == Some (items . len () as u64) => { items . iter () . map (| v | -> :: std :: result :: Result < i32 , :: pilota :: thrift :: Error > { Ok (v . as_i64 () . and_then (| v | i32 :: try_from (v) . ok ()) . ok_or_else (|| :: pilota :: thrift :: json :: unexpected ("i32")) ?) }) . collect :: < :: std :: result :: Result < :: std :: vec :: Vec < i32 > , _ >> () ? } _ => return Err (:: pilota :: thrift :: json :: unexpected ("lst")) , })) }) . collect :: < :: std :: result :: Result < :: std :: collections :: HashMap < :: std :: string :: String , :: std :: vec :: Vec < i32 > > , _ >> () ? } _ => return Err (:: pilota :: thrift :: json :: unexpected ("map")) , }) }) . transpose () ? ;
+                let groups = groups.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field groups is required",
+                    )
+                })?;
+                let anchors = map
+                    .get("13")
+                    .and_then(|v| v.get("map"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::collections::HashMap<i32, Point>,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(match v.as_array().map(|a| a.as_slice()) {
+                                Some([k_tag, v_tag, len, ::serde_json::Value::Object(map)])
+                                    if *k_tag == "i32"
+                                        && *v_tag == "rec"
+                                        && len.as_u64() == Some(map.len() as u64) =>
+                                {
+                                    map.iter()
+                                        .map(
+                                            |(k, v)| -> ::std::result::Result<
+                                                (i32, Point),
+                                                ::pilota::thrift::Error,
+                                            > {
+                                                let key =
+                                                    ::serde_json::from_str::<::serde_json::Value>(
+                                                        k,
+                                                    )
+                                                    .map_err(|_| {
+                                                        ::pilota::thrift::json::unexpected("i32")
+                                                    })?;
+                                                Ok((
+                                                    (&key)
+                                                        .as_i64()
+                                                        .and_then(|v| i32::try_from(v).ok())
+                                                        .ok_or_else(|| {
+                                                            ::pilota::thrift::json::unexpected(
+                                                                "i32",
+                                                            )
+                                                        })?,
+                                                    <Point>::from_thrift_json(v)?,
+                                                ))
+                                            },
+                                        )
+                                        .collect::<::std::result::Result<
+                                            ::std::collections::HashMap<i32, Point>,
+                                            _,
+                                        >>()?
+                                }
+                                _ => return Err(::pilota::thrift::json::unexpected("map")),
+                            })
+                        },
+                    )
+                    .transpose()?;
+                let anchors = anchors.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field anchors is required",
+                    )
+                })?;
+                let shape = map
+                    .get("14")
+                    .and_then(|v| v.get("rec"))
+                    .map(
+                        |v| -> ::std::result::Result<Shape, ::pilota::thrift::Error> {
+                            Ok(<Shape>::from_thrift_json(v)?)
+                        },
+                    )
+                    .transpose()?;
+                let shape = shape.ok_or_else(|| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        "field shape is required",
+                    )
+                })?;
+                let comment = map
+                    .get("15")
+                    .and_then(|v| v.get("str"))
+                    .map(
+                        |v| -> ::std::result::Result<
+                            ::std::string::String,
+                            ::pilota::thrift::Error,
+                        > {
+                            Ok(::std::convert::From::from(
+                                v.as_str()
+                                    .ok_or_else(|| ::pilota::thrift::json::unexpected("str"))?
+                                    .to_string(),
+                            ))
+                        },
+                    )
+                    .transpose()?;
+                Ok(Self {
+                    visible,
+                    layer,
+                    width,
+                    created_at,
+                    scale,
+                    title,
+                    thumbnail,
+                    background,
+                    origin,
+                    points,
+                    labels,
+                    groups,
+                    anchors,
+                    shape,
+                    comment,
+                })
+            }
+        }
+    }
+}
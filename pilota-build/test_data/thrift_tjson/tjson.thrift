@@ -0,0 +1,32 @@
+enum Color {
+    RED = 1,
+    GREEN = 2,
+}
+
+struct Point {
+    1: required i32 x,
+    2: required i32 y,
+}
+
+union Shape {
+    1: Point point,
+    2: list<Point> polygon,
+}
+
+struct Canvas {
+    1: required bool visible,
+    2: required byte layer,
+    3: required i16 width,
+    4: required i64 created_at,
+    5: required double scale,
+    6: required string title,
+    7: required binary thumbnail,
+    8: required Color background,
+    9: optional Point origin,
+    10: required list<Point> points,
+    11: required set<string> labels,
+    12: required map<string, list<i32>> groups,
+    13: required map<i32, Point> anchors,
+    14: required Shape shape,
+    15: optional string comment,
+}
@@ -0,0 +1,5 @@
+struct Blob {
+    1: required binary data,
+    2: required map<string, i32> counts,
+    3: optional list<binary> chunks,
+}
@@ -0,0 +1,11 @@
+typedef i64 Timestamp
+typedef Timestamp CreatedAt
+typedef list<CreatedAt> History
+
+const History EPOCHS = [0, 1]
+
+struct Event {
+    1: required CreatedAt created_at,
+    2: optional History history,
+    3: required map<string, Timestamp> deadlines,
+}
@@ -0,0 +1,13 @@
+struct _Foo {
+    1: required i32 _internal,
+    2: optional string name,
+}
+
+struct Bar {
+    1: required _Foo foo,
+}
+
+enum _Status {
+    _OK = 0,
+    FAILED = 1,
+}
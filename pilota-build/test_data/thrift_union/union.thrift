@@ -0,0 +1,10 @@
+union Shape {
+    1: i32 circle,
+    2: i32 square (default = "true"),
+}
+
+union ShapeV2 {
+    1: i32 circle,
+    2: i32 square,
+    3: i32 triangle,
+}
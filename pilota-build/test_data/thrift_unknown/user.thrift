@@ -0,0 +1,15 @@
+struct UserV1 {
+    1: required i64 id,
+}
+
+struct Address {
+    1: required string city,
+}
+
+struct UserV2 {
+    1: required i64 id,
+    2: optional string name,
+    3: required list<Address> addresses,
+    4: required map<string, double> scores,
+    5: optional binary avatar,
+}
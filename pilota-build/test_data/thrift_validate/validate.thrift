@@ -0,0 +1,7 @@
+struct User {
+    1: required string name (pilota.non_empty = "true", pilota.max = "16"),
+    2: optional i32 age (pilota.min = "0", pilota.max = "150"),
+    3: optional list<string> tags (pilota.min = "1"),
+    4: required i64 id,
+    5: optional i32 score (pilota.max = "high"),
+}
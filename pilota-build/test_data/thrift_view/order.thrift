@@ -0,0 +1,26 @@
+enum Status {
+    OPEN = 1,
+    CLOSED = 2,
+}
+
+struct Point {
+    1: required i32 x,
+    2: required i32 y,
+}
+
+struct Address {
+    1: required string city,
+    2: optional Point location,
+}
+
+struct Order {
+    1: required i64 id,
+    2: required string customer,
+    3: optional string note,
+    4: required binary payload,
+    5: required list<string> items,
+    6: required map<string, i32> quantities,
+    7: required Address address,
+    8: optional Address billing,
+    9: optional Status status,
+}
@@ -0,0 +1,24 @@
+include "shared.thrift"
+
+const i32 MAX_ITEMS = 100
+const list<string> DEFAULT_TAGS = ["new"]
+
+enum State {
+    IN_STOCK = 1,
+    SOLD_OUT = 2,
+}
+
+struct Item {
+    1: required string name,
+    2: optional State state,
+    3: optional shared.Owner owner,
+}
+
+union Entry {
+    1: Item item,
+    2: string note,
+}
+
+service Inventory {
+    Item get(1: string name),
+}
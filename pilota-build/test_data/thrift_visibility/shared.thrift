@@ -0,0 +1,3 @@
+struct Owner {
+    1: required string name,
+}
@@ -0,0 +1,1031 @@
+pub mod sample {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::unused_unit,
+        clippy::needless_borrow,
+        unused_mut
+    )]
+    pub mod sample {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, :: pilota :: derivative :: Derivative)]
+        #[derivative(Default)]
+        #[derive(
+            :: pilota :: num_enum :: IntoPrimitive,
+            :: pilota :: num_enum :: TryFromPrimitive,
+            Clone,
+            PartialEq,
+        )]
+        #[repr(i32)]
+        #[derive(Copy)]
+        pub enum Kind {
+            #[derivative(Default)]
+            A = 1i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Kind {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                protocol.write_i32(*self as i32)?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32()?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Kind, value: {}", value),
+                    )
+                })?)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let value = protocol.read_i32().await?;
+                Ok(Self::try_from(value).map_err(|err| {
+                    ::pilota::thrift::new_protocol_error(
+                        ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                        format!("invalid enum value for Kind, value: {}", value),
+                    )
+                })?)
+            }
+        }
+        impl ::pilota::thrift::Size for Kind {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol.write_i32_len(*self as i32)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Inner {
+            pub x: i32,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Inner {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.x;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("x"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i32(*value)?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut x = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                x = Some(protocol.read_i32().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let x = if let Some(x) = x {
+                    x
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self { x };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Inner {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + {
+                        let value = &self.x;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("x"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(1i16),
+                        }) + protocol.write_i32_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Inner {
+            pub const FIELD_WIRE_TYPES: &'static [(i16, ::pilota::thrift::TType)] =
+                &[(1, ::pilota::thrift::TType::I32)];
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Sample {
+            pub flag: bool,
+            pub small: i16,
+            pub big: i64,
+            pub ratio: f64,
+            pub name: ::std::string::String,
+            pub payload: ::std::option::Option<::std::vec::Vec<u8>>,
+            pub kind: Kind,
+            pub inner: Inner,
+            pub ids: ::std::vec::Vec<i32>,
+            pub labels: ::std::collections::HashSet<::std::string::String>,
+            pub counts: ::std::collections::HashMap<::std::string::String, i64>,
+        }
+        #[::async_trait::async_trait]
+        impl ::pilota::thrift::Message for Sample {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::Error> {
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Sample" };
+                protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let value = &self.flag;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("flag"),
+                        field_type: ::pilota::thrift::TType::Bool,
+                        id: Some(1i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bool(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.small;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("small"),
+                        field_type: ::pilota::thrift::TType::I16,
+                        id: Some(2i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i16(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.big;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("big"),
+                        field_type: ::pilota::thrift::TType::I64,
+                        id: Some(4i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_i64(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.ratio;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("ratio"),
+                        field_type: ::pilota::thrift::TType::Double,
+                        id: Some(5i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_double(*value)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.name;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("name"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(6i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_string(value)?;
+                    protocol.write_field_end()?;
+                }
+                if let Some(value) = self.payload.as_ref() {
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("payload"),
+                        field_type: ::pilota::thrift::TType::String,
+                        id: Some(7i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    protocol.write_bytes(&value)?;
+                    protocol.write_field_end()?;
+                };
+                {
+                    let value = &self.kind;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("kind"),
+                        field_type: ::pilota::thrift::TType::I32,
+                        id: Some(8i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.inner;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("inner"),
+                        field_type: ::pilota::thrift::TType::Struct,
+                        id: Some(9i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    ::pilota::thrift::Message::encode(value, protocol)?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.ids;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("ids"),
+                        field_type: ::pilota::thrift::TType::List,
+                        id: Some(10i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TListIdentifier {
+                        element_type: ::pilota::thrift::TType::I32,
+                        size: value.len(),
+                    };
+                    protocol.write_list_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_i32(*val)?;
+                    }
+                    protocol.write_list_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.labels;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("labels"),
+                        field_type: ::pilota::thrift::TType::Set,
+                        id: Some(11i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let list_ident = ::pilota::thrift::TSetIdentifier {
+                        element_type: ::pilota::thrift::TType::String,
+                        size: value.len(),
+                    };
+                    protocol.write_set_begin(&list_ident)?;
+                    for val in value {
+                        protocol.write_string(val)?;
+                    }
+                    protocol.write_set_end()?;
+                    protocol.write_field_end()?;
+                }
+                {
+                    let value = &self.counts;
+                    let field = ::pilota::thrift::TFieldIdentifier {
+                        name: Some("counts"),
+                        field_type: ::pilota::thrift::TType::Map,
+                        id: Some(12i16),
+                    };
+                    protocol.write_field_begin(&field)?;
+                    let map_ident = ::pilota::thrift::TMapIdentifier {
+                        key_type: ::pilota::thrift::TType::String,
+                        value_type: ::pilota::thrift::TType::I64,
+                        size: value.len(),
+                    };
+                    protocol.write_map_begin(&map_ident)?;
+                    for (key, val) in value.iter() {
+                        protocol.write_string(key)?;
+                        protocol.write_i64(*val)?;
+                    }
+                    protocol.write_map_end()?;
+                    protocol.write_field_end()?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()?;
+                Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut flag = None;
+                let mut small = None;
+                let mut big = None;
+                let mut ratio = None;
+                let mut name = None;
+                let mut kind = None;
+                let mut inner = None;
+                let mut ids = None;
+                let mut labels = None;
+                let mut counts = None;
+                let mut payload = None;
+                protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                flag = Some(protocol.read_bool()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                small = Some(protocol.read_i16()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                big = Some(protocol.read_i64()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                ratio = Some(protocol.read_double()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload = Some(protocol.read_bytes()?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                kind = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                inner = Some(::pilota::thrift::Message::decode(protocol)?);
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                ids = Some({
+                                    let list_ident = protocol.read_list_begin()?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i32()?);
+                                    }
+                                    protocol.read_list_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                labels = Some({
+                                    let list_ident = protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string()?);
+                                    }
+                                    protocol.read_set_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        Some(12i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                counts = Some({
+                                    let map_ident = protocol.read_map_begin()?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string()?;
+                                        let el_val = protocol.read_i64()?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end()?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype)?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype)?;
+                        }
+                    }
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                let flag = if let Some(flag) = flag {
+                    flag
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field flag is required".to_string(),
+                        ),
+                    ));
+                };
+                let small = if let Some(small) = small {
+                    small
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field small is required".to_string(),
+                        ),
+                    ));
+                };
+                let big = if let Some(big) = big {
+                    big
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field big is required".to_string(),
+                        ),
+                    ));
+                };
+                let ratio = if let Some(ratio) = ratio {
+                    ratio
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ratio is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let kind = if let Some(kind) = kind {
+                    kind
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field kind is required".to_string(),
+                        ),
+                    ));
+                };
+                let inner = if let Some(inner) = inner {
+                    inner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field inner is required".to_string(),
+                        ),
+                    ));
+                };
+                let ids = if let Some(ids) = ids {
+                    ids
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ids is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let counts = if let Some(counts) = counts {
+                    counts
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field counts is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    payload,
+                    flag,
+                    small,
+                    big,
+                    ratio,
+                    name,
+                    kind,
+                    inner,
+                    ids,
+                    labels,
+                    counts,
+                };
+                Ok(data)
+            }
+            async fn decode_async<C: ::tokio::io::AsyncRead + Unpin + Send>(
+                protocol: &mut ::pilota::thrift::TAsyncBinaryProtocol<C>,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::Error> {
+                let mut flag = None;
+                let mut small = None;
+                let mut big = None;
+                let mut ratio = None;
+                let mut name = None;
+                let mut kind = None;
+                let mut inner = None;
+                let mut ids = None;
+                let mut labels = None;
+                let mut counts = None;
+                let mut payload = None;
+                protocol.read_struct_begin().await?;
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    let ttype = field_ident.field_type;
+                    if ttype == ::pilota::thrift::TType::Stop {
+                        break;
+                    }
+                    let field_id = field_ident.id;
+                    match field_id {
+                        Some(1i16) => {
+                            if ttype == ::pilota::thrift::TType::Bool {
+                                flag = Some(protocol.read_bool().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(2i16) => {
+                            if ttype == ::pilota::thrift::TType::I16 {
+                                small = Some(protocol.read_i16().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(4i16) => {
+                            if ttype == ::pilota::thrift::TType::I64 {
+                                big = Some(protocol.read_i64().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(5i16) => {
+                            if ttype == ::pilota::thrift::TType::Double {
+                                ratio = Some(protocol.read_double().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(6i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                name = Some(protocol.read_string().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(7i16) => {
+                            if ttype == ::pilota::thrift::TType::String {
+                                payload = Some(protocol.read_bytes().await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(8i16) => {
+                            if ttype == ::pilota::thrift::TType::I32 {
+                                kind =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(9i16) => {
+                            if ttype == ::pilota::thrift::TType::Struct {
+                                inner =
+                                    Some(::pilota::thrift::Message::decode_async(protocol).await?);
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(10i16) => {
+                            if ttype == ::pilota::thrift::TType::List {
+                                ids = Some({
+                                    let list_ident = protocol.read_list_begin().await?;
+                                    let mut val = Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(protocol.read_i32().await?);
+                                    }
+                                    protocol.read_list_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(11i16) => {
+                            if ttype == ::pilota::thrift::TType::Set {
+                                labels = Some({
+                                    let list_ident = protocol.read_set_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(protocol.read_string().await?);
+                                    }
+                                    protocol.read_set_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        Some(12i16) => {
+                            if ttype == ::pilota::thrift::TType::Map {
+                                counts = Some({
+                                    let map_ident = protocol.read_map_begin().await?;
+                                    let mut val =
+                                        ::std::collections::HashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        let el_key = protocol.read_string().await?;
+                                        let el_val = protocol.read_i64().await?;
+                                        val.insert(el_key, el_val);
+                                    }
+                                    protocol.read_map_end().await?;
+                                    val
+                                });
+                            } else {
+                                protocol.skip(ttype).await?;
+                            }
+                        }
+                        _ => {
+                            protocol.skip(ttype).await?;
+                        }
+                    }
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                let flag = if let Some(flag) = flag {
+                    flag
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field flag is required".to_string(),
+                        ),
+                    ));
+                };
+                let small = if let Some(small) = small {
+                    small
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field small is required".to_string(),
+                        ),
+                    ));
+                };
+                let big = if let Some(big) = big {
+                    big
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field big is required".to_string(),
+                        ),
+                    ));
+                };
+                let ratio = if let Some(ratio) = ratio {
+                    ratio
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ratio is required".to_string(),
+                        ),
+                    ));
+                };
+                let name = if let Some(name) = name {
+                    name
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    ));
+                };
+                let kind = if let Some(kind) = kind {
+                    kind
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field kind is required".to_string(),
+                        ),
+                    ));
+                };
+                let inner = if let Some(inner) = inner {
+                    inner
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field inner is required".to_string(),
+                        ),
+                    ));
+                };
+                let ids = if let Some(ids) = ids {
+                    ids
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field ids is required".to_string(),
+                        ),
+                    ));
+                };
+                let labels = if let Some(labels) = labels {
+                    labels
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field labels is required".to_string(),
+                        ),
+                    ));
+                };
+                let counts = if let Some(counts) = counts {
+                    counts
+                } else {
+                    return Err(::pilota::thrift::Error::Protocol(
+                        ::pilota::thrift::ProtocolError::new(
+                            ::pilota::thrift::ProtocolErrorKind::InvalidData,
+                            "field counts is required".to_string(),
+                        ),
+                    ));
+                };
+                let data = Self {
+                    payload,
+                    flag,
+                    small,
+                    big,
+                    ratio,
+                    name,
+                    kind,
+                    inner,
+                    ids,
+                    labels,
+                    counts,
+                };
+                Ok(data)
+            }
+        }
+        impl ::pilota::thrift::Size for Sample {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, protocol: &T) -> usize {
+                protocol
+                    .write_struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Sample" })
+                    + {
+                        let value = &self.flag;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("flag"),
+                            field_type: ::pilota::thrift::TType::Bool,
+                            id: Some(1i16),
+                        }) + protocol.write_bool_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.small;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("small"),
+                            field_type: ::pilota::thrift::TType::I16,
+                            id: Some(2i16),
+                        }) + protocol.write_i16_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.big;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("big"),
+                            field_type: ::pilota::thrift::TType::I64,
+                            id: Some(4i16),
+                        }) + protocol.write_i64_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.ratio;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("ratio"),
+                            field_type: ::pilota::thrift::TType::Double,
+                            id: Some(5i16),
+                        }) + protocol.write_double_len(*value)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.name;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("name"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(6i16),
+                        }) + protocol.write_string_len(&value)
+                            + protocol.write_field_end_len()
+                    }
+                    + if let Some(value) = self.payload.as_ref() {
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("payload"),
+                            field_type: ::pilota::thrift::TType::String,
+                            id: Some(7i16),
+                        }) + protocol.write_bytes_len(value)
+                            + protocol.write_field_end_len()
+                    } else {
+                        0
+                    }
+                    + {
+                        let value = &self.kind;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("kind"),
+                            field_type: ::pilota::thrift::TType::I32,
+                            id: Some(8i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.inner;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("inner"),
+                            field_type: ::pilota::thrift::TType::Struct,
+                            id: Some(9i16),
+                        }) + ::pilota::thrift::Size::size(value, protocol)
+                            + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.ids;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("ids"),
+                            field_type: ::pilota::thrift::TType::List,
+                            id: Some(10i16),
+                        }) + {
+                            let list_ident = ::pilota::thrift::TListIdentifier {
+                                element_type: ::pilota::thrift::TType::I32,
+                                size: value.len(),
+                            };
+                            protocol.write_list_begin_len(&list_ident)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_i32_len(*el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_list_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.labels;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("labels"),
+                            field_type: ::pilota::thrift::TType::Set,
+                            id: Some(11i16),
+                        }) + {
+                            let set_id = ::pilota::thrift::TSetIdentifier {
+                                element_type: ::pilota::thrift::TType::String,
+                                size: value.len(),
+                            };
+                            protocol.write_set_begin_len(&set_id)
+                                + {
+                                    let mut size = 0;
+                                    for el in value {
+                                        size += protocol.write_string_len(&el);
+                                    }
+                                    size
+                                }
+                                + protocol.write_set_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + {
+                        let value = &self.counts;
+                        protocol.write_field_begin_len(&::pilota::thrift::TFieldIdentifier {
+                            name: Some("counts"),
+                            field_type: ::pilota::thrift::TType::Map,
+                            id: Some(12i16),
+                        }) + {
+                            let map_id = ::pilota::thrift::TMapIdentifier {
+                                key_type: ::pilota::thrift::TType::String,
+                                value_type: ::pilota::thrift::TType::I64,
+                                size: value.len(),
+                            };
+                            protocol.write_map_begin_len(&map_id)
+                                + {
+                                    let mut size = 0;
+                                    for (key, val) in value {
+                                        size += protocol.write_string_len(&key);
+                                        size += protocol.write_i64_len(*val);
+                                    }
+                                    size
+                                }
+                                + protocol.write_map_end_len()
+                        } + protocol.write_field_end_len()
+                    }
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+        }
+        impl Sample {
+            pub const FIELD_WIRE_TYPES: &'static [(i16, ::pilota::thrift::TType)] = &[
+                (1, ::pilota::thrift::TType::Bool),
+                (2, ::pilota::thrift::TType::I16),
+                (4, ::pilota::thrift::TType::I64),
+                (5, ::pilota::thrift::TType::Double),
+                (6, ::pilota::thrift::TType::String),
+                (7, ::pilota::thrift::TType::String),
+                (8, ::pilota::thrift::TType::I32),
+                (9, ::pilota::thrift::TType::Struct),
+                (10, ::pilota::thrift::TType::List),
+                (11, ::pilota::thrift::TType::Set),
+                (12, ::pilota::thrift::TType::Map),
+            ];
+        }
+    }
+}
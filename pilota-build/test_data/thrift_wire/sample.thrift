@@ -0,0 +1,21 @@
+enum Kind {
+    A = 1,
+}
+
+struct Inner {
+    1: required i32 x,
+}
+
+struct Sample {
+    1: required bool flag,
+    2: required i16 small,
+    4: required i64 big,
+    5: required double ratio,
+    6: required string name,
+    7: optional binary payload,
+    8: required Kind kind,
+    9: required Inner inner,
+    10: required list<i32> ids,
+    11: required set<string> labels,
+    12: required map<string, i64> counts,
+}
@@ -0,0 +1,10 @@
+//! The code generated with `gen_doc_stubs` compiles under `#![deny(missing_docs)]`, which only
+//! checks the items reachable from the root of a crate, so it is compiled here rather than in
+//! the unit tests.
+#![deny(missing_docs)]
+
+/// The output of `docs.thrift`, see `test_doc_stubs`.
+#[allow(clippy::all)]
+pub mod generated {
+    include!("../test_data/thrift_docs/docs.rs");
+}
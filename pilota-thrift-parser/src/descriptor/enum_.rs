@@ -6,6 +6,7 @@ pub struct EnumValue {
     pub value: Option<IntConstant>,
     pub inner_type: Option<Ident>,
     pub annotations: Option<Annotations>,
+    pub docs: Vec<String>,
 }
 
 #[derive(Debug)]
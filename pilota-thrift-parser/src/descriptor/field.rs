@@ -21,4 +21,5 @@ pub struct Field {
     pub ty: Type,
     pub default: Option<ConstValue>,
     pub annotations: Annotations,
+    pub docs: Vec<String>,
 }
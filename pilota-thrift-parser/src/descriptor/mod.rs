@@ -29,6 +29,9 @@ pub use typedef::Typedef;
 #[derive(Debug, Clone)]
 pub struct Path {
     pub segments: Arc<[Ident]>,
+    /// The length of the input left when the path was parsed, the path starts
+    /// at byte `source.len() - rest_len` of the source.
+    pub rest_len: usize,
 }
 
 impl<Item> FromIterator<Item> for Path
@@ -38,6 +41,7 @@ where
     fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
         Path {
             segments: iter.into_iter().map(Into::into).collect(),
+            rest_len: 0,
         }
     }
 }
@@ -78,6 +82,10 @@ pub struct File {
     pub includes: Vec<Include>,
     pub cpp_includes: Vec<CppInclude>,
     pub items: Vec<Item>,
+    /// The length of the input left when each item in `items` was parsed.
+    pub item_rest_lens: Vec<usize>,
+    /// The lines of the doc comments of each item in `items`.
+    pub item_docs: Vec<Vec<String>>,
 }
 
 impl PartialEq for File {
@@ -75,11 +75,10 @@ impl Parser for Constant {
                 preceded(blank, Ident::parse),
                 preceded(opt(blank), tag("=")),
                 preceded(opt(blank), ConstValue::parse),
-                opt(blank),
-                opt(Annotations::parse),
-                opt(list_separator),
+                opt(preceded(opt(blank), Annotations::parse)),
+                opt(preceded(opt(blank), list_separator)),
             )),
-            |(_, r#type, name, _, value, _, annotations, _)| Constant {
+            |(_, r#type, name, _, value, annotations, _)| Constant {
                 name,
                 r#type,
                 value,
@@ -1,7 +1,7 @@
 use nom::{
     bytes::complete::tag,
     combinator::{map, opt},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -15,21 +15,19 @@ impl Parser for EnumValue {
         map(
             tuple((
                 Ident::parse,
-                opt(blank),
                 opt(map(
-                    tuple((tag("="), opt(blank), IntConstant::parse)),
-                    |(_, _, value)| value,
+                    tuple((opt(blank), tag("="), opt(blank), IntConstant::parse)),
+                    |(_, _, _, value)| value,
                 )),
-                opt(blank),
-                opt(Annotations::parse),
-                opt(list_separator),
-                opt(blank),
+                opt(preceded(opt(blank), Annotations::parse)),
+                opt(preceded(opt(blank), list_separator)),
             )),
-            |(name, _, value, _, annotations, _, _)| EnumValue {
+            |(name, value, annotations, _)| EnumValue {
                 name,
                 value,
                 annotations,
                 inner_type: None,
+                docs: Vec::new(),
             },
         )(input)
     }
@@ -44,14 +42,14 @@ impl Parser for Enum {
                 Ident::parse,
                 opt(blank),
                 tag("{"),
-                opt(blank),
-                many0(EnumValue::parse),
+                many0(map(tuple((docs, EnumValue::parse)), |(docs, value)| {
+                    EnumValue { docs, ..value }
+                })),
                 opt(blank),
                 tag("}"),
-                opt(blank),
-                opt(Annotations::parse),
+                opt(preceded(opt(blank), Annotations::parse)),
             )),
-            |(_, _, name, _, _, _, values, _, _, _, annotations)| Enum {
+            |(_, _, name, _, _, values, _, _, annotations)| Enum {
                 name,
                 values,
                 annotations: annotations.unwrap_or_default(),
@@ -3,7 +3,7 @@ use nom::{
     bytes::complete::tag,
     character::complete::digit1,
     combinator::{map, opt},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -35,23 +35,22 @@ impl Parser for Field {
                 Type::parse,
                 blank,
                 Ident::parse,
-                opt(blank),
+                // the blanks after the field belong to the next one unless followed by more
                 opt(map(
-                    tuple((tag("="), opt(blank), ConstValue::parse)),
-                    |(_, _, default)| default,
+                    tuple((opt(blank), tag("="), opt(blank), ConstValue::parse)),
+                    |(_, _, _, default)| default,
                 )),
-                opt(blank),
-                opt(Annotations::parse),
-                opt(blank),
-                opt(list_separator),
+                opt(preceded(opt(blank), Annotations::parse)),
+                opt(preceded(opt(blank), list_separator)),
             )),
-            |(id, _, attribute, _, r#type, _, name, _, default, _, annotations, _, _)| Field {
+            |(id, _, attribute, _, r#type, _, name, default, annotations, _)| Field {
                 id,
                 attribute: attribute.unwrap_or_default(),
                 ty: r#type,
                 name,
                 default,
                 annotations: annotations.unwrap_or_default(),
+                docs: Vec::new(),
             },
         )(input)
     }
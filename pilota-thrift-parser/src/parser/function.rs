@@ -2,13 +2,13 @@ use nom::{
     bytes::complete::tag,
     combinator::{map, opt},
     multi::many1,
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
 use super::super::{
     descriptor::{Annotations, Field, Function, Ident, Type},
-    parser::{blank, list_separator, Parser},
+    parser::{blank, docs, list_separator, Parser},
     Attribute,
 };
 
@@ -22,15 +22,14 @@ impl Parser for Function {
                 Ident::parse,
                 opt(blank),
                 tag("("),
-                opt(many1(map(
-                    tuple((opt(blank), Field::parse)),
-                    |(_, field)| field,
-                ))),
+                opt(many1(map(tuple((docs, Field::parse)), |(docs, field)| {
+                    Field { docs, ..field }
+                }))),
                 opt(blank),
                 tag(")"),
-                opt(blank),
                 opt(map(
                     tuple((
+                        opt(blank),
                         tag("throws"),
                         opt(blank),
                         tag("("),
@@ -38,13 +37,12 @@ impl Parser for Function {
                         opt(blank),
                         tag(")"),
                     )),
-                    |(_, _, _, fields, _, _)| fields,
+                    |(_, _, _, _, fields, _, _)| fields,
                 )),
-                opt(blank),
-                opt(Annotations::parse),
-                opt(list_separator),
+                opt(preceded(opt(blank), Annotations::parse)),
+                opt(preceded(opt(blank), list_separator)),
             )),
-            |(oneway, r#type, _, name, _, _, arguments, _, _, _, throws, _, annotations, _)| {
+            |(oneway, r#type, _, name, _, _, arguments, _, _, throws, annotations, _)| {
                 let mut args = arguments.unwrap_or_default();
                 args.iter_mut().for_each(|f| {
                     f.attribute = Attribute::Required;
@@ -56,6 +54,7 @@ impl Parser for Function {
                     arguments: args,
                     throws: throws.unwrap_or_default(),
                     annotations: annotations.unwrap_or_default(),
+                    docs: Vec::new(),
                 }
             },
         )(input)
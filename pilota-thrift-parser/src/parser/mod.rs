@@ -23,7 +23,7 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_till, take_until},
     character::complete::{multispace1, one_of},
-    combinator::{map, opt},
+    combinator::{map, not, opt},
     multi::{many0, many1, separated_list1},
     sequence::{preceded, terminated, tuple},
     IResult,
@@ -43,6 +43,7 @@ impl Parser for Path {
             separated_list1(tuple((opt(blank), tag("."), opt(blank))), Ident::parse),
             |idents| Path {
                 segments: Arc::from(idents),
+                rest_len: input.len(),
             },
         )(input)
     }
@@ -63,3 +64,84 @@ fn comment(input: &str) -> IResult<&str, &str> {
 pub(crate) fn blank(input: &str) -> IResult<&str, ()> {
     map(many1(alt((comment, multispace1))), |_| ())(input)
 }
+
+/// The blanks before a definition, keeping the lines of its doc comments, which are the `///`
+/// lines and the `/** */` blocks like in rust.
+pub(crate) fn docs(input: &str) -> IResult<&str, Vec<String>> {
+    map(
+        many0(alt((
+            map(
+                preceded(
+                    terminated(tag("///"), not(tag("/"))),
+                    take_till(|c| c == '\n'),
+                ),
+                |line: &str| {
+                    vec![line
+                        .strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()]
+                },
+            ),
+            map(
+                preceded(
+                    terminated(tag("/**"), not(alt((tag("*"), tag("/"))))),
+                    terminated(take_until("*/"), tag("*/")),
+                ),
+                block_doc_lines,
+            ),
+            map(alt((comment, multispace1)), |_| Vec::new()),
+        ))),
+        |docs| docs.concat(),
+    )(input)
+}
+
+/// Strips the leading `*` of the lines of a block doc comment, or the indentation they share
+/// if some of them do not start with one, and the blank lines around the text.
+fn block_doc_lines(block: &str) -> Vec<String> {
+    let mut lines = block.lines().map(str::trim_end).collect::<Vec<_>>();
+    let rest = lines
+        .iter()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .collect::<Vec<_>>();
+    let lines = if rest.iter().all(|l| l.trim_start().starts_with('*')) {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(idx, l)| {
+                let l = l.trim_start();
+                match l.strip_prefix('*') {
+                    Some(l) if idx > 0 => l.strip_prefix(' ').unwrap_or(l),
+                    _ => l,
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        let indent = rest
+            .iter()
+            .map(|l| l.len() - l.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        if let Some(first) = lines.first_mut() {
+            *first = first.trim_start();
+        }
+        lines
+            .iter()
+            .enumerate()
+            .map(|(idx, l)| match l.get(indent..) {
+                Some(l) if idx > 0 => l,
+                _ => l.trim_start(),
+            })
+            .collect()
+    };
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |end| end + 1);
+    lines[start..end].iter().map(|l| l.to_string()).collect()
+}
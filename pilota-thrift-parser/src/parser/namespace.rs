@@ -22,12 +22,10 @@ impl Parser for Namespace {
                 tag("namespace"),
                 preceded(blank, Scope::parse),
                 preceded(blank, Path::parse),
-                opt(blank),
-                opt(Annotations::parse),
-                opt(blank),
-                opt(list_separator),
+                opt(preceded(opt(blank), Annotations::parse)),
+                opt(preceded(opt(blank), list_separator)),
             )),
-            |(_, scope, name, _, annotations, _, _)| Namespace {
+            |(_, scope, name, annotations, _)| Namespace {
                 scope,
                 name,
                 annotations,
@@ -1,7 +1,7 @@
 use nom::{
     bytes::complete::tag,
     combinator::{map, opt},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -23,13 +23,15 @@ impl Parser for Service {
                 )),
                 opt(blank),
                 tag("{"),
-                many0(map(tuple((opt(blank), Function::parse)), |(_, f)| f)),
+                many0(map(tuple((docs, Function::parse)), |(docs, f)| Function {
+                    docs,
+                    ..f
+                })),
                 opt(blank),
                 tag("}"),
-                opt(blank),
-                opt(Annotations::parse),
+                opt(preceded(opt(blank), Annotations::parse)),
             )),
-            |(_, _, name, extends, _, _, functions, _, _, _, annotations)| Service {
+            |(_, _, name, extends, _, _, functions, _, _, annotations)| Service {
                 name,
                 extends,
                 functions,
@@ -1,7 +1,7 @@
 use nom::{
     bytes::complete::tag,
     combinator::{map, opt},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -46,13 +46,15 @@ impl Parser for StructLike {
                 Ident::parse,
                 opt(blank),
                 tag("{"),
-                many0(map(tuple((opt(blank), Field::parse)), |(_, field)| field)),
+                many0(map(tuple((docs, Field::parse)), |(docs, field)| Field {
+                    docs,
+                    ..field
+                })),
                 opt(blank),
                 tag("}"),
-                opt(blank),
-                opt(Annotations::parse),
+                opt(preceded(opt(blank), Annotations::parse)),
             )),
-            |(name, _, _, fields, _, _, _, annotations)| StructLike {
+            |(name, _, _, fields, _, _, annotations)| StructLike {
                 name,
                 fields,
                 annotations,
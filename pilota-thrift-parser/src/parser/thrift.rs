@@ -1,12 +1,6 @@
 use std::cell::RefCell;
 
-use nom::{
-    branch::alt,
-    combinator::{map, opt},
-    multi::many0,
-    sequence::tuple,
-    IResult,
-};
+use nom::{branch::alt, combinator::map, multi::many0, IResult};
 
 use super::{
     super::{
@@ -19,17 +13,21 @@ use super::{
 };
 
 impl Parser for File {
-    fn parse(input: &str) -> IResult<&str, File> {
+    fn parse<'a>(input: &'a str) -> IResult<&'a str, File> {
         let mut t: File = Default::default();
         let includes = &mut t.includes;
         let cpp_includes = &mut t.cpp_includes;
         let items = RefCell::new(&mut t.items);
+        let item_rest_lens = RefCell::new(&mut t.item_rest_lens);
+        let item_docs = RefCell::new(&mut t.item_docs);
 
         let mut namespaces = Vec::new();
 
-        let (remain, _) = many0(tuple((
-            opt(blank),
-            alt((
+        let (remain, _) = many0(|input: &'a str| {
+            let (input, docs) = docs(input)?;
+            let rest_len = input.len();
+            let before = items.borrow().len();
+            let res = alt((
                 map(Include::parse, |item| includes.push(item)),
                 map(CppInclude::parse, |item| cpp_includes.push(item)),
                 map(Namespace::parse, |item| namespaces.push(item)),
@@ -42,8 +40,13 @@ impl Parser for File {
                     items.borrow_mut().push(item.into())
                 }),
                 map(Service::parse, |item| items.borrow_mut().push(item.into())),
-            )),
-        )))(input)?;
+            ))(input);
+            if items.borrow().len() > before {
+                item_rest_lens.borrow_mut().push(rest_len);
+                item_docs.borrow_mut().push(docs);
+            }
+            res
+        })(input)?;
 
         t.package = namespaces.into_iter().find_map(|n| {
             if n.scope.0 == "rs" {
@@ -128,4 +131,64 @@ mod tests {
         let (_remain, res) = File::parse(body).unwrap();
         assert_eq!(res.includes.len(), 1);
     }
+
+    #[test]
+    fn test_docs() {
+        use crate::descriptor::Item;
+
+        let body = r#"
+        namespace rs docs
+
+        /// A user.
+        ///
+        /// Has a `name`.
+        struct User {
+            /**
+             * The id,
+             *   never reused.
+             */
+            1: required i64 id
+            // not a doc
+            2: optional string name
+            //// not a doc either
+        }
+
+        /** The states. */
+        enum State {
+            /// Active.
+            ACTIVE = 1
+            BANNED = 2,
+        }
+
+        service Users {
+            /// Gets a user.
+            User get(/// The id.
+                     1: i64 id)
+        }
+        "#;
+        let (_remain, res) = File::parse(body).unwrap();
+        assert_eq!(
+            res.item_docs,
+            vec![
+                vec!["A user.".to_string(), "".into(), "Has a `name`.".into()],
+                vec!["The states.".into()],
+                vec![],
+            ]
+        );
+        let Item::Struct(user) = &res.items[0] else {
+            panic!()
+        };
+        assert_eq!(user.fields[0].docs, vec!["The id,", "  never reused."]);
+        assert!(user.fields[1].docs.is_empty());
+        let Item::Enum(state) = &res.items[1] else {
+            panic!()
+        };
+        assert_eq!(state.values[0].docs, vec!["Active."]);
+        assert!(state.values[1].docs.is_empty());
+        let Item::Service(users) = &res.items[2] else {
+            panic!()
+        };
+        assert_eq!(users.functions[0].docs, vec!["Gets a user."]);
+        assert_eq!(users.functions[0].arguments[0].docs, vec!["The id."]);
+    }
 }
@@ -1,7 +1,7 @@
 use nom::{
     bytes::complete::tag,
     combinator::{map, opt},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -19,10 +19,9 @@ impl Parser for Typedef {
                 Type::parse,
                 blank,
                 Ident::parse,
-                opt(blank),
-                opt(Annotations::parse),
+                opt(preceded(opt(blank), Annotations::parse)),
             )),
-            |(_, _, r#type, _, alias, _, annotations)| Typedef {
+            |(_, _, r#type, _, alias, annotations)| Typedef {
                 r#type,
                 alias,
                 annotations,
@@ -0,0 +1,56 @@
+use std::{cmp::Ordering, hash::Hasher};
+
+/// Remembers which fields of a generated struct were changed by its setters,
+/// by the index of the field in the struct.
+///
+/// It doesn't take part in comparing or hashing the struct.
+#[derive(Debug, Default, Clone)]
+pub struct DirtyFields(Vec<u64>);
+
+impl DirtyFields {
+    pub fn mark(&mut self, idx: usize) {
+        let (word, bit) = (idx / 64, idx % 64);
+        if self.0.len() <= word {
+            self.0.resize(word + 1, 0);
+        }
+        self.0[word] |= 1 << bit;
+    }
+
+    pub fn is_dirty(&self, idx: usize) -> bool {
+        self.0
+            .get(idx / 64)
+            .is_some_and(|word| word & (1 << (idx % 64)) != 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.0.len() * 64).filter(|idx| self.is_dirty(*idx))
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+
+impl PartialEq for DirtyFields {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for DirtyFields {}
+
+impl PartialOrd for DirtyFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DirtyFields {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for DirtyFields {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
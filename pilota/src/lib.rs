@@ -3,13 +3,42 @@
 )]
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
+pub mod dirty;
+pub mod protobuf;
 pub mod thrift;
+pub mod validate;
+
+use std::pin::Pin;
+
+/// The error returned by the `FromStr` impls generated for enums, for a string which is the
+/// name of none of the variants.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("`{name}` is not a variant of `{enum_name}`")]
+pub struct ParseEnumError {
+    pub enum_name: &'static str,
+    pub name: String,
+}
+
+/// The error returned by the `build` of a generated message builder when a required field is
+/// not set.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("the required field `{field}` of `{message}` is not set")]
+pub struct MissingField {
+    pub message: &'static str,
+    pub field: &'static str,
+}
+
+/// The messages of a streaming request or response.
+pub type BoxStream<T> =
+    Pin<Box<dyn futures_core::Stream<Item = Result<T, AnyhowError>> + Send + 'static>>;
 
 // reexport
 pub use anyhow::Error as AnyhowError;
 pub use async_recursion;
 pub use async_trait;
+pub use bytes;
 pub use derivative;
+pub use futures_core;
 pub use lazy_static;
 pub use num_enum;
 pub use thiserror::Error as ThisError;
@@ -0,0 +1,216 @@
+use std::{cmp::Ordering, hash::Hasher};
+
+use bytes::{Buf, BufMut};
+
+/// The prefix of the type urls in `google.protobuf.Any`.
+pub const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+/// Implemented by the generated protobuf messages, so they can be packed
+/// into `google.protobuf.Any`.
+pub trait WireName {
+    /// The fully qualified name of the message, such as `google.protobuf.Any`.
+    const WIRE_NAME: &'static str;
+
+    fn type_url() -> String {
+        format!("{TYPE_URL_PREFIX}{}", Self::WIRE_NAME)
+    }
+}
+
+/// `google.protobuf.Empty`, as mapped by the well-known types of `pilota-build`.
+pub type Empty = ();
+
+/// The fields with unknown tags of a protobuf message, kept as their encoded values
+/// in the order they are decoded and encoded after the known fields.
+///
+/// Like the thrift [`UnknownFields`](crate::thrift::unknown::UnknownFields), it doesn't
+/// take part in comparing or hashing the message.
+#[derive(Debug, Default, Clone)]
+pub struct UnknownFields(Vec<(u32, u8, Vec<u8>)>);
+
+const MAXIMUM_GROUP_DEPTH: usize = 100;
+
+impl UnknownFields {
+    /// Reads the value of the field after its key, `wire_type` is the discriminant of the
+    /// `WireType` of prost.
+    pub fn merge_field(
+        &mut self,
+        tag: u32,
+        wire_type: u8,
+        buf: &mut impl Buf,
+    ) -> Result<(), &'static str> {
+        let mut value = Vec::new();
+        copy_value(tag, wire_type, buf, &mut value, 0)?;
+        self.0.push((tag, wire_type, value));
+        Ok(())
+    }
+
+    /// The tags, the wire types and the encoded values of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u8, &[u8])> + '_ {
+        self.0
+            .iter()
+            .map(|(tag, wire_type, value)| (*tag, *wire_type, &**value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear()
+    }
+
+    pub fn encode_raw(&self, buf: &mut impl BufMut) {
+        self.0.iter().for_each(|(tag, wire_type, value)| {
+            put_varint(buf, key(*tag, *wire_type));
+            buf.put_slice(value);
+        })
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(tag, wire_type, value)| varint_len(key(*tag, *wire_type)) + value.len())
+            .sum()
+    }
+}
+
+impl PartialEq for UnknownFields {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UnknownFields {}
+
+impl PartialOrd for UnknownFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UnknownFields {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for UnknownFields {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+fn key(tag: u32, wire_type: u8) -> u64 {
+    (tag as u64) << 3 | wire_type as u64
+}
+
+fn varint_len(v: u64) -> usize {
+    (64 - (v | 1).leading_zeros() as usize).div_ceil(7)
+}
+
+fn put_varint(buf: &mut impl BufMut, mut v: u64) {
+    while v >= 0x80 {
+        buf.put_u8(v as u8 | 0x80);
+        v >>= 7;
+    }
+    buf.put_u8(v as u8)
+}
+
+/// Copies a varint to `out` and returns its value.
+fn copy_varint(buf: &mut impl Buf, out: &mut Vec<u8>) -> Result<u64, &'static str> {
+    let mut v = 0;
+    for shift in (0..70).step_by(7) {
+        if !buf.has_remaining() {
+            return Err("buffer underflow");
+        }
+        let b = buf.get_u8();
+        out.push(b);
+        v |= ((b & 0x7f) as u64) << shift;
+        if b < 0x80 {
+            return Ok(v);
+        }
+    }
+    Err("invalid varint")
+}
+
+fn copy_bytes(buf: &mut impl Buf, out: &mut Vec<u8>, len: usize) -> Result<(), &'static str> {
+    if buf.remaining() < len {
+        return Err("buffer underflow");
+    }
+    let start = out.len();
+    out.resize(start + len, 0);
+    buf.copy_to_slice(&mut out[start..]);
+    Ok(())
+}
+
+/// Copies the value of a field to `out`, a group with all its fields and the end key.
+fn copy_value(
+    tag: u32,
+    wire_type: u8,
+    buf: &mut impl Buf,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> Result<(), &'static str> {
+    match wire_type {
+        0 => copy_varint(buf, out).map(drop),
+        1 => copy_bytes(buf, out, 8),
+        2 => {
+            let len = copy_varint(buf, out)?;
+            copy_bytes(buf, out, len as usize)
+        }
+        3 => {
+            if depth == MAXIMUM_GROUP_DEPTH {
+                return Err("recursion limit reached");
+            }
+            loop {
+                let key = copy_varint(buf, out)?;
+                let (field_tag, field_wire_type) = ((key >> 3) as u32, (key & 7) as u8);
+                if field_wire_type == 4 {
+                    return if field_tag == tag {
+                        Ok(())
+                    } else {
+                        Err("unexpected end group tag")
+                    };
+                }
+                copy_value(field_tag, field_wire_type, buf, out, depth + 1)?;
+            }
+        }
+        5 => copy_bytes(buf, out, 4),
+        _ => Err("invalid wire type"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_fields() {
+        // 5: varint 300, 6: "hi", 7: group { 1: fixed32 }, 8: fixed64
+        let encoded: &[u8] = &[
+            0x28, 0xac, 0x02, 0x32, 0x02, b'h', b'i', 0x3b, 0x0d, 1, 2, 3, 4, 0x3c, 0x41, 1, 2, 3,
+            4, 5, 6, 7, 8,
+        ];
+        let mut buf = encoded;
+        let mut fields = UnknownFields::default();
+        while buf.has_remaining() {
+            let mut key = Vec::new();
+            let key = copy_varint(&mut buf, &mut key).unwrap();
+            fields
+                .merge_field((key >> 3) as u32, (key & 7) as u8, &mut buf)
+                .unwrap();
+        }
+        assert_eq!(
+            fields
+                .iter()
+                .map(|(tag, wt, _)| (tag, wt))
+                .collect::<Vec<_>>(),
+            [(5, 0), (6, 2), (7, 3), (8, 1)]
+        );
+        assert_eq!(fields.encoded_len(), encoded.len());
+        let mut out = Vec::new();
+        fields.encode_raw(&mut out);
+        assert_eq!(out, encoded);
+
+        assert!(fields.merge_field(1, 2, &mut &[0x05, 0][..]).is_err());
+        assert!(fields.merge_field(1, 3, &mut &[0x14][..]).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! Helpers of the code generated for the thrift JSON protocol, which writes binary values
+//! as base64 strings without padding and the non-finite doubles as strings.
+
+use super::{new_protocol_error, Error, ProtocolErrorKind};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `bytes` in base64 without the trailing `=`.
+pub fn encode_base64(bytes: impl AsRef<[u8]>) -> String {
+    let bytes = bytes.as_ref();
+    let mut out = String::with_capacity((bytes.len() * 4).div_ceil(3));
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        (0..=chunk.len()).for_each(|i| {
+            out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        });
+    }
+    out
+}
+
+/// Decodes the base64 string `s`, with or without the trailing `=`.
+pub fn decode_base64(s: &str) -> Result<Vec<u8>, Error> {
+    let s = s.trim_end_matches('=').as_bytes();
+    let mut out = Vec::with_capacity(s.len() * 3 / 4);
+    for chunk in s.chunks(4) {
+        if chunk.len() == 1 {
+            return Err(invalid_base64());
+        }
+        let mut n = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let v = ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(invalid_base64)?;
+            n |= (v as u32) << (18 - 6 * i);
+        }
+        (0..chunk.len() - 1).for_each(|i| out.push((n >> (16 - 8 * i)) as u8));
+    }
+    Ok(out)
+}
+
+/// The string written for a NaN or infinite double, `None` for the finite ones which are
+/// written as numbers.
+pub fn non_finite_double(v: f64) -> Option<&'static str> {
+    if v.is_nan() {
+        Some("NaN")
+    } else if v == f64::INFINITY {
+        Some("Infinity")
+    } else if v == f64::NEG_INFINITY {
+        Some("-Infinity")
+    } else {
+        None
+    }
+}
+
+/// The error of a JSON value which is not the `expected` one.
+pub fn unexpected(expected: &str) -> Error {
+    new_protocol_error(
+        ProtocolErrorKind::InvalidData,
+        format!("invalid thrift json, expected {expected}"),
+    )
+}
+
+fn invalid_base64() -> Error {
+    new_protocol_error(ProtocolErrorKind::InvalidData, "invalid base64 string")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg"),
+            (&[0xff, 0xfe, 0x00, 0x3e][..], "//4APg"),
+        ] {
+            assert_eq!(encode_base64(bytes), encoded);
+            assert_eq!(decode_base64(encoded).unwrap(), bytes);
+        }
+        assert_eq!(decode_base64("Zm8=").unwrap(), b"fo");
+        assert!(decode_base64("Z").is_err());
+        assert!(decode_base64("Zm*v").is_err());
+    }
+
+    #[test]
+    fn test_non_finite_double() {
+        assert_eq!(non_finite_double(f64::NAN), Some("NaN"));
+        assert_eq!(non_finite_double(f64::NEG_INFINITY), Some("-Infinity"));
+        assert_eq!(non_finite_double(1.5), None);
+        for s in ["NaN", "Infinity", "-Infinity"] {
+            assert_eq!(non_finite_double(s.parse().unwrap()), Some(s));
+        }
+    }
+}
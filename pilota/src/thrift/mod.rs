@@ -1,14 +1,16 @@
 pub mod binary;
 pub mod error;
+pub mod json;
 pub mod rw_ext;
+pub mod unknown;
 
 use std::{ops::Deref, sync::Arc};
 
-use bytes::{Buf, BufMut};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 pub use error::*;
 use tokio::io::AsyncRead;
 
-pub use self::binary::TAsyncBinaryProtocol;
+pub use self::binary::{TAsyncBinaryProtocol, TBinaryProtocol};
 
 const MAXIMUM_SKIP_DEPTH: i8 = 64;
 
@@ -45,6 +47,24 @@ impl<M: Message> Message for Box<M> {
     }
 }
 
+#[async_trait::async_trait]
+impl<M: Message + Sync> Message for Arc<M> {
+    fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), Error> {
+        self.deref().encode(protocol)
+    }
+
+    fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, Error> {
+        Ok(Arc::new(M::decode(protocol)?))
+    }
+
+    async fn decode_async<R>(protocol: &mut TAsyncBinaryProtocol<R>) -> Result<Self, Error>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        Ok(Arc::new(M::decode_async(protocol).await?))
+    }
+}
+
 #[async_trait::async_trait]
 pub trait EntryMessage: Sized + Send {
     fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), Error>;
@@ -62,6 +82,17 @@ pub trait EntryMessage: Sized + Send {
         R: AsyncRead + Unpin + Send;
 }
 
+/// The transport used by generated clients to send encoded requests to a
+/// remote service.
+#[async_trait::async_trait]
+pub trait ClientTransport: Send + Sync {
+    /// Send an encoded request of `method` and wait for the encoded response.
+    async fn call(&self, method: &'static str, req: Bytes) -> Result<BytesMut, Error>;
+
+    /// Send an encoded request of a oneway `method`, no response is awaited.
+    async fn oneway(&self, method: &'static str, req: Bytes) -> Result<(), Error>;
+}
+
 pub trait TInputProtocol {
     type Buf: Buf;
     /// Read the beginning of a Thrift message.
@@ -0,0 +1,391 @@
+use std::{cmp::Ordering, hash::Hasher};
+
+use tokio::io::AsyncRead;
+
+use super::{
+    new_protocol_error, Error, ProtocolErrorKind, TAsyncBinaryProtocol, TFieldIdentifier,
+    TInputProtocol, TLengthProtocol, TListIdentifier, TMapIdentifier, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, MAXIMUM_SKIP_DEPTH,
+};
+
+/// A value read without knowing its schema, which can be written back unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TValue {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Double(f64),
+    /// Both strings and binaries.
+    Bytes(Vec<u8>),
+    Struct(Vec<(i16, TValue)>),
+    List(TType, Vec<TValue>),
+    Set(TType, Vec<TValue>),
+    Map(TType, TType, Vec<(TValue, TValue)>),
+}
+
+const UNKNOWN_IDENT: TStructIdentifier = TStructIdentifier { name: "unknown" };
+
+fn depth_limit(ttype: TType) -> Error {
+    new_protocol_error(
+        ProtocolErrorKind::DepthLimit,
+        format!("cannot parse past {:?}", ttype),
+    )
+}
+
+fn unsupported(ttype: TType) -> Error {
+    new_protocol_error(
+        ProtocolErrorKind::InvalidData,
+        format!("cannot read unknown field type {:?}", ttype),
+    )
+}
+
+impl TValue {
+    pub fn ttype(&self) -> TType {
+        match self {
+            TValue::Bool(_) => TType::Bool,
+            TValue::I8(_) => TType::I08,
+            TValue::I16(_) => TType::I16,
+            TValue::I32(_) => TType::I32,
+            TValue::I64(_) => TType::I64,
+            TValue::Double(_) => TType::Double,
+            TValue::Bytes(_) => TType::String,
+            TValue::Struct(_) => TType::Struct,
+            TValue::List(..) => TType::List,
+            TValue::Set(..) => TType::Set,
+            TValue::Map(..) => TType::Map,
+        }
+    }
+
+    pub fn read<T: TInputProtocol>(protocol: &mut T, ttype: TType) -> Result<Self, Error> {
+        Self::read_till_depth(protocol, ttype, MAXIMUM_SKIP_DEPTH)
+    }
+
+    fn read_till_depth<T: TInputProtocol>(
+        protocol: &mut T,
+        ttype: TType,
+        depth: i8,
+    ) -> Result<Self, Error> {
+        if depth == 0 {
+            return Err(depth_limit(ttype));
+        }
+
+        Ok(match ttype {
+            TType::Bool => TValue::Bool(protocol.read_bool()?),
+            TType::I08 => TValue::I8(protocol.read_i8()?),
+            TType::I16 => TValue::I16(protocol.read_i16()?),
+            TType::I32 => TValue::I32(protocol.read_i32()?),
+            TType::I64 => TValue::I64(protocol.read_i64()?),
+            TType::Double => TValue::Double(protocol.read_double()?),
+            TType::String => TValue::Bytes(protocol.read_bytes()?),
+            TType::Struct => {
+                protocol.read_struct_begin()?;
+                let mut fields = Vec::new();
+                loop {
+                    let field_ident = protocol.read_field_begin()?;
+                    if field_ident.field_type == TType::Stop {
+                        break;
+                    }
+                    let value = Self::read_till_depth(protocol, field_ident.field_type, depth - 1)?;
+                    fields.push((field_ident.id.unwrap_or_default(), value));
+                    protocol.read_field_end()?;
+                }
+                protocol.read_struct_end()?;
+                TValue::Struct(fields)
+            }
+            TType::List => {
+                let ident = protocol.read_list_begin()?;
+                let els = (0..ident.size)
+                    .map(|_| Self::read_till_depth(protocol, ident.element_type, depth - 1))
+                    .collect::<Result<_, _>>()?;
+                protocol.read_list_end()?;
+                TValue::List(ident.element_type, els)
+            }
+            TType::Set => {
+                let ident = protocol.read_set_begin()?;
+                let els = (0..ident.size)
+                    .map(|_| Self::read_till_depth(protocol, ident.element_type, depth - 1))
+                    .collect::<Result<_, _>>()?;
+                protocol.read_set_end()?;
+                TValue::Set(ident.element_type, els)
+            }
+            TType::Map => {
+                let ident = protocol.read_map_begin()?;
+                let entries = (0..ident.size)
+                    .map(|_| {
+                        Ok((
+                            Self::read_till_depth(protocol, ident.key_type, depth - 1)?,
+                            Self::read_till_depth(protocol, ident.value_type, depth - 1)?,
+                        ))
+                    })
+                    .collect::<Result<_, Error>>()?;
+                protocol.read_map_end()?;
+                TValue::Map(ident.key_type, ident.value_type, entries)
+            }
+            u => return Err(unsupported(u)),
+        })
+    }
+
+    pub async fn read_async<R>(
+        protocol: &mut TAsyncBinaryProtocol<R>,
+        ttype: TType,
+    ) -> Result<Self, Error>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        Self::read_async_till_depth(protocol, ttype, MAXIMUM_SKIP_DEPTH).await
+    }
+
+    #[async_recursion::async_recursion]
+    async fn read_async_till_depth<R>(
+        protocol: &mut TAsyncBinaryProtocol<R>,
+        ttype: TType,
+        depth: i8,
+    ) -> Result<Self, Error>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        if depth == 0 {
+            return Err(depth_limit(ttype));
+        }
+
+        Ok(match ttype {
+            TType::Bool => TValue::Bool(protocol.read_bool().await?),
+            TType::I08 => TValue::I8(protocol.read_i8().await?),
+            TType::I16 => TValue::I16(protocol.read_i16().await?),
+            TType::I32 => TValue::I32(protocol.read_i32().await?),
+            TType::I64 => TValue::I64(protocol.read_i64().await?),
+            TType::Double => TValue::Double(protocol.read_double().await?),
+            TType::String => TValue::Bytes(protocol.read_bytes().await?),
+            TType::Struct => {
+                protocol.read_struct_begin().await?;
+                let mut fields = Vec::new();
+                loop {
+                    let field_ident = protocol.read_field_begin().await?;
+                    if field_ident.field_type == TType::Stop {
+                        break;
+                    }
+                    let value =
+                        Self::read_async_till_depth(protocol, field_ident.field_type, depth - 1)
+                            .await?;
+                    fields.push((field_ident.id.unwrap_or_default(), value));
+                    protocol.read_field_end().await?;
+                }
+                protocol.read_struct_end().await?;
+                TValue::Struct(fields)
+            }
+            TType::List => {
+                let ident = protocol.read_list_begin().await?;
+                let mut els = Vec::with_capacity(ident.size);
+                for _ in 0..ident.size {
+                    els.push(
+                        Self::read_async_till_depth(protocol, ident.element_type, depth - 1)
+                            .await?,
+                    );
+                }
+                protocol.read_list_end().await?;
+                TValue::List(ident.element_type, els)
+            }
+            TType::Set => {
+                let ident = protocol.read_set_begin().await?;
+                let mut els = Vec::with_capacity(ident.size);
+                for _ in 0..ident.size {
+                    els.push(
+                        Self::read_async_till_depth(protocol, ident.element_type, depth - 1)
+                            .await?,
+                    );
+                }
+                protocol.read_set_end().await?;
+                TValue::Set(ident.element_type, els)
+            }
+            TType::Map => {
+                let ident = protocol.read_map_begin().await?;
+                let mut entries = Vec::with_capacity(ident.size);
+                for _ in 0..ident.size {
+                    let key =
+                        Self::read_async_till_depth(protocol, ident.key_type, depth - 1).await?;
+                    let value =
+                        Self::read_async_till_depth(protocol, ident.value_type, depth - 1).await?;
+                    entries.push((key, value));
+                }
+                protocol.read_map_end().await?;
+                TValue::Map(ident.key_type, ident.value_type, entries)
+            }
+            u => return Err(unsupported(u)),
+        })
+    }
+
+    pub fn write<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), Error> {
+        match self {
+            TValue::Bool(v) => protocol.write_bool(*v),
+            TValue::I8(v) => protocol.write_i8(*v),
+            TValue::I16(v) => protocol.write_i16(*v),
+            TValue::I32(v) => protocol.write_i32(*v),
+            TValue::I64(v) => protocol.write_i64(*v),
+            TValue::Double(v) => protocol.write_double(*v),
+            TValue::Bytes(v) => protocol.write_bytes(v),
+            TValue::Struct(fields) => {
+                protocol.write_struct_begin(&UNKNOWN_IDENT)?;
+                for (id, value) in fields {
+                    write_field(protocol, *id, value)?;
+                }
+                protocol.write_field_stop()?;
+                protocol.write_struct_end()
+            }
+            TValue::List(element_type, els) => {
+                protocol.write_list_begin(&TListIdentifier {
+                    element_type: *element_type,
+                    size: els.len(),
+                })?;
+                els.iter().try_for_each(|el| el.write(protocol))?;
+                protocol.write_list_end()
+            }
+            TValue::Set(element_type, els) => {
+                protocol.write_set_begin(&TSetIdentifier {
+                    element_type: *element_type,
+                    size: els.len(),
+                })?;
+                els.iter().try_for_each(|el| el.write(protocol))?;
+                protocol.write_set_end()
+            }
+            TValue::Map(key_type, value_type, entries) => {
+                protocol.write_map_begin(&TMapIdentifier {
+                    key_type: *key_type,
+                    value_type: *value_type,
+                    size: entries.len(),
+                })?;
+                entries.iter().try_for_each(|(k, v)| {
+                    k.write(protocol)?;
+                    v.write(protocol)
+                })?;
+                protocol.write_map_end()
+            }
+        }
+    }
+
+    pub fn size<T: TLengthProtocol>(&self, protocol: &T) -> usize {
+        match self {
+            TValue::Bool(v) => protocol.write_bool_len(*v),
+            TValue::I8(v) => protocol.write_i8_len(*v),
+            TValue::I16(v) => protocol.write_i16_len(*v),
+            TValue::I32(v) => protocol.write_i32_len(*v),
+            TValue::I64(v) => protocol.write_i64_len(*v),
+            TValue::Double(v) => protocol.write_double_len(*v),
+            TValue::Bytes(v) => protocol.write_bytes_len(v),
+            TValue::Struct(fields) => {
+                protocol.write_struct_begin_len(&UNKNOWN_IDENT)
+                    + fields
+                        .iter()
+                        .map(|(id, value)| field_size(protocol, *id, value))
+                        .sum::<usize>()
+                    + protocol.write_field_stop_len()
+                    + protocol.write_struct_end_len()
+            }
+            TValue::List(element_type, els) => {
+                protocol.write_list_begin_len(&TListIdentifier {
+                    element_type: *element_type,
+                    size: els.len(),
+                }) + els.iter().map(|el| el.size(protocol)).sum::<usize>()
+                    + protocol.write_list_end_len()
+            }
+            TValue::Set(element_type, els) => {
+                protocol.write_set_begin_len(&TSetIdentifier {
+                    element_type: *element_type,
+                    size: els.len(),
+                }) + els.iter().map(|el| el.size(protocol)).sum::<usize>()
+                    + protocol.write_set_end_len()
+            }
+            TValue::Map(key_type, value_type, entries) => {
+                protocol.write_map_begin_len(&TMapIdentifier {
+                    key_type: *key_type,
+                    value_type: *value_type,
+                    size: entries.len(),
+                }) + entries
+                    .iter()
+                    .map(|(k, v)| k.size(protocol) + v.size(protocol))
+                    .sum::<usize>()
+                    + protocol.write_map_end_len()
+            }
+        }
+    }
+}
+
+fn field_ident(id: i16, value: &TValue) -> TFieldIdentifier {
+    TFieldIdentifier {
+        name: None,
+        field_type: value.ttype(),
+        id: Some(id),
+    }
+}
+
+fn write_field<T: TOutputProtocol>(protocol: &mut T, id: i16, value: &TValue) -> Result<(), Error> {
+    protocol.write_field_begin(&field_ident(id, value))?;
+    value.write(protocol)?;
+    protocol.write_field_end()
+}
+
+fn field_size<T: TLengthProtocol>(protocol: &T, id: i16, value: &TValue) -> usize {
+    protocol.write_field_begin_len(&field_ident(id, value))
+        + value.size(protocol)
+        + protocol.write_field_end_len()
+}
+
+/// The fields of a struct whose ids are unknown to the generated code, kept
+/// in the order they are decoded and encoded after the known fields.
+///
+/// Like [`DirtyFields`](crate::dirty::DirtyFields), it doesn't take part in
+/// comparing or hashing the struct.
+#[derive(Debug, Default, Clone)]
+pub struct UnknownFields(Vec<(i16, TValue)>);
+
+impl UnknownFields {
+    pub fn push(&mut self, id: i16, value: TValue) {
+        self.0.push((id, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i16, &TValue)> + '_ {
+        self.0.iter().map(|(id, value)| (*id, value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), Error> {
+        self.0
+            .iter()
+            .try_for_each(|(id, value)| write_field(protocol, *id, value))
+    }
+
+    pub fn size<T: TLengthProtocol>(&self, protocol: &T) -> usize {
+        self.0
+            .iter()
+            .map(|(id, value)| field_size(protocol, *id, value))
+            .sum()
+    }
+}
+
+impl PartialEq for UnknownFields {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UnknownFields {}
+
+impl PartialOrd for UnknownFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UnknownFields {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl std::hash::Hash for UnknownFields {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
@@ -0,0 +1,21 @@
+/// The error returned by the generated `validate` methods.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid field `{field}`: {kind}")]
+pub struct ValidationError {
+    pub field: &'static str,
+    pub kind: ValidationErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationErrorKind {
+    #[error("must not be empty")]
+    Empty,
+    #[error("{actual} is less than the minimum {min}")]
+    LessThanMin { min: i64, actual: i64 },
+    #[error("{actual} is greater than the maximum {max}")]
+    GreaterThanMax { max: i64, actual: i64 },
+    #[error("length {actual} is less than the minimum {min}")]
+    TooShort { min: i64, actual: usize },
+    #[error("length {actual} is greater than the maximum {max}")]
+    TooLong { max: i64, actual: usize },
+}